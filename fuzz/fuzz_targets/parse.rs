@@ -0,0 +1,9 @@
+//! Parsing any pattern must either succeed or return an error, never panic.
+#![no_main]
+
+use grep_starter_rust::Pattern;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|pattern: &str| {
+    let _ = Pattern::new(pattern);
+});
@@ -0,0 +1,16 @@
+//! Searching with any valid pattern must terminate within the step limit, never panic.
+#![no_main]
+
+use grep_starter_rust::Pattern;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: (&str, &str)| {
+    let (pattern, haystack) = input;
+    if let Ok(pattern) = Pattern::new(pattern) {
+        let pattern = pattern.with_step_limit(100_000);
+        let _ = pattern.try_find(haystack);
+        let _ = pattern.run(haystack);
+        pattern.find_iter(haystack).for_each(drop);
+        pattern.rfind_iter(haystack).for_each(drop);
+    }
+});
@@ -0,0 +1,106 @@
+//! Minimal JSON rendering shared by the library and the CLI's `--json` output.
+//!
+//! A match is rendered by [`Match::to_json`](crate::Match::to_json) as
+//!
+//! ```json
+//! {"start": 4, "end": 7, "text": "abc"}
+//! ```
+//!
+//! where `start` and `end` are byte offsets into the searched text. A match with its
+//! groups, from [`Captures::to_json`](crate::Captures::to_json), adds a `groups` array
+//! with one entry per group after group 0, `null` for groups that didn't participate:
+//!
+//! ```json
+//! {"start": 0, "end": 1, "text": "b", "groups": [null, {"start": 0, "end": 1, "text": "b"}]}
+//! ```
+//!
+//! The CLI, through
+//! [`printer::Format::Json`](crate::printer::Format::Json), prints one object per
+//! matching line, embedding the matches found on it:
+//!
+//! ```json
+//! {"line_number": 1, "line": "xx abc", "matches": [{"start": 3, "end": 6, "text": "abc"}]}
+//! ```
+//!
+//! With `--byte-offset`, an `"offset"` after `line_number` gives the byte offset of
+//! the line in the input.
+//!
+//! With `--show-patterns`, a `"patterns"` array after those lists the numbers
+//! of the patterns that matched the line, counting from 1.
+//!
+//! With `--max-per-line`, lines with more matches than that only list the first
+//! ones, and end with `"truncated": true`.
+//!
+//! With `--replace` and `--diff`, each line the replacement changes gives the
+//! replaced line instead of the matches:
+//!
+//! ```json
+//! {"line_number": 1, "line": "a=1", "replaced": "a=10"}
+//! ```
+//!
+//! With `--classify` it prints the name of the rule a line matched instead of its
+//! matches:
+//!
+//! ```json
+//! {"line_number": 1, "rule": "error", "line": "ERROR disk full"}
+//! ```
+//!
+//! With `--count-unique` it prints one object per distinct matched text instead:
+//!
+//! ```json
+//! {"text": "abc", "count": 2}
+//! ```
+//!
+//! With `--count` it prints a single object with the number of matching lines:
+//!
+//! ```json
+//! {"count": 3}
+//! ```
+//!
+//! If it fails, it prints a single object on stderr instead of a message, whose
+//! `"error"` tells what went wrong. An invalid pattern gives `"syntax"`, with the
+//! byte offset of the mistake in the pattern and a hint on fixing it:
+//!
+//! ```json
+//! {"error": "syntax", "message": "Unterminated group", "position": 0, "hint": "close it with `)` or escape the `(` as `\\(`"}
+//! ```
+//!
+//! Other errors have a `"message"` only: `"pattern"` for the pattern's other errors,
+//! with a `"position"` where there is one, `"io"` for failures reading input or files,
+//! and `"other"` for the rest, like invalid options. The message then includes its
+//! causes, separated by `: `.
+
+use std::fmt::Write;
+
+/// Renders `s` as a JSON string literal, quotes included.
+pub fn quote(s: &str) -> String {
+    let mut quoted = String::with_capacity(s.len() + 2);
+    quoted.push('"');
+    for ch in s.chars() {
+        match ch {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            ch if ch.is_control() => {
+                write!(quoted, "\\u{:04x}", ch as u32).expect("Writing to a String can't fail")
+            }
+            ch => quoted.push(ch),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod test {
+    use super::quote;
+
+    #[test]
+    fn quote_escapes() {
+        assert_eq!(quote("plain"), r#""plain""#);
+        assert_eq!(quote("a\"b\\c"), r#""a\"b\\c""#);
+        assert_eq!(quote("tab\tnl\n\u{1}é"), r#""tab\tnl\n\u0001é""#);
+    }
+}
@@ -1,6 +1,31 @@
-use std::{fmt::Debug, iter::Peekable};
+use std::{
+    borrow::Cow,
+    cell::Cell,
+    collections::HashMap,
+    fmt, mem,
+    ops::{AddAssign, BitOr, Range},
+    panic,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread,
+};
 
 use thiserror::Error;
+use unicode::Property;
+
+pub mod json;
+mod literals;
+pub mod printer;
+pub mod replace;
+pub mod set;
+pub mod token;
+mod unicode;
+
+pub use replace::{Replacer, TemplateError};
+pub use set::PatternSet;
+pub use unicode::nfc;
 
 #[derive(Error, Debug)]
 pub enum Error {
@@ -8,34 +33,399 @@ pub enum Error {
     EOF,
     #[error("Unknown character class `\\{0}`")]
     UnknownCharacterType(char),
+    #[error("Matching took more than {0} steps")]
+    StepLimitExceeded(usize),
+    #[error("Matching was cancelled")]
+    Cancelled,
+    /// The pattern is bigger than a limit set with [`PatternBuilder`].
+    #[error("Pattern exceeds the {kind} limit of {limit} at position {position}")]
+    LimitExceeded {
+        kind: Limit,
+        limit: usize,
+        position: usize,
+    },
+    /// A code point escape, `\u{...}` or `\x{...}` at byte offset `position` into
+    /// the pattern, doesn't name a character.
+    #[error("Invalid code point `{escape}` at position {position}, write 1 to 6 hex digits for a code point up to 10FFFF that isn't a surrogate")]
+    InvalidCodePoint { escape: String, position: usize },
+    /// The pattern uses syntax that is recognized but not supported, or that is most
+    /// likely a mistake. `position` is a byte offset into the pattern.
+    #[error("{message} at position {position}, {hint}")]
+    Syntax {
+        message: String,
+        position: usize,
+        hint: String,
+    },
+}
+
+impl Error {
+    /// The byte offset into the pattern of what went wrong, for errors found while
+    /// parsing it.
+    pub fn position(&self) -> Option<usize> {
+        match self {
+            Self::LimitExceeded { position, .. }
+            | Self::InvalidCodePoint { position, .. }
+            | Self::Syntax { position, .. } => Some(*position),
+            Self::EOF
+            | Self::UnknownCharacterType(_)
+            | Self::StepLimitExceeded(_)
+            | Self::Cancelled => None,
+        }
+    }
+
+    fn syntax(message: impl Into<String>, position: usize, hint: impl Into<String>) -> Self {
+        Self::Syntax {
+            message: message.into(),
+            position,
+            hint: hint.into(),
+        }
+    }
 }
 
 pub type Result<T> = std::result::Result<T, Error>;
 
+/// What a [`Warning`] is about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WarningKind {
+    /// An unbounded repetition of something that repeats without bound itself,
+    /// like `(?:a+)+`, which can take exponential time on input that almost matches.
+    NestedRepetition,
+    /// A bracket expression no character can match, like `[^\d\D]`.
+    EmptyClass,
+    /// A capture group no backreference refers to, which is fine if its capture is
+    /// used otherwise, e.g. in a replacement.
+    UnreferencedGroup,
+}
+
+/// Something in a pattern that is valid, but most likely a mistake, see
+/// [`Pattern::warnings`]. `position` is a byte offset into the pattern.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("{message} at position {position}, {hint}")]
+pub struct Warning {
+    kind: WarningKind,
+    message: String,
+    position: usize,
+    hint: String,
+}
+
+impl Warning {
+    fn new(
+        kind: WarningKind,
+        message: impl Into<String>,
+        position: usize,
+        hint: impl Into<String>,
+    ) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+            position,
+            hint: hint.into(),
+        }
+    }
+
+    pub fn kind(&self) -> WarningKind {
+        self.kind
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    pub fn hint(&self) -> &str {
+        &self.hint
+    }
+}
+
+/// Which limit an [`Error::LimitExceeded`] is about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Limit {
+    /// Number of matchers, see [`PatternBuilder::size_limit`].
+    Size,
+    /// Depth of nested groups, see [`PatternBuilder::nest_limit`].
+    Nesting,
+    /// Number of members of a bracket expression, see
+    /// [`PatternBuilder::class_size_limit`].
+    ClassSize,
+}
+
+impl fmt::Display for Limit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Limit::Size => "size",
+            Limit::Nesting => "nesting",
+            Limit::ClassSize => "class size",
+        })
+    }
+}
+
+/// Options that change how parts of a pattern match, combined with `|`. Patterns
+/// set them inline with `(?flags)` for the rest of the group, or `(?flags:...)` for
+/// a group of their own, where `-` turns the flags after it off, as in `(?i-s)`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Flags(u8);
+
+impl Flags {
+    /// `i`: letters also match their other case, in backreferences too.
+    pub const CASE_INSENSITIVE: Self = Self(1);
+    /// `m`: `^` and `$` also match at the start and end of every line.
+    pub const MULTI_LINE: Self = Self(1 << 1);
+    /// `s`: `.` also matches `\n`.
+    pub const DOT_ALL: Self = Self(1 << 2);
+    /// `u`: `\d`, `\w` and `\s` match digits, word characters and whitespace of any
+    /// script instead of only ASCII ones.
+    pub const UNICODE: Self = Self(1 << 3);
+    /// `g`: `.` and negated classes match a whole grapheme cluster, what reads as a
+    /// single character, like `e\u{301}` or an emoji with modifiers, if they match
+    /// its first character. They then have no fixed width for lookbehinds.
+    pub const GRAPHEMES: Self = Self(1 << 4);
+
+    pub fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    fn set(&mut self, other: Self, enabled: bool) {
+        if enabled {
+            self.0 |= other.0;
+        } else {
+            self.0 &= !other.0;
+        }
+    }
+}
+
+impl BitOr for Flags {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+/// The pattern text being parsed, tracking the position of the next character and
+/// how big the parsed pattern has grown.
+#[derive(Debug, Clone)]
+struct Input<'p> {
+    pattern: &'p str,
+    position: usize,
+    /// Extra syntax and limits to parse with.
+    builder: &'p PatternBuilder,
+    /// Number of matchers parsed so far.
+    size: usize,
+    /// Number of groups the next character is nested in.
+    depth: usize,
+    /// Found so far, see [`Pattern::warnings`].
+    warnings: Vec<Warning>,
+    /// Flags in effect at the next character.
+    flags: Flags,
+}
+
+impl<'p> Input<'p> {
+    fn new(pattern: &'p str, builder: &'p PatternBuilder) -> Self {
+        Self {
+            pattern,
+            position: 0,
+            builder,
+            size: 0,
+            depth: 0,
+            warnings: Vec::new(),
+            flags: builder.flags,
+        }
+    }
+
+    /// Fails if `value` is over `limit`, for the part of the pattern at `position`.
+    fn check_limit(kind: Limit, limit: Option<usize>, value: usize, position: usize) -> Result<()> {
+        match limit {
+            Some(limit) if value > limit => Err(Error::LimitExceeded {
+                kind,
+                limit,
+                position,
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    /// Byte offset of the next character in the pattern.
+    fn offset(&self) -> usize {
+        self.position
+    }
+
+    fn rest(&self) -> &'p str {
+        &self.pattern[self.position..]
+    }
+}
+
+impl Iterator for Input<'_> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        let ch = self.peek()?;
+        self.position += ch.len_utf8();
+        Some(ch)
+    }
+}
+
+/// A character class defined by the user, see [`PatternBuilder::class`].
+#[derive(Clone)]
+struct NamedClass {
+    name: String,
+    test: Arc<dyn Fn(char) -> bool + Send + Sync>,
+}
+
+impl fmt::Debug for NamedClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("NamedClass").field(&self.name).finish()
+    }
+}
+
+impl PartialEq for NamedClass {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && Arc::ptr_eq(&self.test, &other.test)
+    }
+}
+
+impl Eq for NamedClass {}
+
+/// The characters `\s` matches.
+const WHITESPACE: [char; 6] = [' ', '\t', '\n', '\r', '\x0C', '\x0B'];
+
+/// A standard POSIX class, `[:name:]` in a bracket expression. They only contain
+/// ASCII characters, as in the C locale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PosixClass {
+    Alnum,
+    Alpha,
+    /// Space or tab.
+    Blank,
+    Cntrl,
+    Digit,
+    /// Visible characters, `print` but space.
+    Graph,
+    Lower,
+    Print,
+    Punct,
+    /// The characters `\s` matches.
+    Space,
+    Upper,
+    Xdigit,
+}
+
+impl PosixClass {
+    const ALL: [Self; 12] = [
+        Self::Alnum,
+        Self::Alpha,
+        Self::Blank,
+        Self::Cntrl,
+        Self::Digit,
+        Self::Graph,
+        Self::Lower,
+        Self::Print,
+        Self::Punct,
+        Self::Space,
+        Self::Upper,
+        Self::Xdigit,
+    ];
+
+    fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|class| class.name() == name)
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Alnum => "alnum",
+            Self::Alpha => "alpha",
+            Self::Blank => "blank",
+            Self::Cntrl => "cntrl",
+            Self::Digit => "digit",
+            Self::Graph => "graph",
+            Self::Lower => "lower",
+            Self::Print => "print",
+            Self::Punct => "punct",
+            Self::Space => "space",
+            Self::Upper => "upper",
+            Self::Xdigit => "xdigit",
+        }
+    }
+
+    fn test(self, ch: char) -> bool {
+        match self {
+            Self::Alnum => ch.is_ascii_alphanumeric(),
+            Self::Alpha => ch.is_ascii_alphabetic(),
+            Self::Blank => ch == ' ' || ch == '\t',
+            Self::Cntrl => ch.is_ascii_control(),
+            Self::Digit => ch.is_ascii_digit(),
+            Self::Graph => ch.is_ascii_graphic(),
+            Self::Lower => ch.is_ascii_lowercase(),
+            Self::Print => ch.is_ascii_graphic() || ch == ' ',
+            Self::Punct => ch.is_ascii_punctuation(),
+            Self::Space => WHITESPACE.contains(&ch),
+            Self::Upper => ch.is_ascii_uppercase(),
+            Self::Xdigit => ch.is_ascii_hexdigit(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 enum SingleCharacterMatcher {
     Literal(char),
+    /// Every character from the first to the second, both included.
+    Range(char, char),
     Any,
+    /// `.` without [`Flags::DOT_ALL`].
+    AnyButNewline,
     Digit,
     Alphanumeric,
+    /// Space, tab, newline, carriage return, form feed or vertical tab.
+    Whitespace,
+    /// `\d` with [`Flags::UNICODE`], see [`unicode::is_digit`].
+    UnicodeDigit,
+    /// `\w` with [`Flags::UNICODE`], see [`unicode::is_word`].
+    UnicodeWord,
+    /// `\s` with [`Flags::UNICODE`], the `White_Space` characters.
+    UnicodeWhitespace,
+    Named(NamedClass),
+    Posix(PosixClass),
+    Property(Property),
     Group(Vec<SingleCharacterMatcher>),
     NegativeGroup(Vec<SingleCharacterMatcher>),
+    /// Matches a character if the inner matcher matches it in any case.
+    Caseless(Box<SingleCharacterMatcher>),
+    /// Matches a character if the inner matcher matches it, with ASCII letters in
+    /// either case, for [`CaseFold::Ascii`].
+    AsciiCaseless(Box<SingleCharacterMatcher>),
 }
 
 impl SingleCharacterMatcher {
-    pub fn new(input: &mut Peekable<impl Iterator<Item = char>>) -> Result<Self> {
+    pub fn new(input: &mut Input<'_>) -> Result<Self> {
+        let start = input.offset();
         match input.next() {
-            Some('\\') => Self::new_class(input.next().ok_or(Error::EOF)?),
-            Some('[') => Self::new_group(input),
-            Some('.') => Ok(Self::Any),
+            Some('\\') => Self::new_escape(input, start),
+            Some('[') => Self::new_group(input, start),
+            Some('.') if input.flags.contains(Flags::DOT_ALL) => Ok(Self::Any),
+            Some('.') => Ok(Self::AnyButNewline),
             Some(ch) => Ok(Self::new_literal(ch)),
             None => Err(Error::EOF),
         }
     }
 
-    fn new_in_group(input: &mut impl Iterator<Item = char>) -> Result<Self> {
+    fn new_in_group(input: &mut Input<'_>) -> Result<Self> {
+        let start = input.offset();
+        let rest = input.rest();
+        if let Some(name) = rest
+            .strip_prefix("[:")
+            .and_then(|r| Self::class_name(r, ":]"))
+        {
+            input.position += name.len() + 4;
+            return Self::new_bracket_class(input.builder, name, start);
+        }
         match input.next() {
-            Some('\\') => Self::new_class(input.next().ok_or(Error::EOF)?),
+            Some('\\') => Self::new_escape(input, start),
             Some(ch) => Ok(Self::new_literal(ch)),
             None => Err(Error::EOF),
         }
@@ -45,18 +435,200 @@ impl SingleCharacterMatcher {
         SingleCharacterMatcher::Literal(ch)
     }
 
-    pub fn new_class(class: char) -> Result<Self> {
+    /// Parses an escape, whose `\` was at `position` in the pattern.
+    fn new_escape(input: &mut Input<'_>, position: usize) -> Result<Self> {
+        let class = input.next().ok_or(Error::EOF)?;
+        if matches!(class, 'p' | 'P') {
+            return Self::new_property(input, class == 'P', position);
+        }
+        if class == 'x' {
+            return Self::new_hex(input, position);
+        }
+        if class == 'u' && input.peek() == Some('{') {
+            return Self::new_code_point(input, position);
+        }
+        let matcher = Self::new_class(class, position)?;
+        Ok(match input.flags.contains(Flags::UNICODE) {
+            true => matcher.unicode(),
+            false => matcher,
+        })
+    }
+
+    /// Parses the rest of `\p{name}`, or `\P{name}` if `negated`, whose `\` was at
+    /// `position` in the pattern. A one letter name, as in `\pL`, needs no braces.
+    fn new_property(input: &mut Input<'_>, negated: bool, position: usize) -> Result<Self> {
+        let rest = input.rest();
+        let name = match rest.strip_prefix('{') {
+            Some(braced) => Self::class_name(braced, "}").map(|name| (name, name.len() + 2)),
+            None => rest
+                .chars()
+                .next()
+                .filter(|ch| ch.is_ascii_alphabetic())
+                .map(|_| (&rest[..1], 1)),
+        };
+        let Some((name, len)) = name else {
+            return Err(Error::syntax(
+                "Invalid class name",
+                position,
+                "use letters, digits and `_` in the name and write it in braces, as in `\\p{L}`",
+            ));
+        };
+        input.position += len;
+        let class = if input.builder.classes.contains_key(name) {
+            Self::new_named(input.builder, name, position)?
+        } else {
+            match Property::from_name(name) {
+                Some(property) => Self::Property(property),
+                None => {
+                    return Err(Error::syntax(
+                        format!("Unknown class `{name}`"),
+                        position,
+                        "use a Unicode general category like `L` or `Nd`, or a script like `Greek`, or register the class with `PatternBuilder::class` first",
+                    ))
+                }
+            }
+        };
+        Ok(if negated {
+            Self::NegativeGroup(vec![class])
+        } else {
+            class
+        })
+    }
+
+    /// Parses the rest of a hex escape, `\xHH` or `\x{H...}`, whose `\` was at
+    /// `position` in the pattern.
+    fn new_hex(input: &mut Input<'_>, position: usize) -> Result<Self> {
+        let rest = input.rest();
+        let (digits, len, counts) = match rest.strip_prefix('{') {
+            Some(braced) => {
+                let digits = braced.find('}').map_or("", |end| &braced[..end]);
+                (digits, digits.len() + 2, 1..=6)
+            }
+            None => (rest.get(..2).unwrap_or_default(), 2, 2..=2),
+        };
+        let hex = digits.chars().all(|ch| ch.is_ascii_hexdigit());
+        if !hex || !counts.contains(&digits.len()) {
+            return Err(Error::syntax(
+                "Invalid hex escape",
+                position,
+                "write two hex digits, as in `\\x1b`, or up to six in braces, as in `\\x{1F600}`",
+            ));
+        }
+        let code = u32::from_str_radix(digits, 16).expect("Checked to be hex digits");
+        let end = input.offset() + len;
+        let ch = char::from_u32(code).ok_or_else(|| Error::InvalidCodePoint {
+            escape: String::from(&input.pattern[position..end]),
+            position,
+        })?;
+        input.position = end;
+        Ok(Self::Literal(ch))
+    }
+
+    /// Parses the rest of a code point escape, `\u{H...}`, whose `\` was at
+    /// `position` in the pattern.
+    fn new_code_point(input: &mut Input<'_>, position: usize) -> Result<Self> {
+        let rest = input.rest();
+        let close = rest.find('}');
+        let end = close.map_or(input.pattern.len(), |close| input.offset() + close + 1);
+        let digits = close.map_or("", |close| &rest[1..close]);
+        let hex = digits.chars().all(|ch| ch.is_ascii_hexdigit());
+        let ch = (hex && matches!(digits.len(), 1..=6))
+            .then(|| u32::from_str_radix(digits, 16).expect("Checked to be hex digits"))
+            .and_then(char::from_u32);
+        let Some(ch) = ch else {
+            return Err(Error::InvalidCodePoint {
+                escape: String::from(&input.pattern[position..end]),
+                position,
+            });
+        };
+        input.position = end;
+        Ok(Self::Literal(ch))
+    }
+
+    /// The class name at the start of `rest` if it is followed by `end`.
+    fn class_name<'r>(rest: &'r str, end: &str) -> Option<&'r str> {
+        let name = &rest[..rest.find(end)?];
+        let valid = |ch: char| ch.is_alphanumeric() || ch == '_';
+        (!name.is_empty() && name.chars().all(valid)).then_some(name)
+    }
+
+    /// Makes the class `[:name:]` of a bracket expression, started at `position`: a
+    /// class registered with [`PatternBuilder::class`], or else a POSIX class.
+    fn new_bracket_class(builder: &PatternBuilder, name: &str, position: usize) -> Result<Self> {
+        if builder.classes.contains_key(name) {
+            return Self::new_named(builder, name, position);
+        }
+        match PosixClass::from_name(name) {
+            Some(class) => Ok(Self::Posix(class)),
+            None => {
+                let names: Vec<_> = PosixClass::ALL.iter().map(|class| class.name()).collect();
+                Err(Error::syntax(
+                    format!("Unknown class `{name}`"),
+                    position,
+                    format!(
+                        "the POSIX classes are {}, register others with `PatternBuilder::class` first",
+                        names.join(", ")
+                    ),
+                ))
+            }
+        }
+    }
+
+    /// Looks up a class registered with [`PatternBuilder::class`], whose reference,
+    /// `[:name:]` or `\p{name}`, started at `position`.
+    fn new_named(builder: &PatternBuilder, name: &str, position: usize) -> Result<Self> {
+        match builder.classes.get(name) {
+            Some(class) => Ok(Self::Named(class.clone())),
+            None => Err(Error::syntax(
+                format!("Unknown class `{name}`"),
+                position,
+                "register it with `PatternBuilder::class` first",
+            )),
+        }
+    }
+
+    /// Parses the escape `\class`, which started at `position` in the pattern.
+    pub fn new_class(class: char, position: usize) -> Result<Self> {
         match class {
             ch if !ch.is_alphanumeric() => Ok(Self::Literal(ch)),
             'd' => Ok(Self::Digit),
             'w' => Ok(Self::Alphanumeric),
-            ch => Err(Error::UnknownCharacterType(ch)),
+            's' => Ok(Self::Whitespace),
+            // The negated classes are negative groups of one, so that they compose
+            // inside bracket expressions like any other group
+            'D' => Ok(Self::NegativeGroup(vec![Self::Digit])),
+            'W' => Ok(Self::NegativeGroup(vec![Self::Alphanumeric])),
+            'S' => Ok(Self::NegativeGroup(vec![Self::Whitespace])),
+            'n' => Ok(Self::Literal('\n')),
+            't' => Ok(Self::Literal('\t')),
+            'r' => Ok(Self::Literal('\r')),
+            'f' => Ok(Self::Literal('\x0C')),
+            'v' => Ok(Self::Literal('\x0B')),
+            '0' => Ok(Self::Literal('\0')),
+            ch => Err(match Self::unsupported_escape_hint(ch) {
+                Some(hint) => Error::syntax(format!("`\\{ch}` is not supported"), position, hint),
+                None => Error::UnknownCharacterType(ch),
+            }),
+        }
+    }
+
+    /// Suggests an alternative for escapes other engines know, but this one doesn't.
+    fn unsupported_escape_hint(class: char) -> Option<&'static str> {
+        match class {
+            'b' | 'B' => Some("match the characters around the boundary instead, e.g. `[^\\w]`"),
+            'A' | 'z' | 'Z' => Some("write the anchor outside of the bracket expression"),
+            'u' => Some("write a code point in braces, as in `\\u{1F600}`"),
+            'k' => Some("write a named backreference as `\\k<name>`"),
+            'Q' => Some("quote the text outside of the bracket expression"),
+            'E' => Some("start the quoted text with `\\Q`"),
+            _ => None,
         }
     }
 
-    pub fn new_group(input: &mut Peekable<impl Iterator<Item = char>>) -> Result<Self> {
+    /// Parses a bracket expression, whose `[` was at `position` in the pattern.
+    pub fn new_group(input: &mut Input<'_>, position: usize) -> Result<Self> {
         let mut options = Vec::new();
-        let negative = if input.peek() == Some(&'^') {
+        let negative = if input.peek() == Some('^') {
             input.next(); // Consume "^"
             true
         } else {
@@ -64,451 +636,3209 @@ impl SingleCharacterMatcher {
         };
 
         while let Some(ch) = input.peek() {
-            if *ch == ']' {
+            if ch == ']' {
                 input.next(); // Consume the ']' character
-                if negative {
-                    return Ok(Self::NegativeGroup(options));
-                } else {
-                    return Ok(Self::Group(options));
-                }
+                let warnings = &mut input.warnings;
+                return Ok(Self::new_bracket(options, negative, position, warnings));
             } else {
-                options.push(Self::new_in_group(input)?);
+                let start = input.offset();
+                let option = Self::new_in_group(input)?;
+                options.push(Self::new_range(input, option, start)?);
+                let limit = input.builder.class_size_limit;
+                Input::check_limit(Limit::ClassSize, limit, options.len(), position)?;
+            }
+        }
+
+        Err(Error::syntax(
+            "Unterminated bracket expression",
+            position,
+            "close it with `]` or escape the `[` as `\\[`",
+        ))
+    }
+
+    /// Makes the bracket expression, which started at `position`, of its members.
+    fn new_bracket(
+        options: Vec<Self>,
+        negative: bool,
+        position: usize,
+        warnings: &mut Vec<Warning>,
+    ) -> Self {
+        let class = if negative {
+            Self::NegativeGroup(options)
+        } else {
+            Self::Group(options)
+        };
+        if class.matches_nothing() {
+            warnings.push(Warning::new(
+                WarningKind::EmptyClass,
+                "Bracket expression matches no character",
+                position,
+                "check for a class and its complement, like `\\d` and `\\D`, after `^`",
+            ));
+        }
+        class
+    }
+
+    /// Parses the rest of a range like `a-z` if `first`, which started at
+    /// `position`, is followed by one. A `-` that ends the bracket expression is
+    /// taken literally instead.
+    fn new_range(input: &mut Input<'_>, first: Self, position: usize) -> Result<Self> {
+        let rest = input.rest();
+        let Self::Literal(low) = first else {
+            return Ok(first);
+        };
+        if !rest.starts_with('-') || matches!(rest[1..].chars().next(), Some(']') | None) {
+            return Ok(first);
+        }
+
+        input.next(); // Consume "-"
+        match Self::new_in_group(input)? {
+            Self::Literal(high) if low <= high => Ok(Self::Range(low, high)),
+            Self::Literal(_) => Err(Error::syntax(
+                "Range out of order",
+                position,
+                "write the smaller character first, e.g. `a-z`",
+            )),
+            _ => Err(Error::syntax(
+                "Range must end with a single character",
+                position,
+                "escape the `-` as `\\-` to match it literally",
+            )),
+        }
+    }
+
+    /// Whether no character can match, like `[^\d\D]`. Only bracket expressions
+    /// without members, or with a class and its complement after `^`, are recognized.
+    fn matches_nothing(&self) -> bool {
+        match self {
+            Self::Group(options) => options.iter().all(Self::matches_nothing),
+            Self::NegativeGroup(options) => options
+                .iter()
+                .any(|option| options.contains(&Self::NegativeGroup(vec![option.clone()]))),
+            _ => false,
+        }
+    }
+
+    /// Makes the classes `\d`, `\w` and `\s` in this, also negated, match those
+    /// characters of any script, for [`Flags::UNICODE`].
+    pub(crate) fn unicode(self) -> Self {
+        match self {
+            Self::Digit => Self::UnicodeDigit,
+            Self::Alphanumeric => Self::UnicodeWord,
+            Self::Whitespace => Self::UnicodeWhitespace,
+            Self::NegativeGroup(options) => {
+                Self::NegativeGroup(options.into_iter().map(Self::unicode).collect())
             }
+            other => other,
         }
+    }
 
-        Err(Error::EOF)
+    /// Makes this match the characters it matches in any case, or only ASCII letters
+    /// in either case with `ascii`.
+    fn caseless(self, ascii: bool) -> Self {
+        match self {
+            Self::Literal(ch) if ascii && !ch.is_ascii_alphabetic() => self,
+            Self::Literal(ch) if case_variants(ch).all(|v| v == ch) => self,
+            Self::Any
+            | Self::AnyButNewline
+            | Self::Digit
+            | Self::Alphanumeric
+            | Self::Whitespace
+            | Self::UnicodeDigit
+            | Self::UnicodeWord
+            | Self::UnicodeWhitespace => self,
+            // A character is excluded if any of its cases is
+            Self::NegativeGroup(options) => {
+                Self::NegativeGroup(vec![Self::Group(options).caseless(ascii)])
+            }
+            other if ascii => Self::AsciiCaseless(Box::new(other)),
+            other => Self::Caseless(Box::new(other)),
+        }
     }
 
     pub fn test(&self, ch: char) -> bool {
         match self {
             SingleCharacterMatcher::Literal(c) => *c == ch,
+            SingleCharacterMatcher::Range(low, high) => (*low..=*high).contains(&ch),
             SingleCharacterMatcher::Digit => ch.is_ascii_digit(),
             SingleCharacterMatcher::Alphanumeric => ch.is_ascii_alphanumeric() || ch == '_',
+            SingleCharacterMatcher::Whitespace => WHITESPACE.contains(&ch),
+            SingleCharacterMatcher::UnicodeDigit => unicode::is_digit(ch),
+            SingleCharacterMatcher::UnicodeWord => unicode::is_word(ch),
+            SingleCharacterMatcher::UnicodeWhitespace => ch.is_whitespace(),
+            SingleCharacterMatcher::Named(class) => (class.test)(ch),
+            SingleCharacterMatcher::Posix(class) => class.test(ch),
+            SingleCharacterMatcher::Property(property) => property.test(ch),
             SingleCharacterMatcher::Group(options) => options.iter().any(|o| o.test(ch)),
             SingleCharacterMatcher::NegativeGroup(options) => !options.iter().any(|o| o.test(ch)),
             SingleCharacterMatcher::Any => true,
+            SingleCharacterMatcher::AnyButNewline => ch != '\n',
+            SingleCharacterMatcher::Caseless(inner) => case_variants(ch).any(|v| inner.test(v)),
+            SingleCharacterMatcher::AsciiCaseless(inner) => {
+                inner.test(ch.to_ascii_lowercase()) || inner.test(ch.to_ascii_uppercase())
+            }
+        }
+    }
+
+    /// Adds the first UTF-8 byte of every character this can match to `set`.
+    fn first_bytes(&self, set: &mut ByteSet) {
+        match self {
+            SingleCharacterMatcher::Literal(ch) => set.insert_char(*ch),
+            SingleCharacterMatcher::Range(low, high) => {
+                // The leading byte of UTF-8 grows with the code point, so the range
+                // starts with every leading byte between those of its ends
+                let lead = |ch: char| ch.encode_utf8(&mut [0; 4]).as_bytes()[0];
+                (lead(*low)..=lead(*high))
+                    .filter(|byte| !(0x80..=0xBF).contains(byte))
+                    .for_each(|byte| set.insert(byte));
+            }
+            SingleCharacterMatcher::Digit => (b'0'..=b'9').for_each(|byte| set.insert(byte)),
+            SingleCharacterMatcher::Alphanumeric => {
+                (0..=0x7F)
+                    .filter(|byte: &u8| byte.is_ascii_alphanumeric() || *byte == b'_')
+                    .for_each(|byte| set.insert(byte));
+            }
+            SingleCharacterMatcher::Whitespace => {
+                WHITESPACE.iter().for_each(|ch| set.insert_char(*ch))
+            }
+            SingleCharacterMatcher::Posix(class) => (0..=0x7F)
+                .filter(|byte: &u8| class.test(char::from(*byte)))
+                .for_each(|byte| set.insert(byte)),
+            SingleCharacterMatcher::UnicodeDigit
+            | SingleCharacterMatcher::UnicodeWord
+            | SingleCharacterMatcher::UnicodeWhitespace
+            | SingleCharacterMatcher::Property(_) => (0..=0x7F)
+                .filter(|byte: &u8| self.test(char::from(*byte)))
+                .chain(0xC0..=0xFF)
+                .for_each(|byte| set.insert(byte)),
+            SingleCharacterMatcher::Group(options) => {
+                options.iter().for_each(|option| option.first_bytes(set))
+            }
+            SingleCharacterMatcher::AnyButNewline => (0..=0x7F)
+                .chain(0xC0..=0xFF)
+                .filter(|byte| *byte != b'\n')
+                .for_each(|byte| set.insert(byte)),
+            SingleCharacterMatcher::Caseless(inner) => {
+                let mut cased = ByteSet::new();
+                inner.first_bytes(&mut cased);
+                for byte in cased.iter() {
+                    set.insert(byte.to_ascii_lowercase());
+                    set.insert(byte.to_ascii_uppercase());
+                }
+                // Other cases of letters, like the Kelvin sign, may not be ASCII
+                if cased
+                    .iter()
+                    .any(|byte| byte.is_ascii_alphabetic() || byte >= 0xC0)
+                {
+                    (0xC0..=0xFF).for_each(|byte| set.insert(byte));
+                }
+            }
+            SingleCharacterMatcher::AsciiCaseless(inner) => {
+                let mut cased = ByteSet::new();
+                inner.first_bytes(&mut cased);
+                for byte in cased.iter() {
+                    set.insert(byte.to_ascii_lowercase());
+                    set.insert(byte.to_ascii_uppercase());
+                }
+            }
+            SingleCharacterMatcher::Any
+            | SingleCharacterMatcher::Named(_)
+            | SingleCharacterMatcher::NegativeGroup(_) => set.insert_all_leading(),
         }
     }
 }
 
-#[derive(Debug, Clone)]
-struct GroupBacktrackState<'a, T> {
-    input: BufferedIterator<T>,
-    captures: Vec<String>,
-    backtrack: BacktrackInfo<'a, T>,
-    matchers_to_resume: std::slice::Iter<'a, Matcher>,
+impl SingleCharacterMatcher {
+    /// One-line description of the matcher, listing group members inline.
+    fn describe(&self) -> String {
+        let list = |options: &[Self]| -> String {
+            let options: Vec<_> = options.iter().map(Self::describe).collect();
+            options.join(", ")
+        };
+        match self {
+            SingleCharacterMatcher::Literal(ch) => format!("literal {ch:?}"),
+            SingleCharacterMatcher::Range(low, high) => format!("range {low:?} to {high:?}"),
+            SingleCharacterMatcher::Any => String::from("any character"),
+            SingleCharacterMatcher::AnyButNewline => String::from("any character but newline"),
+            SingleCharacterMatcher::Digit => String::from("digit"),
+            SingleCharacterMatcher::Alphanumeric => String::from("word character"),
+            SingleCharacterMatcher::Whitespace => String::from("whitespace"),
+            SingleCharacterMatcher::UnicodeDigit => String::from("Unicode digit"),
+            SingleCharacterMatcher::UnicodeWord => String::from("Unicode word character"),
+            SingleCharacterMatcher::UnicodeWhitespace => String::from("Unicode whitespace"),
+            SingleCharacterMatcher::Named(class) => format!("class {}", class.name),
+            SingleCharacterMatcher::Posix(class) => format!("POSIX class {}", class.name()),
+            SingleCharacterMatcher::Property(property) if property.is_script() => {
+                format!("Unicode script {}", property.name())
+            }
+            SingleCharacterMatcher::Property(property) => {
+                format!("Unicode category {}", property.name())
+            }
+            SingleCharacterMatcher::Group(options) => format!("one of [{}]", list(options)),
+            SingleCharacterMatcher::NegativeGroup(options) => {
+                format!("none of [{}]", list(options))
+            }
+            SingleCharacterMatcher::Caseless(inner) => {
+                format!("case-insensitive {}", inner.describe())
+            }
+            SingleCharacterMatcher::AsciiCaseless(inner) => {
+                format!("ASCII case-insensitive {}", inner.describe())
+            }
+        }
+    }
+
+    fn write_tree(&self, f: &mut fmt::Formatter<'_>, depth: usize) -> fmt::Result {
+        let indent = depth * 2;
+        match self {
+            SingleCharacterMatcher::Literal(ch) => writeln!(f, "{:indent$}literal {ch:?}", ""),
+            SingleCharacterMatcher::Range(low, high) => {
+                writeln!(f, "{:indent$}range {low:?} to {high:?}", "")
+            }
+            SingleCharacterMatcher::Any
+            | SingleCharacterMatcher::AnyButNewline
+            | SingleCharacterMatcher::Posix(_)
+            | SingleCharacterMatcher::Property(_)
+            | SingleCharacterMatcher::UnicodeDigit
+            | SingleCharacterMatcher::UnicodeWord
+            | SingleCharacterMatcher::UnicodeWhitespace => {
+                writeln!(f, "{:indent$}{}", "", self.describe())
+            }
+            SingleCharacterMatcher::Digit => writeln!(f, "{:indent$}digit", ""),
+            SingleCharacterMatcher::Alphanumeric => writeln!(f, "{:indent$}word character", ""),
+            SingleCharacterMatcher::Whitespace => writeln!(f, "{:indent$}whitespace", ""),
+            SingleCharacterMatcher::Named(class) => {
+                writeln!(f, "{:indent$}class {}", "", class.name)
+            }
+            SingleCharacterMatcher::Group(options) => {
+                writeln!(f, "{:indent$}one of", "")?;
+                options.iter().try_for_each(|o| o.write_tree(f, depth + 1))
+            }
+            SingleCharacterMatcher::NegativeGroup(options) => {
+                writeln!(f, "{:indent$}none of", "")?;
+                options.iter().try_for_each(|o| o.write_tree(f, depth + 1))
+            }
+            SingleCharacterMatcher::Caseless(inner) => {
+                writeln!(f, "{:indent$}case-insensitive", "")?;
+                inner.write_tree(f, depth + 1)
+            }
+            SingleCharacterMatcher::AsciiCaseless(inner) => {
+                writeln!(f, "{:indent$}ASCII case-insensitive", "")?;
+                inner.write_tree(f, depth + 1)
+            }
+        }
+    }
 }
 
-#[derive(Debug, Clone)]
-enum BacktrackInfo<'a, T> {
-    Range(usize),
-    Group(usize, Vec<GroupBacktrackState<'a, T>>),
-    None,
+/// `ch` and its lowercase and uppercase forms, where those are single characters.
+fn case_variants(ch: char) -> impl Iterator<Item = char> {
+    let (mut lower, mut upper) = (ch.to_lowercase(), ch.to_uppercase());
+    let lower = if lower.len() == 1 { lower.next() } else { None };
+    let upper = if upper.len() == 1 { upper.next() } else { None };
+    [Some(ch), lower, upper].into_iter().flatten()
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-enum Matcher {
-    Repeat {
-        matcher: Box<Matcher>,
-        min: Option<usize>,
+/// Whether `a` and `b` are the same character, ignoring case.
+fn same_caseless(a: char, b: char) -> bool {
+    a == b || case_variants(a).any(|v| case_variants(b).any(|w| v == w))
+}
+
+/// Start and end offsets of every capture group, indexed by group number.
+type Slots = Vec<Option<(usize, usize)>>;
+
+/// One level of a repetition, see [`Matcher::test_repeat`].
+struct Iteration {
+    /// Where the iterations so far ended.
+    at: usize,
+    /// The captures at `at`, only kept if the repeated matcher has groups.
+    captures: Option<Slots>,
+    /// The ways the next iteration can end that weren't tried yet, most preferred
+    /// first, with the captures it made.
+    ends: std::vec::IntoIter<(usize, Option<Slots>)>,
+}
+
+impl Iteration {
+    /// Finds every way iteration `count + 1` of `matcher` can end, starting at `at`.
+    fn new(
+        matcher: &Matcher,
         max: Option<usize>,
-    },
-    CaptureGroup(Vec<Matcher>),
-    SingleCharacter(SingleCharacterMatcher),
-    Backreference(usize),
-    StartOfString,
-    EndOfString,
-    Alternative,
+        count: usize,
+        has_groups: bool,
+        state: &mut State<'_>,
+        at: usize,
+    ) -> Self {
+        let mut ends = Vec::new();
+        if max.is_none_or(|max| count < max) {
+            matcher.test(state, at, &mut |state, end| {
+                ends.push((end, has_groups.then(|| state.captures.clone())));
+                false
+            });
+        }
+        Self {
+            at,
+            captures: has_groups.then(|| state.captures.clone()),
+            ends: ends.into_iter(),
+        }
+    }
 }
 
-#[derive(Debug, Clone)]
-struct BufferedIterator<T> {
-    inner: T,
-    buffer: String,
-    subbuffers: Vec<String>,
-    peeked: Option<Option<(usize, char)>>,
+/// Mutable state of a search, or of successive ones with [`State::restart`].
+struct State<'h> {
+    /// The text a match has to fit in, which may end before the searched text does
+    /// with [`Newlines::Split`].
+    haystack: &'h str,
+    /// Offset `^` matches at, the start of the line being searched with
+    /// [`Newlines::Split`].
+    start: usize,
+    /// Length of the whole searched text.
+    input_len: usize,
+    /// Group 0 is the whole match.
+    captures: Slots,
+    tracer: Option<&'h mut dyn FnMut(&TraceEvent)>,
+    /// Nesting depth of the matcher being tested, only tracked while tracing.
+    depth: usize,
+    /// Number of matchers tested so far, across all starting offsets.
+    steps: usize,
+    step_limit: Option<usize>,
+    cancel: Option<Arc<AtomicBool>>,
+    /// Set once `cancel` was seen raised, from then on every step fails.
+    cancelled: bool,
+    /// Whether a matcher ran out of input, only tracked (`Some`) for
+    /// [`Pattern::is_partial_match`].
+    hit_end: Option<bool>,
+    /// Only kept for [`Pattern::find_counted`], which also counts the steps.
+    counters: Option<SearchCounters>,
 }
 
-impl<T: Iterator<Item = (usize, char)>> BufferedIterator<T> {
-    pub fn new(inner: T) -> Self {
+impl<'h> State<'h> {
+    fn new(pattern: &Pattern, haystack: &'h str) -> Self {
         Self {
-            inner,
-            buffer: String::new(),
-            subbuffers: Vec::new(),
-            peeked: None,
+            haystack,
+            start: 0,
+            input_len: haystack.len(),
+            captures: vec![None; pattern.groups.len() + 1],
+            tracer: None,
+            depth: 0,
+            steps: 0,
+            step_limit: pattern.step_limit,
+            cancel: pattern.cancel.clone(),
+            cancelled: false,
+            hit_end: None,
+            counters: None,
         }
     }
 
-    pub fn subdivide(&mut self) {
-        self.subbuffers.push(String::new());
+    /// Prepares for another search of `haystack`, which gets its own step limit.
+    fn restart(&mut self, haystack: &'h str) {
+        self.haystack = haystack;
+        self.start = 0;
+        self.steps = 0;
     }
 
-    pub fn pop_divided(&mut self) -> Option<String> {
-        self.subbuffers.pop()
+    /// Accounts for one more step, returning `false` once the step limit is exceeded
+    /// or the search was cancelled.
+    fn step(&mut self) -> bool {
+        self.steps += 1;
+        if let Some(cancel) = &self.cancel {
+            self.cancelled |= cancel.load(Ordering::Relaxed);
+        }
+        !self.exhausted()
     }
 
-    pub fn peek(&mut self) -> Option<&(usize, char)> {
-        let iter = &mut self.inner;
-        self.peeked.get_or_insert_with(|| iter.next()).as_ref()
+    fn exhausted(&self) -> bool {
+        self.cancelled || self.step_limit.is_some_and(|limit| self.steps > limit)
     }
 
-    pub fn take(self) -> (T, String) {
-        (self.inner, self.buffer)
+    /// Fails once the search has to stop, because it was cancelled or ran out of steps.
+    fn check(&self) -> Result<()> {
+        match (self.cancelled, self.exhausted(), self.step_limit) {
+            (true, _, _) => Err(Error::Cancelled),
+            (false, true, Some(limit)) => Err(Error::StepLimitExceeded(limit)),
+            _ => Ok(()),
+        }
     }
-}
 
-impl<T: Iterator<Item = (usize, char)>> Iterator for BufferedIterator<T> {
-    type Item = (usize, char);
+    /// Notes that a matcher needed more input at `at`, if that is the end of the
+    /// input. With [`Newlines::Split`], the end of a line doesn't count.
+    fn note_end(&mut self, at: usize) {
+        if let Some(hit_end) = &mut self.hit_end {
+            *hit_end |= at == self.input_len;
+        }
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        let ret = match self.peeked.take() {
-            Some(ch) => ch,
-            None => self.inner.next(),
-        };
+    fn char_at(&self, at: usize) -> Option<char> {
+        self.haystack[at..].chars().next()
+    }
 
-        if let Some((_, ch)) = ret {
-            self.buffer.push(ch);
-            for subbuffer in self.subbuffers.iter_mut() {
-                subbuffer.push(ch);
-            }
+    /// The offset `count` characters before `at`, if there are as many since `start`.
+    fn chars_before(&self, at: usize, count: usize) -> Option<usize> {
+        if count == 0 {
+            return Some(at);
         }
+        let before = &self.haystack[self.start..at];
+        let (offset, _) = before.char_indices().nth_back(count - 1)?;
+        Some(self.start + offset)
+    }
 
-        ret
+    fn trace(&mut self, kind: TraceKind, matcher: impl FnOnce() -> String, span: Range<usize>) {
+        if let Some(tracer) = self.tracer.as_mut() {
+            tracer(&TraceEvent {
+                kind,
+                depth: self.depth,
+                matcher: matcher(),
+                span,
+            });
+        }
     }
 }
 
-impl Matcher {
-    pub fn new(input: &mut Peekable<impl Iterator<Item = char> + Clone>) -> Result<Self> {
-        match input.peek() {
-            Some('^') => {
-                input.next();
-                Ok(Self::StartOfString)
-            }
-            Some('$') => {
-                input.next();
-                Ok(Self::EndOfString)
-            }
-            Some('(') => {
-                input.next();
-                let mut matchers = Vec::new();
-                while let Some(ch) = input.peek() {
-                    if *ch == ')' {
-                        input.next();
-                        break;
-                    }
-                    matchers.push(Matcher::new(input)?);
-                }
-                // FIXME: We're allowing unterminated groups here as well!
-                Ok(Self::CaptureGroup(matchers))
-            }
-            Some('|') => {
-                input.next();
-                Ok(Self::Alternative)
-            }
-            Some(_) => {
-                let mut cloned_iter = input.clone();
-                if let (Some('\\'), Some(dig)) = (cloned_iter.next(), cloned_iter.next()) {
-                    if dig.is_ascii_digit() {
-                        std::mem::swap(input, &mut cloned_iter);
-                        return Ok(Self::Backreference(String::from(dig).parse().expect(
-                            "Dig was checked to be a digit, parsing as usize should pass",
-                        )));
-                    }
-                }
-                let matcher = Self::SingleCharacter(SingleCharacterMatcher::new(input)?);
+/// How much work searches did, see [`Pattern::find_counted`]. Adding up the
+/// counters of many searches helps to tell why a pattern is slow on some text.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SearchCounters {
+    /// Starting offsets a match was tried at.
+    pub positions: usize,
+    /// Starting offsets skipped because no match can start with their byte, see
+    /// [`Pattern::first_bytes`].
+    pub skipped: usize,
+    /// Matchers tested, as limited by [`Pattern::with_step_limit`].
+    pub steps: usize,
+    /// Times a matcher had to give up what it matched because the rest of the
+    /// pattern didn't match after it.
+    pub backtracks: usize,
+}
 
-                Ok(match input.peek() {
-                    Some('+') => {
-                        input.next();
-                        Self::Repeat {
-                            matcher: Box::new(matcher),
-                            min: Some(1),
-                            max: None,
-                        }
-                    }
-                    Some('*') => {
-                        input.next();
-                        Self::Repeat {
-                            matcher: Box::new(matcher),
-                            min: None,
-                            max: None,
-                        }
-                    }
-                    Some('?') => {
-                        input.next();
-                        Self::Repeat {
-                            matcher: Box::new(matcher),
-                            min: None,
-                            max: Some(1),
-                        }
-                    }
-                    _ => matcher,
-                })
+impl AddAssign for SearchCounters {
+    fn add_assign(&mut self, other: Self) {
+        self.positions += other.positions;
+        self.skipped += other.skipped;
+        self.steps += other.steps;
+        self.backtracks += other.backtracks;
+    }
+}
+
+/// What happened in a [`TraceEvent`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TraceKind {
+    /// The pattern is tried at a new starting offset.
+    Start,
+    /// A matcher is tried at an offset.
+    Try,
+    /// A matcher matched the span.
+    Match,
+    /// The rest of the pattern failed after a match, so the matcher looks for
+    /// another way to match.
+    Backtrack,
+    /// The matcher has no (more) ways to match.
+    Fail,
+}
+
+/// A single step of the matching engine, as reported by [`Pattern::trace`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceEvent {
+    pub kind: TraceKind,
+    /// Nesting depth of the matcher within the pattern.
+    pub depth: usize,
+    /// Short description of the matcher.
+    pub matcher: String,
+    /// Byte offsets the step applies to, empty when it only concerns a position.
+    pub span: Range<usize>,
+}
+
+impl fmt::Display for TraceEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let indent = self.depth * 2;
+        let Range { start, end } = self.span;
+        match self.kind {
+            TraceKind::Start => write!(f, "{:indent$}start at {start}", ""),
+            TraceKind::Try => write!(f, "{:indent$}try {} at {start}", "", self.matcher),
+            TraceKind::Match => write!(f, "{:indent$}match {} at {start}..{end}", "", self.matcher),
+            TraceKind::Backtrack => write!(
+                f,
+                "{:indent$}backtrack into {} from {end}",
+                "", self.matcher
+            ),
+            TraceKind::Fail => write!(f, "{:indent$}fail {} at {start}", "", self.matcher),
+        }
+    }
+}
+
+/// Continuation invoked with the offset at which a matcher finished matching.
+///
+/// Returning `false` makes the matcher backtrack and try its next alternative.
+type Next<'n, 'h> = dyn FnMut(&mut State<'h>, usize) -> bool + 'n;
+
+/// Whether `rest` starts like a `{n}`, `{n,}`, `{,m}` or `{n,m}` quantifier, that
+/// is with `{` and a count. Other braces are literal characters.
+fn starts_counted_repetition(rest: &str) -> bool {
+    let count = rest
+        .strip_prefix('{')
+        .map(|r| r.strip_prefix(',').unwrap_or(r));
+    count.is_some_and(|count| count.starts_with(|ch: char| ch.is_ascii_digit()))
+}
+
+/// Least and most repetitions of a quantifier, `None` meaning 0 and unbounded.
+type Bounds = (Option<usize>, Option<usize>);
+
+/// Parses the counted repetition at the start of `rest`, which is at `position` in
+/// the pattern, returning its bounds and length.
+fn counted_repetition(rest: &str, position: usize) -> Result<Option<(Bounds, usize)>> {
+    if !starts_counted_repetition(rest) {
+        return Ok(None);
+    }
+    let malformed = || {
+        Error::syntax(
+            "Malformed counted repetition",
+            position,
+            "write it as `{n}`, `{n,}` or `{n,m}`, or escape the brace as `\\{` to match it literally",
+        )
+    };
+    let (bounds, _) = rest[1..].split_once('}').ok_or_else(malformed)?;
+    let (min, max) = bounds.split_once(',').unwrap_or((bounds, bounds));
+    let count = |count: &str| match count {
+        "" => Ok(None),
+        count if count.chars().all(|ch| ch.is_ascii_digit()) => {
+            count.parse().map(Some).map_err(|_| malformed())
+        }
+        _ => Err(malformed()),
+    };
+    let (min, max) = (count(min)?, count(max)?);
+    if let (Some(min), Some(max)) = (min, max) {
+        if min > max {
+            return Err(Error::syntax(
+                "Repetition bounds out of order",
+                position,
+                "write the smaller count first, e.g. `{2,5}`",
+            ));
+        }
+    }
+    Ok(Some(((min, max), bounds.len() + 2)))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Matcher {
+    Repeat {
+        matcher: Box<Matcher>,
+        min: Option<usize>,
+        max: Option<usize>,
+        /// Written with a trailing `+`, never gives back repetitions once matched.
+        possessive: bool,
+    },
+    CaptureGroup(usize, Vec<Matcher>),
+    /// A non-capturing group, `(?:...)`.
+    Group(Vec<Matcher>),
+    /// An atomic group, `(?>...)`: matches like [`Matcher::Group`], but once it
+    /// matched, other ways of matching it are never tried.
+    Atomic(Vec<Matcher>),
+    /// `(?=...)`, or `(?!...)` if `negative`: matches nothing, but only if the
+    /// group would, or wouldn't, match here. Captures made inside are discarded.
+    Lookahead {
+        negative: bool,
+        inner: Vec<Matcher>,
+    },
+    /// `(?<=...)`, or `(?<!...)` if `negative`: like [`Matcher::Lookahead`], but
+    /// for a match that ends here. Each alternative has a fixed width, in `widths`.
+    Lookbehind {
+        negative: bool,
+        inner: Vec<Matcher>,
+        widths: Vec<usize>,
+    },
+    SingleCharacter(SingleCharacterMatcher),
+    /// `.` or a negated class with [`Flags::GRAPHEMES`]: tests the first character
+    /// of a grapheme cluster, and matches the whole cluster.
+    Grapheme(SingleCharacterMatcher),
+    /// A run of caseless literals with [`CaseFold::Full`] or [`CaseFold::Turkic`]:
+    /// matches text whose full case folding is `text`, which is folded already.
+    Folded {
+        text: String,
+        turkic: bool,
+    },
+    Backreference {
+        index: usize,
+        /// With [`Flags::CASE_INSENSITIVE`].
+        caseless: bool,
+        /// Only ASCII letters compare caseless, with [`CaseFold::Ascii`].
+        ascii: bool,
+    },
+    StartOfString,
+    EndOfString,
+    /// `^` with [`Flags::MULTI_LINE`], also matches after every `\n`.
+    StartOfLine,
+    /// `$` with [`Flags::MULTI_LINE`], also matches before every `\n`.
+    EndOfLine,
+    /// `\A`, only matches at the start of the whole haystack, whatever the flags
+    /// and [`Newlines`] mode.
+    StartOfText,
+    /// `\z`, only matches at the end of the whole haystack.
+    EndOfText,
+    /// `\Z`, matches at the end of the whole haystack and before a `\n` ending it.
+    EndOfTextOrNewline,
+    /// `a|b`, the branches of a group, or of the whole pattern, tried in order. It is
+    /// always the only matcher of its group, see [`Matcher::sequence`].
+    Alternation(Vec<Vec<Matcher>>),
+}
+
+impl Matcher {
+    pub fn new(input: &mut Input<'_>, groups: &mut Vec<GroupInfo>) -> Result<Self> {
+        let start = input.offset();
+        input.size += 1;
+        Input::check_limit(Limit::Size, input.builder.size_limit, input.size, start)?;
+        match input.peek() {
+            Some('^') => {
+                input.next();
+                Self::reject_repeat(input, "an anchor")?;
+                match input.flags.contains(Flags::MULTI_LINE) {
+                    true => Ok(Self::StartOfLine),
+                    false => Ok(Self::StartOfString),
+                }
+            }
+            Some('$') => {
+                input.next();
+                Self::reject_repeat(input, "an anchor")?;
+                match input.flags.contains(Flags::MULTI_LINE) {
+                    true => Ok(Self::EndOfLine),
+                    false => Ok(Self::EndOfString),
+                }
+            }
+            Some('(') => {
+                input.next();
+                if input.rest().starts_with("?:") {
+                    input.position += 2;
+                    let matchers = Self::new_group_body(input, groups, start)?;
+                    return Self::maybe_repeat(input, Self::Group(matchers));
+                }
+                if input.rest().starts_with("?>") {
+                    input.position += 2;
+                    let matchers = Self::new_group_body(input, groups, start)?;
+                    return Self::maybe_repeat(input, Self::Atomic(matchers));
+                }
+                let rest = input.rest();
+                if rest.starts_with("?=") || rest.starts_with("?!") {
+                    let negative = rest.starts_with("?!");
+                    input.position += 2;
+                    let inner = Self::new_group_body(input, groups, start)?;
+                    Self::reject_repeat(input, "a lookahead")?;
+                    return Ok(Self::Lookahead { negative, inner });
+                }
+                if rest.starts_with("?<=") || rest.starts_with("?<!") {
+                    let negative = rest.starts_with("?<!");
+                    input.position += 3;
+                    let inner = Self::new_group_body(input, groups, start)?;
+                    let lookbehind = Self::new_lookbehind(negative, inner, start)?;
+                    Self::reject_repeat(input, "a lookbehind")?;
+                    return Ok(lookbehind);
+                }
+                if input.rest().starts_with("?P=") {
+                    input.position += 3;
+                    return Self::new_named_backreference(input, groups, start, ')');
+                }
+                let name = Self::new_group_name(input, groups, start)?;
+                if name.is_none() && input.peek() == Some('?') {
+                    let (flags, len, scoped) = Self::new_flags(input.rest(), input.flags, start)?;
+                    assert!(
+                        scoped,
+                        "Flags for the rest of a group are set by `set_flags`"
+                    );
+                    input.position += len;
+                    let outer = mem::replace(&mut input.flags, flags);
+                    let matchers = Self::new_group_body(input, groups, start)?;
+                    input.flags = outer;
+                    return Self::maybe_repeat(input, Self::Group(matchers));
+                }
+                let index = Self::open_group(groups, name, start);
+                let matchers = Self::new_group_body(input, groups, start)?;
+                groups[index - 1].span.end = input.offset();
+                Self::maybe_repeat(input, Self::CaptureGroup(index, matchers))
+            }
+            Some(')') => Err(Error::syntax(
+                "Unmatched `)`",
+                start,
+                "escape it as `\\)` to match it literally",
+            )),
+            Some(ch @ ('*' | '+' | '?')) => Err(Error::syntax(
+                "Nothing to repeat",
+                start,
+                format!("escape it as `\\{ch}` to match it literally"),
+            )),
+            Some('{') if starts_counted_repetition(input.rest()) => Err(Error::syntax(
+                "Nothing to repeat",
+                start,
+                "escape it as `\\{` to match it literally",
+            )),
+            Some('\\') if input.rest()[1..].starts_with(['A', 'z', 'Z']) => {
+                input.next(); // Consume "\\"
+                let anchor = match input.next() {
+                    Some('A') => Self::StartOfText,
+                    Some('z') => Self::EndOfText,
+                    _ => Self::EndOfTextOrNewline,
+                };
+                Self::reject_repeat(input, "an anchor")?;
+                Ok(anchor)
+            }
+            Some('\\') if input.rest().starts_with("\\k<") => {
+                input.position += 3;
+                Self::new_named_backreference(input, groups, start, '>')
+            }
+            Some(_) => {
+                let mut cloned_iter = input.clone();
+                if let (Some('\\'), Some(dig)) = (cloned_iter.next(), cloned_iter.next()) {
+                    // `\0` is the NUL character, group 0 can't be referred back to
+                    if dig.is_ascii_digit() && dig != '0' {
+                        std::mem::swap(input, &mut cloned_iter);
+                        Self::reject_repeat(input, "a backreference")?;
+                        return Ok(Self::Backreference {
+                            index: String::from(dig).parse().expect(
+                                "Dig was checked to be a digit, parsing as usize should pass",
+                            ),
+                            caseless: input.flags.contains(Flags::CASE_INSENSITIVE),
+                            ascii: input.builder.case_fold == CaseFold::Ascii,
+                        });
+                    }
+                }
+                let matcher = SingleCharacterMatcher::new(input)?;
+                let matcher = Self::character(matcher, input.flags, input.builder.case_fold);
+                Self::maybe_repeat(input, matcher)
             }
             None => Err(Error::EOF),
         }
     }
 
-    pub fn test<'a, T>(
-        &'a self,
-        input: &mut BufferedIterator<T>,
-        captured_groups: &Vec<String>,
-        backtrack: Option<BacktrackInfo<'a, T>>,
-    ) -> (bool, Vec<String>, Option<BacktrackInfo<'a, T>>)
-    where
-        T: Iterator<Item = (usize, char)> + Clone + Debug,
-    {
-        match self {
-            Matcher::SingleCharacter(c) => (
-                input.next().is_some_and(|ch| c.test(ch.1)),
-                Vec::new(),
-                None,
-            ),
-            Matcher::StartOfString => (
-                input.peek().is_some_and(|(idx, _)| *idx == 0),
-                Vec::new(),
-                None,
-            ),
-            Matcher::EndOfString => (input.peek().is_none(), Vec::new(), None),
-            Matcher::CaptureGroup(inner) => {
-                Self::test_group(inner, input, captured_groups, backtrack)
-            }
-            Matcher::Backreference(index) => {
-                let index = index - 0;
-                if index >= captured_groups.len() {
-                    eprintln!(
-                        "Referenced nonexistent group {index}. Captured: {captured_groups:?}"
-                    );
-                    (false, Vec::new(), None)
-                } else {
-                    eprintln!(
-                        "Backreference {index}: '{:?}' - '{:?}'",
-                        captured_groups,
-                        Iterator::take(input.clone(), captured_groups[index].len())
-                            .collect::<Vec<_>>()
-                    );
-                    (
-                        captured_groups[index]
-                            .chars()
-                            .all(|ch| input.next().is_some_and(|c| c.1 == ch)),
-                        Vec::new(),
-                        None,
-                    )
-                }
-            }
-            Matcher::Alternative => todo!("Alternatives are only supported in capture groups"),
-            Matcher::Repeat { matcher, min, max } => {
-                let mut count = 0;
-                loop {
-                    let mut input_clone = input.clone();
-                    if !matcher
-                        .test(&mut input_clone, captured_groups, backtrack.clone())
-                        .0
-                    {
-                        break;
-                    }
-                    if let Some(BacktrackInfo::Range(consumed)) = backtrack {
-                        if count >= (consumed - 1) {
-                            eprintln!(
-                                "Range limiting due to backtracking {count} >= ({consumed} - 1)"
-                            );
-                            break;
-                        }
-                    }
-                    std::mem::swap(input, &mut input_clone);
-                    count += 1;
-                    if let Some(max) = max {
-                        if count == *max {
-                            eprintln!("Range limiting due to max {count} == {max}");
-                            break;
-                        }
-                    }
-                }
+    /// Matches `matcher` as a single character, in any case with
+    /// [`Flags::CASE_INSENSITIVE`], or as a whole grapheme cluster with
+    /// [`Flags::GRAPHEMES`] if it can match characters that start one. Caseless
+    /// literals are folded with [`CaseFold::Full`] and [`CaseFold::Turkic`].
+    pub(crate) fn character(
+        matcher: SingleCharacterMatcher,
+        flags: Flags,
+        case_fold: CaseFold,
+    ) -> Self {
+        let matcher = match (matcher, flags.contains(Flags::CASE_INSENSITIVE)) {
+            (SingleCharacterMatcher::Literal(ch), true)
+                if matches!(case_fold, CaseFold::Full | CaseFold::Turkic) =>
+            {
+                let turkic = case_fold == CaseFold::Turkic;
+                let text = match unicode::case_fold(ch, turkic) {
+                    Some(folded) => String::from(folded),
+                    None => String::from(ch),
+                };
+                return Self::Folded { text, turkic };
+            }
+            (matcher, true) => matcher.caseless(case_fold == CaseFold::Ascii),
+            (matcher, false) => matcher,
+        };
+        let any = matches!(
+            matcher,
+            SingleCharacterMatcher::Any
+                | SingleCharacterMatcher::AnyButNewline
+                | SingleCharacterMatcher::NegativeGroup(_)
+        );
+        match any && flags.contains(Flags::GRAPHEMES) {
+            true => Self::Grapheme(matcher),
+            false => Self::SingleCharacter(matcher),
+        }
+    }
+
+    /// Parses the `?<name>` or `?P<name>` of a named group, whose `(` was at
+    /// `position`, if there is one.
+    fn new_group_name(
+        input: &mut Input<'_>,
+        groups: &[GroupInfo],
+        position: usize,
+    ) -> Result<Option<String>> {
+        let rest = input.rest();
+        let Some(after) = rest.strip_prefix("?P<").or_else(|| rest.strip_prefix("?<")) else {
+            return Ok(None);
+        };
+        if after.starts_with(['=', '!']) {
+            return Ok(None); // A lookbehind
+        }
+
+        let name = after.find('>').map(|end| &after[..end]).ok_or_else(|| {
+            Error::syntax(
+                "Unterminated group name",
+                position,
+                "close the name with `>`",
+            )
+        })?;
+        Self::check_group_name(name, groups, position)?;
+        input.position += rest.len() - after.len() + name.len() + 1;
+        Ok(Some(String::from(name)))
+    }
+
+    /// Checks that `name` can name a new group, which starts at `position`.
+    fn check_group_name(name: &str, groups: &[GroupInfo], position: usize) -> Result<()> {
+        let mut chars = name.chars();
+        let valid = chars
+            .next()
+            .is_some_and(|ch| ch.is_ascii_alphabetic() || ch == '_')
+            && chars.all(|ch| ch.is_ascii_alphanumeric() || ch == '_');
+        if !valid {
+            return Err(Error::syntax(
+                "Invalid group name",
+                position,
+                "use ASCII letters, digits and `_`, not starting with a digit",
+            ));
+        }
+        if groups.iter().any(|group| group.name() == Some(name)) {
+            return Err(Error::syntax(
+                format!("Duplicate group name `{name}`"),
+                position,
+                "give each group its own name",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Adds a capture group starting at `position` to `groups`, nested in the
+    /// innermost group still open, and returns its index.
+    fn open_group(groups: &mut Vec<GroupInfo>, name: Option<String>, position: usize) -> usize {
+        let index = groups.len() + 1;
+        let parent = groups
+            .iter()
+            .rev()
+            .find(|group| group.span.end == usize::MAX)
+            .map_or(0, |group| group.index);
+        groups.push(GroupInfo {
+            index,
+            name,
+            // The end is set once the group is closed
+            span: position..usize::MAX,
+            parent,
+        });
+        index
+    }
+
+    /// The index of the group called `name`, referred to at `position`.
+    fn group_index(groups: &[GroupInfo], name: &str, position: usize) -> Result<usize> {
+        let group = groups.iter().find(|group| group.name() == Some(name));
+        group.map(|group| group.index).ok_or_else(|| {
+            Error::syntax(
+                format!("Unknown group name `{name}`"),
+                position,
+                "name a group before referring to it, e.g. `(?<name>...)`",
+            )
+        })
+    }
+
+    /// Makes a lookbehind, which started at `position`, checking that it has a
+    /// fixed width.
+    fn new_lookbehind(negative: bool, inner: Vec<Self>, position: usize) -> Result<Self> {
+        let widths = Self::alternative_widths(&inner).ok_or_else(|| {
+            Error::syntax(
+                "Lookbehind must have a fixed width",
+                position,
+                "use counted repetitions like `a{3}` instead of `*`, `+` and `?`, \
+                 and no backreferences",
+            )
+        })?;
+        Ok(Self::Lookbehind {
+            negative,
+            inner,
+            widths,
+        })
+    }
+
+    /// Parses the name of a backreference at `position`, after its `\k<` or `(?P=`,
+    /// up to and including `close`, and resolves it to the group's index.
+    fn new_named_backreference(
+        input: &mut Input<'_>,
+        groups: &[GroupInfo],
+        position: usize,
+        close: char,
+    ) -> Result<Self> {
+        let rest = input.rest();
+        let name = rest.find(close).map(|end| &rest[..end]).ok_or_else(|| {
+            Error::syntax(
+                "Unterminated backreference",
+                position,
+                format!("close the group name with `{close}`"),
+            )
+        })?;
+        let index = Self::group_index(groups, name, position)?;
+        input.position += name.len() + close.len_utf8();
+        Self::reject_repeat(input, "a backreference")?;
+        Ok(Self::Backreference {
+            index,
+            caseless: input.flags.contains(Flags::CASE_INSENSITIVE),
+            ascii: input.builder.case_fold == CaseFold::Ascii,
+        })
+    }
+
+    /// Parses the flags at the start of `rest`, which follows the `(` at `position`,
+    /// up to and including the `)` of `(?flags)` or the `:` of `(?flags:...)`. Returns
+    /// `flags` changed by them, their length and whether they are for a group.
+    fn new_flags(rest: &str, mut flags: Flags, position: usize) -> Result<(Flags, usize, bool)> {
+        let mut enable = true;
+        for (offset, ch) in rest.char_indices().skip(1) {
+            let flag = match ch {
+                ':' | ')' if offset > 1 => return Ok((flags, offset + 1, ch == ':')),
+                ')' => {
+                    return Err(Error::syntax(
+                        "Missing group flags",
+                        position,
+                        "write flags like `(?i)`, or escape the `?` as `\\?` to match it literally",
+                    ))
+                }
+                '-' if enable => {
+                    enable = false;
+                    continue;
+                }
+                'i' => Flags::CASE_INSENSITIVE,
+                'm' => Flags::MULTI_LINE,
+                's' => Flags::DOT_ALL,
+                'u' => Flags::UNICODE,
+                'g' => Flags::GRAPHEMES,
+                _ => {
+                    return Err(Error::syntax(
+                        format!("Unknown group flag `{ch}`"),
+                        position,
+                        "use the flags `i`, `m`, `s`, `u` and `g`, or escape the `?` as `\\?` to match it literally",
+                    ))
+                }
+            };
+            flags.set(flag, enable);
+        }
+        Err(Error::syntax(
+            "Unterminated group",
+            position,
+            "close it with `)` or escape the `(` as `\\(`",
+        ))
+    }
+
+    /// Parses flags for the rest of the group, like `(?i)`, if they are next.
+    fn set_flags(input: &mut Input<'_>) -> Result<bool> {
+        let rest = input.rest();
+        let starts_flags = rest
+            .strip_prefix("(?")
+            .is_some_and(|r| r.starts_with(['i', 'm', 's', 'u', 'g', '-']));
+        if !starts_flags {
+            return Ok(false);
+        }
+        let (flags, len, scoped) = Self::new_flags(&rest[1..], input.flags, input.offset())?;
+        if scoped {
+            return Ok(false);
+        }
+        input.flags = flags;
+        input.position += len + 1;
+        Ok(true)
+    }
+
+    /// Parses what is next if it isn't a matcher of its own, but flags for the rest
+    /// of the group or quoted text, whose literals are pushed to `matchers`.
+    fn parse_inline(input: &mut Input<'_>, matchers: &mut Vec<Self>) -> Result<bool> {
+        Ok(Self::set_flags(input)? || Self::push_quoted(input, matchers)?)
+    }
+
+    /// Parses `\Q...\E`, if it is next: the text up to `\E`, or to the end of the
+    /// pattern, matched literally. A quantifier after it repeats its last character,
+    /// like after any other run of literals.
+    fn push_quoted(input: &mut Input<'_>, matchers: &mut Vec<Self>) -> Result<bool> {
+        if !input.rest().starts_with("\\Q") {
+            return Ok(false);
+        }
+        input.position += 2;
+        let start = input.offset();
+        let rest = input.rest();
+        let (text, len) = match rest.find("\\E") {
+            Some(end) => (&rest[..end], end + 2),
+            None => (rest, rest.len()),
+        };
+        input.position += len;
+        for (offset, ch) in text.char_indices() {
+            input.size += 1;
+            let limit = input.builder.size_limit;
+            Input::check_limit(Limit::Size, limit, input.size, start + offset)?;
+            let literal = SingleCharacterMatcher::new_literal(ch);
+            matchers.push(Self::character(
+                literal,
+                input.flags,
+                input.builder.case_fold,
+            ));
+        }
+        if !text.is_empty() {
+            let last = matchers.pop().expect("Pushed above");
+            matchers.push(Self::maybe_repeat(input, last)?);
+        }
+        Ok(true)
+    }
+
+    /// Parses the matchers of a group, whose `(` was at `position`, up to and
+    /// including its `)`.
+    fn new_group_body(
+        input: &mut Input<'_>,
+        groups: &mut Vec<GroupInfo>,
+        position: usize,
+    ) -> Result<Vec<Self>> {
+        input.depth += 1;
+        let limit = Some(input.builder.nest_limit);
+        Input::check_limit(Limit::Nesting, limit, input.depth, position)?;
+        let flags = input.flags;
+        let mut branches = Vec::new();
+        let mut matchers = Vec::new();
+        loop {
+            match input.peek() {
+                Some(')') => {
+                    input.next();
+                    input.depth -= 1;
+                    input.flags = flags;
+                    branches.push(matchers);
+                    return Ok(Self::sequence(branches));
+                }
+                Some('|') => {
+                    input.next();
+                    branches.push(mem::take(&mut matchers));
+                }
+                Some(_) if Self::parse_inline(input, &mut matchers)? => {}
+                Some(_) => matchers.push(Matcher::new(input, groups)?),
+                None => {
+                    return Err(Error::syntax(
+                        "Unterminated group",
+                        position,
+                        "close it with `)` or escape the `(` as `\\(`",
+                    ))
+                }
+            }
+        }
+    }
+
+    /// The matchers of a group, or of the whole pattern, made of `branches`: those of
+    /// the only branch, or else an [`Matcher::Alternation`] of them all.
+    fn sequence(mut branches: Vec<Vec<Self>>) -> Vec<Self> {
+        branches.iter_mut().for_each(Self::join_folded);
+        match branches.len() {
+            1 => branches.pop().expect("Checked to have a branch"),
+            _ => vec![Self::Alternation(branches)],
+        }
+    }
+
+    /// Joins the consecutive [`Matcher::Folded`] runs of `matchers`, so that a
+    /// character folding to several, like `ß` to `ss`, matches across literals.
+    fn join_folded(matchers: &mut Vec<Self>) {
+        matchers.dedup_by(|next, joined| match (next, joined) {
+            (
+                Self::Folded { text: more, turkic },
+                Self::Folded {
+                    text,
+                    turkic: joined_turkic,
+                },
+            ) if turkic == joined_turkic => {
+                text.push_str(more);
+                true
+            }
+            _ => false,
+        });
+    }
+
+    /// The branches of the matchers of a group, `inner` itself if it has only one.
+    fn branches(inner: &[Self]) -> impl Iterator<Item = &[Self]> {
+        let (options, single) = match inner {
+            [Self::Alternation(options)] => (options.as_slice(), None),
+            _ => (&[][..], Some(inner)),
+        };
+        options.iter().map(Vec::as_slice).chain(single)
+    }
+
+    /// Wraps `matcher` in a repetition if a quantifier follows it.
+    fn maybe_repeat(input: &mut Input<'_>, matcher: Self) -> Result<Self> {
+        let position = input.offset();
+        let (min, max) = match input.peek() {
+            Some('+') => (Some(1), None),
+            Some('*') => (None, None),
+            Some('?') => (None, Some(1)),
+            Some('{') => match counted_repetition(input.rest(), input.offset())? {
+                Some((bounds, len)) => {
+                    input.position += len - 1;
+                    bounds
+                }
+                None => return Ok(matcher),
+            },
+            _ => return Ok(matcher),
+        };
+        input.next(); // Consume the quantifier, or the closing "}"
+        let possessive = input.peek() == Some('+');
+        if possessive {
+            input.next();
+        }
+        let warnings = &mut input.warnings;
+        Ok(Self::new_repeat(
+            matcher,
+            (min, max),
+            possessive,
+            position,
+            warnings,
+        ))
+    }
+
+    /// Wraps `matcher` in a repetition, whose quantifier is at `position`.
+    fn new_repeat(
+        matcher: Self,
+        (min, max): Bounds,
+        possessive: bool,
+        position: usize,
+        warnings: &mut Vec<Warning>,
+    ) -> Self {
+        if max.is_none() && !possessive && matcher.has_unbounded_repeat() {
+            warnings.push(Warning::new(
+                WarningKind::NestedRepetition,
+                "Nested unbounded repetition",
+                position,
+                "this can take exponential time on input that almost matches, \
+                 bound or remove one of the repetitions",
+            ));
+        }
+        Self::Repeat {
+            matcher: Box::new(matcher),
+            min,
+            max,
+            possessive,
+        }
+    }
+
+    /// Whether this is, or contains, a repetition without an upper bound.
+    fn has_unbounded_repeat(&self) -> bool {
+        match self {
+            Self::Repeat { max: None, .. } => true,
+            Self::Repeat { matcher, .. } => matcher.has_unbounded_repeat(),
+            Self::Group(inner) | Self::CaptureGroup(_, inner) => {
+                inner.iter().any(Self::has_unbounded_repeat)
+            }
+            Self::Alternation(options) => options.iter().flatten().any(Self::has_unbounded_repeat),
+            _ => false,
+        }
+    }
+
+    /// The number of characters every match of this matcher has, if that is fixed.
+    fn fixed_width(&self) -> Option<usize> {
+        match self {
+            Self::SingleCharacter(_) => Some(1),
+            Self::Repeat {
+                matcher, min, max, ..
+            } if Some(min.unwrap_or(0)) == *max => Some(matcher.fixed_width()? * min.unwrap_or(0)),
+            Self::Repeat { .. } | Self::Backreference { .. } | Self::Grapheme(_) => None,
+            // A character folding to several matches them all at once
+            Self::Folded { text, .. } => {
+                (!unicode::has_expansion(text)).then(|| text.chars().count())
+            }
+            Self::Group(inner) | Self::CaptureGroup(_, inner) | Self::Atomic(inner) => {
+                inner.iter().map(Self::fixed_width).sum()
+            }
+            Self::Alternation(_) => {
+                let widths = Self::alternative_widths(std::slice::from_ref(self))?;
+                let first = widths[0];
+                widths.iter().all(|&w| w == first).then_some(first)
+            }
+            Self::StartOfString
+            | Self::EndOfString
+            | Self::StartOfLine
+            | Self::EndOfLine
+            | Self::StartOfText
+            | Self::EndOfText
+            | Self::EndOfTextOrNewline
+            | Self::Lookahead { .. }
+            | Self::Lookbehind { .. } => Some(0),
+        }
+    }
+
+    /// The fixed width of each branch of a group, see [`Matcher::fixed_width`].
+    fn alternative_widths(inner: &[Self]) -> Option<Vec<usize>> {
+        Self::branches(inner)
+            .map(|option| option.iter().map(Self::fixed_width).sum())
+            .collect()
+    }
+
+    /// Adds the indices of the groups that `matchers` refer back to to `found`.
+    fn backreferences(matchers: &[Self], found: &mut Vec<usize>) {
+        for matcher in matchers {
+            match matcher {
+                Self::Backreference { index, .. } => found.push(*index),
+                Self::Repeat { matcher, .. } => {
+                    Self::backreferences(std::slice::from_ref(matcher), found)
+                }
+                Self::Group(inner)
+                | Self::CaptureGroup(_, inner)
+                | Self::Atomic(inner)
+                | Self::Lookahead { inner, .. }
+                | Self::Lookbehind { inner, .. } => Self::backreferences(inner, found),
+                Self::Alternation(options) => {
+                    options.iter().for_each(|o| Self::backreferences(o, found))
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Fails if a quantifier follows an item that can't be repeated.
+    fn reject_repeat(input: &Input<'_>, what: &str) -> Result<()> {
+        let rest = input.rest();
+        match input.peek() {
+            Some('{') if starts_counted_repetition(rest) => Err(Error::syntax(
+                format!("Repeating {what} is not supported"),
+                input.offset(),
+                "repeat it by hand, or escape the brace as `\\{` to match it literally",
+            )),
+            Some(ch @ ('*' | '+' | '?')) => Err(Error::syntax(
+                format!("Repeating {what} is not supported"),
+                input.offset(),
+                format!(
+                    "repeat it by hand, or escape the quantifier as `\\{ch}` to match it literally"
+                ),
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Tries to match at byte offset `at`, calling `next` with the end offset of every
+    /// way this matcher can match, most preferred first, until `next` accepts one.
+    ///
+    /// Captures recorded in `state` are left untouched when this returns `false`.
+    fn test<'h>(&self, state: &mut State<'h>, at: usize, next: &mut Next<'_, 'h>) -> bool {
+        if !state.step() {
+            return false;
+        }
+        if state.tracer.is_none() && state.counters.is_none() {
+            return self.test_step(state, at, next);
+        }
+
+        let depth = state.depth;
+        state.trace(TraceKind::Try, || self.describe(), at..at);
+        state.depth = depth + 1;
+        let matched = self.test_step(state, at, &mut |state, end| {
+            let inner_depth = state.depth;
+            state.depth = depth;
+            state.trace(TraceKind::Match, || self.describe(), at..end);
+            if next(state, end) {
+                return true;
+            }
+            state.trace(TraceKind::Backtrack, || self.describe(), at..end);
+            if let Some(counters) = &mut state.counters {
+                counters.backtracks += 1;
+            }
+            state.depth = inner_depth;
+            false
+        });
+        state.depth = depth;
+        if !matched {
+            state.trace(TraceKind::Fail, || self.describe(), at..at);
+        }
+        matched
+    }
+
+    fn test_step<'h>(&self, state: &mut State<'h>, at: usize, next: &mut Next<'_, 'h>) -> bool {
+        match self {
+            Matcher::SingleCharacter(c) => match state.char_at(at) {
+                Some(ch) if c.test(ch) => next(state, at + ch.len_utf8()),
+                Some(_) => false,
+                None => {
+                    state.note_end(at);
+                    false
+                }
+            },
+            Matcher::Grapheme(c) => match state.char_at(at) {
+                Some(ch) if c.test(ch) => {
+                    let end = unicode::grapheme_end(state.haystack, at);
+                    if end == state.haystack.len() {
+                        state.note_end(end);
+                    }
+                    // `\r\n` is a single cluster, that only matchers of `\n` match
+                    let newline = state.haystack[at..end].contains('\n');
+                    (!newline || c.test('\n')) && next(state, end)
+                }
+                Some(_) => false,
+                None => {
+                    state.note_end(at);
+                    false
+                }
+            },
+            Matcher::Folded { text, turkic } => {
+                let mut rest = text.as_str();
+                let mut end = at;
+                while !rest.is_empty() {
+                    let Some(ch) = state.char_at(end) else {
+                        state.note_end(end);
+                        return false;
+                    };
+                    let mut buffer = [0; 4];
+                    let folded = match unicode::case_fold(ch, *turkic) {
+                        Some(folded) => folded,
+                        None => ch.encode_utf8(&mut buffer),
+                    };
+                    // A character folding to several only matches as a whole
+                    match rest.strip_prefix(folded) {
+                        Some(after) => rest = after,
+                        None => return false,
+                    }
+                    end += ch.len_utf8();
+                }
+                next(state, end)
+            }
+            Matcher::StartOfString => at == state.start && next(state, at),
+            Matcher::EndOfString => at == state.haystack.len() && next(state, at),
+            Matcher::StartOfLine => {
+                (at == state.start || state.haystack[..at].ends_with('\n')) && next(state, at)
+            }
+            Matcher::EndOfLine => {
+                (at == state.haystack.len() || state.haystack[at..].starts_with('\n'))
+                    && next(state, at)
+            }
+            Matcher::StartOfText => at == 0 && next(state, at),
+            Matcher::EndOfText => at == state.input_len && next(state, at),
+            Matcher::EndOfTextOrNewline => {
+                // A split haystack ends at a `\n` when it ends before the searched text
+                let newline = || {
+                    state
+                        .haystack
+                        .as_bytes()
+                        .get(at)
+                        .is_none_or(|b| *b == b'\n')
+                };
+                (at == state.input_len || (at + 1 == state.input_len && newline()))
+                    && next(state, at)
+            }
+            Matcher::CaptureGroup(index, inner) => Self::test_group(*index, inner, state, at, next),
+            Matcher::Group(inner) => Self::test_sequence(inner, state, at, next),
+            Matcher::Atomic(inner) => {
+                Self::test_committed(Self::has_groups(inner), state, next, |state, commit| {
+                    Self::test_sequence(inner, state, at, commit)
+                })
+            }
+            Matcher::Alternation(options) => options
+                .iter()
+                .any(|option| Self::test_sequence(option, state, at, next)),
+            Matcher::Lookahead { negative, inner } => {
+                let found = Self::test_sequence(inner, state, at, &mut |_, _| true);
+                Self::clear_groups(inner, state);
+                found != *negative && next(state, at)
+            }
+            Matcher::Lookbehind {
+                negative,
+                inner,
+                widths,
+            } => {
+                let found = Self::branches(inner).zip(widths).any(|(option, &width)| {
+                    let Some(start) = state.chars_before(at, width) else {
+                        return false;
+                    };
+                    Self::test_sequence(option, state, start, &mut |_, end| end == at)
+                });
+                Self::clear_groups(inner, state);
+                found != *negative && next(state, at)
+            }
+            Matcher::Backreference {
+                index,
+                caseless: true,
+                ascii,
+            } => {
+                let Some(Some((start, end))) = state.captures.get(*index).copied() else {
+                    return false;
+                };
+                let haystack = state.haystack;
+                let mut rest = haystack[at..].char_indices();
+                for expected in haystack[start..end].chars() {
+                    match rest.next() {
+                        Some((_, ch)) if *ascii && ch.eq_ignore_ascii_case(&expected) => {}
+                        Some((_, ch)) if !*ascii && same_caseless(ch, expected) => {}
+                        Some(_) => return false,
+                        None => {
+                            state.note_end(haystack.len());
+                            return false;
+                        }
+                    }
+                }
+                let end = rest
+                    .next()
+                    .map_or(haystack.len(), |(offset, _)| at + offset);
+                next(state, end)
+            }
+            Matcher::Backreference { index, .. } => {
+                let Some(Some((start, end))) = state.captures.get(*index).copied() else {
+                    return false;
+                };
+                let haystack = state.haystack;
+                if end - start > haystack.len() - at
+                    && haystack[start..end].starts_with(&haystack[at..])
+                {
+                    state.note_end(haystack.len());
+                }
+                haystack[at..].starts_with(&haystack[start..end]) && next(state, at + (end - start))
+            }
+            Matcher::Repeat {
+                matcher,
+                min,
+                max,
+                possessive,
+            } => {
+                let min = min.unwrap_or(0);
+                if *possessive {
+                    Self::test_possessive(matcher, min, *max, state, at, next)
+                } else if let Matcher::SingleCharacter(c) = matcher.as_ref() {
+                    Self::test_repeat_single(c, min, *max, state, at, next)
+                } else {
+                    Self::test_repeat(matcher, min, *max, state, at, next)
+                }
+            }
+        }
+    }
+
+    /// Matches `matcher` as often as it can, up to `max` times, then continues from
+    /// there only: fewer repetitions are never tried.
+    fn test_possessive<'h>(
+        matcher: &Self,
+        min: usize,
+        max: Option<usize>,
+        state: &mut State<'h>,
+        at: usize,
+        next: &mut Next<'_, 'h>,
+    ) -> bool {
+        let has_groups = Self::has_groups(std::slice::from_ref(matcher));
+        Self::test_committed(has_groups, state, next, |state, commit| match matcher {
+            Matcher::SingleCharacter(c) => Self::test_repeat_single(c, min, max, state, at, commit),
+            matcher => Self::test_repeat(matcher, min, max, state, at, commit),
+        })
+    }
+
+    /// Continues from where `attempt` first reaches its continuation only, it is
+    /// never retried. What groups captured in it is undone if the rest fails.
+    fn test_committed<'h>(
+        has_groups: bool,
+        state: &mut State<'h>,
+        next: &mut Next<'_, 'h>,
+        attempt: impl FnOnce(&mut State<'h>, &mut Next<'_, 'h>) -> bool,
+    ) -> bool {
+        let saved = has_groups.then(|| state.captures.clone());
+        let mut end = None;
+        let mut commit = |_: &mut State<'h>, at| {
+            end = Some(at);
+            true
+        };
+        if attempt(state, &mut commit) && next(state, end.expect("Set by the successful match")) {
+            return true;
+        }
+        if let Some(saved) = saved {
+            state.captures = saved;
+        }
+        false
+    }
+
+    /// Forgets what the groups in `matchers` captured. Used after lookarounds, whose
+    /// groups were unset before, so that nothing has to be saved up front.
+    fn clear_groups(matchers: &[Self], state: &mut State<'_>) {
+        for matcher in matchers {
+            match matcher {
+                Self::CaptureGroup(index, inner) => {
+                    state.captures[*index] = None;
+                    Self::clear_groups(inner, state);
+                }
+                Self::Repeat { matcher, .. } => {
+                    Self::clear_groups(std::slice::from_ref(matcher), state)
+                }
+                Self::Group(inner)
+                | Self::Atomic(inner)
+                | Self::Lookahead { inner, .. }
+                | Self::Lookbehind { inner, .. } => Self::clear_groups(inner, state),
+                Self::Alternation(options) => {
+                    options.iter().for_each(|o| Self::clear_groups(o, state))
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Whether `matchers` contain a capturing group.
+    fn has_groups(matchers: &[Self]) -> bool {
+        matchers.iter().any(|matcher| match matcher {
+            Self::CaptureGroup(..) => true,
+            Self::Repeat { matcher, .. } => Self::has_groups(std::slice::from_ref(matcher)),
+            Self::Group(inner)
+            | Self::Atomic(inner)
+            | Self::Lookahead { inner, .. }
+            | Self::Lookbehind { inner, .. } => Self::has_groups(inner),
+            Self::Alternation(options) => options.iter().any(|o| Self::has_groups(o)),
+            _ => false,
+        })
+    }
+
+    /// Matches `inner` as group number `index`.
+    fn test_group<'h>(
+        index: usize,
+        inner: &[Self],
+        state: &mut State<'h>,
+        at: usize,
+        next: &mut Next<'_, 'h>,
+    ) -> bool {
+        Self::test_sequence(inner, state, at, &mut |state, end| {
+            let previous = state.captures[index].replace((at, end));
+            if next(state, end) {
+                return true;
+            }
+            state.captures[index] = previous;
+            false
+        })
+    }
+
+    fn test_sequence<'h>(
+        matchers: &[Self],
+        state: &mut State<'h>,
+        at: usize,
+        next: &mut Next<'_, 'h>,
+    ) -> bool {
+        match matchers.split_first() {
+            Some((first, rest)) => first.test(state, at, &mut |state, end| {
+                Self::test_sequence(rest, state, end, next)
+            }),
+            None => next(state, at),
+        }
+    }
+
+    /// Greedy repetition of a single character: consumes as many characters as allowed
+    /// up front, then gives them back one at a time.
+    fn test_repeat_single<'h>(
+        matcher: &SingleCharacterMatcher,
+        min: usize,
+        max: Option<usize>,
+        state: &mut State<'h>,
+        at: usize,
+        next: &mut Next<'_, 'h>,
+    ) -> bool {
+        let mut count = 0;
+        let mut end = at;
+        for ch in state.haystack[at..].chars() {
+            if max.is_some_and(|max| count >= max) || !matcher.test(ch) {
+                break;
+            }
+            count += 1;
+            end += ch.len_utf8();
+        }
+        if end == state.haystack.len() && max.is_none_or(|max| count < max) {
+            state.note_end(end);
+        }
+        if count < min {
+            return false;
+        }
+
+        loop {
+            if next(state, end) {
+                return true;
+            }
+            if count == min {
+                return false;
+            }
+            let ch = state.haystack[..end].chars().next_back();
+            end -= ch.expect("Consumed by the repetition").len_utf8();
+            count -= 1;
+        }
+    }
+
+    /// Greedy repetition of any other matcher. Iterations are tried depth first, as
+    /// nested calls would, but they are kept on a stack of their own so that a long
+    /// line of iterations can't overflow the call stack.
+    ///
+    /// An iteration that matched the empty string, once `min` is reached, ends the
+    /// repetition right there: more of them couldn't lead anywhere new.
+    fn test_repeat<'h>(
+        matcher: &Self,
+        min: usize,
+        max: Option<usize>,
+        state: &mut State<'h>,
+        at: usize,
+        next: &mut Next<'_, 'h>,
+    ) -> bool {
+        let has_groups = Self::has_groups(std::slice::from_ref(matcher));
+        let mut stack = vec![Iteration::new(matcher, max, 0, has_groups, state, at)];
+        while let Some(count) = stack.len().checked_sub(1) {
+            let top = &mut stack[count];
+            match top.ends.next() {
+                Some((end, captures)) => {
+                    if let Some(captures) = captures {
+                        state.captures = captures;
+                    }
+                    if end != top.at || count + 1 < min {
+                        let iteration =
+                            Iteration::new(matcher, max, count + 1, has_groups, state, end);
+                        stack.push(iteration);
+                    } else if next(state, end) {
+                        return true;
+                    }
+                }
+                None => {
+                    let Iteration { at, captures, .. } = stack.pop().expect("Checked to be there");
+                    if let Some(captures) = captures {
+                        state.captures = captures;
+                    }
+                    if count >= min && next(state, at) {
+                        return true;
+                    }
+                }
+            }
+        }
+        false
+    }
+
+    /// Adds the bytes a match of this matcher can start with to `set`, returning
+    /// whether it can also match without consuming anything.
+    fn first_bytes(&self, set: &mut ByteSet) -> bool {
+        match self {
+            Matcher::SingleCharacter(c) | Matcher::Grapheme(c) => {
+                c.first_bytes(set);
+                false
+            }
+            Matcher::Folded { text, .. } => {
+                let first = text.chars().next().expect("Folded text is never empty");
+                set.insert_char(first);
+                unicode::folding_to(first).for_each(|ch| set.insert_char(ch));
+                false
+            }
+            Matcher::Repeat { matcher, min, .. } => {
+                matcher.first_bytes(set) || min.unwrap_or(0) == 0
+            }
+            Matcher::CaptureGroup(_, inner) | Matcher::Group(inner) | Matcher::Atomic(inner) => {
+                Self::sequence_first_bytes(inner, set)
+            }
+            Matcher::Alternation(options) => {
+                // Every branch adds its bytes, so no short-circuiting
+                let mut nullable = false;
+                for option in options {
+                    nullable |= Self::sequence_first_bytes(option, set);
+                }
+                nullable
+            }
+            Matcher::Backreference { .. } => {
+                set.insert_all_leading();
+                true
+            }
+            Matcher::StartOfString
+            | Matcher::EndOfString
+            | Matcher::StartOfLine
+            | Matcher::EndOfLine
+            | Matcher::StartOfText
+            | Matcher::EndOfText
+            | Matcher::EndOfTextOrNewline
+            | Matcher::Lookahead { .. }
+            | Matcher::Lookbehind { .. } => true,
+        }
+    }
+
+    /// Like [`Matcher::first_bytes`] for the matchers of a group.
+    fn sequence_first_bytes(matchers: &[Self], set: &mut ByteSet) -> bool {
+        // `all` stops at the first matcher that has to consume something
+        matchers.iter().all(|matcher| matcher.first_bytes(set))
+    }
+}
+
+impl Matcher {
+    /// One-line description of the matcher, without the matchers nested in it.
+    fn describe(&self) -> String {
+        match self {
+            Matcher::SingleCharacter(c) => c.describe(),
+            Matcher::Grapheme(_) => String::from("grapheme cluster"),
+            Matcher::Folded {
+                text,
+                turkic: false,
+            } => format!("case-folded {text:?}"),
+            Matcher::Folded { text, turkic: true } => format!("Turkic case-folded {text:?}"),
+            Matcher::StartOfString => String::from("start of string"),
+            Matcher::EndOfString => String::from("end of string"),
+            Matcher::StartOfLine => String::from("start of line"),
+            Matcher::EndOfLine => String::from("end of line"),
+            Matcher::StartOfText => String::from("start of text"),
+            Matcher::EndOfText => String::from("end of text"),
+            Matcher::EndOfTextOrNewline => String::from("end of text or before its final newline"),
+            Matcher::Backreference {
+                index,
+                caseless: false,
+                ..
+            } => format!("backreference \\{index}"),
+            Matcher::Backreference {
+                index,
+                caseless: true,
+                ascii: false,
+            } => format!("case-insensitive backreference \\{index}"),
+            Matcher::Backreference {
+                index,
+                caseless: true,
+                ascii: true,
+            } => format!("ASCII case-insensitive backreference \\{index}"),
+            Matcher::Alternation(_) => String::from("alternation"),
+            Matcher::CaptureGroup(index, _) => format!("group {index}"),
+            Matcher::Group(_) => String::from("non-capturing group"),
+            Matcher::Atomic(_) => String::from("atomic group"),
+            Matcher::Lookahead {
+                negative: false, ..
+            } => String::from("lookahead"),
+            Matcher::Lookahead { negative: true, .. } => String::from("negative lookahead"),
+            Matcher::Lookbehind {
+                negative: false, ..
+            } => String::from("lookbehind"),
+            Matcher::Lookbehind { negative: true, .. } => String::from("negative lookbehind"),
+            Matcher::Repeat {
+                min,
+                max,
+                possessive,
+                ..
+            } => {
+                let kind = if *possessive {
+                    "possessive repeat"
+                } else {
+                    "repeat"
+                };
+                match max {
+                    Some(max) => format!("{kind} {{{},{max}}}", min.unwrap_or(0)),
+                    None => format!("{kind} {{{},}}", min.unwrap_or(0)),
+                }
+            }
+        }
+    }
+
+    fn write_tree(&self, f: &mut fmt::Formatter<'_>, depth: usize) -> fmt::Result {
+        let indent = depth * 2;
+        match self {
+            Matcher::SingleCharacter(c) => c.write_tree(f, depth),
+            Matcher::CaptureGroup(_, inner)
+            | Matcher::Group(inner)
+            | Matcher::Atomic(inner)
+            | Matcher::Lookahead { inner, .. }
+            | Matcher::Lookbehind { inner, .. } => {
+                writeln!(f, "{:indent$}{}", "", self.describe())?;
+                Self::write_group_tree(inner, f, depth + 1)
+            }
+            Matcher::Repeat { matcher, .. } => {
+                writeln!(f, "{:indent$}{}", "", self.describe())?;
+                matcher.write_tree(f, depth + 1)
+            }
+            Matcher::Grapheme(c) => {
+                writeln!(f, "{:indent$}{}", "", self.describe())?;
+                c.write_tree(f, depth + 1)
+            }
+            // The branches are listed right in the group they are the only matcher of
+            Matcher::Alternation(options) => options.iter().try_for_each(|option| {
+                writeln!(f, "{:indent$}branch", "")?;
+                Self::write_group_tree(option, f, depth + 1)
+            }),
+            _ => writeln!(f, "{:indent$}{}", "", self.describe()),
+        }
+    }
+
+    fn write_group_tree(inner: &[Self], f: &mut fmt::Formatter<'_>, depth: usize) -> fmt::Result {
+        inner.iter().try_for_each(|m| m.write_tree(f, depth))
+    }
+}
+
+/// Indented dump of a pattern's matcher tree, see [`Pattern::tree`].
+#[derive(Debug, Clone, Copy)]
+pub struct Tree<'p>(&'p Pattern);
+
+impl fmt::Display for Tree<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "group 0")?;
+        Matcher::write_group_tree(&self.0.matchers, f, 1)
+    }
+}
+
+/// A single match of a [`Pattern`] in a haystack.
+///
+/// Positions are byte offsets into the haystack, so they can be used to slice it
+/// directly. Use [`Match::char_range`] where character positions are needed instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match<'h> {
+    haystack: &'h str,
+    start: usize,
+    end: usize,
+}
+
+impl<'h> Match<'h> {
+    fn new(haystack: &'h str, start: usize, end: usize) -> Self {
+        Self {
+            haystack,
+            start,
+            end,
+        }
+    }
+
+    /// Byte offset of the first character of the match.
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// Byte offset just past the last character of the match.
+    pub fn end(&self) -> usize {
+        self.end
+    }
+
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    pub fn as_str(&self) -> &'h str {
+        &self.haystack[self.range()]
+    }
+
+    /// Renders the match as a JSON object, see the [`json`] module for the schema.
+    pub fn to_json(&self) -> String {
+        format!(
+            r#"{{"start": {}, "end": {}, "text": {}}}"#,
+            self.start,
+            self.end,
+            json::quote(self.as_str())
+        )
+    }
+
+    /// The position of the match counted in `char`s rather than bytes.
+    pub fn char_range(&self) -> Range<usize> {
+        let start = self.haystack[..self.start].chars().count();
+        start..start + self.as_str().chars().count()
+    }
+
+    /// The match together with up to `size` of the surrounding haystack, clipped to
+    /// the start and end of the haystack.
+    pub fn context(&self, size: ContextSize) -> Context<'h> {
+        let haystack = self.haystack;
+        let (start, end) = match size {
+            ContextSize::Chars(chars) => {
+                let before = haystack[..self.start].char_indices().rev().take(chars);
+                let after = haystack[self.end..].chars().take(chars);
+                (
+                    before.last().map_or(self.start, |(at, _)| at),
+                    self.end + after.map(char::len_utf8).sum::<usize>(),
+                )
+            }
+            ContextSize::Lines(lines) => {
+                let line_start = |at: usize| haystack[..at].rfind('\n').map_or(0, |i| i + 1);
+                let line_end =
+                    |at: usize| haystack[at..].find('\n').map_or(haystack.len(), |i| at + i);
+
+                let mut start = line_start(self.start);
+                for _ in 0..lines {
+                    if start == 0 {
+                        break;
+                    }
+                    start = line_start(start - 1);
+                }
+
+                // A match that ends with a line break doesn't reach into the next line
+                let last = match self.as_str().strip_suffix('\n') {
+                    Some(_) => self.end - 1,
+                    None => self.end,
+                };
+                let mut end = line_end(last).max(self.end);
+                for _ in 0..lines {
+                    if end == haystack.len() {
+                        break;
+                    }
+                    end = line_end(end + 1);
+                }
+                (start, end)
+            }
+        };
+        Context {
+            matched: *self,
+            start,
+            end,
+        }
+    }
+}
+
+/// How much of the haystack around a match [`Match::context`] takes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContextSize {
+    /// This many characters on either side of the match.
+    Chars(usize),
+    /// The whole lines the match is on, and this many more lines on either side.
+    /// Lines are separated by `\n`, which is not included at either end.
+    Lines(usize),
+}
+
+/// A match with some of the haystack around it, see [`Match::context`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Context<'h> {
+    matched: Match<'h>,
+    start: usize,
+    end: usize,
+}
+
+impl<'h> Context<'h> {
+    pub fn matched(&self) -> Match<'h> {
+        self.matched
+    }
+
+    /// The text before the match.
+    pub fn before(&self) -> &'h str {
+        &self.matched.haystack[self.start..self.matched.start]
+    }
+
+    /// The text after the match.
+    pub fn after(&self) -> &'h str {
+        &self.matched.haystack[self.matched.end..self.end]
+    }
+
+    /// Byte offsets of the whole context, match included.
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+
+    /// The whole context, match included.
+    pub fn as_str(&self) -> &'h str {
+        &self.matched.haystack[self.range()]
+    }
+}
+
+/// The groups of a single match of a [`Pattern`], see [`Pattern::captures`].
+///
+/// Group 0 is the whole match and always participates. Other groups only
+/// participate if the matching path went through them: `(a)|b` matching `b`
+/// leaves group 1 unset, which is different from a group matching the empty string.
+///
+/// Groups are numbered by their opening parentheses, left to right from 1. Every
+/// capturing group counts, named or not, wherever it is: in an alternative, a
+/// repetition or a lookaround. Non-capturing groups, lookarounds and flag groups
+/// don't. The numbers only depend on the pattern, never on the haystack or on which
+/// alternative matched, so `(a)|(b)` always has `b` in group 2.
+///
+/// In a repetition, a group keeps what it captured in the last iteration it took
+/// part in: `(?:(a)|b)+` matching `ab` leaves `a` in group 1. Groups inside a
+/// lookaround are only set while it is tested, and are unset in the result.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Captures<'h> {
+    haystack: &'h str,
+    slots: Slots,
+    /// The groups of the pattern, to look up names.
+    groups: Arc<[GroupInfo]>,
+}
+
+impl<'h> Captures<'h> {
+    /// The match of group `index`, `None` if the group didn't participate or doesn't
+    /// exist.
+    pub fn get(&self, index: usize) -> Option<Match<'h>> {
+        let (start, end) = (*self.slots.get(index)?)?;
+        Some(Match::new(self.haystack, start, end))
+    }
+
+    /// The match of the group called `name`, `None` if it didn't participate or
+    /// there is no such group.
+    pub fn name(&self, name: &str) -> Option<Match<'h>> {
+        let group = self
+            .groups
+            .iter()
+            .find(|group| group.name() == Some(name))?;
+        self.get(group.index)
+    }
+
+    /// Whether group `index` took part in the match, even if it matched nothing.
+    pub fn participated(&self, index: usize) -> bool {
+        self.get(index).is_some()
+    }
+
+    /// The whole match, group 0.
+    pub fn whole(&self) -> Match<'h> {
+        self.get(0).expect("Group 0 is set by every match")
+    }
+
+    /// Number of groups including group 0, participating or not.
+    pub fn len(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// Always `false`, there is at least group 0.
+    pub fn is_empty(&self) -> bool {
+        self.slots.is_empty()
+    }
+
+    /// Iterates over all groups in order, starting with group 0.
+    pub fn iter(&self) -> impl Iterator<Item = Option<Match<'h>>> + '_ {
+        (0..self.len()).map(|index| self.get(index))
+    }
+
+    /// Renders the whole match as a JSON object with a `groups` array, see the
+    /// [`json`] module for the schema.
+    pub fn to_json(&self) -> String {
+        let whole = self.whole();
+        let groups: Vec<String> = self
+            .iter()
+            .skip(1)
+            .map(|group| group.map_or_else(|| String::from("null"), |m| m.to_json()))
+            .collect();
+        format!(
+            r#"{{"start": {}, "end": {}, "text": {}, "groups": [{}]}}"#,
+            whole.start(),
+            whole.end(),
+            json::quote(whole.as_str()),
+            groups.join(", ")
+        )
+    }
+}
+
+/// Number of times each distinct text was matched, see [`Pattern::count_unique`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UniqueCounts {
+    counts: HashMap<String, usize>,
+}
+
+impl UniqueCounts {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Counts every match of `pattern` in `haystack`, so that counts can be
+    /// accumulated over many haystacks, like the lines of a file.
+    pub fn add_matches(&mut self, pattern: &Pattern, haystack: &str) {
+        for (start, end) in pattern.match_indices(haystack) {
+            self.add(&haystack[start..end]);
+        }
+    }
+
+    pub fn add(&mut self, text: &str) {
+        match self.counts.get_mut(text) {
+            Some(count) => *count += 1,
+            None => {
+                self.counts.insert(String::from(text), 1);
+            }
+        }
+    }
+
+    /// Number of distinct texts.
+    pub fn len(&self) -> usize {
+        self.counts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    /// The distinct texts with their counts, most frequent first and in text order
+    /// among equally frequent ones.
+    pub fn sorted(&self) -> Vec<(&str, usize)> {
+        let mut counts: Vec<_> = self
+            .counts
+            .iter()
+            .map(|(text, count)| (text.as_str(), *count))
+            .collect();
+        counts.sort_by(|(a, a_count), (b, b_count)| b_count.cmp(a_count).then(a.cmp(b)));
+        counts
+    }
+}
+
+/// Iterator over successive non-overlapping matches, see [`Pattern::find_iter`].
+///
+/// Iteration stops early if a search runs out of steps or is cancelled, see
+/// [`Pattern::with_step_limit`] and [`Pattern::with_cancellation`].
+#[derive(Debug, Clone)]
+pub struct Matches<'p, 'h> {
+    pattern: &'p Pattern,
+    haystack: &'h str,
+    at: usize,
+    last_end: Option<usize>,
+}
+
+impl<'h> Matches<'_, 'h> {
+    /// The capture offsets of the next match.
+    fn next_slots(&mut self) -> Option<Slots> {
+        loop {
+            if self.at > self.haystack.len() {
+                return None;
+            }
+
+            let captures = self.pattern.search(self.haystack, self.at).ok()??;
+            let (start, end) = captures[0].expect("Group 0 is set by every match");
+            if start == end && self.last_end == Some(end) {
+                // Don't report an empty match right where the previous one ended
+                self.at = next_char_boundary(self.haystack, end);
+                continue;
+            }
+
+            self.at = if start == end {
+                next_char_boundary(self.haystack, end)
+            } else {
+                end
+            };
+            self.last_end = Some(end);
+            return Some(captures);
+        }
+    }
+}
+
+impl<'h> Iterator for Matches<'_, 'h> {
+    type Item = Match<'h>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let captures = self.next_slots()?;
+        let (start, end) = captures[0].expect("Group 0 is set by every match");
+        Some(Match::new(self.haystack, start, end))
+    }
+}
+
+/// Iterator over the offsets of successive non-overlapping matches, see
+/// [`Pattern::match_indices`].
+pub struct MatchIndices<'p, 'h> {
+    pattern: &'p Pattern,
+    haystack: &'h str,
+    /// Reused by every search, so that iterating doesn't allocate.
+    state: State<'h>,
+    at: usize,
+    last_end: Option<usize>,
+}
+
+impl fmt::Debug for MatchIndices<'_, '_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MatchIndices")
+            .field("pattern", &self.pattern)
+            .field("haystack", &self.haystack)
+            .field("at", &self.at)
+            .field("last_end", &self.last_end)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Iterator for MatchIndices<'_, '_> {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.at <= self.haystack.len() {
+            self.state.restart(self.haystack);
+            if !self.pattern.search_in(&mut self.state, self.at).ok()? {
+                return None;
+            }
+            let (start, end) = self.state.captures[0].expect("Group 0 is set by every match");
+            // Same rules as `Matches::next_slots`
+            self.at = if start == end {
+                next_char_boundary(self.haystack, end)
+            } else {
+                end
+            };
+            if start == end && self.last_end == Some(end) {
+                continue;
+            }
+            self.last_end = Some(end);
+            return Some((start, end));
+        }
+        None
+    }
+}
+
+/// Iterator over the groups of successive non-overlapping matches, see
+/// [`Pattern::captures_iter`].
+#[derive(Debug, Clone)]
+pub struct CaptureMatches<'p, 'h>(Matches<'p, 'h>);
+
+impl<'h> Iterator for CaptureMatches<'_, 'h> {
+    type Item = Captures<'h>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let slots = self.0.next_slots()?;
+        Some(Captures {
+            haystack: self.0.haystack,
+            slots,
+            groups: self.0.pattern.groups.clone(),
+        })
+    }
+}
+
+/// Iterator over successive non-overlapping matches from the end of the haystack,
+/// see [`Pattern::rfind_iter`].
+#[derive(Debug, Clone)]
+pub struct RMatches<'p, 'h> {
+    pattern: &'p Pattern,
+    haystack: &'h str,
+    /// The last starting offset to try, `None` once iteration is done.
+    to: Option<usize>,
+    /// Matches must end at or before this, where the previous match started.
+    bound: usize,
+}
+
+impl<'h> Iterator for RMatches<'_, 'h> {
+    type Item = Match<'h>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let to = self.to.take()?;
+        let captures = self.pattern.search_back(self.haystack, to, self.bound);
+        let (start, end) = captures.ok()??[0].expect("Group 0 is set by every match");
+        self.bound = start;
+        self.to = self.haystack[..start]
+            .char_indices()
+            .next_back()
+            .map(|(at, _)| at);
+        Some(Match::new(self.haystack, start, end))
+    }
+}
+
+/// Iterator over the parts of a haystack between matches, see [`Pattern::split_str`].
+#[derive(Debug, Clone)]
+pub struct Split<'p, 'h> {
+    matches: Matches<'p, 'h>,
+    /// Where the next part starts, `None` once the last part was returned.
+    last: Option<usize>,
+}
+
+impl<'h> Iterator for Split<'_, 'h> {
+    type Item = &'h str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.last?;
+        let haystack = self.matches.haystack;
+        match self.matches.next() {
+            Some(m) => {
+                self.last = Some(m.end());
+                Some(&haystack[start..m.start()])
+            }
+            None => {
+                self.last = None;
+                Some(&haystack[start..])
+            }
+        }
+    }
+}
+
+fn next_char_boundary(haystack: &str, at: usize) -> usize {
+    haystack[at..]
+        .chars()
+        .next()
+        .map_or(at + 1, |ch| at + ch.len_utf8())
+}
+
+/// A set of byte values, see [`Pattern::first_bytes`].
+#[derive(Clone, PartialEq, Eq)]
+pub struct ByteSet([bool; 256]);
+
+impl ByteSet {
+    fn new() -> Self {
+        Self([false; 256])
+    }
+
+    fn insert(&mut self, byte: u8) {
+        self.0[usize::from(byte)] = true;
+    }
+
+    fn insert_char(&mut self, ch: char) {
+        let mut buffer = [0; 4];
+        self.insert(ch.encode_utf8(&mut buffer).as_bytes()[0]);
+    }
+
+    /// Adds every byte that can start a UTF-8 encoded character.
+    fn insert_all_leading(&mut self) {
+        (0..=0x7F)
+            .chain(0xC0..=0xFF)
+            .for_each(|byte| self.insert(byte));
+    }
+
+    pub fn contains(&self, byte: u8) -> bool {
+        self.0[usize::from(byte)]
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.iter().filter(|member| **member).count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The bytes in the set, in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = u8> + '_ {
+        (0..=u8::MAX).filter(|byte| self.contains(*byte))
+    }
+}
+
+impl fmt::Debug for ByteSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+/// A capture group as written in a pattern, see [`Pattern::groups`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GroupInfo {
+    index: usize,
+    name: Option<String>,
+    span: Range<usize>,
+    parent: usize,
+}
+
+impl GroupInfo {
+    /// The group number, as used in backreferences and [`Captures::get`].
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The name of a group written as `(?<name>...)`.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Byte offsets of the group in the pattern, parentheses included.
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+
+    /// The number of the innermost group containing this one, 0 when it is not
+    /// nested in another group.
+    pub fn parent(&self) -> usize {
+        self.parent
+    }
+}
+
+/// How searches treat haystacks made of several lines, see
+/// [`PatternBuilder::newlines`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Newlines {
+    /// `\n` is a character like any other: negated classes match it, and `^` and `$`
+    /// only match at the ends of the whole haystack, unless
+    /// [multi-line](PatternBuilder::multi_line). `.` only matches it in
+    /// [dot-all](PatternBuilder::dot_all) mode.
+    #[default]
+    Ordinary,
+    /// Haystacks are expected to be single lines, searching one that contains `\n`
+    /// panics. This catches callers that pass whole files by mistake.
+    Assert,
+    /// Every line of a haystack is searched on its own, so matches never span a
+    /// `\n` and `^` and `$` match at the ends of each line. Offsets are still into
+    /// the whole haystack.
+    Split,
+}
+
+/// How caseless literals compare, with [`Flags::CASE_INSENSITIVE`], see
+/// [`PatternBuilder::case_fold`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CaseFold {
+    /// A character matches its lowercase and uppercase forms, where those are single
+    /// characters.
+    #[default]
+    Simple,
+    /// Runs of literals match any text with the same full case folding, where a
+    /// character may fold to several: `strasse` matches `Straße`, and `ﬁ` matches
+    /// `FI`. Classes and backreferences still compare single characters.
+    Full,
+    /// Like [`CaseFold::Full`], with the special cases of Turkish and Azerbaijani:
+    /// `I` folds to the dotless `ı`, and `İ` to `i`.
+    Turkic,
+    /// Only ASCII letters match their other case, so `é` doesn't match `É`, and the
+    /// Kelvin sign doesn't match `k`.
+    Ascii,
+}
+
+/// Parses patterns with extra syntax registered up front, or with limits on their
+/// size. See [`Pattern::new`] for parsing with the defaults.
+#[derive(Debug, Clone)]
+pub struct PatternBuilder {
+    classes: HashMap<String, NamedClass>,
+    size_limit: Option<usize>,
+    nest_limit: usize,
+    class_size_limit: Option<usize>,
+    newlines: Newlines,
+    flags: Flags,
+    case_fold: CaseFold,
+}
+
+impl Default for PatternBuilder {
+    fn default() -> Self {
+        Self {
+            classes: HashMap::new(),
+            size_limit: None,
+            nest_limit: 250,
+            class_size_limit: None,
+            newlines: Newlines::Ordinary,
+            flags: Flags::default(),
+            case_fold: CaseFold::Simple,
+        }
+    }
+}
+
+impl PatternBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limits the number of matchers a pattern may parse into, roughly one per
+    /// character, group or anchor. There is no limit by default.
+    pub fn size_limit(mut self, limit: usize) -> Self {
+        self.size_limit = Some(limit);
+        self
+    }
+
+    /// Limits how deeply groups may be nested, 250 by default. Parsing and matching
+    /// recurse into nested groups, so this bounds their stack use.
+    pub fn nest_limit(mut self, limit: usize) -> Self {
+        self.nest_limit = limit;
+        self
+    }
+
+    /// Limits the number of members of a single bracket expression. There is no
+    /// limit by default.
+    pub fn class_size_limit(mut self, limit: usize) -> Self {
+        self.class_size_limit = Some(limit);
+        self
+    }
+
+    /// Sets how patterns search haystacks with several lines, by default `\n` is an
+    /// ordinary character.
+    pub fn newlines(mut self, newlines: Newlines) -> Self {
+        self.newlines = newlines;
+        self
+    }
+
+    /// Turns on `flags` for whole patterns, as if they started with `(?flags)`, so
+    /// inline flags can still turn them off.
+    pub fn flags(mut self, flags: Flags) -> Self {
+        self.flags = self.flags | flags;
+        self
+    }
+
+    /// Makes `^` and `$` also match right after and before every `\n`, so that one
+    /// pattern can search the lines of a whole file at once. Off by default, and
+    /// patterns can still switch it with `(?m)` and `(?-m)`.
+    pub fn multi_line(mut self, enabled: bool) -> Self {
+        self.flags.set(Flags::MULTI_LINE, enabled);
+        self
+    }
+
+    /// Makes `.` match `\n` too, so that matches can cross lines. Off by default, and
+    /// patterns can still switch it with `(?s)` and `(?-s)`.
+    pub fn dot_all(mut self, enabled: bool) -> Self {
+        self.flags.set(Flags::DOT_ALL, enabled);
+        self
+    }
+
+    /// Makes `\d`, `\w` and `\s` match digits, word characters and whitespace of any
+    /// script. Off by default, where they only match ASCII characters like grep, and
+    /// patterns can still switch it with `(?u)` and `(?-u)`.
+    pub fn unicode(mut self, enabled: bool) -> Self {
+        self.flags.set(Flags::UNICODE, enabled);
+        self
+    }
+
+    /// Makes `.` and negated classes match whole grapheme clusters, see
+    /// [`Flags::GRAPHEMES`]. Off by default, and patterns can still switch it with
+    /// `(?g)` and `(?-g)`.
+    pub fn graphemes(mut self, enabled: bool) -> Self {
+        self.flags.set(Flags::GRAPHEMES, enabled);
+        self
+    }
+
+    /// Sets how literals compare with [`Flags::CASE_INSENSITIVE`], by default
+    /// [`CaseFold::Simple`]. Patterns are still caseless only where the flag is on.
+    pub fn case_fold(mut self, case_fold: CaseFold) -> Self {
+        self.case_fold = case_fold;
+        self
+    }
+
+    /// Registers a class of characters, accepted by `predicate`, that patterns can
+    /// refer to as `[:name:]` inside a bracket expression or as `\p{name}`, e.g.
+    /// `[[:hexdigit:]]+`. Registering a name again replaces the earlier class. A
+    /// registered class takes precedence over the POSIX class or Unicode general
+    /// category of the same name.
+    pub fn class(
+        mut self,
+        name: impl Into<String>,
+        predicate: impl Fn(char) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        let name = name.into();
+        let class = NamedClass {
+            name: name.clone(),
+            test: Arc::new(predicate),
+        };
+        self.classes.insert(name, class);
+        self
+    }
+
+    /// Registers a class, like [`PatternBuilder::class`], made of the characters of
+    /// `members`.
+    pub fn class_set(self, name: impl Into<String>, members: &str) -> Self {
+        let members: Vec<char> = members.chars().collect();
+        self.class(name, move |ch| members.contains(&ch))
+    }
+
+    pub fn build(&self, pattern: &str) -> Result<Pattern> {
+        Ok(self.compile(&self.parse(pattern)?))
+    }
+
+    /// Parses `pattern` without compiling it, so that it can be compiled with
+    /// different options by [`PatternBuilder::compile`] without parsing it again.
+    ///
+    /// Registered classes and limits apply here, the other options when compiling.
+    pub fn parse(&self, pattern: &str) -> Result<Ast> {
+        let mut input = Input::new(pattern, self);
+        let mut branches = Vec::new();
+        let mut matchers = Vec::new();
+        let mut groups = Vec::new();
+        while let Some(ch) = input.peek() {
+            if ch == '|' {
+                input.next();
+                branches.push(mem::take(&mut matchers));
+            } else if !Matcher::parse_inline(&mut input, &mut matchers)? {
+                matchers.push(Matcher::new(&mut input, &mut groups)?);
+            }
+        }
+        branches.push(matchers);
+        Ok(Ast::new(
+            Matcher::sequence(branches),
+            groups,
+            input.warnings,
+        ))
+    }
+
+    /// Like [`PatternBuilder::build`], for a pattern given as tokens instead of text,
+    /// see the [`token`] module.
+    pub fn build_tokens(&self, tokens: impl IntoIterator<Item = token::Token>) -> Result<Pattern> {
+        Ok(self.compile(&self.parse_tokens(tokens)?))
+    }
+
+    /// Like [`PatternBuilder::parse`], for a pattern given as tokens instead of text.
+    pub fn parse_tokens(&self, tokens: impl IntoIterator<Item = token::Token>) -> Result<Ast> {
+        token::parse(self, tokens)
+    }
+
+    /// Compiles a pattern parsed by [`PatternBuilder::parse`], possibly of another
+    /// builder, with the options of this one.
+    pub fn compile(&self, ast: &Ast) -> Pattern {
+        let matchers = ast.matchers.clone();
+        let first_bytes = Pattern::compute_first_bytes(&matchers);
+        Pattern {
+            matchers,
+            groups: Arc::from(ast.groups.as_slice()),
+            warnings: ast.warnings.clone(),
+            first_bytes,
+            newlines: self.newlines,
+            step_limit: None,
+            cancel: None,
+        }
+    }
+}
+
+/// A parsed pattern, see [`PatternBuilder::parse`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ast {
+    matchers: Vec<Matcher>,
+    groups: Vec<GroupInfo>,
+    warnings: Vec<Warning>,
+}
+
+impl Ast {
+    /// Adds the warnings about the whole pattern to those found while parsing it.
+    fn new(matchers: Vec<Matcher>, groups: Vec<GroupInfo>, mut warnings: Vec<Warning>) -> Self {
+        let mut referenced = Vec::new();
+        Matcher::backreferences(&matchers, &mut referenced);
+        for group in groups.iter().filter(|g| !referenced.contains(&g.index)) {
+            warnings.push(Warning::new(
+                WarningKind::UnreferencedGroup,
+                format!("Group {} is never referred to", group.index),
+                group.span.start,
+                "use `(?:...)` if it only groups",
+            ));
+        }
+        warnings.sort_by_key(|warning| warning.position);
+        Self {
+            matchers,
+            groups,
+            warnings,
+        }
+    }
+
+    /// The capture groups of the pattern, see [`Pattern::groups`].
+    pub fn groups(&self) -> &[GroupInfo] {
+        &self.groups
+    }
+
+    /// See [`Pattern::warnings`].
+    pub fn warnings(&self) -> &[Warning] {
+        &self.warnings
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Pattern {
+    matchers: Vec<Matcher>,
+    groups: Arc<[GroupInfo]>,
+    warnings: Vec<Warning>,
+    /// See [`Pattern::first_bytes`], kept up to date as the matchers are edited.
+    first_bytes: Option<ByteSet>,
+    newlines: Newlines,
+    step_limit: Option<usize>,
+    cancel: Option<Arc<AtomicBool>>,
+}
+
+impl Pattern {
+    /// Parses a pattern with the default options, see [`PatternBuilder`] for others.
+    ///
+    /// Degenerate patterns are valid and follow the general rules:
+    ///
+    /// - The empty pattern, like `(?:)` or `|`, matches every haystack, with an
+    ///   empty match at offset 0. This is why `grep ''` prints every line.
+    /// - A pattern of anchors only matches where they all hold: `^$` matches the
+    ///   empty haystack, or every empty line in [multi-line](PatternBuilder::multi_line)
+    ///   mode.
+    /// - A bracket expression that no character matches, like `[]` or `[^\d\D]`,
+    ///   never matches, and is reported by [`Pattern::warnings`].
+    /// - A repetition stops at the first iteration that matches the empty string,
+    ///   once it has its minimum: `(?:|a)*` only matches the empty string, because
+    ///   the empty branch comes first.
+    ///
+    /// Haystacks are searched as they are, line terminators included, so `^$`
+    /// doesn't match `"\n"`. Use [`Pattern::test_line`] for lines that still have
+    /// their terminator, the CLI strips them the same way.
+    pub fn new(input: &str) -> Result<Self> {
+        PatternBuilder::new().build(input)
+    }
+
+    /// Parses a pattern with `flags` turned on, see [`PatternBuilder::flags`].
+    pub fn new_with_flags(input: &str, flags: Flags) -> Result<Self> {
+        PatternBuilder::new().flags(flags).build(input)
+    }
+
+    /// Builds a pattern from tokens instead of text, see the [`token`] module.
+    pub fn from_tokens(tokens: impl IntoIterator<Item = token::Token>) -> Result<Self> {
+        PatternBuilder::new().build_tokens(tokens)
+    }
+
+    /// Bounds the work a single search may do to `limit` matcher steps, so that
+    /// patterns that backtrack heavily can't run for an unbounded time.
+    ///
+    /// Searches that run out of steps count as not matching, use [`Pattern::try_find`]
+    /// to tell the two apart.
+    pub fn with_step_limit(mut self, limit: usize) -> Self {
+        self.step_limit = Some(limit);
+        self
+    }
+
+    /// Makes searches stop as soon as `cancel` is set, from any thread.
+    ///
+    /// Cancelled searches count as not matching, use [`Pattern::try_find`] to tell
+    /// the two apart. The flag is checked on every step, so even a single search
+    /// through a long haystack stops promptly.
+    pub fn with_cancellation(mut self, cancel: Arc<AtomicBool>) -> Self {
+        self.cancel = Some(cancel);
+        self
+    }
+
+    /// The bytes a match can start with, `None` if the pattern can match the empty
+    /// string and so match anywhere.
+    ///
+    /// Searches use this to skip offsets where no match can start, and it can serve
+    /// as a prefilter for callers that look for candidate positions themselves.
+    pub fn first_bytes(&self) -> Option<&ByteSet> {
+        self.first_bytes.as_ref()
+    }
+
+    fn compute_first_bytes(matchers: &[Matcher]) -> Option<ByteSet> {
+        let mut set = ByteSet::new();
+        match Matcher::sequence_first_bytes(matchers, &mut set) {
+            true => None,
+            false => Some(set),
+        }
+    }
+
+    /// The capture groups of the pattern, in order of their opening parentheses,
+    /// starting with group 1.
+    pub fn groups(&self) -> &[GroupInfo] {
+        &self.groups
+    }
+
+    /// Parts of the pattern that are valid, but most likely mistakes, in the order
+    /// they appear in the pattern. These are found when parsing, so they describe
+    /// the pattern as written, not as edited since.
+    pub fn warnings(&self) -> &[Warning] {
+        &self.warnings
+    }
+
+    /// The groups directly nested in group `index`, where group 0 is the whole
+    /// pattern.
+    pub fn child_groups(&self, index: usize) -> impl Iterator<Item = &GroupInfo> + '_ {
+        self.groups
+            .iter()
+            .filter(move |group| group.parent == index)
+    }
+
+    /// A copy of the pattern that only matches at the start of the haystack, as if
+    /// it were wrapped in `^(...)`, but without adding a group.
+    pub fn anchored_start(&self) -> Pattern {
+        self.anchored(true, false)
+    }
+
+    /// A copy of the pattern that only matches at the end of the haystack, as if it
+    /// were wrapped in `(...)$`, but without adding a group.
+    pub fn anchored_end(&self) -> Pattern {
+        self.anchored(false, true)
+    }
+
+    /// A copy of the pattern that only matches the whole haystack, as if it were
+    /// wrapped in `^(...)$`, but without adding a group.
+    pub fn anchored_both(&self) -> Pattern {
+        self.anchored(true, true)
+    }
+
+    /// Anchors every top-level alternative, which is what anchoring a group around
+    /// all of them amounts to.
+    fn anchored(&self, start: bool, end: bool) -> Pattern {
+        let anchor = |option: &[Matcher]| {
+            let mut matchers = Vec::with_capacity(option.len() + 2);
+            if start && option.first() != Some(&Matcher::StartOfString) {
+                matchers.push(Matcher::StartOfString);
+            }
+            matchers.extend_from_slice(option);
+            if end && option.last() != Some(&Matcher::EndOfString) {
+                matchers.push(Matcher::EndOfString);
+            }
+            matchers
+        };
+        let branches = Matcher::branches(&self.matchers).map(anchor).collect();
+        let matchers = Matcher::sequence(branches);
+        Pattern {
+            first_bytes: Self::compute_first_bytes(&matchers),
+            matchers,
+            ..self.clone()
+        }
+    }
+
+    /// Appends `text` to the pattern as literal characters, without parsing the
+    /// pattern again. Appending after an alternation extends its last branch, just
+    /// like typing the escaped text at the end of the pattern would.
+    ///
+    /// Unlike typed text, the characters always match exactly: inline flags like
+    /// `(?i)` aren't kept once the pattern is parsed, so they don't apply.
+    pub fn push_literal(&mut self, text: &str) {
+        self.edit_last_branch(|branch| {
+            branch.extend(
+                text.chars()
+                    .map(|ch| Matcher::SingleCharacter(SingleCharacterMatcher::new_literal(ch))),
+            )
+        });
+        self.first_bytes = Self::compute_first_bytes(&self.matchers);
+    }
+
+    /// Removes the last matcher if it is a literal character, returning it. This
+    /// undoes [`Pattern::push_literal`] one character at a time.
+    pub fn pop_literal(&mut self) -> Option<char> {
+        let popped = self.edit_last_branch(|branch| match branch.last()? {
+            Matcher::SingleCharacter(SingleCharacterMatcher::Literal(ch)) => {
+                let ch = *ch;
+                branch.pop();
+                Some(ch)
+            }
+            _ => None,
+        })?;
+        self.first_bytes = Self::compute_first_bytes(&self.matchers);
+        Some(popped)
+    }
+
+    /// Calls `edit` with the matchers of the last top-level branch, all of them
+    /// without alternation.
+    fn edit_last_branch<T>(&mut self, edit: impl FnOnce(&mut Vec<Matcher>) -> T) -> T {
+        match self.matchers.as_mut_slice() {
+            [Matcher::Alternation(options)] => {
+                edit(options.last_mut().expect("An alternation has branches"))
+            }
+            _ => edit(&mut self.matchers),
+        }
+    }
+
+    pub fn test(&self, input: &str) -> bool {
+        self.find(input).is_some()
+    }
+
+    /// Tests a line as read with [`BufRead::read_line`](std::io::BufRead::read_line),
+    /// leaving out its `\n` or `\r\n` terminator, if any.
+    pub fn test_line(&self, line: &str) -> bool {
+        let line = line.strip_suffix('\n').unwrap_or(line);
+        self.test(line.strip_suffix('\r').unwrap_or(line))
+    }
+
+    /// Tests every line, spreading the work over all available cores, and returns
+    /// the results in the same order.
+    ///
+    /// Lines are split into one contiguous chunk per thread, so this pays off for
+    /// many lines rather than a few long ones.
+    pub fn test_many<S: AsRef<str> + Sync>(&self, lines: &[S]) -> Vec<bool> {
+        let threads = thread::available_parallelism().map_or(1, |n| n.get());
+        let chunk_size = lines.len().div_ceil(threads).max(1);
+        thread::scope(|scope| {
+            let chunks: Vec<_> = lines
+                .chunks(chunk_size)
+                .map(|chunk| {
+                    scope.spawn(move || {
+                        let tests = chunk.iter().map(|line| self.test(line.as_ref()));
+                        tests.collect::<Vec<_>>()
+                    })
+                })
+                .collect();
+            chunks
+                .into_iter()
+                .flat_map(|chunk| chunk.join().unwrap_or_else(|e| panic::resume_unwind(e)))
+                .collect()
+        })
+    }
+
+    /// Describes the parsed matchers as an indented tree, one matcher per line, with
+    /// group numbers and repetition bounds spelled out.
+    pub fn tree(&self) -> Tree<'_> {
+        Tree(self)
+    }
+
+    /// Finds the leftmost match in `haystack`.
+    pub fn find<'h>(&self, haystack: &'h str) -> Option<Match<'h>> {
+        self.try_find(haystack).ok().flatten()
+    }
+
+    /// Finds the leftmost match in `haystack`, failing if the search exceeds the step
+    /// limit set with [`Pattern::with_step_limit`] or is cancelled through the flag
+    /// set with [`Pattern::with_cancellation`].
+    pub fn try_find<'h>(&self, haystack: &'h str) -> Result<Option<Match<'h>>> {
+        Ok(self.search(haystack, 0)?.map(|captures| {
+            let (start, end) = captures[0].expect("Group 0 is set by every match");
+            Match::new(haystack, start, end)
+        }))
+    }
+
+    /// Same as [`Pattern::find`], but adds the work the search did to `counters`.
+    ///
+    /// Counting backtracks makes the search somewhat slower.
+    pub fn find_counted<'h>(
+        &self,
+        haystack: &'h str,
+        counters: &mut SearchCounters,
+    ) -> Option<Match<'h>> {
+        let mut state = State::new(self, haystack);
+        state.counters = Some(SearchCounters::default());
+        let found = self.search_with(&mut state, 0).ok().flatten();
+        let mut searched = state.counters.expect("Set above");
+        searched.steps = state.steps;
+        *counters += searched;
+        found.map(|captures| {
+            let (start, end) = captures[0].expect("Group 0 is set by every match");
+            Match::new(haystack, start, end)
+        })
+    }
+
+    /// Finds the last match in `haystack`, trying starting offsets from the end so
+    /// that the rest of the haystack is never searched.
+    pub fn find_last<'h>(&self, haystack: &'h str) -> Option<Match<'h>> {
+        self.rfind_iter(haystack).next()
+    }
+
+    /// Iterates over non-overlapping matches in `haystack`, right to left.
+    ///
+    /// Each match is the one found by trying starting offsets backwards from the
+    /// previous match, preferring the earliest start among matches that end in the
+    /// same place. Where matches can overlap they may differ from those of
+    /// [`Pattern::find_iter`]: `aa` finds `1..3` in `aaa`, not `0..2`.
+    pub fn rfind_iter<'p, 'h>(&'p self, haystack: &'h str) -> RMatches<'p, 'h> {
+        RMatches {
+            pattern: self,
+            haystack,
+            to: Some(haystack.len()),
+            bound: haystack.len(),
+        }
+    }
+
+    /// Iterates over all non-overlapping matches in `haystack`, left to right.
+    pub fn find_iter<'p, 'h>(&'p self, haystack: &'h str) -> Matches<'p, 'h> {
+        Matches {
+            pattern: self,
+            haystack,
+            at: 0,
+            last_end: None,
+        }
+    }
+
+    /// Iterates over the `(start, end)` byte offsets of the same matches as
+    /// [`Pattern::find_iter`], for counting matches or working with their offsets.
+    ///
+    /// Only this call allocates, to make room for the group offsets. Iterating
+    /// doesn't, unless the pattern repeats groups, which keep their iterations
+    /// apart from the call stack.
+    pub fn match_indices<'p, 'h>(&'p self, haystack: &'h str) -> MatchIndices<'p, 'h> {
+        MatchIndices {
+            pattern: self,
+            haystack,
+            state: State::new(self, haystack),
+            at: 0,
+            last_end: None,
+        }
+    }
+
+    /// Finds the leftmost match in `haystack` along with the offsets of every group.
+    pub fn captures<'h>(&self, haystack: &'h str) -> Option<Captures<'h>> {
+        let slots = self.search(haystack, 0).ok()??;
+        let groups = self.groups.clone();
+        Some(Captures {
+            haystack,
+            slots,
+            groups,
+        })
+    }
+
+    /// Iterates over the groups of all non-overlapping matches in `haystack`, with
+    /// the same matches as [`Pattern::find_iter`].
+    pub fn captures_iter<'p, 'h>(&'p self, haystack: &'h str) -> CaptureMatches<'p, 'h> {
+        CaptureMatches(self.find_iter(haystack))
+    }
+
+    /// Checks that `template`, for [`Pattern::replace_all`], only refers to groups of
+    /// this pattern and has no malformed references, see the [`replace`] module.
+    pub fn validate_template(&self, template: &str) -> std::result::Result<(), TemplateError> {
+        replace::validate(template, &self.groups)
+    }
+
+    /// Replaces every match in `haystack` with what `replacer` makes of it, either a
+    /// template string (see the [`replace`] module for its syntax) or a closure
+    /// taking the [`Captures`] of the match.
+    pub fn replace_all<'h>(&self, haystack: &'h str, mut replacer: impl Replacer) -> Cow<'h, str> {
+        let mut matches = self.captures_iter(haystack).peekable();
+        if matches.peek().is_none() {
+            return Cow::Borrowed(haystack);
+        }
 
-                if let Some(min) = min {
-                    if count < *min {
-                        eprintln!("Range failed due to min {count} < {min}");
-                        return (false, Vec::new(), None);
-                    }
-                }
+        let mut replaced = String::with_capacity(haystack.len());
+        let mut last = 0;
+        for captures in matches {
+            let whole = captures.whole();
+            replaced.push_str(&haystack[last..whole.start()]);
+            replacer.replace_append(&captures, &mut replaced);
+            last = whole.end();
+        }
+        replaced.push_str(&haystack[last..]);
+        Cow::Owned(replaced)
+    }
 
-                if count > 0 {
-                    return (true, Vec::new(), Some(BacktrackInfo::Range(count)));
-                } else {
-                    return (true, Vec::new(), None);
-                }
+    /// Like [`Pattern::captures`], but groups that didn't participate in the match
+    /// are reported as empty strings.
+    pub fn run(&self, input: &str) -> (bool, String, Vec<String>) {
+        match self.search(input, 0).ok().flatten() {
+            Some(captures) => {
+                let mut captures = captures.into_iter().map(|capture| {
+                    capture
+                        .map(|(start, end)| String::from(&input[start..end]))
+                        .unwrap_or_default()
+                });
+                let all = captures.next().expect("Group 0 is set by every match");
+                (true, all, captures.collect())
             }
+            None => (false, String::new(), Vec::new()),
         }
     }
 
-    fn test_group<'a, T>(
-        inner: &'a Vec<Self>,
-        input: &mut BufferedIterator<T>,
-        captured_groups: &Vec<String>,
-        backtrack: Option<BacktrackInfo<'a, T>>,
-    ) -> (bool, Vec<String>, Option<BacktrackInfo<'a, T>>)
-    where
-        T: Iterator<Item = (usize, char)> + Clone + Debug,
-    {
-        let options = inner.split(|m| m == &Matcher::Alternative);
-        'option_loop: for (option_id, option) in options.enumerate() {
-            let mut backtrack_stack: Vec<GroupBacktrackState<'_, T>> =
-                if let Some(BacktrackInfo::Group(stack_option, stack)) = backtrack.clone() {
-                    if stack_option == option_id {
-                        if stack.is_empty() {
-                            continue 'option_loop;
-                        }
-                        stack
-                    } else {
-                        Vec::new()
-                    }
-                } else {
-                    Vec::new()
-                };
-            let mut backtrack_info = None;
-            let mut buffered_input = input.clone();
-            let mut our_captures = Vec::new();
-            let mut all_captures = captured_groups.clone();
-            all_captures.push(String::new()); // Placeholder for our group
-            buffered_input.subdivide();
-
-            let mut matcher_iter = option.iter();
-            let mut backtrack_matcher_iter = matcher_iter.clone();
-
-            if let Some(mut state) = backtrack_stack.pop() {
-                //eprintln!("Backtracking on entry: {:?}, {:?}", buffered_input, state);
-                std::mem::swap(&mut buffered_input, &mut state.input);
-                std::mem::swap(&mut our_captures, &mut state.captures);
-                all_captures = captured_groups
-                    .iter()
-                    .chain(vec![String::new()].iter())
-                    .chain(our_captures.iter())
-                    .map(|c| c.clone())
-                    .collect();
-                backtrack_info = Some(state.backtrack);
-                std::mem::swap(&mut matcher_iter, &mut state.matchers_to_resume);
-                backtrack_matcher_iter = matcher_iter.clone();
-            }
-
-            'match_loop: while let Some(m) = matcher_iter.next() {
-                let mut backtrack_state = GroupBacktrackState {
-                    input: buffered_input.clone(),
-                    captures: our_captures.clone(),
-                    backtrack: BacktrackInfo::None,
-                    matchers_to_resume: backtrack_matcher_iter.clone(),
-                };
-                backtrack_matcher_iter.next();
-
-                let (matched, captures, backtrack) =
-                    m.test(&mut buffered_input, &all_captures, backtrack_info.take());
-
-                if !matched {
-                    if let Some(mut state) = backtrack_stack.pop() {
-                        eprintln!(
-                            "Match failed, backtracking: {:?}, {:?}",
-                            buffered_input.pop_divided(),
-                            state
-                        );
-                        std::mem::swap(&mut buffered_input, &mut state.input);
-                        std::mem::swap(&mut our_captures, &mut state.captures);
-                        all_captures = captured_groups
-                            .iter()
-                            .chain(vec![String::new()].iter())
-                            .chain(our_captures.iter())
-                            .map(|c| c.clone())
-                            .collect();
-                        backtrack_info = Some(state.backtrack);
-                        std::mem::swap(&mut matcher_iter, &mut state.matchers_to_resume);
-                        backtrack_matcher_iter = matcher_iter.clone();
-                        continue 'match_loop;
-                    } else {
-                        //eprintln!("Match failed, nothing to backtrack");
-                        continue 'option_loop;
-                    }
-                } else {
-                    for capture in captures {
-                        our_captures.push(capture.clone());
-                        all_captures.push(capture);
-                    }
-                    if let Some(backtrack) = backtrack {
-                        backtrack_state.backtrack = backtrack;
-                        backtrack_stack.push(backtrack_state);
-                    }
-                }
-            }
+    /// Like [`Pattern::run`], but returns the groups that have a name by their name.
+    pub fn run_named(&self, input: &str) -> (bool, String, HashMap<String, String>) {
+        let Some(captures) = self.captures(input) else {
+            return (false, String::new(), HashMap::new());
+        };
+        let named = self.groups.iter().filter_map(|group| {
+            let text = captures.get(group.index).map(|m| m.as_str());
+            Some((
+                String::from(group.name()?),
+                String::from(text.unwrap_or_default()),
+            ))
+        });
+        (
+            true,
+            String::from(captures.whole().as_str()),
+            named.collect(),
+        )
+    }
 
-            let matched_value = buffered_input
-                .pop_divided()
-                .expect("We have subdivided before, popping should succeed");
-            std::mem::swap(input, &mut buffered_input);
+    /// Counts how often each distinct text is matched in `haystack`.
+    pub fn count_unique(&self, haystack: &str) -> UniqueCounts {
+        let mut counts = UniqueCounts::new();
+        counts.add_matches(self, haystack);
+        counts
+    }
 
-            our_captures.insert(0, matched_value);
-            return (
-                true,
-                our_captures,
-                Some(BacktrackInfo::Group(option_id, backtrack_stack)),
-            );
+    /// Splits `haystack` on every match, like [`str::split`] does on a substring.
+    pub fn split_str<'p, 'h>(&'p self, haystack: &'h str) -> Split<'p, 'h> {
+        Split {
+            matches: self.find_iter(haystack),
+            last: Some(0),
         }
+    }
 
-        (false, Vec::new(), None)
+    /// Matches the pattern anchored at the very start of `haystack`, returning the
+    /// match and the rest of `haystack` after it.
+    ///
+    /// This is the building block for hand-written parsers: try a token pattern,
+    /// then continue with the remainder.
+    pub fn strip_prefix<'h>(&self, haystack: &'h str) -> Option<(Match<'h>, &'h str)> {
+        let mut state = State::new(self, haystack);
+        self.enter_line(&mut state, haystack, 0);
+        if !self.match_at(&mut state, 0) {
+            return None;
+        }
+        let (start, end) = state.captures[0].expect("Group 0 is set by every match");
+        Some((Match::new(haystack, start, end), &haystack[end..]))
     }
-}
 
-#[derive(Debug, Clone)]
-pub struct Pattern {
-    matchers: Vec<Matcher>,
-}
+    /// Returns the rest of `haystack` if the pattern matches at its very start, like
+    /// [`str::strip_prefix`] does for a substring.
+    pub fn strip_prefix_match<'h>(&self, haystack: &'h str) -> Option<&'h str> {
+        self.strip_prefix(haystack).map(|(_, rest)| rest)
+    }
 
-impl Pattern {
-    pub fn new(input: &str) -> Result<Self> {
-        let mut input = input.chars().peekable();
-        let mut matchers = Vec::new();
-        while let Some(_) = input.peek() {
-            matchers.push(Matcher::new(&mut input)?);
-        }
+    /// Whether `input` matches or could still match with more text appended, because
+    /// the search ran out of input rather than failing. Validators of text that is
+    /// still being typed use this to tell incomplete input from wrong input.
+    ///
+    /// Only matches that start within `input` count, or at its start if it is empty.
+    pub fn is_partial_match(&self, input: &str) -> bool {
+        let mut state = State::new(self, input);
+        state.hit_end = Some(false);
+        let found = self.search_with(&mut state, 0);
+        found.is_ok_and(|captures| captures.is_some()) || state.hit_end == Some(true)
+    }
 
-        Ok(Self { matchers })
+    /// Same as [`Pattern::test`], but reports every step the engine takes to `tracer`.
+    pub fn trace(&self, input: &str, mut tracer: impl FnMut(&TraceEvent)) -> bool {
+        let mut state = State::new(self, input);
+        state.tracer = Some(&mut tracer);
+        self.search_with(&mut state, 0)
+            .is_ok_and(|captures| captures.is_some())
     }
 
-    pub fn test(&self, input: &str) -> bool {
-        self.run(input).0
+    /// Returns the capture offsets of the leftmost match starting at or after `from`.
+    fn search(&self, haystack: &str, from: usize) -> Result<Option<Slots>> {
+        self.search_with(&mut State::new(self, haystack), from)
     }
 
-    pub fn run(&self, input: &str) -> (bool, String, Vec<String>) {
-        let mut iter = BufferedIterator::new(input.chars().enumerate());
+    fn search_with(&self, state: &mut State<'_>, from: usize) -> Result<Option<Slots>> {
+        Ok(self
+            .search_in(state, from)?
+            .then(|| mem::take(&mut state.captures)))
+    }
 
-        while let Some(_) = iter.peek() {
-            let mut buffered_iter = iter.clone();
-            let (matched, captured) = self.test_section(&mut buffered_iter);
-            if matched {
-                let (_, buffered) = buffered_iter.take();
-                return (true, buffered, captured);
+    /// Looks for the leftmost match starting at or after `from`, leaving its capture
+    /// offsets in `state`.
+    fn search_in(&self, state: &mut State<'_>, from: usize) -> Result<bool> {
+        let haystack = state.haystack;
+        self.assert_single_line(haystack);
+        let mut line_end = None;
+        // A partial match can start anywhere, even where no match could
+        let first_bytes = self
+            .first_bytes
+            .as_ref()
+            .filter(|_| state.hit_end.is_none());
+        let counting = state.counters.is_some();
+        let skipped = Cell::new(0);
+        let candidate = |at: &usize| {
+            let candidate = match first_bytes {
+                Some(set) => haystack
+                    .as_bytes()
+                    .get(*at)
+                    .is_some_and(|b| set.contains(*b)),
+                None => haystack.is_char_boundary(*at),
+            };
+            if counting && !candidate && haystack.is_char_boundary(*at) {
+                skipped.set(skipped.get() + 1);
+            }
+            candidate
+        };
+
+        let mut found = Ok(false);
+        for at in (from..=haystack.len()).filter(candidate) {
+            if self.newlines == Newlines::Split && line_end.is_none_or(|end| at > end) {
+                self.enter_line(state, haystack, at);
+                line_end = Some(state.haystack.len());
+            }
+            if let Some(counters) = &mut state.counters {
+                counters.positions += 1;
             }
-            iter.next();
+            let hit_end = state.hit_end;
+            if self.match_at(state, at) {
+                found = Ok(true);
+                break;
+            }
+            if at == haystack.len() && at > 0 {
+                // Any input can be completed by appending a whole match, that
+                // doesn't make it a partial match
+                state.hit_end = hit_end;
+            }
+            if let Err(error) = state.check() {
+                found = Err(error);
+                break;
+            }
+        }
+        if let Some(counters) = &mut state.counters {
+            counters.skipped += skipped.get();
         }
+        found
+    }
 
-        (false, String::new(), Vec::new())
+    /// Returns the capture offsets of the last match starting at or before `to` and
+    /// ending at or before `bound`.
+    ///
+    /// Starting offsets are tried from `to` backwards. Once a match is found, earlier
+    /// offsets with a match ending at the same place still take precedence, so that
+    /// `\d+` finds the whole last number rather than its last digit.
+    fn search_back(&self, haystack: &str, to: usize, bound: usize) -> Result<Option<Slots>> {
+        let mut state = State::new(self, haystack);
+        self.assert_single_line(haystack);
+        let mut line_start = None;
+        let mut found: Option<Slots> = None;
+        for at in (0..=to).rev().filter(|at| haystack.is_char_boundary(*at)) {
+            if self.newlines == Newlines::Split && line_start.is_none_or(|start| at < start) {
+                self.enter_line(&mut state, haystack, at);
+                line_start = Some(state.start);
+            }
+            let matched = self.match_at(&mut state, at);
+            state.check()?;
+            let end = matched.then(|| state.captures[0].expect("Group 0 is set by every match"));
+            match (&found, end) {
+                (None, Some((_, end))) if end <= bound => found = Some(state.captures.clone()),
+                (None, _) => {}
+                (Some(previous), Some((_, end))) if previous[0].is_some_and(|(_, e)| e == end) => {
+                    found = Some(state.captures.clone())
+                }
+                (Some(_), _) => break,
+            }
+        }
+        Ok(found)
     }
 
-    fn test_section<T>(&self, input: &mut BufferedIterator<T>) -> (bool, Vec<String>)
-    where
-        T: Iterator<Item = (usize, char)> + Clone + Debug,
-    {
-        let matcher = Matcher::CaptureGroup(self.matchers.clone());
-        let (matched, mut captures, _backtrack) = matcher.test(input, &Vec::new(), None);
+    /// Restricts `state` to the line of `haystack` that contains `at`, with
+    /// [`Newlines::Split`].
+    fn enter_line<'h>(&self, state: &mut State<'h>, haystack: &'h str, at: usize) {
+        if self.newlines == Newlines::Split {
+            state.start = haystack[..at].rfind('\n').map_or(0, |i| i + 1);
+            let end = haystack[at..].find('\n').map_or(haystack.len(), |i| at + i);
+            state.haystack = &haystack[..end];
+        }
+    }
 
-        if matched {
-            captures.remove(0);
-            (true, captures)
-        } else {
-            (false, Vec::new())
+    /// Panics if `haystack` has several lines, with [`Newlines::Assert`].
+    fn assert_single_line(&self, haystack: &str) {
+        if self.newlines == Newlines::Assert {
+            if let Some(at) = haystack.find('\n') {
+                panic!(
+                    "Haystack has a newline at offset {at}, but is expected to be a single line"
+                );
+            }
         }
     }
+
+    /// Tries to match starting exactly at `at`, leaving the capture offsets in `state`.
+    fn match_at(&self, state: &mut State<'_>, at: usize) -> bool {
+        state.captures.fill(None);
+        state.depth = 0;
+        state.trace(TraceKind::Start, String::new, at..at);
+        state.depth = 1;
+        Matcher::test_group(0, &self.matchers, state, at, &mut |_, _| true)
+    }
 }
 
 #[cfg(test)]
 mod test {
-    use crate::Pattern;
+    use std::{
+        sync::{
+            atomic::{AtomicBool, Ordering},
+            Arc,
+        },
+        thread,
+        time::Duration,
+    };
+
+    use crate::{
+        token::{GroupKind, Token},
+        CaseFold, ContextSize, Error, Flags, Limit, Newlines, Pattern, PatternBuilder,
+        SearchCounters, TraceKind, WarningKind,
+    };
 
     #[test]
     fn single_character_match() {
@@ -523,99 +3853,739 @@ mod test {
     }
 
     #[test]
-    fn literal_match() {
-        let pattern = Pattern::new("abc").expect("Pattern is correct");
-        assert!(pattern.test("abc"));
-        assert!(pattern.test("abcde"));
-        assert!(pattern.test("012abcde"));
-        assert!(!pattern.test("lax"));
-        assert!(!pattern.test("abxc"));
+    fn literal_match() {
+        let pattern = Pattern::new("abc").expect("Pattern is correct");
+        assert!(pattern.test("abc"));
+        assert!(pattern.test("abcde"));
+        assert!(pattern.test("012abcde"));
+        assert!(!pattern.test("lax"));
+        assert!(!pattern.test("abxc"));
+    }
+
+    #[test]
+    fn digit_match() {
+        let pattern = Pattern::new(r"\d").expect("Pattern is correct");
+        assert!(pattern.test("1"));
+        assert!(pattern.test("a2"));
+        assert!(pattern.test("012abcde"));
+        assert!(!pattern.test("lax"));
+        assert!(!pattern.test("abxc"));
+    }
+
+    #[test]
+    fn alphanumeric_match() {
+        let pattern = Pattern::new(r"\w").expect("Pattern is correct");
+        assert!(pattern.test("1"));
+        assert!(pattern.test("a"));
+        assert!(pattern.test("Z"));
+        assert!(pattern.test("_"));
+        assert!(!pattern.test("-"));
+        assert!(!pattern.test(":"));
+    }
+
+    #[test]
+    fn whitespace_match() {
+        let pattern = Pattern::new(r"a\sb").expect("Pattern is correct");
+        assert!(pattern.test("a b"));
+        assert!(pattern.test("a\tb"));
+        assert!(!pattern.test("ab"));
+        assert!(!pattern.test("a_b"));
+
+        let pattern = Pattern::new(r"^\S+$").expect("Pattern is correct");
+        assert!(pattern.test("abc"));
+        assert!(!pattern.test("a c"));
+
+        let pattern = Pattern::new(r"^[\s,]+$").expect("Pattern is correct");
+        assert!(pattern.test(" ,\r\n"));
+        assert!(!pattern.test(" x"));
+        let pattern = Pattern::new(r"^[^\S]$").expect("Pattern is correct");
+        assert!(pattern.test("\x0B"));
+        assert!(!pattern.test("x"));
+    }
+
+    #[test]
+    fn negated_class_match() {
+        let pattern = Pattern::new(r"^\D\W$").expect("Pattern is correct");
+        assert!(pattern.test("a-"));
+        assert!(!pattern.test("1-"));
+        assert!(!pattern.test("a_"));
+
+        let pattern = Pattern::new(r"^[^\D]+$").expect("Pattern is correct");
+        assert!(pattern.test("123"));
+        assert!(!pattern.test("1a"));
+        let pattern = Pattern::new(r"^[^\W_]+$").expect("Pattern is correct");
+        assert!(pattern.test("a1"));
+        assert!(!pattern.test("a_1"));
+        let pattern = Pattern::new(r"^[\Wx]+$").expect("Pattern is correct");
+        assert!(pattern.test("x-x "));
+        assert!(!pattern.test("xy"));
+    }
+
+    #[test]
+    fn group_match() {
+        let pattern = Pattern::new(r"[a\d]").expect("Pattern is correct");
+        assert!(pattern.test("1"));
+        assert!(pattern.test("a"));
+        assert!(pattern.test("9"));
+        assert!(pattern.test("za"));
+        assert!(!pattern.test("b"));
+        assert!(!pattern.test(":"));
+    }
+
+    #[test]
+    fn negative_group_match() {
+        let pattern = Pattern::new(r"[^a\d]").expect("Pattern is correct");
+        assert!(!pattern.test("1"));
+        assert!(!pattern.test("a"));
+        assert!(!pattern.test("9"));
+        assert!(pattern.test("za"));
+        assert!(pattern.test("b"));
+        assert!(pattern.test(":"));
+    }
+
+    #[test]
+    fn range_match() {
+        let pattern = Pattern::new(r"^[a-cx-z0-9]+$").expect("Pattern is correct");
+        assert!(pattern.test("ab9z"));
+        assert!(!pattern.test("abd"));
+
+        let pattern = Pattern::new(r"^[-a][b-][^\--/]$").expect("Pattern is correct");
+        assert!(pattern.test("--0"));
+        assert!(pattern.test("ab0"));
+        assert!(!pattern.test("ab."));
+
+        let pattern = Pattern::new("[à-ÿ]").expect("Pattern is correct");
+        assert!(pattern.test("é"));
+        assert!(!pattern.test("e"));
+        assert_eq!(pattern.first_bytes().map(|set| set.len()), Some(1));
+
+        let error = |pattern| match Pattern::new(pattern) {
+            Err(Error::Syntax { position, .. }) => position,
+            other => panic!("Expected a syntax error for {pattern:?}, got {other:?}"),
+        };
+        assert_eq!(error("[az-a]"), 2);
+        assert_eq!(error(r"[a-\d]"), 1);
+    }
+
+    #[test]
+    fn start_of_string_match() {
+        let pattern = Pattern::new(r"^a").expect("Pattern is correct");
+        assert!(pattern.test("a"));
+        assert!(pattern.test("ab"));
+        assert!(!pattern.test("ba"));
+    }
+
+    #[test]
+    fn end_of_string_match() {
+        let pattern = Pattern::new(r"a$").expect("Pattern is correct");
+        assert!(pattern.test("a"));
+        assert!(!pattern.test("ab"));
+        assert!(pattern.test("ba"));
+    }
+
+    #[test]
+    fn one_or_more_match() {
+        let pattern = Pattern::new(r"ab+c").expect("Pattern is correct");
+        assert!(pattern.test("abc"));
+        assert!(pattern.test("abbc"));
+        assert!(pattern.test("abbbc"));
+        assert!(!pattern.test("ac"));
+    }
+
+    #[test]
+    fn zero_or_more_match() {
+        let pattern = Pattern::new(r"ab*c").expect("Pattern is correct");
+        assert!(pattern.test("abc"));
+        assert!(pattern.test("abbc"));
+        assert!(pattern.test("abbbc"));
+        assert!(pattern.test("ac"));
+    }
+
+    #[test]
+    fn zero_or_one_match() {
+        let pattern = Pattern::new(r"ab?c").expect("Pattern is correct");
+        assert!(pattern.test("abc"));
+        assert!(!pattern.test("abbc"));
+        assert!(!pattern.test("abbbc"));
+        assert!(pattern.test("ac"));
+    }
+
+    #[test]
+    fn counted_repetition_match() {
+        let pattern = Pattern::new(r"^\d{3}-\d{2,}-\d{1,2}$").expect("Pattern is correct");
+        assert!(pattern.test("123-45-6"));
+        assert!(pattern.test("123-4567-89"));
+        assert!(!pattern.test("12-45-6"));
+        assert!(!pattern.test("123-4-6"));
+        assert!(!pattern.test("123-45-678"));
+
+        let pattern = Pattern::new(r"^a{,2}b{0}$").expect("Pattern is correct");
+        assert!(pattern.test(""));
+        assert!(pattern.test("aa"));
+        assert!(!pattern.test("aaa"));
+        assert!(!pattern.test("b"));
+
+        let pattern = Pattern::new(r"x{1,3}x").expect("Pattern is correct");
+        assert_eq!(pattern.find("xxxx").map(|m| m.range()), Some(0..4));
+        assert_eq!(pattern.find("xx").map(|m| m.range()), Some(0..2));
+    }
+
+    #[test]
+    fn repeated_capture_group() {
+        let pattern = Pattern::new(r"^(\w)+-(x)?b$").expect("Pattern is correct");
+        let captures = pattern.captures("abc-b").expect("Pattern matches");
+        assert_eq!(captures.get(1).map(|m| m.range()), Some(2..3));
+        assert_eq!(captures.get(2), None);
+        assert_eq!(
+            pattern
+                .captures("a-xb")
+                .and_then(|c| c.get(2))
+                .map(|m| m.range()),
+            Some(2..3)
+        );
+        assert!(!pattern.test("-b"));
+
+        let pattern = Pattern::new(r"^(ab){2}\1$").expect("Pattern is correct");
+        assert!(pattern.test("ababab"));
+        assert!(!pattern.test("abab"));
+    }
+
+    #[test]
+    fn repeat_long_line() {
+        let haystack = "ab".repeat(1 << 19);
+        let pattern = Pattern::new(r"^(?:ab)*$").expect("Pattern is correct");
+        assert_eq!(pattern.find(&haystack).map(|m| m.end()), Some(1 << 20));
+        let pattern = Pattern::new(r"(?:a|(b))*+$").expect("Pattern is correct");
+        let captures = pattern.captures(&haystack).expect("Pattern matches");
+        assert_eq!(captures.get(1).map(|m| m.start()), Some((1 << 20) - 1));
+        let pattern = Pattern::new(r"^(?:a|b)*c").expect("Pattern is correct");
+        assert!(!pattern.test(&haystack));
+    }
+
+    #[test]
+    fn possessive_repetition() {
+        let pattern = Pattern::new(r"^a++a").expect("Pattern is correct");
+        assert!(!pattern.test("aaa"));
+        let pattern = Pattern::new(r"^a*+b").expect("Pattern is correct");
+        assert!(pattern.test("aab"));
+        assert!(pattern.test("b"));
+
+        let pattern = Pattern::new(r"\d{2,3}+\d").expect("Pattern is correct");
+        assert_eq!(pattern.find("12345").map(|m| m.range()), Some(0..4));
+        assert_eq!(pattern.find("123"), None);
+        assert!(!pattern.test("12"));
+
+        let pattern = Pattern::new(r"x?+x").expect("Pattern is correct");
+        assert_eq!(pattern.find("xx").map(|m| m.range()), Some(0..2));
+        assert_eq!(pattern.find("x"), None);
+    }
+
+    #[test]
+    fn non_capturing_group() {
+        let pattern = Pattern::new(r"^(?:ab|c)+(d)\1$").expect("Pattern is correct");
+        assert!(pattern.test("abcabdd"));
+        assert!(!pattern.test("dd"));
+        assert_eq!(pattern.groups().len(), 1);
+        let captures = pattern.captures("cdd").expect("Pattern matches");
+        assert_eq!(captures.get(1).map(|m| m.as_str()), Some("d"));
+
+        let pattern = Pattern::new(r"^(?:(a)|b){2}$").expect("Pattern is correct");
+        assert!(pattern.test("ab"));
+        assert!(!pattern.test("abb"));
+        let captures = pattern.captures("ba").expect("Pattern matches");
+        assert_eq!(captures.get(1).map(|m| m.range()), Some(1..2));
+    }
+
+    #[test]
+    fn atomic_group() {
+        let pattern = Pattern::new(r"(?>a|ab)c").expect("Pattern is correct");
+        assert!(pattern.test("ac"));
+        assert!(!pattern.test("abc"));
+        let pattern = Pattern::new(r"^(?>\w+)\d").expect("Pattern is correct");
+        assert!(!pattern.test("abc1"));
+
+        let pattern = Pattern::new(r"(?>(a+))\1").expect("Pattern is correct");
+        assert!(!pattern.test("aaaa"));
+        let pattern = Pattern::new(r"^(?:(?>(a)b)|ac)").expect("Pattern is correct");
+        let captures = pattern.captures("ac").expect("Pattern matches");
+        assert_eq!(captures.get(1), None);
+
+        let haystack = format!("{}b", "a".repeat(40));
+        let pattern = Pattern::new(r"(?>a+)+$")
+            .expect("Pattern is correct")
+            .with_step_limit(10_000);
+        assert!(pattern.warnings().is_empty());
+        assert!(pattern.try_find(&haystack).is_ok_and(|m| m.is_none()));
+        let pattern = Pattern::new(r"(?:a+)+$")
+            .expect("Pattern is correct")
+            .with_step_limit(10_000);
+        assert!(pattern.try_find(&haystack).is_err());
+    }
+
+    #[test]
+    fn lookahead() {
+        let pattern = Pattern::new(r"\w+(?=\d)").expect("Pattern is correct");
+        assert_eq!(pattern.find("ab1").map(|m| m.as_str()), Some("ab"));
+        assert!(!pattern.test("ab"));
+
+        let pattern = Pattern::new(r"a(?!b)").expect("Pattern is correct");
+        assert_eq!(pattern.find("abac").map(|m| m.range()), Some(2..3));
+        assert!(!pattern.test("ab"));
+
+        let pattern = Pattern::new(r"^(?=(\w))\w+$").expect("Pattern is correct");
+        let captures = pattern.captures("abc").expect("Pattern matches");
+        assert_eq!(captures.whole().as_str(), "abc");
+        assert_eq!(captures.get(1), None);
+
+        let pattern = Pattern::new(r"^(?=.*\d)(?=.*[a-z]).{4,}$").expect("Pattern is correct");
+        assert!(pattern.test("ab12"));
+        assert!(!pattern.test("abcd"));
+        assert!(!pattern.test("a1"));
+        assert!(Pattern::new(r"(?=a)*").is_err());
+    }
+
+    #[test]
+    fn lookbehind() {
+        let pattern = Pattern::new(r"(?<=\$)\d+").expect("Pattern is correct");
+        assert_eq!(pattern.find("5 or $12").map(|m| m.as_str()), Some("12"));
+        assert!(!pattern.test("12"));
+
+        let pattern = Pattern::new(r"(?<!é)b").expect("Pattern is correct");
+        assert_eq!(pattern.find("éb ab").map(|m| m.range()), Some(5..6));
+
+        let pattern = Pattern::new(r"(?<=ab|^x|(c)\d{2})y").expect("Pattern is correct");
+        assert!(pattern.test("aby"));
+        assert!(pattern.test("xy"));
+        assert!(!pattern.test("axy"));
+        assert!(pattern.test("c12y"));
+        assert_eq!(pattern.captures("c12y").and_then(|c| c.get(1)), None);
+
+        let pattern = PatternBuilder::new()
+            .newlines(Newlines::Split)
+            .build(r"(?<!a)b")
+            .expect("Pattern is correct");
+        assert_eq!(pattern.find("ab\nb").map(|m| m.range()), Some(3..4));
+
+        assert!(Pattern::new(r"(?<=a+)b").is_err());
+        assert!(Pattern::new(r"(?<=(a|bc))b").is_err());
+        assert!(Pattern::new(r"(?<=a)?b").is_err());
+    }
+
+    #[test]
+    fn inline_flags() {
+        let pattern = Pattern::new(r"a(?i)bc").expect("Pattern is correct");
+        assert!(pattern.test("aBC"));
+        assert!(!pattern.test("ABC"));
+
+        let pattern = Pattern::new(r"(?i:[a-c]+|x)Y").expect("Pattern is correct");
+        assert_eq!(pattern.find("zAbCY").map(|m| m.range()), Some(1..5));
+        assert!(!pattern.test("aby"));
+        assert!(pattern.test("XY"));
+
+        let pattern = Pattern::new(r"(a(?i)b)B").expect("Pattern is correct");
+        assert!(pattern.test("aBB"));
+        assert!(!pattern.test("aBb"));
+
+        let pattern = Pattern::new(r"(?i)[^k]\w").expect("Pattern is correct");
+        assert!(!pattern.test("Kx"));
+        assert!(!pattern.test("\u{212A}x"));
+        assert!(pattern.test("ax"));
+        let pattern = Pattern::new(r"(?i)k").expect("Pattern is correct");
+        assert!(pattern.test("\u{212A}") && pattern.test("K"));
+
+        let pattern = Pattern::new(r"(?i)(\w+) \1").expect("Pattern is correct");
+        assert_eq!(pattern.find("Hey hEY").map(|m| m.as_str()), Some("Hey hEY"));
+
+        let pattern = Pattern::new(r"(?m)^b$").expect("Pattern is correct");
+        assert_eq!(pattern.find("a\nb\nc").map(|m| m.range()), Some(2..3));
+        assert!(!Pattern::new(r"^b$")
+            .expect("Pattern is correct")
+            .test("a\nb"));
+
+        let pattern = Pattern::new(r"(?s)a.c(?-s)a.c").expect("Pattern is correct");
+        assert!(pattern.test("a\nca-c"));
+        assert!(!pattern.test("a\nca\nc"));
+
+        let error = |pattern| match Pattern::new(pattern) {
+            Err(Error::Syntax { message, .. }) => message,
+            other => panic!("Expected a syntax error for {pattern:?}, got {other:?}"),
+        };
+        assert_eq!(error(r"(?x)a"), "Unknown group flag `x`");
+        assert_eq!(error(r"(?i-m-s)a"), "Unknown group flag `-`");
+        assert_eq!(error(r"(?)a"), "Missing group flags");
+        assert_eq!(error(r"(?i)*"), "Nothing to repeat");
+    }
+
+    #[test]
+    fn case_insensitive() {
+        let pattern = Pattern::new_with_flags(r"(?<w>a[b-d]\w)-\k<w>", Flags::CASE_INSENSITIVE)
+            .expect("Pattern is correct");
+        assert!(pattern.test("ACx-acX"));
+        assert!(!pattern.test("ACx-aex"));
+        assert_eq!(pattern.find("xxABC-abc").map(|m| m.start()), Some(2));
+
+        let pattern = Pattern::new_with_flags(r"a(?-i)b", Flags::CASE_INSENSITIVE)
+            .expect("Pattern is correct");
+        assert!(pattern.test("Ab"));
+        assert!(!pattern.test("AB"));
+
+        let pattern = PatternBuilder::new()
+            .flags(Flags::CASE_INSENSITIVE | Flags::MULTI_LINE)
+            .build(r"^x.$")
+            .expect("Pattern is correct");
+        assert_eq!(pattern.find("ab\nX\nXy\n").map(|m| m.range()), Some(5..7));
+    }
+
+    #[test]
+    fn multi_line() {
+        let pattern = PatternBuilder::new()
+            .multi_line(true)
+            .build(r"^\w+$")
+            .expect("Pattern is correct");
+        let found: Vec<_> = pattern
+            .find_iter("ab\n\ncd e\nf")
+            .map(|m| m.range())
+            .collect();
+        assert_eq!(found, [0..2, 9..10]);
+        assert!(pattern.test("x y\nz"));
+
+        let pattern = PatternBuilder::new()
+            .flags(Flags::MULTI_LINE)
+            .multi_line(false)
+            .build(r"^z")
+            .expect("Pattern is correct");
+        assert!(!pattern.test("x y\nz"));
+    }
+
+    #[test]
+    fn absolute_anchors() {
+        let builder = PatternBuilder::new().multi_line(true);
+        let pattern = builder.build(r"\Aa|b\z").expect("Pattern is correct");
+        let found: Vec<_> = pattern.find_iter("a\nab\nb").map(|m| m.range()).collect();
+        assert_eq!(found, [0..1, 5..6]);
+        let pattern = builder.build(r"\w\Z").expect("Pattern is correct");
+        assert_eq!(pattern.find("a\nb\n").map(|m| m.range()), Some(2..3));
+        assert!(!pattern.test("a\n\n"));
+
+        let builder = PatternBuilder::new().newlines(Newlines::Split);
+        let pattern = builder.build(r"\Ab|b\Z").expect("Pattern is correct");
+        assert_eq!(pattern.find("ab\nb\n").map(|m| m.range()), Some(3..4));
+        assert!(!builder
+            .build(r"a\z")
+            .expect("Pattern is correct")
+            .test("a\nb"));
+        assert!(matches!(
+            Pattern::new(r"\A*"),
+            Err(Error::Syntax { position: 2, .. })
+        ));
     }
 
     #[test]
-    fn digit_match() {
-        let pattern = Pattern::new(r"\d").expect("Pattern is correct");
-        assert!(pattern.test("1"));
-        assert!(pattern.test("a2"));
-        assert!(pattern.test("012abcde"));
-        assert!(!pattern.test("lax"));
-        assert!(!pattern.test("abxc"));
+    fn quoted_text() {
+        let pattern = Pattern::new(r"\Q1.5*[x]\E+$").expect("Pattern is correct");
+        assert_eq!(pattern.find("a1.5*[x]]]").map(|m| m.range()), Some(1..10));
+        assert!(!pattern.test("115*[x]"));
+        let pattern = Pattern::new(r"(?i)\Q(a|b)").expect("Pattern is correct");
+        assert!(pattern.test("x(A|B)"));
+        assert!(!pattern.test("a"));
+        assert!(Pattern::new(r"a\Q\E|b").is_ok_and(|p| p.test("a")));
+
+        assert!(matches!(
+            Pattern::new(r"[\Q]\E]"),
+            Err(Error::Syntax { position: 1, .. })
+        ));
+        assert!(matches!(
+            Pattern::new(r"a\E"),
+            Err(Error::Syntax { position: 1, .. })
+        ));
     }
 
     #[test]
-    fn alphanumeric_match() {
-        let pattern = Pattern::new(r"\w").expect("Pattern is correct");
-        assert!(pattern.test("1"));
-        assert!(pattern.test("a"));
-        assert!(pattern.test("Z"));
-        assert!(pattern.test("_"));
-        assert!(!pattern.test("-"));
-        assert!(!pattern.test(":"));
+    fn dot_all() {
+        let pattern = Pattern::new("a.b").expect("Pattern is correct");
+        assert!(!pattern.test("a\nb"));
+        assert!(pattern.test("a\rb"));
+        assert!(Pattern::new("a[^x]b")
+            .expect("Pattern is correct")
+            .test("a\nb"));
+
+        let builder = PatternBuilder::new().dot_all(true);
+        assert!(builder
+            .build("a.b")
+            .expect("Pattern is correct")
+            .test("a\nb"));
+        assert!(!builder
+            .build("(?-s)a.b")
+            .expect("Pattern is correct")
+            .test("a\nb"));
     }
 
     #[test]
-    fn group_match() {
-        let pattern = Pattern::new(r"[a\d]").expect("Pattern is correct");
-        assert!(pattern.test("1"));
-        assert!(pattern.test("a"));
-        assert!(pattern.test("9"));
-        assert!(pattern.test("za"));
-        assert!(!pattern.test("b"));
-        assert!(!pattern.test(":"));
+    fn unicode_shorthands() {
+        let pattern = Pattern::new(r"\d+").expect("Pattern is correct");
+        assert_eq!(pattern.find("٣٤ 56").map(|m| m.as_str()), Some("56"));
+        let pattern = Pattern::new(r"(?u)^\w+\s\d+$").expect("Pattern is correct");
+        assert!(pattern.test("naïve_日本\u{2003}٣٤"));
+        let pattern = Pattern::new(r"(?u)^[^\W\d]+$").expect("Pattern is correct");
+        assert!(pattern.test("é_"));
+        assert!(!pattern.test("é٣"));
+
+        let builder = PatternBuilder::new().unicode(true);
+        assert!(builder
+            .build(r"^\S+$")
+            .expect("Pattern is correct")
+            .test("école"));
+        assert!(!builder
+            .build(r"(?-u)^\w+$")
+            .expect("Pattern is correct")
+            .test("école"));
     }
 
     #[test]
-    fn negative_group_match() {
-        let pattern = Pattern::new(r"[^a\d]").expect("Pattern is correct");
-        assert!(!pattern.test("1"));
-        assert!(!pattern.test("a"));
-        assert!(!pattern.test("9"));
-        assert!(pattern.test("za"));
-        assert!(pattern.test("b"));
-        assert!(pattern.test(":"));
+    fn grapheme_clusters() {
+        let pattern = Pattern::new("(?g)a.c").expect("Pattern is correct");
+        assert_eq!(
+            pattern.find("xae\u{301}c").map(|m| m.as_str()),
+            Some("ae\u{301}c")
+        );
+        assert!(!Pattern::new("a.c")
+            .expect("Pattern is correct")
+            .test("ae\u{301}c"));
+        let pattern = Pattern::new("(?g)^.{3}$").expect("Pattern is correct");
+        assert!(pattern.test("🇫🇷👍🏽\u{1100}\u{1161}"));
+        // `\r\n` is one cluster, with a newline
+        assert!(!Pattern::new("(?g)a.b")
+            .expect("Pattern is correct")
+            .test("a\r\nb"));
+        assert!(Pattern::new("(?gs)a.b")
+            .expect("Pattern is correct")
+            .test("a\r\nb"));
+        assert!(Pattern::new("(?g)(?<=.)a").is_err());
+
+        let builder = PatternBuilder::new().graphemes(true);
+        let pattern = builder.build(r"^[^x]\D$").expect("Pattern is correct");
+        assert!(pattern.test("e\u{301}👨\u{200D}👩\u{200D}👧"));
+        assert!(!pattern.test("x\u{301}!"));
+        assert!(!builder
+            .build("(?-g)^.$")
+            .expect("Pattern is correct")
+            .test("e\u{301}"));
     }
 
     #[test]
-    fn start_of_string_match() {
-        let pattern = Pattern::new(r"^a").expect("Pattern is correct");
-        assert!(pattern.test("a"));
-        assert!(pattern.test("ab"));
-        assert!(!pattern.test("ba"));
+    fn case_folding() {
+        let full = PatternBuilder::new().case_fold(CaseFold::Full);
+        let find = |builder: &PatternBuilder, pattern, haystack| {
+            let pattern = builder.build(pattern).expect("Pattern is correct");
+            pattern.find(haystack).map(|m| m.as_str().to_owned())
+        };
+        assert_eq!(
+            find(&full, "(?i)strasse", "Die Straße"),
+            Some(String::from("Straße"))
+        );
+        assert_eq!(
+            find(&full, "(?i)STRAẞE", "strasse"),
+            Some(String::from("strasse"))
+        );
+        assert_eq!(find(&full, "(?i)ﬁle", "FILE"), Some(String::from("FILE")));
+        // `ß` only matches as a whole, and only where the flag is on
+        assert_eq!(find(&full, "(?i)stras", "Straße"), None);
+        assert_eq!(find(&full, "stra(?i)sse", "STRAße"), None);
+        assert_eq!(
+            find(&full, r"(?i)\Qß\E+", "SSss"),
+            Some(String::from("SSss"))
+        );
+        // Classes still compare single characters
+        assert_eq!(find(&full, "(?i)stra[ß]e", "STRASSE"), None);
+        assert!(full
+            .build("(?i)(?<=ss)x")
+            .is_err_and(|error| error.to_string().contains("fixed width")));
+        assert!(full
+            .build("(?i)(?<=ab)x")
+            .expect("Pattern is correct")
+            .test("ABx"));
+
+        let simple = PatternBuilder::new();
+        assert_eq!(find(&simple, "(?i)strasse", "Straße"), None);
+        assert_eq!(find(&simple, "(?i)i", "I"), Some(String::from("I")));
+
+        let turkic = PatternBuilder::new()
+            .flags(Flags::CASE_INSENSITIVE)
+            .case_fold(CaseFold::Turkic);
+        assert_eq!(
+            find(&turkic, "istanbul", "İSTANBUL"),
+            Some(String::from("İSTANBUL"))
+        );
+        assert_eq!(find(&turkic, "ılık", "ILIK"), Some(String::from("ILIK")));
+        assert_eq!(find(&turkic, "i", "I"), None);
+        assert_eq!(find(&full, "(?i)i", "I"), Some(String::from("I")));
+        // The Kelvin sign folds to `k` too
+        let pattern = turkic.build("kapı").expect("Pattern is correct");
+        let first_bytes = pattern.first_bytes().expect("Starts with a literal");
+        assert_eq!(first_bytes.iter().collect::<Vec<_>>(), [b'K', b'k', 0xE2]);
+
+        let ascii = PatternBuilder::new()
+            .flags(Flags::CASE_INSENSITIVE)
+            .case_fold(CaseFold::Ascii);
+        assert_eq!(find(&ascii, "café", "CAFé"), Some(String::from("CAFé")));
+        assert_eq!(find(&ascii, "café", "CAFÉ"), None);
+        assert_eq!(
+            find(&ascii, "[a-z]+", "Kelvin\u{212A}"),
+            Some(String::from("Kelvin"))
+        );
+        assert_eq!(find(&ascii, r"(\w)\1", "aA"), Some(String::from("aA")));
+        assert_eq!(find(&ascii, r"(.)\1", "éÉ"), None);
+        let pattern = ascii.build("kelvin").expect("Pattern is correct");
+        let first_bytes = pattern.first_bytes().expect("Starts with a literal");
+        assert_eq!(first_bytes.iter().collect::<Vec<_>>(), [b'K', b'k']);
     }
 
     #[test]
-    fn end_of_string_match() {
-        let pattern = Pattern::new(r"a$").expect("Pattern is correct");
-        assert!(pattern.test("a"));
-        assert!(!pattern.test("ab"));
-        assert!(pattern.test("ba"));
+    fn capture_numbering() {
+        let groups = |pattern: &Pattern, haystack| -> Vec<Option<&str>> {
+            let captures = pattern.captures(haystack).expect("Pattern matches");
+            (1..=pattern.groups().len())
+                .map(|index| captures.get(index).map(|m| m.as_str()))
+                .collect()
+        };
+        let pattern = Pattern::new(r"(a)|(?<b>b)|(?:c)(c)").expect("Pattern is correct");
+        assert_eq!(groups(&pattern, "b"), [None, Some("b"), None]);
+        assert_eq!(groups(&pattern, "cc"), [None, None, Some("c")]);
+
+        let pattern = Pattern::new(r"(?:(a)|(b))+").expect("Pattern is correct");
+        assert_eq!(groups(&pattern, "ab"), [Some("a"), Some("b")]);
+        assert_eq!(groups(&pattern, "aab"), [Some("a"), Some("b")]);
+        let pattern = Pattern::new(r"(?:(a)|b)+").expect("Pattern is correct");
+        assert_eq!(groups(&pattern, "ab"), [Some("a")]);
+
+        let pattern = Pattern::new(r"(?i)(?=(\w))(x)(?<!(y))").expect("Pattern is correct");
+        assert_eq!(groups(&pattern, "X"), [None, Some("X"), None]);
+
+        let tokens = [
+            Token::Open(GroupKind::NonCapture),
+            Token::Open(GroupKind::Capture(None)),
+            Token::Literal('a'),
+            Token::Close,
+            Token::Close,
+            Token::Open(GroupKind::Capture(None)),
+            Token::Literal('b'),
+            Token::Close,
+        ];
+        let pattern = Pattern::from_tokens(tokens).expect("Tokens are correct");
+        assert_eq!(groups(&pattern, "ab"), [Some("a"), Some("b")]);
     }
 
     #[test]
-    fn one_or_more_match() {
-        let pattern = Pattern::new(r"ab+c").expect("Pattern is correct");
-        assert!(pattern.test("abc"));
-        assert!(pattern.test("abbc"));
-        assert!(pattern.test("abbbc"));
-        assert!(!pattern.test("ac"));
+    fn degenerate_patterns() {
+        for empty in ["", "|", "(?:)", "(?:)*"] {
+            let pattern = Pattern::new(empty).expect("Pattern is correct");
+            assert_eq!(pattern.find("ab").map(|m| m.range()), Some(0..0), "{empty}");
+            assert!(pattern.test(""), "{empty}");
+            assert!(pattern.warnings().is_empty(), "{empty}");
+        }
+        let pattern = Pattern::new("").expect("Pattern is correct");
+        assert_eq!(pattern.find_iter("ab").count(), 3);
+
+        for anchors in ["^$", "$^", "^^$"] {
+            let pattern = Pattern::new(anchors).expect("Pattern is correct");
+            assert!(pattern.test("") && !pattern.test(" "), "{anchors}");
+        }
+        let pattern = Pattern::new("^$").expect("Pattern is correct");
+        assert!(!pattern.test("\n"));
+        assert!(pattern.test_line("\n") && pattern.test_line("\r\n"));
+        assert!(!pattern.test_line("\n\n"));
+        let pattern = PatternBuilder::new()
+            .multi_line(true)
+            .build("^$")
+            .expect("Pattern is correct");
+        let found: Vec<_> = pattern.find_iter("a\n\nb\n").map(|m| m.start()).collect();
+        assert_eq!(found, [2, 5]);
+
+        for never in ["[]", r"[^\d\D]", r"a[^\s\S]"] {
+            let pattern = Pattern::new(never).expect("Pattern is correct");
+            assert!(!pattern.test("") && !pattern.test("a\n1 x"), "{never}");
+            assert_eq!(pattern.warnings()[0].kind(), WarningKind::EmptyClass);
+        }
     }
 
     #[test]
-    fn zero_or_more_match() {
-        let pattern = Pattern::new(r"ab*c").expect("Pattern is correct");
-        assert!(pattern.test("abc"));
-        assert!(pattern.test("abbc"));
-        assert!(pattern.test("abbbc"));
-        assert!(pattern.test("ac"));
+    fn named_groups() {
+        let pattern = Pattern::new(r"(?<key>\w+)=(?P<value>\d*)(?:(?<rest>;)|$)")
+            .expect("Pattern is correct");
+        let groups: Vec<_> = pattern.groups().iter().map(|g| g.name()).collect();
+        assert_eq!(groups, [Some("key"), Some("value"), Some("rest")]);
+
+        let captures = pattern.captures("a=12").expect("Pattern matches");
+        assert_eq!(captures.name("key").map(|m| m.as_str()), Some("a"));
+        assert_eq!(captures.name("value").map(|m| m.as_str()), Some("12"));
+        assert_eq!(captures.name("rest"), None);
+        assert_eq!(captures.name("missing"), None);
+
+        let (matched, all, named) = pattern.run_named("x b=;");
+        assert!(matched);
+        assert_eq!(all, "b=;");
+        assert_eq!(named["key"], "b");
+        assert_eq!(named["value"], "");
+        assert_eq!(named.len(), 3);
+
+        let error = |pattern| match Pattern::new(pattern) {
+            Err(Error::Syntax {
+                position, message, ..
+            }) => (position, message),
+            other => panic!("Expected a syntax error for {pattern:?}, got {other:?}"),
+        };
+        assert_eq!(error(r"a(?<1x>b)").0, 1);
+        assert_eq!(error(r"(?<x>a)(?<x>b)").1, "Duplicate group name `x`");
+        assert_eq!(error(r"(?<x").0, 0);
     }
 
     #[test]
-    fn zero_or_one_match() {
-        let pattern = Pattern::new(r"ab?c").expect("Pattern is correct");
-        assert!(pattern.test("abc"));
-        assert!(!pattern.test("abbc"));
-        assert!(!pattern.test("abbbc"));
-        assert!(pattern.test("ac"));
+    fn warnings() {
+        let warnings = |pattern| {
+            let pattern = Pattern::new(pattern).expect("Pattern is correct");
+            let found: Vec<_> = pattern
+                .warnings()
+                .iter()
+                .map(|w| (w.kind(), w.position()))
+                .collect();
+            found
+        };
+        assert_eq!(warnings(r"(\w+) \1"), []);
+        assert_eq!(warnings(r"a+b*[^\s]"), []);
+        assert_eq!(
+            warnings(r"(?:a+b)*x[^\d\D]"),
+            [
+                (WarningKind::NestedRepetition, 7),
+                (WarningKind::EmptyClass, 9)
+            ]
+        );
+        assert_eq!(warnings(r"(?:(a)b*)?(?:a+)++\1"), []);
+        assert_eq!(
+            warnings(r"(?:a*){2,}"),
+            [(WarningKind::NestedRepetition, 6)]
+        );
+        assert_eq!(warnings(r"(a)(b)\1"), [(WarningKind::UnreferencedGroup, 3)]);
+    }
+
+    #[test]
+    fn named_backreferences() {
+        let pattern = Pattern::new(r#"(?<q>['"])\w+\k<q>"#).expect("Pattern is correct");
+        assert!(pattern.test(r#"say "hi" now"#));
+        assert!(!pattern.test(r#"say "hi' now"#));
+
+        let pattern = Pattern::new(r"(?P<word>\w+) (?P=word)").expect("Pattern is correct");
+        assert_eq!(pattern.find("a bb bb").map(|m| m.as_str()), Some("bb bb"));
+
+        let error = |pattern| match Pattern::new(pattern) {
+            Err(Error::Syntax { message, .. }) => message,
+            other => panic!("Expected a syntax error for {pattern:?}, got {other:?}"),
+        };
+        assert_eq!(error(r"\k<x>(?<x>a)"), "Unknown group name `x`");
+        assert_eq!(error(r"(?<x>a)\k<x"), "Unterminated backreference");
+        assert_eq!(
+            error(r"(?<x>a)(?P=x)+"),
+            "Repeating a backreference is not supported"
+        );
     }
 
     #[test]
@@ -626,6 +4596,27 @@ mod test {
         assert!(!pattern.test("xyz"));
     }
 
+    #[test]
+    fn alternation_anywhere() {
+        let pattern = Pattern::new("cat|dog").expect("Pattern is correct");
+        assert_eq!(pattern.find("hotdog").map(|m| m.range()), Some(3..6));
+        assert!(!pattern.test("cow"));
+        let pattern = Pattern::new("^a|b$|").expect("Pattern is correct");
+        assert_eq!(pattern.find("xab").map(|m| m.range()), Some(0..0));
+        let pattern = Pattern::new("(?<=ab|c)x(?=y|zz)").expect("Pattern is correct");
+        assert!(pattern.test("abxzz") && pattern.test("cxy") && !pattern.test("bxz"));
+        assert!(Pattern::new("(?:a|bc)+$")
+            .expect("Pattern is correct")
+            .test("abca"));
+
+        let mut pattern = Pattern::new("a|b").expect("Pattern is correct");
+        pattern.push_literal("c");
+        assert!(pattern.test("a") && !pattern.test("b") && pattern.test("bc"));
+        assert_eq!(pattern.pop_literal(), Some('c'));
+        assert!(pattern.anchored_both().test("b"));
+        assert!(!pattern.anchored_both().test("ab"));
+    }
+
     #[test]
     fn match_test() {
         let pattern = Pattern::new(r"([abc]+)(\d+)").expect("Pattern is correct");
@@ -703,6 +4694,651 @@ mod test {
         assert!(pattern.test("abc-def is abc-def, not efg, abc, or def"));
     }
 
+    #[test]
+    fn find_reports_byte_offsets() {
+        let pattern = Pattern::new(r"l+o").expect("Pattern is correct");
+        let found = pattern.find("héllo wörld").expect("Pattern matches");
+        assert_eq!(found.range(), 3..6);
+        assert_eq!(found.char_range(), 2..5);
+        assert_eq!(found.as_str(), "llo");
+        assert!(pattern.find("hello").is_some_and(|m| m.start() == 2));
+        assert!(pattern.find("help").is_none());
+    }
+
+    #[test]
+    fn find_iter_test() {
+        let pattern = Pattern::new(r"\d+").expect("Pattern is correct");
+        let found: Vec<_> = pattern.find_iter("a1b22c333").map(|m| m.as_str()).collect();
+        assert_eq!(found, ["1", "22", "333"]);
+
+        let pattern = Pattern::new(r"x*").expect("Pattern is correct");
+        let found: Vec<_> = pattern.find_iter("axxé").map(|m| m.range()).collect();
+        assert_eq!(found, [0..0, 1..3, 5..5]);
+
+        let pattern = Pattern::new(r"(?:|\s)*").expect("Pattern is correct");
+        let found: Vec<_> = pattern.find_iter(" ").map(|m| m.range()).collect();
+        assert_eq!(found, [0..0, 1..1]);
+    }
+
+    #[test]
+    fn match_indices_test() {
+        for (pattern, haystack) in [
+            (r"\d+", "a1b22c333"),
+            (r"x*", "axxé"),
+            (r"(?<=(a))b(?=(c))", "abcabxabc"),
+            (r"(?:(a)b)?+c", "abcc"),
+        ] {
+            let pattern = Pattern::new(pattern).expect("Pattern is correct");
+            let found: Vec<_> = pattern
+                .find_iter(haystack)
+                .map(|m| (m.start(), m.end()))
+                .collect();
+            assert_eq!(pattern.match_indices(haystack).collect::<Vec<_>>(), found);
+        }
+
+        let pattern = Pattern::new(r"(?:a|aa)*$")
+            .expect("Pattern is correct")
+            .with_step_limit(100);
+        assert_eq!(pattern.match_indices("aaaaab").count(), 0);
+        assert_eq!(pattern.match_indices("a").count(), 1);
+    }
+
+    #[test]
+    fn tree_test() {
+        let pattern = Pattern::new(r"^(a|[^\d])x+\1$").expect("Pattern is correct");
+        assert_eq!(
+            pattern.tree().to_string(),
+            [
+                "group 0",
+                "  start of string",
+                "  group 1",
+                "    branch",
+                "      literal 'a'",
+                "    branch",
+                "      none of",
+                "        digit",
+                "  repeat {1,}",
+                "    literal 'x'",
+                "  backreference \\1",
+                "  end of string",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn trace_test() {
+        let pattern = Pattern::new(r"a?b").expect("Pattern is correct");
+        let mut events = Vec::new();
+        assert!(pattern.trace("ab", |event| events.push(event.to_string())));
+        assert_eq!(
+            events,
+            [
+                "start at 0",
+                "  try repeat {0,1} at 0",
+                "  match repeat {0,1} at 0..1",
+                "  try literal 'b' at 1",
+                "  match literal 'b' at 1..2",
+            ]
+        );
+
+        let mut events = Vec::new();
+        assert!(!pattern.trace("a", |event| events.push(event.kind)));
+        assert!(events.contains(&TraceKind::Backtrack));
+        assert!(events.contains(&TraceKind::Fail));
+    }
+
+    #[test]
+    fn syntax_error_hints() {
+        let error = |pattern| match Pattern::new(pattern) {
+            Err(Error::Syntax { position, hint, .. }) => (position, hint),
+            other => panic!("Expected a syntax error for {pattern:?}, got {other:?}"),
+        };
+
+        assert_eq!(error(r"a(?#b)").0, 1);
+        assert_eq!(error(r"\d{3").0, 2);
+        assert_eq!(error(r"\d{3,1}").0, 2);
+        assert_eq!(error(r"a{2}{3}").0, 4);
+        assert_eq!(error(r"*a").1, r"escape it as `\*` to match it literally");
+        assert_eq!(error(r"ab)").0, 2);
+        assert_eq!(error(r"x(ab").0, 1);
+        assert_eq!(error(r"é[ab").0, 2);
+        assert_eq!(error(r"a\b").0, 1);
+        assert!(matches!(
+            Pattern::new(r"\q"),
+            Err(Error::UnknownCharacterType('q'))
+        ));
+
+        let pattern = Pattern::new(r"{a}x{}").expect("Braces are literal unless they repeat");
+        assert!(pattern.test("{a}x{}"));
+    }
+
+    #[test]
+    fn hex_escapes() {
+        let pattern = Pattern::new(r"\x1b\[[\x30-\x39;]*m").expect("Pattern is correct");
+        assert_eq!(
+            pattern.find("a\u{1b}[1;31mb").map(|m| m.range()),
+            Some(1..8)
+        );
+        let pattern = Pattern::new(r"\x{1F600}\x{e9}\x41").expect("Pattern is correct");
+        assert!(pattern.test("😀éA"));
+        assert_eq!(
+            Pattern::new(r"[\x{0}-\x{7F}]+")
+                .expect("Pattern is correct")
+                .find("éabc")
+                .map(|m| m.range()),
+            Some(2..5)
+        );
+
+        for (pattern, position) in [
+            (r"a\x4", 1),
+            (r"\xg0", 0),
+            (r"\x{}", 0),
+            (r"\x{1234567}", 0),
+            (r"[\x{41]", 1),
+        ] {
+            match Pattern::new(pattern) {
+                Err(Error::Syntax { position: at, .. }) => assert_eq!(at, position, "{pattern}"),
+                other => panic!("Expected a syntax error for {pattern}, got {other:?}"),
+            }
+        }
+        assert!(matches!(
+            Pattern::new(r"\x{D800}"),
+            Err(Error::InvalidCodePoint { position: 0, .. })
+        ));
+    }
+
+    #[test]
+    fn control_escapes() {
+        let pattern = Pattern::new(r"a\tb\r\n\f\v\0").expect("Pattern is correct");
+        assert!(pattern.test("a\tb\r\n\x0C\x0B\0"));
+        let pattern = Pattern::new(r"^[\t\n\0]+$").expect("Pattern is correct");
+        assert!(pattern.test("\t\0\n"));
+        assert!(!pattern.test("\t "));
+        let pattern = Pattern::new(r"(a)\1\01").expect("Pattern is correct");
+        assert!(pattern.test("aa\x001"));
+        assert!(Pattern::new(r"[^\n]+")
+            .expect("Pattern is correct")
+            .test("a\n"));
+    }
+
+    #[test]
+    fn code_point_escapes() {
+        let pattern = Pattern::new(r"\u{1F600}+[\u{61}-\u{63}]").expect("Pattern is correct");
+        assert_eq!(pattern.find("x😀😀b").map(|m| m.range()), Some(1..10));
+
+        for (pattern, escape, position) in [
+            (r"a\u{}", r"\u{}", 1),
+            (r"\u{110000}", r"\u{110000}", 0),
+            (r"\u{DFFF}", r"\u{DFFF}", 0),
+            (r"\u{0000041}", r"\u{0000041}", 0),
+            (r"[\u{+41}]", r"\u{+41}", 1),
+            (r"\u{41", r"\u{41", 0),
+        ] {
+            match Pattern::new(pattern) {
+                Err(Error::InvalidCodePoint {
+                    escape: text,
+                    position: at,
+                }) => assert_eq!((text.as_str(), at), (escape, position), "{pattern}"),
+                other => panic!("Expected an invalid code point for {pattern}, got {other:?}"),
+            }
+        }
+        assert!(matches!(Pattern::new(r"\u0041"), Err(Error::Syntax { .. })));
+    }
+
+    #[test]
+    fn match_to_json() {
+        let pattern = Pattern::new(r"\w+").expect("Pattern is correct");
+        let found = pattern.find("é \"quoted\"").expect("Pattern matches");
+        assert_eq!(
+            found.to_json(),
+            r#"{"start": 4, "end": 10, "text": "quoted"}"#
+        );
+    }
+
+    #[test]
+    fn step_limit() {
+        let pattern = Pattern::new(r"\w*\w*\w*\w*\w*!")
+            .expect("Pattern is correct")
+            .with_step_limit(1000);
+        let haystack = "a".repeat(30);
+        assert!(matches!(
+            pattern.try_find(&haystack),
+            Err(Error::StepLimitExceeded(1000))
+        ));
+        assert!(!pattern.test(&haystack));
+        assert!(pattern.try_find("ab!").is_ok_and(|m| m.is_some()));
+    }
+
+    #[test]
+    fn cancellation() {
+        let cancel = Arc::new(AtomicBool::new(false));
+        let pattern = Pattern::new(r"\w*\w*\w*\w*\w*\w*\w*!")
+            .expect("Pattern is correct")
+            .with_cancellation(Arc::clone(&cancel));
+        let haystack = "a".repeat(100);
+        let result = thread::scope(|scope| {
+            let search = scope.spawn(|| pattern.try_find(&haystack));
+            thread::sleep(Duration::from_millis(10));
+            cancel.store(true, Ordering::Relaxed);
+            search.join().expect("Search doesn't panic")
+        });
+        assert!(matches!(result, Err(Error::Cancelled)));
+
+        cancel.store(false, Ordering::Relaxed);
+        assert!(pattern.try_find("ab!").is_ok_and(|m| m.is_some()));
+    }
+
+    #[test]
+    fn count_unique_test() {
+        let pattern = Pattern::new(r"\d+\.\d+").expect("Pattern is correct");
+        let mut counts = pattern.count_unique("1.2 from 3.4, 1.2 again");
+        counts.add_matches(&pattern, "then 3.4 and 5.6");
+        assert_eq!(counts.len(), 3);
+        assert_eq!(counts.sorted(), [("1.2", 2), ("3.4", 2), ("5.6", 1)]);
+    }
+
+    #[test]
+    fn find_last_test() {
+        let pattern = Pattern::new(r"\d+").expect("Pattern is correct");
+        let found = pattern
+            .find_last("12 and 345 or 6é")
+            .expect("Pattern matches");
+        assert_eq!((found.as_str(), found.start()), ("6", 14));
+        let found: Vec<_> = pattern
+            .rfind_iter("12 ab 345")
+            .map(|m| m.as_str())
+            .collect();
+        assert_eq!(found, ["345", "12"]);
+        assert_eq!(pattern.find_last("none"), None);
+
+        let pattern = Pattern::new("aa").expect("Pattern is correct");
+        let found = pattern.find_last("aaa").map(|m| m.range());
+        assert_eq!(found, Some(1..3));
+
+        let pattern = Pattern::new("x*").expect("Pattern is correct");
+        let found: Vec<_> = pattern.rfind_iter("ax").map(|m| m.range()).collect();
+        assert_eq!(found, [1..2, 0..0]);
+
+        let pattern = Pattern::new("$").expect("Pattern is correct");
+        assert_eq!(pattern.rfind_iter("ab").count(), 1);
+    }
+
+    #[test]
+    fn edit_literals() {
+        let mut pattern = Pattern::new(r"^\d|x").expect("Pattern is correct");
+        pattern.push_literal("y.");
+        assert!(pattern.test("axy."));
+        assert!(!pattern.test("axyz"));
+        assert!(pattern.test("1"));
+        assert_eq!(pattern.pop_literal(), Some('.'));
+        assert!(pattern.test("axy."));
+
+        let mut pattern = Pattern::new(r"a+").expect("Pattern is correct");
+        assert_eq!(pattern.pop_literal(), None);
+        pattern.push_literal("é");
+        assert_eq!(pattern.find("xaaé").map(|m| m.range()), Some(1..5));
+
+        let mut pattern = Pattern::new("(?i)a").expect("Pattern is correct");
+        pattern.push_literal("B");
+        assert!(pattern.test("AB"));
+        assert!(!pattern.test("ab"));
+        assert_eq!(pattern.pop_literal(), Some('B'));
+    }
+
+    #[test]
+    fn group_structure() {
+        let pattern = Pattern::new(r"(a(b)(c(d)))|(e)").expect("Pattern is correct");
+        let spans: Vec<_> = pattern.groups().iter().map(|g| g.span()).collect();
+        assert_eq!(spans, [0..12, 2..5, 5..11, 7..10, 13..16]);
+        let parents: Vec<_> = pattern.groups().iter().map(|g| g.parent()).collect();
+        assert_eq!(parents, [0, 1, 1, 3, 0]);
+        let children: Vec<_> = pattern.child_groups(1).map(|g| g.index()).collect();
+        assert_eq!(children, [2, 3]);
+        let children: Vec<_> = pattern.child_groups(0).map(|g| g.index()).collect();
+        assert_eq!(children, [1, 5]);
+    }
+
+    #[test]
+    fn first_bytes_test() {
+        let first = |pattern: &str| {
+            let pattern = Pattern::new(pattern).expect("Pattern is correct");
+            pattern
+                .first_bytes()
+                .map(|set| set.iter().collect::<Vec<_>>())
+        };
+        assert_eq!(first("abc|x?y|é"), Some(vec![b'a', b'x', b'y', 0xC3]));
+        assert_eq!(
+            first(r"^[\d-]+"),
+            Some([b'-'].into_iter().chain(b'0'..=b'9').collect())
+        );
+        assert_eq!(first("a*b*"), None);
+        assert_eq!(first("(a|)b"), Some(vec![b'a', b'b']));
+        assert_eq!(first("$"), None);
+        let any = Pattern::new(".").expect("Pattern is correct");
+        let first_bytes = any.first_bytes().expect("Pattern starts with a character");
+        assert!(!first_bytes.contains(0x80));
+
+        let mut pattern = Pattern::new("a?").expect("Pattern is correct");
+        pattern.push_literal("b");
+        assert_eq!(pattern.first_bytes().map(|set| set.len()), Some(2));
+        assert_eq!(pattern.find("xxab").map(|m| m.range()), Some(2..4));
+    }
+
+    #[test]
+    fn split_lines() {
+        let builder = PatternBuilder::new().newlines(Newlines::Split);
+        let pattern = builder.build("^b.*$").expect("Pattern is correct");
+        assert_eq!(pattern.find("a\nbc\nd").map(|m| m.range()), Some(2..4));
+        assert_eq!(pattern.find("ab\nb").map(|m| m.range()), Some(3..4));
+        assert_eq!(pattern.find_last("b\nb\n").map(|m| m.range()), Some(2..3));
+        assert_eq!(pattern.strip_prefix_match("b1\nb2"), Some("\nb2"));
+
+        let pattern = builder.build(r"a\sb").expect("Pattern is correct");
+        assert!(!pattern.test("a\nb"));
+        let ordinary = Pattern::new(r"a\sb").expect("Pattern is correct");
+        assert!(ordinary.test("a\nb"));
+    }
+
+    #[test]
+    #[should_panic(expected = "newline at offset 1")]
+    fn assert_single_line() {
+        let builder = PatternBuilder::new().newlines(Newlines::Assert);
+        let pattern = builder.build("a").expect("Pattern is correct");
+        assert!(pattern.test("ab"));
+        pattern.test("b\na");
+    }
+
+    #[test]
+    fn compile_parsed() {
+        let ast = PatternBuilder::new()
+            .parse("(?s)^a.b$")
+            .expect("Pattern is correct");
+        assert!(ast.groups().is_empty());
+
+        let ordinary = PatternBuilder::new().compile(&ast);
+        let split = PatternBuilder::new()
+            .newlines(Newlines::Split)
+            .compile(&ast);
+        assert!(ordinary.test("a\nb"));
+        assert!(!split.test("a\nb"));
+        assert!(split.test("x\na b"));
+    }
+
+    #[test]
+    fn partial_match() {
+        let pattern = Pattern::new(r"^\d{3}-\d{4}$").expect("Pattern is correct");
+        assert!(pattern.is_partial_match(""));
+        assert!(pattern.is_partial_match("555-1"));
+        assert!(pattern.is_partial_match("555-1234"));
+        assert!(!pattern.is_partial_match("555-12345"));
+        assert!(!pattern.is_partial_match("55x"));
+
+        let pattern = Pattern::new(r"ab+c").expect("Pattern is correct");
+        assert!(pattern.is_partial_match("xxabbb"));
+        assert!(pattern.is_partial_match("xxa"));
+        assert!(!pattern.is_partial_match("xxabd"));
+
+        let pattern = Pattern::new(r"(ab)-\1").expect("Pattern is correct");
+        assert!(pattern.is_partial_match("ab-a"));
+        assert!(!pattern.is_partial_match("ab-b"));
+    }
+
+    #[test]
+    fn anchoring() {
+        let pattern = Pattern::new("ab|^c").expect("Pattern is correct");
+        let start = pattern.anchored_start();
+        assert!(start.test("ab"));
+        assert!(start.test("cx"));
+        assert!(!start.test("xab"));
+
+        let end = pattern.anchored_end();
+        assert!(end.test("xab"));
+        assert!(!end.test("abx"));
+        assert!(!end.test("xc"));
+
+        let both = pattern.anchored_both();
+        assert!(both.test("ab"));
+        assert!(both.test("c"));
+        assert!(!both.test("abab"));
+        assert_eq!(
+            both.tree().to_string(),
+            pattern.anchored_end().anchored_start().tree().to_string()
+        );
+        assert!(pattern.test("xab"));
+    }
+
+    #[test]
+    fn test_many_lines() {
+        let pattern = Pattern::new(r"^\d+$").expect("Pattern is correct");
+        let lines: Vec<String> = (0..1000)
+            .map(|n| {
+                if n % 3 == 0 {
+                    format!("{n}")
+                } else {
+                    format!("x{n}")
+                }
+            })
+            .collect();
+        let results = pattern.test_many(&lines);
+        assert_eq!(results.len(), 1000);
+        assert!(results.iter().enumerate().all(|(n, r)| *r == (n % 3 == 0)));
+        assert!(pattern.test_many::<&str>(&[]).is_empty());
+    }
+
+    #[test]
+    fn search_counters() {
+        let pattern = Pattern::new(r"ab+c").expect("Pattern is correct");
+        let mut counters = SearchCounters::default();
+        let found = pattern.find_counted("xxabbd abc", &mut counters);
+        assert_eq!(found.map(|m| m.range()), Some(7..10));
+        assert_eq!(counters.positions, 2);
+        assert_eq!(counters.skipped, 6);
+        assert!(counters.backtracks > 0);
+        assert!(counters.steps > 4);
+
+        let before = counters;
+        assert_eq!(pattern.find_counted("x", &mut counters), None);
+        assert_eq!(counters.skipped, before.skipped + 2);
+    }
+
+    #[test]
+    fn split_str_test() {
+        let pattern = Pattern::new(r"[,;] ?").expect("Pattern is correct");
+        let parts: Vec<_> = pattern.split_str("a, b;c,").collect();
+        assert_eq!(parts, ["a", "b", "c", ""]);
+        assert_eq!(pattern.split_str("").collect::<Vec<_>>(), [""]);
+    }
+
+    #[test]
+    fn strip_prefix_match_test() {
+        let pattern = Pattern::new(r"\d+").expect("Pattern is correct");
+        assert_eq!(pattern.strip_prefix_match("123abc"), Some("abc"));
+        assert_eq!(pattern.strip_prefix_match("123"), Some(""));
+        assert_eq!(pattern.strip_prefix_match("abc123"), None);
+
+        let number = Pattern::new(r"\d+").expect("Pattern is correct");
+        let plus = Pattern::new(r" *\+ *").expect("Pattern is correct");
+        let (first, rest) = number.strip_prefix("12 + 3").expect("Pattern matches");
+        let (_, rest) = plus.strip_prefix(rest).expect("Pattern matches");
+        let (second, rest) = number.strip_prefix(rest).expect("Pattern matches");
+        assert_eq!((first.as_str(), second.as_str(), rest), ("12", "3", ""));
+    }
+
+    #[test]
+    fn capture_participation() {
+        let pattern = Pattern::new("(a)|(b)()").expect("Pattern is correct");
+        let captures = pattern.captures("b").expect("Pattern matches");
+        assert_eq!(captures.len(), 4);
+        assert_eq!(captures.whole().as_str(), "b");
+        assert!(!captures.participated(1));
+        assert_eq!(captures.get(2).map(|m| m.as_str()), Some("b"));
+        assert!(captures.participated(3));
+        assert_eq!(captures.get(3).map(|m| m.range()), Some(1..1));
+        assert!(!captures.participated(4));
+        assert_eq!(
+            captures.to_json(),
+            r#"{"start": 0, "end": 1, "text": "b", "groups": [null, {"start": 0, "end": 1, "text": "b"}, {"start": 1, "end": 1, "text": ""}]}"#
+        );
+
+        assert!(pattern.captures("c").is_none());
+    }
+
+    #[test]
+    fn match_context() {
+        let pattern = Pattern::new("needle").expect("Pattern is correct");
+        let haystack = "one\ntwo\nthe needle é\nfour\nfive";
+        let found = pattern.find(haystack).expect("Pattern matches");
+
+        let context = found.context(ContextSize::Chars(2));
+        assert_eq!((context.before(), context.after()), ("e ", " é"));
+        let context = found.context(ContextSize::Chars(100));
+        assert_eq!(context.as_str(), haystack);
+
+        let context = found.context(ContextSize::Lines(0));
+        assert_eq!(context.as_str(), "the needle é");
+        let context = found.context(ContextSize::Lines(1));
+        assert_eq!(
+            (context.before(), context.after()),
+            ("two\nthe ", " é\nfour")
+        );
+        assert_eq!(found.context(ContextSize::Lines(5)).as_str(), haystack);
+
+        let pattern = Pattern::new("o\n").expect("Pattern is correct");
+        let found = pattern.find("one\ntwo\nthree").expect("Pattern matches");
+        assert_eq!(found.context(ContextSize::Lines(0)).as_str(), "two\n");
+    }
+
+    #[test]
+    fn named_classes() {
+        let builder = PatternBuilder::new()
+            .class("hexdigit", |ch| ch.is_ascii_hexdigit())
+            .class_set("sign", "+-");
+        let pattern = builder
+            .build("^[[:sign:]]?0x[[:hexdigit:]_]+$")
+            .expect("Pattern is correct");
+        assert!(pattern.test("0xdead_BEEF"));
+        assert!(pattern.test("-0x1"));
+        assert!(!pattern.test("0xg"));
+        let pattern = builder
+            .build(r"\p{hexdigit}\p{sign}")
+            .expect("Pattern is correct");
+        assert!(pattern.test("f+"));
+        assert!(!pattern.test("g+"));
+
+        assert!(builder
+            .build("[[:hexdigit]")
+            .expect("Pattern is correct")
+            .test(":"));
+        for (pattern, position) in [("[[:octal:]]", 1), (r"a\p{octal}", 1), (r"\p{a b}", 0)] {
+            match builder.build(pattern) {
+                Err(Error::Syntax { position: at, .. }) => assert_eq!(at, position, "{pattern}"),
+                other => panic!("Expected a syntax error for {pattern}, got {other:?}"),
+            }
+        }
+        assert!(Pattern::new("[[:hexdigit:]]").is_err());
+    }
+
+    #[test]
+    fn posix_classes() {
+        let pattern = Pattern::new("^[[:alpha:]_][[:alnum:]_]*$").expect("Pattern is correct");
+        assert!(pattern.test("_tmp1"));
+        assert!(!pattern.test("1tmp"));
+        assert!(!pattern.test("é"));
+        let pattern = Pattern::new("[^[:space:][:punct:]]").expect("Pattern is correct");
+        assert_eq!(pattern.find(" ,\t.x").map(|m| m.start()), Some(4));
+        let pattern = Pattern::new("(?i)^[[:upper:]]+$").expect("Pattern is correct");
+        assert!(pattern.test("aBc"));
+        assert!(Pattern::new("[[:xdigit:][:blank:]]+")
+            .expect("Pattern is correct")
+            .test("f 0"));
+
+        let builder = PatternBuilder::new().class_set("digit", "01");
+        assert!(!builder
+            .build("[[:digit:]]")
+            .expect("Pattern is correct")
+            .test("2"));
+        match Pattern::new("[a[:alfa:]]") {
+            Err(Error::Syntax {
+                message, position, ..
+            }) => assert_eq!((message.as_str(), position), ("Unknown class `alfa`", 2)),
+            other => panic!("Expected a syntax error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn unicode_properties() {
+        let pattern = Pattern::new(r"^\p{L}+ \p{Nd}+\p{Punct}$").expect("Pattern is correct");
+        assert!(pattern.test("Größe ٤٢!"));
+        assert!(!pattern.test("Größe 42a"));
+        let pattern = Pattern::new(r"\pL\P{L}+").expect("Pattern is correct");
+        assert_eq!(pattern.find("éé—1").map(|m| m.as_str()), Some("é—1"));
+        let pattern = Pattern::new(r"[^\p{Lu}\s]+").expect("Pattern is correct");
+        assert_eq!(
+            pattern.find("ÉCOLE école").map(|m| m.as_str()),
+            Some("école")
+        );
+        assert!(Pattern::new(r"(?i)^\p{Lu}$")
+            .expect("Pattern is correct")
+            .test("ж"));
+        let pattern = Pattern::new(r"\p{Greek}+ \P{Cyrillic}").expect("Pattern is correct");
+        assert_eq!(pattern.find("абв αβγ δ").map(|m| m.as_str()), Some("αβγ δ"));
+
+        let builder = PatternBuilder::new().class_set("L", "ab");
+        assert!(!builder
+            .build(r"\p{L}")
+            .expect("Pattern is correct")
+            .test("c"));
+        for (pattern, message, position) in [
+            (r"a\p{Klingon}", "Unknown class `Klingon`", 1),
+            (r"\P1", "Invalid class name", 0),
+            (r"\p{L", "Invalid class name", 0),
+        ] {
+            match Pattern::new(pattern) {
+                Err(Error::Syntax {
+                    message: m,
+                    position: p,
+                    ..
+                }) => assert_eq!((m.as_str(), p), (message, position)),
+                other => panic!("Expected a syntax error for {pattern}, got {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn size_limits() {
+        let builder = PatternBuilder::new()
+            .size_limit(5)
+            .nest_limit(2)
+            .class_size_limit(3);
+        assert!(builder.build("((a))[abc]").is_ok());
+        for (pattern, kind, position) in [
+            ("abcdef", Limit::Size, 5),
+            ("(a(b(c)))", Limit::Nesting, 4),
+            ("[abcd]", Limit::ClassSize, 0),
+        ] {
+            match builder.build(pattern) {
+                Err(Error::LimitExceeded {
+                    kind: k,
+                    position: p,
+                    ..
+                }) => assert_eq!((k, p), (kind, position), "{pattern}"),
+                other => panic!("Expected a limit error for {pattern}, got {other:?}"),
+            }
+        }
+
+        let deep = format!("{}a{}", "(".repeat(10_000), ")".repeat(10_000));
+        assert!(matches!(
+            Pattern::new(&deep),
+            Err(Error::LimitExceeded {
+                kind: Limit::Nesting,
+                limit: 250,
+                ..
+            })
+        ));
+    }
+
     #[test]
     fn full_test() {
         let pattern = Pattern::new(r"a\d[\w:][^x]").expect("Pattern is correct");
@@ -0,0 +1,141 @@
+//! Searching for many literal strings at once, in a single pass over the haystack,
+//! see [`Literals`]. This is what makes [`PatternSet::literals`] scale to large
+//! lists of fixed strings.
+//!
+//! [`PatternSet::literals`]: crate::PatternSet::literals
+
+use std::collections::VecDeque;
+
+/// The root of the trie, the state before any byte was read.
+const ROOT: usize = 0;
+
+/// An Aho-Corasick automaton over the bytes of a list of literals.
+///
+/// States are the prefixes of the literals, in a trie. When the next byte doesn't
+/// continue the current prefix, the search falls back to the longest suffix of it
+/// that is also a prefix, so every byte of the haystack is read once.
+#[derive(Debug, Clone)]
+pub(crate) struct Literals {
+    /// Transitions out of every state, sorted by byte.
+    next: Vec<Vec<(u8, usize)>>,
+    /// The state to fall back to when no transition matches.
+    fail: Vec<usize>,
+    /// Indices of the literals that end at every state, also those ending at
+    /// the states it falls back to.
+    found: Vec<Vec<usize>>,
+}
+
+impl Literals {
+    pub(crate) fn new<S: AsRef<str>>(literals: &[S]) -> Self {
+        let mut automaton = Self {
+            next: vec![Vec::new()],
+            fail: vec![ROOT],
+            found: vec![Vec::new()],
+        };
+        for (index, literal) in literals.iter().enumerate() {
+            let mut state = ROOT;
+            for &byte in literal.as_ref().as_bytes() {
+                state = match automaton.step(state, byte) {
+                    Some(next) => next,
+                    None => automaton.add_state(state, byte),
+                };
+            }
+            automaton.found[state].push(index);
+        }
+        automaton.link();
+        automaton
+    }
+
+    fn add_state(&mut self, from: usize, byte: u8) -> usize {
+        let state = self.next.len();
+        self.next.push(Vec::new());
+        self.fail.push(ROOT);
+        self.found.push(Vec::new());
+        let next = &mut self.next[from];
+        let at = next.partition_point(|(b, _)| *b < byte);
+        next.insert(at, (byte, state));
+        state
+    }
+
+    /// The trie transition from `state` on `byte`, if there is one.
+    fn step(&self, state: usize, byte: u8) -> Option<usize> {
+        let next = &self.next[state];
+        let at = next.binary_search_by_key(&byte, |(b, _)| *b).ok()?;
+        Some(next[at].1)
+    }
+
+    /// The state after `byte`, falling back as far as needed.
+    fn advance(&self, mut state: usize, byte: u8) -> usize {
+        loop {
+            if let Some(next) = self.step(state, byte) {
+                return next;
+            }
+            if state == ROOT {
+                return ROOT;
+            }
+            state = self.fail[state];
+        }
+    }
+
+    /// Sets the fall back of every state below the root, whose children fall back to
+    /// it. Breadth first, so that those of shorter prefixes are known first.
+    fn link(&mut self) {
+        let mut queue: VecDeque<usize> = self.next[ROOT].iter().map(|(_, s)| *s).collect();
+        while let Some(state) = queue.pop_front() {
+            for at in 0..self.next[state].len() {
+                let (byte, next) = self.next[state][at];
+                let fail = self.advance(self.fail[state], byte);
+                self.fail[next] = fail;
+                let inherited = self.found[fail].clone();
+                self.found[next].extend(inherited);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    /// Whether any of the literals occurs in `haystack`.
+    pub(crate) fn is_match(&self, haystack: &str) -> bool {
+        if !self.found[ROOT].is_empty() {
+            return true;
+        }
+        let mut state = ROOT;
+        haystack.bytes().any(|byte| {
+            state = self.advance(state, byte);
+            !self.found[state].is_empty()
+        })
+    }
+
+    /// Indices of the literals that occur in `haystack`, in ascending order.
+    pub(crate) fn matches(&self, haystack: &str) -> Vec<usize> {
+        let mut matched = self.found[ROOT].clone();
+        let mut state = ROOT;
+        for byte in haystack.bytes() {
+            state = self.advance(state, byte);
+            matched.extend(&self.found[state]);
+        }
+        matched.sort_unstable();
+        matched.dedup();
+        matched
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Literals;
+
+    #[test]
+    fn find_literals() {
+        let literals = Literals::new(&["he", "she", "his", "hers", "é", "he"]);
+        assert_eq!(literals.matches("ushers"), [0, 1, 3, 5]);
+        assert_eq!(literals.matches("this"), [2]);
+        assert_eq!(literals.matches("café"), [4]);
+        assert!(literals.matches("xyz").is_empty());
+        assert!(literals.is_match("ahisb"));
+        assert!(!literals.is_match("hi"));
+
+        let literals = Literals::new(&["abc", ""]);
+        assert_eq!(literals.matches("x"), [1]);
+        assert!(literals.is_match(""));
+        assert!(!Literals::new::<&str>(&[]).is_match("abc"));
+    }
+}
@@ -1,25 +1,1538 @@
+use std::borrow::Cow;
+use std::collections::HashSet;
 use std::env;
+use std::fs;
 use std::io;
+use std::io::{BufRead, IsTerminal, Write};
+use std::mem;
+use std::num::NonZeroUsize;
 use std::process;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, OnceLock};
+use std::time::Instant;
 
-use grep_starter_rust::Pattern;
+use anyhow::{bail, Context};
+use grep_starter_rust::printer::{Format, Printer};
+use grep_starter_rust::set::{parse_pattern_file, parse_rules_file, Classifier, SetStats};
+use grep_starter_rust::{
+    json, nfc, CaseFold, Error, Flags, PatternBuilder, PatternSet, SearchCounters, UniqueCounts,
+    WarningKind,
+};
 
-// Usage: echo <input_text> | your_grep.sh -E <pattern>
-fn main() -> anyhow::Result<()> {
-    if env::args().nth(1).unwrap() != "-E" {
-        eprintln!("Expected first argument to be '-E'");
-        process::exit(1);
+/// What to do with input lines that are not valid UTF-8.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum InvalidUtf8 {
+    /// Fail the run, naming the offending line.
+    Error,
+    /// Replace invalid sequences with U+FFFD and keep searching.
+    Lossy,
+    /// Warn about the offending line and leave it out of the search.
+    Skip,
+    /// Search the input lossily, but only report that binary input matched.
+    Binary,
+}
+
+impl FromStr for InvalidUtf8 {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        match s {
+            "error" => Ok(Self::Error),
+            "lossy" => Ok(Self::Lossy),
+            "skip" => Ok(Self::Skip),
+            "binary" => Ok(Self::Binary),
+            other => bail!("Unknown invalid UTF-8 policy `{other}`"),
+        }
+    }
+}
+
+/// What to do with lines longer than `--max-line-length`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LongLines {
+    /// Warn about the line and leave it out of the search.
+    Skip,
+    /// Search the line in consecutive windows of the maximum length. Matches that
+    /// cross a window boundary are not found, and `^` and `$` match at the ends of
+    /// every window. The line is still counted once, as matching if any window did.
+    Window,
+}
+
+impl FromStr for LongLines {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        match s {
+            "skip" => Ok(Self::Skip),
+            "window" => Ok(Self::Window),
+            other => bail!("Unknown long line mode `{other}`"),
+        }
+    }
+}
+
+/// Which repeated matching lines `--dedupe` leaves out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Dedupe {
+    /// Lines identical to the previous printed line.
+    Consecutive,
+    /// Lines identical to any earlier printed line, as far as the last
+    /// [`DEDUPE_LIMIT`] distinct lines are remembered.
+    Global,
+}
+
+impl FromStr for Dedupe {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        match s {
+            "consecutive" => Ok(Self::Consecutive),
+            "global" => Ok(Self::Global),
+            other => bail!("Unknown dedupe mode `{other}`"),
+        }
+    }
+}
+
+/// Environment variable giving the step limit when `--step-limit` isn't, so that
+/// deployed scripts can be tuned without editing every call.
+const STEP_LIMIT_VAR: &str = "GREP_RS_STEP_LIMIT";
+
+/// The shortest `--max-line-length`, that of the longest UTF-8 character: windows
+/// of long lines have to fit one to make progress.
+const MIN_LINE_LENGTH: usize = 4;
+
+/// How many distinct lines `--dedupe=global` remembers before starting over, which
+/// bounds its memory use on huge inputs.
+const DEDUPE_LIMIT: usize = 100_000;
+
+/// A random sample of the input's lines, kept while reading it for `--sample-seed`.
+///
+/// Reservoir sampling: the `n`th line replaces a random kept one with probability
+/// `size / n`, so that every line is equally likely to end up in the sample.
+#[derive(Debug)]
+struct Reservoir {
+    size: usize,
+    /// `(number, offset, line)` of the kept lines.
+    lines: Vec<(usize, usize, Vec<u8>)>,
+    seen: usize,
+    /// State of a splitmix64 generator, good enough to pick lines.
+    state: u64,
+}
+
+impl Reservoir {
+    fn new(size: usize, seed: u64) -> Self {
+        Self {
+            size,
+            lines: Vec::with_capacity(size),
+            seen: 0,
+            state: seed,
+        }
+    }
+
+    fn random(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    fn offer(&mut self, number: usize, offset: usize, line: &[u8]) {
+        self.seen += 1;
+        if self.lines.len() < self.size {
+            self.lines.push((number, offset, line.to_vec()));
+            return;
+        }
+        let index = (self.random() % self.seen as u64) as usize;
+        if index < self.size {
+            self.lines[index] = (number, offset, line.to_vec());
+        }
+    }
+
+    /// The kept lines, in input order.
+    fn into_lines(mut self) -> Vec<(usize, usize, Vec<u8>)> {
+        self.lines.sort_by_key(|(number, _, _)| *number);
+        self.lines
+    }
+}
+
+/// Remembers printed lines to recognize repeats, see [`Dedupe`].
+#[derive(Debug)]
+struct Deduper {
+    mode: Dedupe,
+    seen: HashSet<String>,
+    last: Option<String>,
+}
+
+impl Deduper {
+    fn new(mode: Dedupe) -> Self {
+        Self {
+            mode,
+            seen: HashSet::new(),
+            last: None,
+        }
+    }
+
+    /// Whether `line` repeats an earlier line, remembering it if it doesn't.
+    fn is_repeat(&mut self, line: &str) -> bool {
+        match self.mode {
+            Dedupe::Consecutive => {
+                if self.last.as_deref() == Some(line) {
+                    return true;
+                }
+                self.last = Some(String::from(line));
+                false
+            }
+            Dedupe::Global => {
+                if self.seen.contains(line) {
+                    return true;
+                }
+                if self.seen.len() == DEDUPE_LIMIT {
+                    self.seen.clear();
+                }
+                self.seen.insert(String::from(line));
+                false
+            }
+        }
+    }
+}
+
+/// A pattern given on the command line, kept in order until all arguments are
+/// known, since `-F` changes how pattern files are read.
+#[derive(Debug)]
+enum PatternArg {
+    Pattern(String),
+    /// The path and contents of a pattern file.
+    File(String, String),
+}
+
+#[derive(Debug)]
+struct Options {
+    /// Lines matching any of these are printed, there is at least one unless
+    /// `classify` is given.
+    patterns: Vec<String>,
+    /// Match `patterns` as they are, with no special characters. Pattern files
+    /// then have one string per line, with no definitions.
+    fixed_strings: bool,
+    /// `(name, pattern)` rules to sort lines by instead: lines are printed prefixed
+    /// with the name of the first rule they match, see the library's `Classifier`.
+    classify: Option<Vec<(String, String)>>,
+    /// Match patterns in any case, comparing their literals with this folding. `-i`
+    /// folds single characters only.
+    case_fold: Option<CaseFold>,
+    invalid_utf8: InvalidUtf8,
+    /// Normalize lines and patterns to NFC before matching, so that precomposed
+    /// characters and combining marks match each other. Lines are printed as
+    /// normalized.
+    normalize: bool,
+    line_terminator: u8,
+    max_line_length: Option<usize>,
+    long_lines: LongLines,
+    debug_ast: bool,
+    debug_trace: bool,
+    /// Report on stderr how much work the engine did: the offsets it tried and
+    /// those the first byte prefilter skipped, matcher steps and backtracks.
+    debug_perf: bool,
+    /// Don't report likely mistakes in the patterns on stderr before searching.
+    no_pattern_warnings: bool,
+    /// How much to report on stderr: 1 for a summary of each phase, 2 to also
+    /// report every searched line.
+    verbose: u8,
+    /// Print matching lines as JSON records, see the library's `json` module.
+    json: bool,
+    /// Print matching lines with every match replaced by this template, see the
+    /// library's `replace` module for its syntax. With several patterns, those that
+    /// matched the line replace in turn, each seeing the previous replacements.
+    replace: Option<String>,
+    /// With `replace`, print each line it changes as a pair, as it was after `-`
+    /// and as it becomes after `+`, highlighting the change on a terminal.
+    diff: bool,
+    /// Copy every line to the output, with all matches replaced by this template,
+    /// like `sed 's/.../.../g'`.
+    rewrite: Option<String>,
+    /// Instead of matching lines, print each distinct matched text with the number
+    /// of times it was matched, most frequent first.
+    count_unique: bool,
+    /// Instead of matching lines, print how many lines matched, if any did.
+    count: bool,
+    /// With `count`, also print the count when no line matched.
+    include_zero: bool,
+    /// Prefix matching lines with the numbers of the patterns that matched them,
+    /// counting from 1 in the order they were given.
+    show_patterns: bool,
+    /// Leave out matching lines that repeat earlier ones.
+    dedupe: Option<Dedupe>,
+    /// Print each match on its own line instead of the lines they are on.
+    only_matching: bool,
+    /// With `only_matching`, print at most this many matches of each line.
+    max_per_line: Option<usize>,
+    /// Prefix output with its byte offset in the input, as `offset:length:` for
+    /// `-o` matches.
+    byte_offset: bool,
+    /// Report on stderr how many lines each pattern matched and the time spent on
+    /// it, slowest first, to find dead or slow rules in large pattern files.
+    pattern_stats: bool,
+    /// Stop once this many lines were printed. When output is cut short by this or
+    /// `max_output_bytes`, a notice goes to stderr and the exit status is 3.
+    max_output_lines: Option<usize>,
+    /// Stop once this many bytes were printed, cutting the last line short.
+    max_output_bytes: Option<usize>,
+    /// Only search this many lines, the first ones, then report on stderr how many
+    /// lines would match in the whole input at the same rate.
+    sample: Option<usize>,
+    /// Sample lines at random, with this seed, instead of taking the first ones.
+    sample_seed: Option<u64>,
+    /// Bound the matcher steps of every search, lines that need more don't match.
+    /// Taken from [`STEP_LIMIT_VAR`] if not given.
+    step_limit: Option<usize>,
+}
+
+impl Options {
+    fn parse(mut args: impl Iterator<Item = String>) -> anyhow::Result<Self> {
+        let mut pattern_args = Vec::new();
+        let mut fixed_strings = false;
+        let mut classify = None;
+        let mut case_fold = None;
+        let mut invalid_utf8 = InvalidUtf8::Error;
+        let mut normalize = false;
+        let mut line_terminator = b'\n';
+        let mut max_line_length = None;
+        let mut long_lines = LongLines::Skip;
+        let mut debug_ast = false;
+        let mut debug_trace = false;
+        let mut debug_perf = false;
+        let mut no_pattern_warnings = false;
+        let mut verbose = 0;
+        let mut json = false;
+        let mut replace = None;
+        let mut diff = false;
+        let mut rewrite = None;
+        let mut count_unique = false;
+        let mut count = false;
+        let mut include_zero = false;
+        let mut show_patterns = false;
+        let mut dedupe = None;
+        let mut only_matching = false;
+        let mut max_per_line = None;
+        let mut byte_offset = false;
+        let mut pattern_stats = false;
+        let mut max_output_lines = None;
+        let mut max_output_bytes = None;
+        let mut sample = None;
+        let mut sample_seed = None;
+        let mut step_limit = None;
+
+        while let Some(arg) = args.next() {
+            if arg == "-E" || arg == "-e" {
+                pattern_args
+                    .push(PatternArg::Pattern(args.next().with_context(|| {
+                        format!("Expected a pattern after '{arg}'")
+                    })?));
+            } else if arg == "-f" {
+                let path = args.next().context("Expected a file after '-f'")?;
+                let text = fs::read_to_string(&path)
+                    .with_context(|| format!("Failed to read pattern file `{path}`"))?;
+                pattern_args.push(PatternArg::File(path, text));
+            } else if arg == "-F" || arg == "--fixed-strings" {
+                fixed_strings = true;
+            } else if arg == "--classify" {
+                let path = args.next().context("Expected a file after '--classify'")?;
+                let text = fs::read_to_string(&path)
+                    .with_context(|| format!("Failed to read rules file `{path}`"))?;
+                classify = Some(
+                    parse_rules_file(&text)
+                        .with_context(|| format!("Invalid rules file `{path}`"))?,
+                );
+            } else if arg == "-i" || arg == "--ignore-case" {
+                case_fold = case_fold.or(Some(CaseFold::Simple));
+            } else if arg == "--normalize" {
+                normalize = true;
+            } else if arg == "--debug-ast" {
+                debug_ast = true;
+            } else if arg == "--debug-trace" {
+                debug_trace = true;
+            } else if arg == "--debug-perf" {
+                debug_perf = true;
+            } else if arg == "--no-pattern-warnings" {
+                no_pattern_warnings = true;
+            } else if arg == "--json" {
+                json = true;
+            } else if arg == "--diff" {
+                diff = true;
+            } else if arg == "-c" || arg == "--count" {
+                count = true;
+            } else if arg == "--include-zero" {
+                include_zero = true;
+            } else if arg == "--count-unique" {
+                count_unique = true;
+            } else if arg == "--show-patterns" {
+                show_patterns = true;
+            } else if arg == "-o" || arg == "--only-matching" {
+                only_matching = true;
+            } else if arg == "-b" || arg == "--byte-offset" {
+                byte_offset = true;
+            } else if arg == "--pattern-stats" {
+                pattern_stats = true;
+            } else if arg == "--dedupe" {
+                dedupe = Some(Dedupe::Global);
+            } else if arg == "-v" || arg == "--verbose" {
+                verbose += 1;
+            } else if arg == "-vv" {
+                verbose += 2;
+            } else if let Some(policy) = arg.strip_prefix("--invalid-utf8=") {
+                invalid_utf8 = policy.parse()?;
+            } else if let Some(mode) = arg.strip_prefix("--case-fold=") {
+                case_fold = Some(parse_case_fold(mode)?);
+            } else if let Some(terminator) = arg.strip_prefix("--line-terminator=") {
+                line_terminator = parse_terminator(terminator)?;
+            } else if let Some(length) = arg.strip_prefix("--max-line-length=") {
+                max_line_length = Some(
+                    length
+                        .parse()
+                        .with_context(|| format!("Invalid line length `{length}`"))?,
+                );
+            } else if let Some(mode) = arg.strip_prefix("--long-lines=") {
+                long_lines = mode.parse()?;
+            } else if let Some(lines) = arg.strip_prefix("--max-output-lines=") {
+                max_output_lines = Some(
+                    lines
+                        .parse()
+                        .with_context(|| format!("Invalid line count `{lines}`"))?,
+                );
+            } else if let Some(matches) = arg.strip_prefix("--max-per-line=") {
+                max_per_line = Some(
+                    matches
+                        .parse()
+                        .with_context(|| format!("Invalid match count `{matches}`"))?,
+                );
+            } else if let Some(bytes) = arg.strip_prefix("--max-output-bytes=") {
+                max_output_bytes = Some(
+                    bytes
+                        .parse()
+                        .with_context(|| format!("Invalid byte count `{bytes}`"))?,
+                );
+            } else if let Some(steps) = arg.strip_prefix("--step-limit=") {
+                step_limit = Some(
+                    steps
+                        .parse()
+                        .with_context(|| format!("Invalid step limit `{steps}`"))?,
+                );
+            } else if let Some(lines) = arg.strip_prefix("--sample=") {
+                sample = Some(
+                    lines
+                        .parse()
+                        .map(NonZeroUsize::get)
+                        .with_context(|| format!("Invalid line count `{lines}`"))?,
+                );
+            } else if let Some(seed) = arg.strip_prefix("--sample-seed=") {
+                sample_seed = Some(
+                    seed.parse()
+                        .with_context(|| format!("Invalid seed `{seed}`"))?,
+                );
+            } else if let Some(mode) = arg.strip_prefix("--dedupe=") {
+                dedupe = Some(mode.parse()?);
+            } else if let Some(template) = arg.strip_prefix("--replace=") {
+                replace = Some(String::from(template));
+            } else if let Some(template) = arg.strip_prefix("--rewrite=") {
+                rewrite = Some(String::from(template));
+            } else {
+                bail!("Unknown argument `{arg}`");
+            }
+        }
+
+        if step_limit.is_none() {
+            if let Ok(steps) = env::var(STEP_LIMIT_VAR) {
+                step_limit = Some(steps.parse().with_context(|| {
+                    format!("Invalid step limit `{steps}` in {STEP_LIMIT_VAR}")
+                })?);
+            }
+        }
+
+        let mut patterns = Vec::new();
+        for pattern_arg in pattern_args {
+            match pattern_arg {
+                PatternArg::Pattern(pattern) => patterns.push(pattern),
+                PatternArg::File(_, text) if fixed_strings => {
+                    patterns.extend(text.lines().map(String::from))
+                }
+                PatternArg::File(path, text) => patterns.extend(
+                    parse_pattern_file(&text)
+                        .with_context(|| format!("Invalid pattern file `{path}`"))?,
+                ),
+            }
+        }
+
+        if normalize {
+            let rules = classify.iter_mut().flatten().map(|(_, pattern)| pattern);
+            for pattern in patterns.iter_mut().chain(rules) {
+                *pattern = nfc(pattern).into_owned();
+            }
+        }
+
+        if patterns.is_empty() && classify.is_none() {
+            bail!("Expected a pattern given with '-E', '-e' or '-f'");
+        }
+        if !patterns.is_empty() && classify.is_some() {
+            bail!("'--classify' takes its patterns from the rules file, not '-E', '-e' or '-f'");
+        }
+        if fixed_strings && classify.is_some() {
+            bail!("'--fixed-strings' doesn't apply to the patterns of '--classify'");
+        }
+        if fixed_strings && case_fold.is_some() {
+            bail!("'--fixed-strings' can't be combined with '--ignore-case' or '--case-fold'");
+        }
+        if count && (rewrite.is_some() || count_unique || classify.is_some()) {
+            bail!("'--count' can't be combined with '--rewrite', '--count-unique' or '--classify'");
+        }
+        if max_per_line.is_some() && !only_matching {
+            bail!("'--max-per-line' only applies to '--only-matching'");
+        }
+        if only_matching
+            && byte_offset
+            && (normalize || matches!(invalid_utf8, InvalidUtf8::Lossy | InvalidUtf8::Binary))
+        {
+            // Matches are found in the converted line, their spans wouldn't be those
+            // of the input
+            bail!("'--only-matching' with '--byte-offset' can't be combined with '--normalize', '--invalid-utf8=lossy' or 'binary'");
+        }
+        if max_line_length.is_some_and(|length| length < MIN_LINE_LENGTH) {
+            bail!("'--max-line-length' must be at least {MIN_LINE_LENGTH}, to fit any character");
+        }
+        if sample_seed.is_some() && sample.is_none() {
+            bail!("'--sample-seed' only applies to '--sample'");
+        }
+        if sample_seed.is_some() && long_lines == LongLines::Window {
+            bail!("'--sample-seed' can't be combined with '--long-lines=window'");
+        }
+        if diff && replace.is_none() {
+            bail!("'--diff' only applies to '--replace'");
+        }
+        if diff && (only_matching || count || count_unique) {
+            bail!(
+                "'--diff' can't be combined with '--only-matching', '--count' or '--count-unique'"
+            );
+        }
+        if include_zero && !count {
+            bail!("'--include-zero' only applies to '--count'");
+        }
+        if debug_perf && (pattern_stats || classify.is_some()) {
+            bail!("'--debug-perf' can't be combined with '--pattern-stats' or '--classify'");
+        }
+        if pattern_stats && classify.is_some() {
+            bail!("'--pattern-stats' can't be combined with '--classify'");
+        }
+
+        Ok(Self {
+            patterns,
+            fixed_strings,
+            classify,
+            case_fold,
+            invalid_utf8,
+            normalize,
+            line_terminator,
+            max_line_length,
+            long_lines,
+            debug_ast,
+            debug_trace,
+            debug_perf,
+            no_pattern_warnings,
+            verbose,
+            json,
+            replace,
+            diff,
+            rewrite,
+            count_unique,
+            count,
+            include_zero,
+            show_patterns,
+            dedupe,
+            only_matching,
+            max_per_line,
+            byte_offset,
+            pattern_stats,
+            max_output_lines,
+            max_output_bytes,
+            sample,
+            sample_seed,
+            step_limit,
+        })
+    }
+}
+
+/// Parses a `--case-fold` mode: `simple`, `full`, `turkic` or `ascii`.
+fn parse_case_fold(mode: &str) -> anyhow::Result<CaseFold> {
+    match mode {
+        "simple" => Ok(CaseFold::Simple),
+        "full" => Ok(CaseFold::Full),
+        "turkic" => Ok(CaseFold::Turkic),
+        "ascii" => Ok(CaseFold::Ascii),
+        other => bail!("Unknown case folding `{other}`"),
+    }
+}
+
+/// Parses a line terminator given either as a single ASCII character or as one
+/// of the escapes `\n`, `\r`, `\t` and `\0`.
+fn parse_terminator(terminator: &str) -> anyhow::Result<u8> {
+    match terminator {
+        "\\n" => Ok(b'\n'),
+        "\\r" => Ok(b'\r'),
+        "\\t" => Ok(b'\t'),
+        "\\0" => Ok(b'\0'),
+        _ => match terminator.as_bytes() {
+            [byte] if byte.is_ascii() => Ok(*byte),
+            _ => bail!("Line terminator must be a single ASCII character, got `{terminator}`"),
+        },
+    }
+}
+
+/// Exit status when the output was closed early, as if killed by `SIGPIPE`.
+const BROKEN_PIPE_STATUS: i32 = 128 + 13;
+
+/// Exit status when stopped by Ctrl-C, as if killed by `SIGINT`.
+const INTERRUPTED_STATUS: i32 = 128 + 2;
+
+/// Raised on Ctrl-C, see [`handle_interrupts`].
+static INTERRUPTED: OnceLock<Arc<AtomicBool>> = OnceLock::new();
+
+/// Makes Ctrl-C raise the returned flag instead of killing the process, so that the
+/// search can stop between lines, with its output and statistics intact. Patterns
+/// given the flag also cancel the search in progress.
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+fn handle_interrupts() -> Arc<AtomicBool> {
+    use std::ffi::c_int;
+
+    // SIGINT is 2 on both Linux and macOS. `signal` returns the previous handler, a
+    // `sighandler_t`, which is a function pointer and so as wide as `usize`; it is
+    // never looked at.
+    const SIGINT: c_int = 2;
+    extern "C" {
+        fn signal(signum: c_int, handler: extern "C" fn(c_int)) -> usize;
+    }
+    extern "C" fn interrupt(_: c_int) {
+        if let Some(interrupted) = INTERRUPTED.get() {
+            interrupted.store(true, Ordering::Relaxed);
+        }
+    }
+
+    let interrupted = INTERRUPTED.get_or_init(Arc::default).clone();
+    // SAFETY: the handler only stores to an atomic, which is safe in a signal handler
+    unsafe { signal(SIGINT, interrupt) };
+    interrupted
+}
+
+/// Elsewhere Ctrl-C keeps ending the process right away.
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+fn handle_interrupts() -> Arc<AtomicBool> {
+    Arc::default()
+}
+
+// Usage: echo <input_text> | your_grep.sh -E <pattern> [-e <pattern>...] [-f <file>...]
+fn main() {
+    // Known before the options are, so that errors parsing them are JSON too
+    let json = env::args().skip(1).any(|arg| arg == "--json");
+    let status = match run() {
+        Ok(status) => status,
+        // The reader, e.g. `head`, has seen enough, so just stop
+        Err(error) if is_broken_pipe(&error) => BROKEN_PIPE_STATUS,
+        Err(error) if json => {
+            eprintln!("{}", error_json(&error));
+            1
+        }
+        Err(error) => {
+            eprintln!("Error: {error:?}");
+            1
+        }
+    };
+    process::exit(status)
+}
+
+/// Renders `error` as a JSON record for `--json`, see the library's `json` module
+/// for the fields of each kind of error.
+fn error_json(error: &anyhow::Error) -> String {
+    let found = error
+        .chain()
+        .find_map(|cause| cause.downcast_ref::<Error>());
+    match found {
+        Some(Error::Syntax {
+            message,
+            position,
+            hint,
+        }) => format!(
+            r#"{{"error": "syntax", "message": {}, "position": {position}, "hint": {}}}"#,
+            json::quote(message),
+            json::quote(hint)
+        ),
+        Some(found) => match found.position() {
+            Some(position) => format!(
+                r#"{{"error": "pattern", "message": {}, "position": {position}}}"#,
+                json::quote(&found.to_string())
+            ),
+            None => format!(
+                r#"{{"error": "pattern", "message": {}}}"#,
+                json::quote(&found.to_string())
+            ),
+        },
+        None => {
+            let io = error.chain().any(|cause| cause.is::<io::Error>());
+            format!(
+                r#"{{"error": "{}", "message": {}}}"#,
+                if io { "io" } else { "other" },
+                json::quote(&format!("{error:#}"))
+            )
+        }
+    }
+}
+
+fn is_broken_pipe(error: &anyhow::Error) -> bool {
+    error.chain().any(|cause| {
+        cause
+            .downcast_ref::<io::Error>()
+            .is_some_and(|e| e.kind() == io::ErrorKind::BrokenPipe)
+    })
+}
+
+/// Reports the likely mistakes the engine found in the patterns. Groups that aren't
+/// referred to are fine when a replacement template may use them.
+fn warn_about_patterns(patterns: &PatternSet, options: &Options) {
+    let templated = options.replace.is_some() || options.rewrite.is_some();
+    for (index, pattern) in patterns.patterns().iter().enumerate() {
+        for warning in pattern.warnings() {
+            if templated && warning.kind() == WarningKind::UnreferencedGroup {
+                continue;
+            }
+            if patterns.len() > 1 {
+                eprintln!("Warning: pattern {}: {warning}", index + 1);
+            } else {
+                eprintln!("Warning: {warning}");
+            }
+        }
+    }
+}
+
+/// Runs the search, returning the exit status. All output goes through fallible
+/// writes, so that a closed stdout surfaces as an error instead of a panic.
+fn run() -> anyhow::Result<i32> {
+    let options = Options::parse(env::args().skip(1))?;
+    let started = Instant::now();
+    let mut builder = PatternBuilder::new();
+    if let Some(case_fold) = options.case_fold {
+        builder = builder.flags(Flags::CASE_INSENSITIVE).case_fold(case_fold);
+    }
+    let mut classifier = options
+        .classify
+        .clone()
+        .map(|rules| Classifier::build(&builder, rules))
+        .transpose()?;
+    let mut patterns = match &classifier {
+        Some(classifier) => classifier.patterns().clone(),
+        None if options.fixed_strings => PatternSet::literals(&options.patterns)?,
+        None => PatternSet::build(&builder, &options.patterns)?,
+    };
+    if let Some(limit) = options.step_limit {
+        classifier = classifier.map(|classifier| classifier.with_step_limit(limit));
+        patterns = patterns.with_step_limit(limit);
+    }
+    let interrupted = handle_interrupts();
+    classifier = classifier.map(|classifier| classifier.with_cancellation(interrupted.clone()));
+    patterns = patterns.with_cancellation(interrupted.clone());
+    let is_interrupted = || interrupted.load(Ordering::Relaxed);
+    if options.verbose >= 1 {
+        eprintln!(
+            "Parsed {} pattern(s) in {:?}",
+            patterns.len(),
+            started.elapsed()
+        );
+    }
+    for template in [&options.replace, &options.rewrite].into_iter().flatten() {
+        for (index, pattern) in patterns.patterns().iter().enumerate() {
+            pattern
+                .validate_template(template)
+                .with_context(|| format!("Invalid template for pattern {}", index + 1))?;
+        }
+    }
+    if !options.no_pattern_warnings {
+        warn_about_patterns(&patterns, &options);
+    }
+    if options.debug_ast {
+        let mut output = io::stdout().lock();
+        for (index, pattern) in patterns.patterns().iter().enumerate() {
+            if patterns.len() > 1 {
+                writeln!(output, "pattern {}", index + 1)?;
+            }
+            write!(output, "{}", pattern.tree())?;
+        }
+        output.flush()?;
+        return Ok(0);
     }
 
-    let pattern = env::args().nth(2).unwrap();
-    let pattern = Pattern::new(&pattern)?;
-    let mut input_line = String::new();
+    let (mut input, encoding) = open_input(io::stdin().lock(), options.invalid_utf8)?;
+    let bom = match encoding {
+        Encoding::Utf8 { bom } => bom,
+        Encoding::Utf16 if options.byte_offset => {
+            bail!("'--byte-offset' can't be used on UTF-16 input, which is searched as UTF-8")
+        }
+        Encoding::Utf16 => 0,
+    };
+    let mut searcher = Searcher {
+        patterns: &patterns,
+        classifier: classifier.as_ref(),
+        options: &options,
+        printer: Printer::new(
+            Limited {
+                output: io::stdout().lock(),
+                terminator: if options.json {
+                    b'\n'
+                } else {
+                    options.line_terminator
+                },
+                lines_left: options.max_output_lines,
+                bytes_left: options.max_output_bytes,
+                truncated: false,
+            },
+            if options.json {
+                Format::Json
+            } else {
+                Format::Lines
+            },
+        )
+        .with_line_terminator(options.line_terminator)
+        .with_color(io::stdout().is_terminal()),
+        searched_lines: 0,
+        matching_lines: 0,
+        record_searched: false,
+        record_matched: false,
+        skip_record: false,
+        binary: false,
+        unique: UniqueCounts::new(),
+        deduper: options.dedupe.map(Deduper::new),
+        stats: options.pattern_stats.then(|| patterns.stats()),
+        counters: options.debug_perf.then(SearchCounters::default),
+    };
 
-    io::stdin().read_line(&mut input_line).unwrap();
+    let started = Instant::now();
+    let mut record = Vec::new();
+    let mut number = 1;
+    // Byte offset in the input of the first byte in `record`
+    let mut offset = bom;
+    let mut reservoir = options
+        .sample
+        .zip(options.sample_seed)
+        .map(|(size, seed)| Reservoir::new(size, seed));
+    while !searcher.printer.get_ref().truncated && !is_interrupted() {
+        if reservoir.is_none() && options.sample.is_some_and(|sample| number > sample) {
+            break;
+        }
+        match read_record(
+            &mut input,
+            options.line_terminator,
+            options.max_line_length,
+            &mut record,
+        )? {
+            Record::End => break,
+            Record::Complete => {
+                match &mut reservoir {
+                    Some(reservoir) => reservoir.offer(number, offset, &record),
+                    None => {
+                        searcher.search(&record, number, offset, true)?;
+                    }
+                }
+                offset += record.len() + 1;
+                record.clear();
+                number += 1;
+            }
+            Record::Truncated => match options.long_lines {
+                LongLines::Skip => {
+                    eprintln!("Skipping line {number}: longer than {} bytes", record.len());
+                    offset += record.len() + skip_record(&mut input, options.line_terminator)?;
+                    record.clear();
+                    number += 1;
+                }
+                LongLines::Window => {
+                    let searched = searcher.search(&record, number, offset, false)?;
+                    offset += searched;
+                    record.drain(..searched);
+                    if mem::take(&mut searcher.skip_record) {
+                        offset += record.len() + skip_record(&mut input, options.line_terminator)?;
+                        record.clear();
+                        number += 1;
+                    }
+                }
+            },
+        }
+    }
+    for (number, offset, record) in reservoir.map(Reservoir::into_lines).unwrap_or_default() {
+        if searcher.printer.get_ref().truncated || is_interrupted() {
+            break;
+        }
+        searcher.search(&record, number, offset, true)?;
+    }
+    // Lines left unread after the sample still count towards the whole input
+    let total_lines = match options.sample {
+        Some(_) if !is_interrupted() => {
+            Some(number - 1 + count_records(&mut input, options.line_terminator)?)
+        }
+        _ => None,
+    };
+    for (text, count) in searcher.unique.sorted() {
+        searcher.printer.print_count(text, count)?;
+    }
+    if options.count && (searcher.matching_lines > 0 || options.include_zero) {
+        searcher.printer.print_line_count(searcher.matching_lines)?;
+    } else if searcher.matching_lines > 0 && searcher.binary {
+        searcher.printer.print_binary_match()?;
+    }
+    searcher.printer.flush()?;
+    if options.verbose >= 1 {
+        eprintln!(
+            "Searched {} lines in {:?}, {} matched",
+            searcher.searched_lines,
+            started.elapsed(),
+            searcher.matching_lines
+        );
+    }
 
-    if pattern.test(&input_line) {
-        process::exit(0)
+    if let Some(total) = total_lines {
+        let sampled = searcher.searched_lines;
+        let estimate = searcher.matching_lines * total / sampled.max(1);
+        eprintln!(
+            "Sampled {sampled} of {total} lines, {} matched: about {estimate} would in the whole input",
+            searcher.matching_lines
+        );
+    }
+
+    // Statistics are printed on interrupt too, so that a search stopped for taking
+    // too long still shows which pattern was slow and how far it got
+    if let Some(stats) = &searcher.stats {
+        for index in stats.slowest() {
+            let pattern = stats.patterns()[index];
+            eprintln!(
+                "pattern {}: matched {} of {} lines in {:?}",
+                index + 1,
+                pattern.hits,
+                pattern.searches,
+                pattern.time
+            );
+        }
+    }
+
+    if let Some(counters) = &searcher.counters {
+        eprintln!("Offsets tried: {}", counters.positions);
+        eprintln!("Offsets skipped by the prefilter: {}", counters.skipped);
+        eprintln!("Matcher steps: {}", counters.steps);
+        eprintln!("Backtracks: {}", counters.backtracks);
+    }
+
+    if is_interrupted() {
+        eprintln!("Interrupted, searched {} lines", searcher.searched_lines);
+        Ok(INTERRUPTED_STATUS)
+    } else if searcher.printer.get_ref().truncated {
+        eprintln!("Output truncated, see --max-output-lines and --max-output-bytes");
+        Ok(3)
+    } else if searcher.matching_lines > 0 {
+        Ok(0)
     } else {
-        process::exit(1)
+        Ok(1)
+    }
+}
+
+/// Output that stops passing writes through once a line or byte limit is reached.
+///
+/// Writes past the limit are discarded rather than failed, so that the search can
+/// notice the truncation and stop on its own terms.
+struct Limited<W> {
+    output: W,
+    /// The byte that ends a line of output.
+    terminator: u8,
+    lines_left: Option<usize>,
+    bytes_left: Option<usize>,
+    truncated: bool,
+}
+
+impl<W: Write> Write for Limited<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut allowed = buf.len();
+        if let Some(bytes_left) = self.bytes_left {
+            allowed = allowed.min(bytes_left);
+        }
+        if let Some(lines_left) = self.lines_left {
+            let terminators = buf[..allowed].iter().enumerate();
+            let mut ends = terminators.filter(|(_, byte)| **byte == self.terminator);
+            allowed = match lines_left {
+                0 => 0,
+                lines => ends.nth(lines - 1).map_or(allowed, |(at, _)| at + 1),
+            };
+        }
+        if self.truncated || (allowed == 0 && !buf.is_empty()) {
+            self.truncated = true;
+            return Ok(buf.len());
+        }
+
+        let written = self.output.write(&buf[..allowed])?;
+        if let Some(bytes_left) = &mut self.bytes_left {
+            *bytes_left -= written;
+        }
+        if let Some(lines_left) = &mut self.lines_left {
+            let ends = buf[..written]
+                .iter()
+                .filter(|byte| **byte == self.terminator);
+            *lines_left -= ends.count();
+        }
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.output.flush()
+    }
+}
+
+struct Searcher<'a, W> {
+    patterns: &'a PatternSet,
+    classifier: Option<&'a Classifier>,
+    options: &'a Options,
+    printer: Printer<W>,
+    searched_lines: usize,
+    matching_lines: usize,
+    /// Whether the record being searched, in windows of a long line, was searched
+    /// and matched so far. It is counted once, when its last window is searched.
+    record_searched: bool,
+    record_matched: bool,
+    /// Set when a window of a long line was skipped, so the rest of the line should
+    /// be too.
+    skip_record: bool,
+    /// Set once invalid UTF-8 was seen under the `binary` policy, from then on
+    /// matching lines are no longer printed.
+    binary: bool,
+    /// Matched texts counted for `--count-unique`.
+    unique: UniqueCounts,
+    deduper: Option<Deduper>,
+    /// Collected for `--pattern-stats`.
+    stats: Option<SetStats>,
+    /// Collected for `--debug-perf`.
+    counters: Option<SearchCounters>,
+}
+
+impl<W: Write> Searcher<'_, W> {
+    /// Searches a single record, or a window of one when `complete` is false, and
+    /// returns how many bytes of it were searched. `offset` is where the record, or
+    /// window, starts in the input.
+    ///
+    /// A window may end in the middle of a character, those trailing bytes are left
+    /// for the next window.
+    fn search(
+        &mut self,
+        record: &[u8],
+        number: usize,
+        offset: usize,
+        complete: bool,
+    ) -> anyhow::Result<usize> {
+        let searched = self.search_window(record, number, offset, complete)?;
+        if complete || self.skip_record {
+            self.searched_lines += usize::from(mem::take(&mut self.record_searched));
+            self.matching_lines += usize::from(mem::take(&mut self.record_matched));
+        }
+        Ok(searched)
+    }
+
+    /// Like [`Searcher::search`], but leaves counting the record to it.
+    fn search_window(
+        &mut self,
+        record: &[u8],
+        number: usize,
+        offset: usize,
+        complete: bool,
+    ) -> anyhow::Result<usize> {
+        let (record, crlf) = match (complete, self.options.line_terminator) {
+            (true, b'\n') => match record.strip_suffix(b"\r") {
+                Some(record) => (record, true),
+                None => (record, false),
+            },
+            _ => (record, false),
+        };
+        let (line, searched) = match std::str::from_utf8(record) {
+            Ok(line) => (Cow::Borrowed(line), record.len()),
+            Err(e) if !complete && e.error_len().is_none() => {
+                let valid = e.valid_up_to();
+                let line = std::str::from_utf8(&record[..valid]).expect("Checked to be valid");
+                (Cow::Borrowed(line), valid)
+            }
+            Err(_) => match self.options.invalid_utf8 {
+                InvalidUtf8::Error => {
+                    bail!("Line {number} is not valid UTF-8, see --invalid-utf8 for other policies")
+                }
+                InvalidUtf8::Lossy => (String::from_utf8_lossy(record), record.len()),
+                InvalidUtf8::Skip => {
+                    eprintln!("Skipping line {number}: not valid UTF-8");
+                    self.skip_record = !complete;
+                    return Ok(record.len());
+                }
+                InvalidUtf8::Binary => {
+                    self.binary = true;
+                    (String::from_utf8_lossy(record), record.len())
+                }
+            },
+        };
+        let line = match self.options.normalize.then(|| nfc(&line)) {
+            Some(Cow::Owned(normalized)) => Cow::Owned(normalized),
+            _ => line,
+        };
+
+        if let Some(classifier) = self.classifier {
+            self.record_searched = true;
+            if let Some(rule) = classifier.classify(&line) {
+                self.record_matched = true;
+                let offset = self.options.byte_offset.then_some(offset);
+                self.printer
+                    .print_classified_line(rule, number, offset, &line)?;
+            }
+            return Ok(searched);
+        }
+
+        let matched: Vec<usize> = if self.options.debug_trace {
+            eprintln!("line {number}: {line:?}");
+            let patterns = self.patterns.patterns().iter().enumerate();
+            patterns
+                .filter(|(_, pattern)| pattern.trace(&line, |event| eprintln!("{event}")))
+                .map(|(index, _)| index)
+                .collect()
+        } else if let Some(counters) = &mut self.counters {
+            let patterns = self.patterns.patterns().iter().enumerate();
+            patterns
+                .filter(|(_, pattern)| pattern.find_counted(&line, counters).is_some())
+                .map(|(index, _)| index)
+                .collect()
+        } else if let Some(stats) = &mut self.stats {
+            self.patterns.matches_recording(&line, stats)
+        } else {
+            self.patterns.matches(&line)
+        };
+        self.record_searched = true;
+        if self.options.verbose >= 2 {
+            let result = if matched.is_empty() {
+                "no match"
+            } else {
+                "matched"
+            };
+            eprintln!("Line {number}: {result}");
+        }
+        if let Some(template) = &self.options.rewrite {
+            if !matched.is_empty() {
+                self.record_matched = true;
+            }
+            let mut line = self.replace(line, &matched, template);
+            if crlf {
+                line.to_mut().push('\r');
+            }
+            self.printer.print_part(&line, complete)?;
+            return Ok(searched);
+        }
+        if matched.is_empty() {
+            return Ok(searched);
+        }
+
+        self.record_matched = true;
+        if self.options.count {
+            return Ok(searched);
+        }
+        if let Some(deduper) = &mut self.deduper {
+            if !self.options.count_unique && deduper.is_repeat(&line) {
+                return Ok(searched);
+            }
+        }
+        let matching = || {
+            matched
+                .iter()
+                .map(|index| &self.patterns.patterns()[*index])
+        };
+        let shown: Vec<usize> = if self.options.show_patterns {
+            matched.iter().map(|index| index + 1).collect()
+        } else {
+            Vec::new()
+        };
+        if self.options.count_unique {
+            for pattern in matching() {
+                self.unique.add_matches(pattern, &line);
+            }
+            return Ok(searched);
+        }
+
+        let offset = self.options.byte_offset.then_some(offset);
+        if let (true, Some(template)) = (self.options.diff, &self.options.replace) {
+            if self.binary && !self.options.json {
+                return Ok(searched);
+            }
+            let replaced = self.replace(Cow::Borrowed(&line), &matched, template);
+            if replaced != line {
+                self.printer
+                    .print_diff(number, offset, &line, &replaced, &shown)?;
+            }
+        } else if self.options.json || (self.options.only_matching && !self.binary) {
+            let mut matches: Vec<_> = matching()
+                .flat_map(|pattern| pattern.find_iter(&line))
+                .collect();
+            matches.sort_by_key(|found| (found.start(), found.end()));
+            if self.options.only_matching {
+                // Like grep, don't print empty matches as blank lines
+                matches.retain(|found| !found.is_empty());
+            }
+            let truncated = match self.options.max_per_line {
+                Some(max) if matches.len() > max => {
+                    matches.truncate(max);
+                    true
+                }
+                _ => false,
+            };
+            if self.options.json && truncated {
+                self.printer
+                    .print_truncated_line(number, offset, &line, &matches, &shown)?;
+            } else if self.options.json {
+                self.printer
+                    .print_attributed_line(number, offset, &line, &matches, &shown)?;
+            } else {
+                for found in &matches {
+                    self.printer.print_match(offset, found)?;
+                }
+            }
+        } else if !self.binary {
+            let line = match &self.options.replace {
+                Some(template) => self.replace(line, &matched, template),
+                None => line,
+            };
+            self.printer
+                .print_attributed_line(number, offset, &line, &[], &shown)?;
+        }
+
+        Ok(searched)
+    }
+}
+
+impl<W> Searcher<'_, W> {
+    /// Replaces the matches of the patterns in `matched` with `template`, each
+    /// pattern in turn seeing the previous replacements.
+    fn replace<'l>(
+        &self,
+        mut line: Cow<'l, str>,
+        matched: &[usize],
+        template: &str,
+    ) -> Cow<'l, str> {
+        for index in matched {
+            let pattern = &self.patterns.patterns()[*index];
+            if let Cow::Owned(replaced) = pattern.replace_all(&line, template) {
+                line = Cow::Owned(replaced);
+            }
+        }
+        line
+    }
+}
+
+/// Outcome of reading a single record from the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Record {
+    /// The input is exhausted, nothing was read.
+    End,
+    /// A whole record was read, without its terminator.
+    Complete,
+    /// The record reached the length limit before its terminator.
+    Truncated,
+}
+
+/// Appends the next record to `record`, reading at most until it is `limit` bytes long.
+fn read_record(
+    input: &mut impl BufRead,
+    terminator: u8,
+    limit: Option<usize>,
+    record: &mut Vec<u8>,
+) -> io::Result<Record> {
+    let mut read_any = false;
+    loop {
+        let available = input.fill_buf()?;
+        if available.is_empty() {
+            return Ok(if read_any || !record.is_empty() {
+                Record::Complete
+            } else {
+                Record::End
+            });
+        }
+        read_any = true;
+
+        let room = limit.map_or(usize::MAX, |limit| limit.saturating_sub(record.len()));
+        if room == 0 {
+            if available[0] == terminator {
+                input.consume(1);
+                return Ok(Record::Complete);
+            }
+            return Ok(Record::Truncated);
+        }
+
+        let available = &available[..available.len().min(room)];
+        match available.iter().position(|byte| *byte == terminator) {
+            Some(end) => {
+                record.extend_from_slice(&available[..end]);
+                input.consume(end + 1);
+                return Ok(Record::Complete);
+            }
+            None => {
+                let len = available.len();
+                record.extend_from_slice(available);
+                input.consume(len);
+            }
+        }
+    }
+}
+
+/// Discards the rest of the current record, including its terminator, returning
+/// the number of bytes discarded.
+fn skip_record(input: &mut impl BufRead, terminator: u8) -> io::Result<usize> {
+    input.skip_until(terminator)
+}
+
+/// Reads the rest of the input, returning how many records it has.
+fn count_records(input: &mut impl BufRead, terminator: u8) -> io::Result<usize> {
+    let mut count = 0;
+    while skip_record(input, terminator)? > 0 {
+        count += 1;
+    }
+    Ok(count)
+}
+
+/// How the input was encoded, as told by its byte order mark, see [`open_input`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Encoding {
+    /// UTF-8, after a BOM of this many bytes, if any.
+    Utf8 { bom: usize },
+    /// UTF-16 of either byte order, transcoded to UTF-8, so offsets in it don't map
+    /// back to the input.
+    Utf16,
+}
+
+/// Wraps the raw input, picking the encoding from a leading byte order mark.
+///
+/// The mark itself is stripped, so that `^` still anchors to the first character
+/// of the first line. Input without a BOM is passed through as UTF-8, leaving
+/// invalid sequences to be handled line by line. UTF-16 input is decoded up front.
+fn open_input<'a>(
+    mut input: impl BufRead + 'a,
+    invalid_utf8: InvalidUtf8,
+) -> anyhow::Result<(Box<dyn BufRead + 'a>, Encoding)> {
+    let start = input.fill_buf()?;
+    if start.starts_with(b"\xEF\xBB\xBF") {
+        input.consume(3);
+        Ok((Box::new(input), Encoding::Utf8 { bom: 3 }))
+    } else if start.starts_with(b"\xFF\xFE") || start.starts_with(b"\xFE\xFF") {
+        let from_bytes = if start[0] == 0xFF {
+            u16::from_le_bytes
+        } else {
+            u16::from_be_bytes
+        };
+        input.consume(2);
+        let mut rest = Vec::new();
+        input.read_to_end(&mut rest)?;
+        let decoded = decode_utf16(&rest, from_bytes, invalid_utf8)?;
+        Ok((Box::new(io::Cursor::new(decoded)), Encoding::Utf16))
+    } else {
+        Ok((Box::new(input), Encoding::Utf8 { bom: 0 }))
+    }
+}
+
+fn decode_utf16(
+    input: &[u8],
+    from_bytes: fn([u8; 2]) -> u16,
+    invalid_utf8: InvalidUtf8,
+) -> anyhow::Result<Vec<u8>> {
+    let pairs = input.chunks_exact(2);
+    if !pairs.remainder().is_empty() {
+        bail!("UTF-16 input has an odd number of bytes");
+    }
+
+    let units: Vec<u16> = pairs.map(|pair| from_bytes([pair[0], pair[1]])).collect();
+    match String::from_utf16(&units) {
+        Ok(text) => Ok(text.into_bytes()),
+        Err(_) if invalid_utf8 == InvalidUtf8::Error => bail!("Input is not valid UTF-16"),
+        Err(_) => Ok(String::from_utf16_lossy(&units).into_bytes()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use grep_starter_rust::CaseFold;
+
+    use std::io::{self, Write};
+
+    use anyhow::Context;
+
+    use super::{
+        is_broken_pipe, parse_terminator, read_record, skip_record, Dedupe, Deduper, InvalidUtf8,
+        Limited, Options, Record, Reservoir,
+    };
+
+    fn parse(args: &[&str]) -> anyhow::Result<Options> {
+        Options::parse(args.iter().map(|arg| String::from(*arg)))
+    }
+
+    #[test]
+    fn parse_options() {
+        let options = parse(&["-E", "a", "--max-line-length=4", "--long-lines=window"])
+            .expect("Options are correct");
+        assert_eq!(options.max_line_length, Some(4));
+        let args = ["-o", "-b", "-E", "a", "--invalid-utf8=lossy"];
+        assert!(parse(&args).is_err());
+        let options = parse(&["-E", "e\u{301}", "--normalize"]).expect("Options are correct");
+        assert_eq!(options.patterns, ["é"]);
+        assert!(parse(&["-o", "-b", "-E", "a", "--normalize"]).is_err());
+        assert_eq!(
+            parse(&["-E", "a", "--sample=3"])
+                .expect("Options are correct")
+                .sample,
+            Some(3)
+        );
+        assert!(parse(&["-E", "a", "--sample=0"]).is_err());
+        let options = parse(&["-E", "a", "--invalid-utf8=skip"]).expect("Options are correct");
+        assert_eq!(options.invalid_utf8, InvalidUtf8::Skip);
+        assert!(parse(&["-E", "a", "--invalid-utf8=ignore"]).is_err());
+        let options = parse(&["--case-fold=turkic", "-i", "-E", "a"]).expect("Options are correct");
+        assert_eq!(options.case_fold, Some(CaseFold::Turkic));
+        let options = parse(&["-E", "a", "--case-fold=ascii"]).expect("Options are correct");
+        assert_eq!(options.case_fold, Some(CaseFold::Ascii));
+        assert!(parse(&["-E", "a", "--case-fold=latin1"]).is_err());
+        assert!(parse(&["-F", "-i", "-E", "a"]).is_err());
+        for length in ["0", "1", "3"] {
+            let arg = format!("--max-line-length={length}");
+            assert!(parse(&["-E", "a", &arg]).is_err(), "{length}");
+        }
+    }
+
+    #[test]
+    fn read_records() {
+        let mut input = &b"ab\ncdefg\n\nh"[..];
+        let mut record = Vec::new();
+        let read = |input: &mut &[u8], record: &mut Vec<u8>| {
+            let read = read_record(input, b'\n', Some(3), record).expect("Reading a slice");
+            let text = String::from_utf8(record.split_off(0)).expect("Records are UTF-8");
+            (read, text)
+        };
+        assert_eq!(
+            read(&mut input, &mut record),
+            (Record::Complete, "ab".into())
+        );
+        assert_eq!(
+            read(&mut input, &mut record),
+            (Record::Truncated, "cde".into())
+        );
+        assert_eq!(skip_record(&mut input, b'\n').expect("Reading a slice"), 3);
+        assert_eq!(read(&mut input, &mut record), (Record::Complete, "".into()));
+        assert_eq!(
+            read(&mut input, &mut record),
+            (Record::Complete, "h".into())
+        );
+        assert_eq!(read(&mut input, &mut record), (Record::End, "".into()));
+
+        // A terminator right at the limit still completes the record
+        let mut input = &b"abc;d"[..];
+        let read = read_record(&mut input, b';', Some(3), &mut record).expect("Reading a slice");
+        assert_eq!((read, record.as_slice()), (Record::Complete, &b"abc"[..]));
+        assert_eq!(input, b"d");
+    }
+
+    #[test]
+    fn limited_output() {
+        let limited = |lines_left, bytes_left| Limited {
+            output: Vec::new(),
+            terminator: b'\n',
+            lines_left,
+            bytes_left,
+            truncated: false,
+        };
+        let mut output = limited(Some(2), None);
+        output.write_all(b"a\nb\n").expect("Writing to a Vec");
+        assert!(!output.truncated);
+        output.write_all(b"c\n").expect("Writing to a Vec");
+        assert_eq!(
+            (output.output.as_slice(), output.truncated),
+            (&b"a\nb\n"[..], true)
+        );
+
+        let mut output = limited(Some(5), Some(4));
+        output.write_all(b"ab\ncd\n").expect("Writing to a Vec");
+        assert_eq!(
+            (output.output.as_slice(), output.truncated),
+            (&b"ab\nc"[..], true)
+        );
+
+        let mut output = Limited {
+            terminator: b';',
+            ..limited(Some(1), None)
+        };
+        output.write_all(b"a\nb;c;").expect("Writing to a Vec");
+        assert_eq!(output.output, b"a\nb;");
+    }
+
+    #[test]
+    fn reservoir_sampling() {
+        let sample = |size, seed, lines: usize| {
+            let mut reservoir = Reservoir::new(size, seed);
+            for number in 1..=lines {
+                reservoir.offer(number, number * 10, number.to_string().as_bytes());
+            }
+            let lines = reservoir.into_lines();
+            lines
+                .into_iter()
+                .map(|(number, _, _)| number)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(sample(5, 7, 3), [1, 2, 3]);
+        let kept = sample(3, 7, 100);
+        assert_eq!(kept.len(), 3);
+        assert!(kept.windows(2).all(|pair| pair[0] < pair[1]), "{kept:?}");
+        assert_eq!(sample(3, 7, 100), kept);
+        assert_ne!(sample(3, 8, 100), kept);
+
+        // Every line is about as likely to be kept
+        let mut counts = [0; 4];
+        for seed in 0..4000 {
+            counts[sample(1, seed, 4)[0] - 1] += 1;
+        }
+        assert!(
+            counts.iter().all(|count| (850..1150).contains(count)),
+            "{counts:?}"
+        );
+    }
+
+    #[test]
+    fn line_terminators() {
+        assert_eq!(parse_terminator(";").expect("Terminator is correct"), b';');
+        assert_eq!(
+            parse_terminator("\\0").expect("Terminator is correct"),
+            b'\0'
+        );
+        assert_eq!(
+            parse_terminator("\\r").expect("Terminator is correct"),
+            b'\r'
+        );
+        for terminator in ["", ";;", "é", "\\x"] {
+            assert!(parse_terminator(terminator).is_err(), "{terminator}");
+        }
+    }
+
+    #[test]
+    fn dedupe() {
+        let repeats = |mode, lines: &[&str]| {
+            let mut deduper = Deduper::new(mode);
+            let repeats = lines.iter().map(|line| deduper.is_repeat(line));
+            repeats.collect::<Vec<_>>()
+        };
+        let lines = ["a", "a", "b", "a", "b"];
+        let consecutive = repeats(Dedupe::Consecutive, &lines);
+        assert_eq!(consecutive, [false, true, false, false, false]);
+        let global = repeats(Dedupe::Global, &lines);
+        assert_eq!(global, [false, true, false, true, true]);
+        assert!(parse(&["-E", "a", "--dedupe=every"]).is_err());
+    }
+
+    #[test]
+    fn broken_pipe() {
+        let closed = || io::Error::from(io::ErrorKind::BrokenPipe);
+        assert!(is_broken_pipe(&anyhow::Error::from(closed())));
+        let error = Err::<(), _>(closed()).context("Printing a line");
+        assert!(is_broken_pipe(&error.expect_err("Failed above")));
+        let error = io::Error::from(io::ErrorKind::PermissionDenied);
+        assert!(!is_broken_pipe(&anyhow::Error::from(error)));
     }
 }
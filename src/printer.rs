@@ -0,0 +1,446 @@
+//! grep-style output of matching lines, written to any [`io::Write`].
+//!
+//! This is the formatting the CLI uses, exposed so that other tools can print results
+//! from their own match sources the same way.
+
+use std::{
+    io::{self, Write},
+    ops::Range,
+};
+
+use crate::{json, Match};
+
+/// How a [`Printer`] renders matching lines.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    /// The line itself, followed by the line terminator.
+    #[default]
+    Lines,
+    /// One JSON record per line, see the [`json`] module for the schema.
+    Json,
+}
+
+#[derive(Debug)]
+pub struct Printer<W> {
+    output: W,
+    format: Format,
+    line_terminator: u8,
+    /// Highlight changes with ANSI escapes, see [`Printer::print_diff`].
+    color: bool,
+}
+
+impl<W: Write> Printer<W> {
+    pub fn new(output: W, format: Format) -> Self {
+        Self {
+            output,
+            format,
+            line_terminator: b'\n',
+            color: false,
+        }
+    }
+
+    /// Sets the byte written after every line in [`Format::Lines`], `\n` by default.
+    pub fn with_line_terminator(mut self, line_terminator: u8) -> Self {
+        self.line_terminator = line_terminator;
+        self
+    }
+
+    /// Highlights what [`Printer::print_diff`] changed with ANSI colors, off by default.
+    pub fn with_color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Prints a matching line, `number` counting from 1, with the matches found in it.
+    pub fn print_line(&mut self, number: usize, line: &str, matches: &[Match]) -> io::Result<()> {
+        self.print_attributed_line(number, None, line, matches, &[])
+    }
+
+    /// Like [`Printer::print_line`], but also names the patterns that matched the line
+    /// by their numbers and gives the byte offset of the line in the input, if known.
+    /// Nothing extra is printed when `patterns` is empty.
+    pub fn print_attributed_line(
+        &mut self,
+        number: usize,
+        offset: Option<usize>,
+        line: &str,
+        matches: &[Match],
+        patterns: &[usize],
+    ) -> io::Result<()> {
+        self.print_line_record(number, offset, line, matches, patterns, false)
+    }
+
+    /// Like [`Printer::print_attributed_line`], for a line with more matches than
+    /// the first few given in `matches`. [`Format::Json`] marks the record with
+    /// `"truncated": true`.
+    pub fn print_truncated_line(
+        &mut self,
+        number: usize,
+        offset: Option<usize>,
+        line: &str,
+        matches: &[Match],
+        patterns: &[usize],
+    ) -> io::Result<()> {
+        self.print_line_record(number, offset, line, matches, patterns, true)
+    }
+
+    fn print_line_record(
+        &mut self,
+        number: usize,
+        offset: Option<usize>,
+        line: &str,
+        matches: &[Match],
+        patterns: &[usize],
+        truncated: bool,
+    ) -> io::Result<()> {
+        let patterns: Vec<_> = patterns.iter().map(usize::to_string).collect();
+        match self.format {
+            Format::Lines => {
+                if !patterns.is_empty() {
+                    write!(self.output, "{}:", patterns.join(","))?;
+                }
+                if let Some(offset) = offset {
+                    write!(self.output, "{offset}:")?;
+                }
+                self.output.write_all(line.as_bytes())?;
+                self.output.write_all(&[self.line_terminator])
+            }
+            Format::Json => {
+                let matches: Vec<_> = matches.iter().map(Match::to_json).collect();
+                let patterns = if patterns.is_empty() {
+                    String::new()
+                } else {
+                    format!(r#", "patterns": [{}]"#, patterns.join(", "))
+                };
+                let offset = offset.map_or_else(String::new, |o| format!(r#", "offset": {o}"#));
+                let truncated = if truncated {
+                    r#", "truncated": true"#
+                } else {
+                    ""
+                };
+                writeln!(
+                    self.output,
+                    r#"{{"line_number": {number}{offset}{patterns}, "line": {}, "matches": [{}]{truncated}}}"#,
+                    json::quote(line),
+                    matches.join(", ")
+                )
+            }
+        }
+    }
+
+    /// Prints a line, `number` counting from 1, as it was `before` and as it is
+    /// `after` an edit. [`Format::Lines`] prints it twice, after `-` and `+` and the
+    /// prefixes of [`Printer::print_attributed_line`], with the changed part in red
+    /// and green if color is on. [`Format::Json`] gives the edited line as
+    /// `"replaced"`.
+    pub fn print_diff(
+        &mut self,
+        number: usize,
+        offset: Option<usize>,
+        before: &str,
+        after: &str,
+        patterns: &[usize],
+    ) -> io::Result<()> {
+        let patterns: Vec<_> = patterns.iter().map(usize::to_string).collect();
+        match self.format {
+            Format::Lines => {
+                let (removed, added) = changed_ranges(before, after);
+                for (sign, line, changed, color) in [
+                    ('-', before, removed, "\x1b[1;31m"),
+                    ('+', after, added, "\x1b[1;32m"),
+                ] {
+                    write!(self.output, "{sign}")?;
+                    if !patterns.is_empty() {
+                        write!(self.output, "{}:", patterns.join(","))?;
+                    }
+                    if let Some(offset) = offset {
+                        write!(self.output, "{offset}:")?;
+                    }
+                    if self.color && !changed.is_empty() {
+                        write!(
+                            self.output,
+                            "{}{color}{}\x1b[0m{}",
+                            &line[..changed.start],
+                            &line[changed.clone()],
+                            &line[changed.end..]
+                        )?;
+                    } else {
+                        self.output.write_all(line.as_bytes())?;
+                    }
+                    self.output.write_all(&[self.line_terminator])?;
+                }
+                Ok(())
+            }
+            Format::Json => {
+                let patterns = if patterns.is_empty() {
+                    String::new()
+                } else {
+                    format!(r#", "patterns": [{}]"#, patterns.join(", "))
+                };
+                let offset = offset.map_or_else(String::new, |o| format!(r#", "offset": {o}"#));
+                writeln!(
+                    self.output,
+                    r#"{{"line_number": {number}{offset}{patterns}, "line": {}, "replaced": {}}}"#,
+                    json::quote(before),
+                    json::quote(after)
+                )
+            }
+        }
+    }
+
+    /// Prints a line with the name of the [`Classifier`](crate::set::Classifier) rule
+    /// it matched, and its byte offset in the input if known.
+    pub fn print_classified_line(
+        &mut self,
+        rule: &str,
+        number: usize,
+        offset: Option<usize>,
+        line: &str,
+    ) -> io::Result<()> {
+        match self.format {
+            Format::Lines => {
+                write!(self.output, "{rule}:")?;
+                if let Some(offset) = offset {
+                    write!(self.output, "{offset}:")?;
+                }
+                self.output.write_all(line.as_bytes())?;
+                self.output.write_all(&[self.line_terminator])
+            }
+            Format::Json => {
+                let offset = offset.map_or_else(String::new, |o| format!(r#", "offset": {o}"#));
+                writeln!(
+                    self.output,
+                    r#"{{"line_number": {number}{offset}, "rule": {}, "line": {}}}"#,
+                    json::quote(rule),
+                    json::quote(line)
+                )
+            }
+        }
+    }
+
+    /// Prints `text` as is, followed by the line terminator if it ends the line. This
+    /// passes input through, possibly edited, one line or part of a line at a time.
+    pub fn print_part(&mut self, text: &str, line_end: bool) -> io::Result<()> {
+        self.output.write_all(text.as_bytes())?;
+        if line_end {
+            self.output.write_all(&[self.line_terminator])?;
+        }
+        Ok(())
+    }
+
+    /// Prints a single match on its own line, as `offset:length:text` when the byte
+    /// offset of the line it was found on is known.
+    pub fn print_match(&mut self, line_offset: Option<usize>, found: &Match) -> io::Result<()> {
+        match self.format {
+            Format::Lines => {
+                if let Some(offset) = line_offset {
+                    write!(self.output, "{}:{}:", offset + found.start(), found.len())?;
+                }
+                self.output.write_all(found.as_str().as_bytes())?;
+                self.output.write_all(&[self.line_terminator])
+            }
+            Format::Json => writeln!(self.output, "{}", found.to_json()),
+        }
+    }
+
+    /// Prints how many times `text` was matched, for a summary of distinct matches.
+    pub fn print_count(&mut self, text: &str, count: usize) -> io::Result<()> {
+        match self.format {
+            Format::Lines => {
+                write!(self.output, "{count}\t")?;
+                self.output.write_all(text.as_bytes())?;
+                self.output.write_all(&[self.line_terminator])
+            }
+            Format::Json => writeln!(
+                self.output,
+                r#"{{"text": {}, "count": {count}}}"#,
+                json::quote(text)
+            ),
+        }
+    }
+
+    /// Prints the number of matching lines, for a count of matches only.
+    pub fn print_line_count(&mut self, count: usize) -> io::Result<()> {
+        match self.format {
+            Format::Lines => {
+                write!(self.output, "{count}")?;
+                self.output.write_all(&[self.line_terminator])
+            }
+            Format::Json => writeln!(self.output, r#"{{"count": {count}}}"#),
+        }
+    }
+
+    /// Reports a match in input that is not printed because it is binary.
+    pub fn print_binary_match(&mut self) -> io::Result<()> {
+        writeln!(self.output, "Binary input matches")
+    }
+
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.output.flush()
+    }
+
+    pub fn get_ref(&self) -> &W {
+        &self.output
+    }
+
+    pub fn into_inner(self) -> W {
+        self.output
+    }
+}
+
+/// The byte ranges of `before` and `after` between their longest common prefix and
+/// suffix, the parts that differ.
+fn changed_ranges(before: &str, after: &str) -> (Range<usize>, Range<usize>) {
+    let prefix: usize = before
+        .chars()
+        .zip(after.chars())
+        .take_while(|(a, b)| a == b)
+        .map(|(a, _)| a.len_utf8())
+        .sum();
+    let suffix: usize = before[prefix..]
+        .chars()
+        .rev()
+        .zip(after[prefix..].chars().rev())
+        .take_while(|(a, b)| a == b)
+        .map(|(a, _)| a.len_utf8())
+        .sum();
+    (prefix..before.len() - suffix, prefix..after.len() - suffix)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Format, Printer};
+    use crate::Pattern;
+
+    #[test]
+    fn print_lines() {
+        let mut printer = Printer::new(Vec::new(), Format::Lines).with_line_terminator(b';');
+        printer.print_line(1, "abc", &[]).expect("Writing to a Vec");
+        printer.print_line(5, "def", &[]).expect("Writing to a Vec");
+        assert_eq!(printer.into_inner(), b"abc;def;");
+    }
+
+    #[test]
+    fn print_attributed() {
+        let mut printer = Printer::new(Vec::new(), Format::Lines);
+        printer
+            .print_attributed_line(1, None, "abc", &[], &[1, 3])
+            .expect("Writing to a Vec");
+        assert_eq!(printer.into_inner(), b"1,3:abc\n");
+
+        let mut printer = Printer::new(Vec::new(), Format::Json);
+        printer
+            .print_attributed_line(2, Some(7), "abc", &[], &[2])
+            .expect("Writing to a Vec");
+        assert_eq!(
+            printer.into_inner(),
+            b"{\"line_number\": 2, \"offset\": 7, \"patterns\": [2], \"line\": \"abc\", \"matches\": []}\n"
+        );
+    }
+
+    #[test]
+    fn print_truncated() {
+        let pattern = Pattern::new(r"\d").expect("Pattern is correct");
+        let matches: Vec<_> = pattern.find_iter("1 2").take(1).collect();
+        let mut printer = Printer::new(Vec::new(), Format::Json);
+        printer
+            .print_truncated_line(1, None, "1 2", &matches, &[])
+            .expect("Writing to a Vec");
+        assert_eq!(
+            String::from_utf8(printer.into_inner()).expect("Output is UTF-8"),
+            concat!(
+                r#"{"line_number": 1, "line": "1 2", "matches": "#,
+                r#"[{"start": 0, "end": 1, "text": "1"}], "truncated": true}"#,
+                "\n"
+            )
+        );
+    }
+
+    #[test]
+    fn print_diffs() {
+        let mut printer = Printer::new(Vec::new(), Format::Lines);
+        printer
+            .print_diff(1, Some(4), "a=1;", "a=10;", &[])
+            .expect("Writing to a Vec");
+        assert_eq!(printer.into_inner(), b"-4:a=1;\n+4:a=10;\n");
+
+        let mut printer = Printer::new(Vec::new(), Format::Lines).with_color(true);
+        printer
+            .print_diff(1, None, "café au lait", "café noir", &[2])
+            .expect("Writing to a Vec");
+        assert_eq!(
+            String::from_utf8(printer.into_inner()).expect("Output is UTF-8"),
+            "-2:café \x1b[1;31mau lait\x1b[0m\n+2:café \x1b[1;32mnoir\x1b[0m\n"
+        );
+
+        let mut printer = Printer::new(Vec::new(), Format::Json);
+        printer
+            .print_diff(3, None, "ab", "b", &[])
+            .expect("Writing to a Vec");
+        assert_eq!(
+            printer.into_inner(),
+            b"{\"line_number\": 3, \"line\": \"ab\", \"replaced\": \"b\"}\n"
+        );
+    }
+
+    #[test]
+    fn print_classified() {
+        let mut printer = Printer::new(Vec::new(), Format::Lines);
+        printer
+            .print_classified_line("error", 1, Some(4), "ERROR x")
+            .expect("Writing to a Vec");
+        assert_eq!(printer.into_inner(), b"error:4:ERROR x\n");
+
+        let mut printer = Printer::new(Vec::new(), Format::Json);
+        printer
+            .print_classified_line("error", 2, None, "ERROR x")
+            .expect("Writing to a Vec");
+        assert_eq!(
+            printer.into_inner(),
+            b"{\"line_number\": 2, \"rule\": \"error\", \"line\": \"ERROR x\"}\n"
+        );
+    }
+
+    #[test]
+    fn print_matches() {
+        let pattern = Pattern::new(r"\d+").expect("Pattern is correct");
+        let mut printer = Printer::new(Vec::new(), Format::Lines);
+        for found in pattern.find_iter("a12b3") {
+            printer
+                .print_match(Some(10), &found)
+                .expect("Writing to a Vec");
+            printer.print_match(None, &found).expect("Writing to a Vec");
+        }
+        assert_eq!(printer.into_inner(), b"11:2:12\n12\n14:1:3\n3\n");
+    }
+
+    #[test]
+    fn print_counts() {
+        let mut printer = Printer::new(Vec::new(), Format::Lines);
+        printer.print_count("abc", 2).expect("Writing to a Vec");
+        printer.print_line_count(0).expect("Writing to a Vec");
+        assert_eq!(printer.into_inner(), b"2\tabc\n0\n");
+
+        let mut printer = Printer::new(Vec::new(), Format::Json);
+        printer.print_line_count(3).expect("Writing to a Vec");
+        assert_eq!(printer.into_inner(), b"{\"count\": 3}\n");
+    }
+
+    #[test]
+    fn print_json() {
+        let pattern = Pattern::new(r"\d").expect("Pattern is correct");
+        let matches: Vec<_> = pattern.find_iter("a1b2").collect();
+        let mut printer = Printer::new(Vec::new(), Format::Json);
+        printer
+            .print_line(3, "a1b2", &matches)
+            .expect("Writing to a Vec");
+        assert_eq!(
+            String::from_utf8(printer.into_inner()).expect("Output is UTF-8"),
+            concat!(
+                r#"{"line_number": 3, "line": "a1b2", "matches": ["#,
+                r#"{"start": 1, "end": 2, "text": "1"}, {"start": 3, "end": 4, "text": "2"}]}"#,
+                "\n"
+            )
+        );
+    }
+}
@@ -0,0 +1,303 @@
+//! Replacement of matches, see [`Pattern::replace_all`](crate::Pattern::replace_all).
+//!
+//! A template string is copied to the output with these references expanded:
+//!
+//! | Syntax          | Expands to                                                     |
+//! |-----------------|----------------------------------------------------------------|
+//! | `$1`, `${1}`    | the text of group 1, `$0` being the whole match                |
+//! | `$name`         | the text of the group called `name`                            |
+//! | `${1:-text}`    | group 1, or `text` if it didn't participate or matched nothing |
+//! | `${1-text}`     | group 1, or `text` if it didn't participate                    |
+//! | `${1^^}`        | group 1 in uppercase                                           |
+//! | `${1,,}`        | group 1 in lowercase                                           |
+//! | `$$`            | a literal `$`                                                  |
+//!
+//! The unbraced forms take as many digits, or name characters, as follow the `$`,
+//! so `$1a` refers to a group called `1a`; write `${1}a` instead. References to
+//! groups that don't exist or didn't participate expand to nothing, and a `$` that
+//! doesn't start a reference is copied as is.
+//!
+//! [`Pattern::validate_template`](crate::Pattern::validate_template) checks a template
+//! before any matching, and rejects those quiet cases: references to groups the
+//! pattern doesn't have, and `${` that doesn't start a valid reference.
+
+use crate::{Captures, GroupInfo};
+
+/// A mistake in a template, found by
+/// [`Pattern::validate_template`](crate::Pattern::validate_template). `position` is
+/// a byte offset into the template.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum TemplateError {
+    #[error(
+        "Group {index} at position {position} doesn't exist, the pattern has {groups} group(s)"
+    )]
+    NoSuchGroup {
+        index: usize,
+        position: usize,
+        groups: usize,
+    },
+    #[error("No group is called `{name}` at position {position}, write `${{1}}{name}` if the name should follow a group's text")]
+    NoSuchName { name: String, position: usize },
+    #[error("Invalid reference at position {position}, write `$${{` for a literal `${{`")]
+    InvalidReference { position: usize },
+}
+
+/// Produces the replacement for each match in [`Pattern::replace_all`](crate::Pattern::replace_all).
+///
+/// Implemented for template strings and for closures returning the replacement text.
+pub trait Replacer {
+    /// Appends the replacement for the match described by `captures` to `dst`.
+    fn replace_append(&mut self, captures: &Captures<'_>, dst: &mut String);
+}
+
+impl Replacer for &str {
+    fn replace_append(&mut self, captures: &Captures<'_>, dst: &mut String) {
+        expand(self, captures, dst);
+    }
+}
+
+impl Replacer for &String {
+    fn replace_append(&mut self, captures: &Captures<'_>, dst: &mut String) {
+        expand(self, captures, dst);
+    }
+}
+
+impl<F, T> Replacer for F
+where
+    F: FnMut(&Captures<'_>) -> T,
+    T: AsRef<str>,
+{
+    fn replace_append(&mut self, captures: &Captures<'_>, dst: &mut String) {
+        dst.push_str(self(captures).as_ref());
+    }
+}
+
+/// Expands `template` for the match described by `captures`, appending to `dst`.
+pub fn expand(template: &str, captures: &Captures<'_>, dst: &mut String) {
+    let mut rest = template;
+    while let Some(dollar) = rest.find('$') {
+        dst.push_str(&rest[..dollar]);
+        rest = &rest[dollar..];
+        match Reference::parse(rest) {
+            Some((reference, len)) => {
+                reference.expand(captures, dst);
+                rest = &rest[len..];
+            }
+            None => {
+                dst.push('$');
+                rest = &rest[1..];
+            }
+        }
+    }
+    dst.push_str(rest);
+}
+
+/// Checks that every reference in `template` is to one of `groups`, see
+/// [`Pattern::validate_template`](crate::Pattern::validate_template).
+pub(crate) fn validate(template: &str, groups: &[GroupInfo]) -> Result<(), TemplateError> {
+    let mut position = 0;
+    while let Some(dollar) = template[position..].find('$') {
+        position += dollar;
+        let rest = &template[position..];
+        position += match Reference::parse(rest) {
+            Some((Reference::Group(Group::Index(index), _), _)) if index > groups.len() => {
+                return Err(TemplateError::NoSuchGroup {
+                    index,
+                    position,
+                    groups: groups.len(),
+                });
+            }
+            Some((Reference::Group(Group::Name(name), _), _))
+                if !groups.iter().any(|group| group.name() == Some(name)) =>
+            {
+                return Err(TemplateError::NoSuchName {
+                    name: String::from(name),
+                    position,
+                });
+            }
+            Some((_, len)) => len,
+            None if rest.starts_with("${") => {
+                return Err(TemplateError::InvalidReference { position });
+            }
+            None => 1,
+        };
+    }
+    Ok(())
+}
+
+/// How a group is referred to in a template.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Group<'t> {
+    Index(usize),
+    Name(&'t str),
+}
+
+/// What is done with the text of a referenced group.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operation<'t> {
+    Copy,
+    /// Use the fallback if the group didn't participate, or with `or_empty` also if
+    /// it matched nothing.
+    Fallback {
+        text: &'t str,
+        or_empty: bool,
+    },
+    Uppercase,
+    Lowercase,
+}
+
+/// A single `$` reference in a template.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Reference<'t> {
+    Dollar,
+    Group(Group<'t>, Operation<'t>),
+}
+
+impl<'t> Reference<'t> {
+    /// Parses the reference at the start of `template`, which starts with `$`,
+    /// returning it with its length in bytes.
+    fn parse(template: &'t str) -> Option<(Self, usize)> {
+        let after = &template[1..];
+        if after.starts_with('$') {
+            return Some((Self::Dollar, 2));
+        }
+        if let Some(braced) = after.strip_prefix('{') {
+            let end = braced.find('}')?;
+            let body = &braced[..end];
+            let name_len = Self::name_len(body);
+            let group = Self::group(&body[..name_len])?;
+            let operation = match &body[name_len..] {
+                "" => Operation::Copy,
+                "^^" => Operation::Uppercase,
+                ",," => Operation::Lowercase,
+                rest => match (rest.strip_prefix(":-"), rest.strip_prefix('-')) {
+                    (Some(text), _) => Operation::Fallback {
+                        text,
+                        or_empty: true,
+                    },
+                    (None, Some(text)) => Operation::Fallback {
+                        text,
+                        or_empty: false,
+                    },
+                    (None, None) => return None,
+                },
+            };
+            return Some((Self::Group(group, operation), end + 3));
+        }
+
+        let name_len = Self::name_len(after);
+        let group = Self::group(&after[..name_len])?;
+        Some((Self::Group(group, Operation::Copy), name_len + 1))
+    }
+
+    fn name_len(text: &str) -> usize {
+        text.find(|ch: char| !(ch.is_ascii_alphanumeric() || ch == '_'))
+            .unwrap_or(text.len())
+    }
+
+    fn group(name: &'t str) -> Option<Group<'t>> {
+        if name.is_empty() {
+            None
+        } else if let Ok(index) = name.parse() {
+            Some(Group::Index(index))
+        } else {
+            Some(Group::Name(name))
+        }
+    }
+
+    fn expand(&self, captures: &Captures<'_>, dst: &mut String) {
+        let (group, operation) = match self {
+            Self::Dollar => return dst.push('$'),
+            Self::Group(group, operation) => (group, operation),
+        };
+        let text = match group {
+            Group::Index(index) => captures.get(*index).map(|m| m.as_str()),
+            Group::Name(name) => captures.name(name).map(|m| m.as_str()),
+        };
+        match *operation {
+            Operation::Copy => dst.push_str(text.unwrap_or_default()),
+            Operation::Fallback {
+                text: fallback,
+                or_empty,
+            } => match text {
+                Some(text) if !(or_empty && text.is_empty()) => dst.push_str(text),
+                _ => dst.push_str(fallback),
+            },
+            Operation::Uppercase => dst.push_str(&text.unwrap_or_default().to_uppercase()),
+            Operation::Lowercase => dst.push_str(&text.unwrap_or_default().to_lowercase()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::TemplateError;
+    use crate::Pattern;
+
+    #[test]
+    fn replace_with_template() {
+        let pattern = Pattern::new(r"(\w+)=(\d+)").expect("Pattern is correct");
+        assert_eq!(pattern.replace_all("a=1, b=22", "$2=$1"), "1=a, 22=b");
+        assert_eq!(pattern.replace_all("a=1", "${1}x $0 $$1 $"), "ax a=1 $1 $");
+        assert_eq!(pattern.replace_all("a=1", "[$3][$name][${1"), "[][][${1");
+        assert_eq!(pattern.replace_all("no match", "$1"), "no match");
+    }
+
+    #[test]
+    fn replace_named() {
+        let pattern = Pattern::new(r"(?<key>\w+)=(?<value>\w+)").expect("Pattern is correct");
+        assert_eq!(pattern.replace_all("a=1", "$value=${key}"), "1=a");
+        assert_eq!(pattern.replace_all("a=1", "${value^^}[$other]"), "1[]");
+    }
+
+    #[test]
+    fn replace_with_fallback_and_case() {
+        let pattern = Pattern::new("(a)|b()").expect("Pattern is correct");
+        assert_eq!(pattern.replace_all("ab", "${1:-x}"), "ax");
+        assert_eq!(pattern.replace_all("ab", "${1-x}${2-y}${2:-z}"), "ayzxz");
+
+        let pattern = Pattern::new(r"(\w+) (\w+)").expect("Pattern is correct");
+        assert_eq!(
+            pattern.replace_all("Hello World", "${1^^} ${2,,}"),
+            "HELLO world"
+        );
+    }
+
+    #[test]
+    fn validate_templates() {
+        let pattern = Pattern::new(r"(?<key>\w+)=(\w+)").expect("Pattern is correct");
+        assert_eq!(
+            pattern.validate_template("$0 ${2:-x} $key $$3 $ end"),
+            Ok(())
+        );
+        assert_eq!(
+            pattern.validate_template("$1 $3"),
+            Err(TemplateError::NoSuchGroup {
+                index: 3,
+                position: 3,
+                groups: 2
+            })
+        );
+        assert_eq!(
+            pattern.validate_template("$2x"),
+            Err(TemplateError::NoSuchName {
+                name: String::from("2x"),
+                position: 0
+            })
+        );
+        assert_eq!(
+            pattern.validate_template("a${key"),
+            Err(TemplateError::InvalidReference { position: 1 })
+        );
+    }
+
+    #[test]
+    fn replace_with_closure() {
+        let pattern = Pattern::new(r"\d+").expect("Pattern is correct");
+        let doubled = pattern.replace_all("1 and 21", |captures: &crate::Captures<'_>| {
+            let number: u32 = captures.whole().as_str().parse().expect("Matched digits");
+            (number * 2).to_string()
+        });
+        assert_eq!(doubled, "2 and 42");
+    }
+}
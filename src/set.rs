@@ -0,0 +1,642 @@
+//! Several patterns searched together, see [`PatternSet`] and [`Classifier`], and
+//! the files they are usually loaded from, see [`parse_pattern_file`] and
+//! [`parse_rules_file`].
+
+use std::{
+    cmp::Reverse,
+    collections::HashMap,
+    sync::{atomic::AtomicBool, Arc},
+    time::{Duration, Instant},
+};
+
+use crate::{literals::Literals, token::Token, Error, Pattern, PatternBuilder, Result};
+
+/// A list of patterns that reports which of them match a haystack.
+///
+/// Patterns are identified by their index in the list the set was built from.
+#[derive(Debug, Clone)]
+pub struct PatternSet {
+    patterns: Vec<Pattern>,
+    /// Searches for all patterns at once, when they are all literals.
+    literals: Option<Literals>,
+}
+
+impl PatternSet {
+    /// Parses every pattern, failing on the first one that is invalid.
+    pub fn new<I, S>(patterns: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        Self::build(&PatternBuilder::new(), patterns)
+    }
+
+    /// Parses every pattern with `builder`, like [`PatternSet::new`].
+    pub fn build<I, S>(builder: &PatternBuilder, patterns: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let patterns = patterns
+            .into_iter()
+            .map(|pattern| builder.build(pattern.as_ref()))
+            .collect::<Result<_>>()?;
+        Ok(Self {
+            patterns,
+            literals: None,
+        })
+    }
+
+    /// Builds a set of patterns that match `literals` as they are, with no special
+    /// characters, like `grep -F`. Which of them match a haystack is found in a
+    /// single pass over it, however many there are.
+    pub fn literals<I, S>(literals: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let literals: Vec<S> = literals.into_iter().collect();
+        let patterns = literals
+            .iter()
+            .map(|literal| Pattern::from_tokens(literal.as_ref().chars().map(Token::Literal)))
+            .collect::<Result<_>>()?;
+        Ok(Self {
+            patterns,
+            literals: Some(Literals::new(&literals)),
+        })
+    }
+
+    /// Bounds every search of every pattern, see [`Pattern::with_step_limit`].
+    pub fn with_step_limit(mut self, limit: usize) -> Self {
+        self.patterns = self
+            .patterns
+            .into_iter()
+            .map(|pattern| pattern.with_step_limit(limit))
+            .collect();
+        self
+    }
+
+    /// Makes every search of every pattern stop as soon as `cancel` is set, see
+    /// [`Pattern::with_cancellation`].
+    pub fn with_cancellation(mut self, cancel: Arc<AtomicBool>) -> Self {
+        self.patterns = self
+            .patterns
+            .into_iter()
+            .map(|pattern| pattern.with_cancellation(cancel.clone()))
+            .collect();
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.patterns.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.patterns.is_empty()
+    }
+
+    pub fn patterns(&self) -> &[Pattern] {
+        &self.patterns
+    }
+
+    /// Whether any of the patterns matches `haystack`, stopping at the first that does.
+    pub fn is_match(&self, haystack: &str) -> bool {
+        match &self.literals {
+            Some(literals) => literals.is_match(haystack),
+            None => self.patterns.iter().any(|pattern| pattern.test(haystack)),
+        }
+    }
+
+    /// Whether at least `n` of the patterns match `haystack`.
+    ///
+    /// Patterns are tried in order and the answer is given as soon as it is known:
+    /// after the `n`th match, or once too few patterns are left to reach `n`.
+    pub fn matches_at_least(&self, haystack: &str, n: usize) -> bool {
+        if let Some(literals) = &self.literals {
+            return literals.matches(haystack).len() >= n;
+        }
+        let mut needed = n;
+        for (tried, pattern) in self.patterns.iter().enumerate() {
+            if needed == 0 {
+                return true;
+            }
+            if self.patterns.len() - tried < needed {
+                return false;
+            }
+            if pattern.test(haystack) {
+                needed -= 1;
+            }
+        }
+        needed == 0
+    }
+
+    /// Indices of all patterns that match `haystack`, in ascending order.
+    pub fn matches(&self, haystack: &str) -> Vec<usize> {
+        if let Some(literals) = &self.literals {
+            return literals.matches(haystack);
+        }
+        self.patterns
+            .iter()
+            .enumerate()
+            .filter(|(_, pattern)| pattern.test(haystack))
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// Like [`PatternSet::matches`], also adding how each pattern did to `stats`,
+    /// which has to come from [`PatternSet::stats`] of this set.
+    pub fn matches_recording(&self, haystack: &str, stats: &mut SetStats) -> Vec<usize> {
+        assert_eq!(stats.patterns.len(), self.len(), "Stats of a different set");
+        let mut matched = Vec::new();
+        for (index, (pattern, stats)) in self.patterns.iter().zip(&mut stats.patterns).enumerate() {
+            let started = Instant::now();
+            let found = pattern.test(haystack);
+            stats.time += started.elapsed();
+            stats.searches += 1;
+            if found {
+                stats.hits += 1;
+                matched.push(index);
+            }
+        }
+        matched
+    }
+
+    /// Empty statistics to collect with [`PatternSet::matches_recording`].
+    pub fn stats(&self) -> SetStats {
+        SetStats {
+            patterns: vec![PatternStats::default(); self.len()],
+        }
+    }
+}
+
+/// How each pattern of a [`PatternSet`] did over a number of searches, to find
+/// rules that never match or that are slow.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetStats {
+    patterns: Vec<PatternStats>,
+}
+
+impl SetStats {
+    /// The statistics of every pattern, by index in the set.
+    pub fn patterns(&self) -> &[PatternStats] {
+        &self.patterns
+    }
+
+    /// Indices of the patterns that were searched for but never matched.
+    pub fn dead(&self) -> impl Iterator<Item = usize> + '_ {
+        let patterns = self.patterns.iter().enumerate();
+        patterns
+            .filter(|(_, stats)| stats.searches > 0 && stats.hits == 0)
+            .map(|(index, _)| index)
+    }
+
+    /// Indices of the patterns, the one that took the most time first.
+    pub fn slowest(&self) -> Vec<usize> {
+        let mut indices: Vec<usize> = (0..self.patterns.len()).collect();
+        indices.sort_by_key(|index| Reverse(self.patterns[*index].time));
+        indices
+    }
+}
+
+/// How a single pattern did, see [`SetStats`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PatternStats {
+    /// How many haystacks it was searched in.
+    pub searches: usize,
+    /// How many of them it matched.
+    pub hits: usize,
+    /// The time spent searching for it.
+    pub time: Duration,
+}
+
+/// Named patterns that sort lines into categories, the first matching rule winning.
+///
+/// Rules are tried in the order they were given and trying stops at the first that
+/// matches, so put the most specific ones first. A final rule with an empty pattern
+/// catches every line the others didn't.
+#[derive(Debug, Clone)]
+pub struct Classifier {
+    names: Vec<String>,
+    set: PatternSet,
+}
+
+impl Classifier {
+    /// Parses the pattern of every `(name, pattern)` rule, failing on the first one
+    /// that is invalid.
+    pub fn new<I, N, P>(rules: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = (N, P)>,
+        N: Into<String>,
+        P: AsRef<str>,
+    {
+        Self::build(&PatternBuilder::new(), rules)
+    }
+
+    /// Parses the pattern of every rule with `builder`, like [`Classifier::new`].
+    pub fn build<I, N, P>(builder: &PatternBuilder, rules: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = (N, P)>,
+        N: Into<String>,
+        P: AsRef<str>,
+    {
+        let (names, patterns): (Vec<String>, Vec<P>) = rules
+            .into_iter()
+            .map(|(name, pattern)| (name.into(), pattern))
+            .unzip();
+        let set = PatternSet::build(builder, patterns)?;
+        Ok(Self { names, set })
+    }
+
+    /// Bounds every search of every rule, see [`Pattern::with_step_limit`]. A rule
+    /// that runs out of steps on a line doesn't match it.
+    pub fn with_step_limit(mut self, limit: usize) -> Self {
+        self.set = self.set.with_step_limit(limit);
+        self
+    }
+
+    /// Makes every search of every rule stop as soon as `cancel` is set, see
+    /// [`Pattern::with_cancellation`]. A cancelled rule doesn't match.
+    pub fn with_cancellation(mut self, cancel: Arc<AtomicBool>) -> Self {
+        self.set = self.set.with_cancellation(cancel);
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// The rule names, in order.
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    pub fn patterns(&self) -> &PatternSet {
+        &self.set
+    }
+
+    /// The index of the first rule matching `line`.
+    pub fn classify_index(&self, line: &str) -> Option<usize> {
+        let mut patterns = self.set.patterns().iter();
+        patterns.position(|pattern| pattern.test(line))
+    }
+
+    /// The name of the first rule matching `line`.
+    pub fn classify(&self, line: &str) -> Option<&str> {
+        self.classify_index(line)
+            .map(|index| self.names[index].as_str())
+    }
+}
+
+/// Reads the patterns of a pattern file, one per line, expanding definitions.
+///
+/// A line of the form `name = pattern` defines `name` instead of adding a pattern,
+/// and `{name}` in later lines is replaced by the defined text as is, so wrap it in
+/// a group where it has to act as one unit:
+///
+/// ```text
+/// call = \w+\(
+/// ^{call}\)$
+/// ```
+///
+/// Names are made of ASCII letters, digits and `_`, not starting with a digit, so
+/// `{3}` is left alone. Write `\{` for a literal `{` before a name, or `\=` to keep
+/// a pattern that looks like a definition.
+pub fn parse_pattern_file(text: &str) -> Result<Vec<String>> {
+    let mut definitions: HashMap<&str, String> = HashMap::new();
+    let mut patterns = Vec::new();
+    for line in text.lines() {
+        let line_offset = line.as_ptr() as usize - text.as_ptr() as usize;
+        match line.split_once(" = ") {
+            Some((name, body)) if is_name(name.trim()) => {
+                let body_offset = line_offset + line.len() - body.len();
+                let body = expand_references(body, body_offset, &definitions)?;
+                definitions.insert(name.trim(), body);
+            }
+            _ => patterns.push(expand_references(line, line_offset, &definitions)?),
+        }
+    }
+    Ok(patterns)
+}
+
+/// Reads the `(name, pattern)` rules of a [`Classifier`] from a rules file.
+///
+/// The file is the subset of TOML made of top-level `name = "pattern"` pairs, in
+/// the order they are to be tried, with `#` comments:
+///
+/// ```text
+/// # Most specific first
+/// panic = 'panicked at'
+/// error = "^(ERROR|FATAL) "
+/// ```
+///
+/// Prefer literal strings in single quotes for patterns, they take backslashes as
+/// is. Basic strings in double quotes understand the escapes `\\`, `\"`, `\n`,
+/// `\r` and `\t`, so `\d` has to be written `"\\d"` in them. Names are bare keys
+/// made of ASCII letters, digits, `_` and `-`.
+pub fn parse_rules_file(text: &str) -> Result<Vec<(String, String)>> {
+    let mut rules: Vec<(String, String)> = Vec::new();
+    for line in text.lines() {
+        let line_offset = line.as_ptr() as usize - text.as_ptr() as usize;
+        let content = line.trim_start();
+        let at = line_offset + line.len() - content.len();
+        if content.is_empty() || content.starts_with('#') {
+            continue;
+        }
+        if content.starts_with('[') {
+            return Err(Error::syntax(
+                "Tables are not supported in rules files",
+                at,
+                "list every rule as `name = 'pattern'` at the top level",
+            ));
+        }
+
+        let (name, value) = content.split_once('=').ok_or_else(|| {
+            Error::syntax("Expected a rule", at, "write rules as `name = 'pattern'`")
+        })?;
+        let name = name.trim_end();
+        let is_key_char = |ch: char| ch.is_ascii_alphanumeric() || ch == '_' || ch == '-';
+        if name.is_empty() || !name.chars().all(is_key_char) {
+            return Err(Error::syntax(
+                format!("Invalid rule name `{name}`"),
+                at,
+                "names are made of ASCII letters, digits, `_` and `-`",
+            ));
+        }
+        if rules.iter().any(|(defined, _)| defined == name) {
+            return Err(Error::syntax(
+                format!("Rule `{name}` is defined twice"),
+                at,
+                "give each rule its own name",
+            ));
+        }
+
+        let value_text = value.trim_start();
+        let value_at = line_offset + line.len() - value_text.len();
+        let (pattern, rest) = parse_toml_string(value_text, value_at)?;
+        let rest = rest.trim_start();
+        if !(rest.is_empty() || rest.starts_with('#')) {
+            return Err(Error::syntax(
+                "Unexpected text after the pattern",
+                line_offset + line.len() - rest.len(),
+                "put comments after a `#`",
+            ));
+        }
+        rules.push((String::from(name), pattern));
+    }
+    Ok(rules)
+}
+
+/// Parses the TOML string at the start of `text`, which starts at `offset` in its
+/// file, returning its value and the text after it.
+fn parse_toml_string(text: &str, offset: usize) -> Result<(String, &str)> {
+    let unterminated = || {
+        Error::syntax(
+            "Unterminated string",
+            offset,
+            "close it on the same line it was opened",
+        )
+    };
+    if let Some(literal) = text.strip_prefix('\'') {
+        let end = literal.find('\'').ok_or_else(unterminated)?;
+        return Ok((String::from(&literal[..end]), &literal[end + 1..]));
+    }
+    let Some(basic) = text.strip_prefix('"') else {
+        return Err(Error::syntax(
+            "Expected a quoted pattern",
+            offset,
+            "quote it as 'pattern', which takes backslashes as is",
+        ));
+    };
+
+    let mut value = String::new();
+    let mut chars = basic.char_indices();
+    while let Some((at, ch)) = chars.next() {
+        match ch {
+            '"' => return Ok((value, &basic[at + 1..])),
+            '\\' => match chars.next() {
+                Some((_, '\\')) => value.push('\\'),
+                Some((_, '"')) => value.push('"'),
+                Some((_, 'n')) => value.push('\n'),
+                Some((_, 'r')) => value.push('\r'),
+                Some((_, 't')) => value.push('\t'),
+                Some(_) => {
+                    return Err(Error::syntax(
+                        "Unsupported escape in a string",
+                        offset + 1 + at,
+                        "use a literal string in single quotes to keep backslashes as is",
+                    ))
+                }
+                None => return Err(unterminated()),
+            },
+            ch => value.push(ch),
+        }
+    }
+    Err(unterminated())
+}
+
+fn is_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|ch| ch.is_ascii_alphabetic() || ch == '_')
+        && chars.all(|ch| ch.is_ascii_alphanumeric() || ch == '_')
+}
+
+/// Replaces every `{name}` in `line`, which starts at `offset` in the file.
+fn expand_references(
+    line: &str,
+    offset: usize,
+    definitions: &HashMap<&str, String>,
+) -> Result<String> {
+    let mut expanded = String::with_capacity(line.len());
+    let mut chars = line.char_indices();
+    while let Some((at, ch)) = chars.next() {
+        match ch {
+            '\\' => {
+                expanded.push(ch);
+                expanded.extend(chars.next().map(|(_, escaped)| escaped));
+            }
+            '{' => {
+                let rest = &line[at + 1..];
+                let name = rest
+                    .find('}')
+                    .map(|end| &rest[..end])
+                    .filter(|n| is_name(n));
+                match name {
+                    Some(name) => {
+                        let definition = definitions.get(name).ok_or_else(|| {
+                            Error::syntax(
+                                format!("Unknown definition `{name}`"),
+                                offset + at,
+                                "define it on an earlier line as `name = pattern`",
+                            )
+                        })?;
+                        expanded.push_str(definition);
+                        chars.nth(name.len()); // Skip the name and "}"
+                    }
+                    None => expanded.push(ch),
+                }
+            }
+            ch => expanded.push(ch),
+        }
+    }
+    Ok(expanded)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse_pattern_file, parse_rules_file, Classifier, PatternSet};
+    use crate::Error;
+    use std::sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    };
+
+    #[test]
+    fn which_patterns_match() {
+        let set = PatternSet::new([r"\d", "^a", "z$"]).expect("Patterns are correct");
+        assert_eq!(set.len(), 3);
+        assert_eq!(set.matches("a1"), [0, 1]);
+        assert_eq!(set.matches("xyz"), [2]);
+        assert!(set.matches("b").is_empty());
+        assert!(set.is_match("1"));
+        assert!(!set.is_match("b"));
+
+        assert!(PatternSet::new(["a", "("]).is_err());
+    }
+
+    #[test]
+    fn literal_patterns() {
+        let set = PatternSet::literals(["a.b", "(x", "b"]).expect("Literals are correct");
+        assert_eq!(set.matches("a.b"), [0, 2]);
+        assert_eq!(set.matches("axb"), [2]);
+        assert!(set.is_match("((x"));
+        assert!(set.matches_at_least("(xb", 2));
+        assert!(!set.matches_at_least("(x", 2));
+
+        let found: Vec<_> = set.patterns()[0]
+            .find_iter("a.b a.b")
+            .map(|m| m.range())
+            .collect();
+        assert_eq!(found, [0..3, 4..7]);
+    }
+
+    #[test]
+    fn step_limit() {
+        let haystack = "aaaaaaaaaaaaaaaaaaaab";
+        let set = PatternSet::new(["^(?:a|aa)*c|b", "b"]).expect("Patterns are correct");
+        assert_eq!(set.matches(haystack), [0, 1]);
+        assert_eq!(set.with_step_limit(1000).matches(haystack), [1]);
+
+        let classifier = Classifier::new([("slow", "^(?:a|aa)*c|b"), ("other", "")])
+            .expect("Patterns are correct");
+        assert_eq!(classifier.classify(haystack), Some("slow"));
+        let classifier = classifier.with_step_limit(1000);
+        assert_eq!(classifier.classify(haystack), Some("other"));
+    }
+
+    #[test]
+    fn cancellation() {
+        let cancel = Arc::new(AtomicBool::new(false));
+        let set = PatternSet::new(["a+", r"\d"])
+            .expect("Patterns are correct")
+            .with_cancellation(cancel.clone());
+        assert_eq!(set.matches("a1"), [0, 1]);
+        cancel.store(true, Ordering::Relaxed);
+        assert!(set.matches("a1").is_empty());
+    }
+
+    #[test]
+    fn matches_at_least() {
+        let set = PatternSet::new(["a", "b", "c", r"\d"]).expect("Patterns are correct");
+        assert!(set.matches_at_least("ab", 0));
+        assert!(set.matches_at_least("ab", 2));
+        assert!(!set.matches_at_least("ab", 3));
+        assert!(set.matches_at_least("abc1", 4));
+        assert!(!set.matches_at_least("abc1", 5));
+        assert!(!set.matches_at_least("x", 1));
+    }
+
+    #[test]
+    fn recording_stats() {
+        let set = PatternSet::new(["a", "b", "z"]).expect("Patterns are correct");
+        let mut stats = set.stats();
+        assert_eq!(set.matches_recording("ab", &mut stats), [0, 1]);
+        assert_eq!(set.matches_recording("a", &mut stats), [0]);
+
+        let hits: Vec<_> = stats
+            .patterns()
+            .iter()
+            .map(|p| (p.searches, p.hits))
+            .collect();
+        assert_eq!(hits, [(2, 2), (2, 1), (2, 0)]);
+        assert_eq!(stats.dead().collect::<Vec<_>>(), [2]);
+        assert_eq!(stats.slowest().len(), 3);
+    }
+
+    #[test]
+    fn pattern_file_definitions() {
+        let file = concat!(
+            "ident = [abc_]\\w*\n",
+            "call = {ident}\\(\n",
+            "^{call}\n",
+            "\\{ident} x{2}\n",
+            "a \\= b\n",
+        );
+        let patterns = parse_pattern_file(file).expect("File is correct");
+        assert_eq!(patterns, [r"^[abc_]\w*\(", r"\{ident} x{2}", r"a \= b"]);
+
+        match parse_pattern_file("a\nx{missing}") {
+            Err(Error::Syntax { position, .. }) => assert_eq!(position, 3),
+            other => panic!("Expected a syntax error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn classify_lines() {
+        let rules = parse_rules_file(concat!(
+            "# Most specific first\n",
+            "panic = 'panicked at'  # from Rust\n",
+            "error = \"^(ERROR|FATAL) \\\\d\"\n",
+            "\n",
+            "other = ''\n",
+        ))
+        .expect("Rules are correct");
+        assert_eq!(
+            rules[1],
+            (String::from("error"), String::from(r"^(ERROR|FATAL) \d"))
+        );
+
+        let classifier = Classifier::new(rules).expect("Patterns are correct");
+        assert_eq!(classifier.len(), 3);
+        assert_eq!(
+            classifier.classify("ERROR 1 thread panicked at x"),
+            Some("panic")
+        );
+        assert_eq!(classifier.classify("FATAL 2"), Some("error"));
+        assert_eq!(classifier.classify("FATAL x"), Some("other"));
+        assert_eq!(classifier.classify_index("FATAL 2"), Some(1));
+
+        let classifier = Classifier::new([("digits", r"\d")]).expect("Pattern is correct");
+        assert_eq!(classifier.classify("none"), None);
+    }
+
+    #[test]
+    fn rules_file_errors() {
+        let position = |text| match parse_rules_file(text) {
+            Err(Error::Syntax { position, .. }) => position,
+            other => panic!("Expected a syntax error for {text:?}, got {other:?}"),
+        };
+        assert_eq!(position("a = 'x'\n[table]"), 8);
+        assert_eq!(position("a = 'x'\na = 'y'"), 8);
+        assert_eq!(position("a = 'x"), 4);
+        assert_eq!(position(r#"a = "\d""#), 5);
+        assert_eq!(position("a = x"), 4);
+        assert_eq!(position("a = 'x' y"), 8);
+        assert_eq!(position("a b = 'x'"), 0);
+    }
+}
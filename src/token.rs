@@ -0,0 +1,468 @@
+//! Patterns built from [`Token`]s instead of text, for tools that generate patterns
+//! and would otherwise have to escape and concatenate them, see
+//! [`PatternBuilder::build_tokens`](crate::PatternBuilder::build_tokens).
+//!
+//! Tokens go through the same checks as pattern text, so a token sequence is
+//! accepted exactly when the equivalent text would be. Positions in errors,
+//! warnings and [`GroupInfo::span`](crate::GroupInfo::span) are indices into the
+//! token sequence instead of byte offsets. The builder's
+//! [`flags`](crate::PatternBuilder::flags) apply to the tokens like they do to text.
+
+use std::mem;
+
+use crate::{
+    Ast, CaseFold, Error, Flags, Input, Limit, Matcher, PatternBuilder, Result,
+    SingleCharacterMatcher, Warning,
+};
+
+/// A piece of a pattern, with the text it stands for in brackets.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Token {
+    /// The character itself, never special.
+    Literal(char),
+    /// Any character but `\n`, or any at all in dot-all mode, `.`.
+    Any,
+    /// One of `items`, `[...]`, or with `negated` any other character, `[^...]`.
+    Class {
+        items: Vec<ClassItem>,
+        negated: bool,
+    },
+    /// `^`
+    Start,
+    /// `$`
+    End,
+    /// Opens a group, to be closed by [`Token::Close`].
+    Open(GroupKind),
+    /// `)`
+    Close,
+    /// `|`
+    Alternation,
+    /// Repeats the token or group before it, `{min,max}`, with `possessive` like
+    /// `{min,max}+`. No `max` means no upper bound.
+    Repeat {
+        min: usize,
+        max: Option<usize>,
+        possessive: bool,
+    },
+    /// `\1`
+    Backreference(usize),
+    /// `\k<name>`
+    NamedBackreference(String),
+}
+
+/// What a [`Token::Open`] starts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GroupKind {
+    /// `(...)`, or `(?<name>...)` with a name.
+    Capture(Option<String>),
+    /// `(?:...)`
+    NonCapture,
+    /// `(?>...)`
+    Atomic,
+    /// `(?=...)`, or `(?!...)` if `negative`.
+    Lookahead { negative: bool },
+    /// `(?<=...)`, or `(?<!...)` if `negative`.
+    Lookbehind { negative: bool },
+}
+
+/// A member of a [`Token::Class`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClassItem {
+    Char(char),
+    /// Every character from the first to the second, both included, `a-z`.
+    Range(char, char),
+    /// `\d`
+    Digit,
+    /// `\D`
+    NotDigit,
+    /// `\w`
+    Word,
+    /// `\W`
+    NotWord,
+    /// `\s`
+    Whitespace,
+    /// `\S`
+    NotWhitespace,
+    /// A class registered with [`PatternBuilder::class`], or else a POSIX class like
+    /// `alpha`, `[:name:]`.
+    Named(String),
+}
+
+/// A group opened by a token that isn't closed yet.
+struct OpenGroup {
+    kind: GroupKind,
+    /// Index of the token that opened it.
+    position: usize,
+    /// The group's number if it captures.
+    index: usize,
+    /// The matchers before the group, on the level it is nested in.
+    outer: Vec<Matcher>,
+    /// The branches before those, if that level has alternation.
+    outer_branches: Vec<Vec<Matcher>>,
+}
+
+/// Parses `tokens` like [`PatternBuilder::parse`] parses text.
+pub(crate) fn parse(
+    builder: &PatternBuilder,
+    tokens: impl IntoIterator<Item = Token>,
+) -> Result<Ast> {
+    let mut groups = Vec::new();
+    let mut warnings = Vec::new();
+    let mut open: Vec<OpenGroup> = Vec::new();
+    let mut branches = Vec::new();
+    let mut matchers = Vec::new();
+    let mut size = 0;
+    let caseless = builder.flags.contains(Flags::CASE_INSENSITIVE);
+    let ascii = builder.case_fold == CaseFold::Ascii;
+    let multi_line = builder.flags.contains(Flags::MULTI_LINE);
+    for (position, token) in tokens.into_iter().enumerate() {
+        if !matches!(
+            token,
+            Token::Close | Token::Repeat { .. } | Token::Alternation
+        ) {
+            size += 1;
+            Input::check_limit(Limit::Size, builder.size_limit, size, position)?;
+        }
+        let character = |matcher: SingleCharacterMatcher| {
+            Matcher::character(matcher, builder.flags, builder.case_fold)
+        };
+        let matcher = match token {
+            Token::Literal(ch) => character(SingleCharacterMatcher::Literal(ch)),
+            Token::Any if builder.flags.contains(Flags::DOT_ALL) => {
+                character(SingleCharacterMatcher::Any)
+            }
+            Token::Any => character(SingleCharacterMatcher::AnyButNewline),
+            Token::Class { items, negated } => {
+                let limit = builder.class_size_limit;
+                Input::check_limit(Limit::ClassSize, limit, items.len(), position)?;
+                let options = items
+                    .into_iter()
+                    .map(|item| class_item(builder, item, position))
+                    .collect::<Result<_>>()?;
+                let class =
+                    SingleCharacterMatcher::new_bracket(options, negated, position, &mut warnings);
+                character(class)
+            }
+            Token::Start if multi_line => Matcher::StartOfLine,
+            Token::Start => Matcher::StartOfString,
+            Token::End if multi_line => Matcher::EndOfLine,
+            Token::End => Matcher::EndOfString,
+            Token::Alternation => {
+                branches.push(mem::take(&mut matchers));
+                continue;
+            }
+            Token::Backreference(index) => Matcher::Backreference {
+                index,
+                caseless,
+                ascii,
+            },
+            Token::NamedBackreference(name) => Matcher::Backreference {
+                index: Matcher::group_index(&groups, &name, position)?,
+                caseless,
+                ascii,
+            },
+            Token::Open(kind) => {
+                let limit = Some(builder.nest_limit);
+                Input::check_limit(Limit::Nesting, limit, open.len() + 1, position)?;
+                let index = match &kind {
+                    GroupKind::Capture(name) => {
+                        if let Some(name) = name {
+                            Matcher::check_group_name(name, &groups, position)?;
+                        }
+                        Matcher::open_group(&mut groups, name.clone(), position)
+                    }
+                    _ => 0,
+                };
+                open.push(OpenGroup {
+                    kind,
+                    position,
+                    index,
+                    outer: mem::take(&mut matchers),
+                    outer_branches: mem::take(&mut branches),
+                });
+                continue;
+            }
+            Token::Close => {
+                let group = open.pop().ok_or_else(|| {
+                    Error::syntax(
+                        "Unmatched `)`",
+                        position,
+                        "open the group with a `Token::Open` first",
+                    )
+                })?;
+                branches.push(mem::replace(&mut matchers, group.outer));
+                let inner = Matcher::sequence(mem::replace(&mut branches, group.outer_branches));
+                match group.kind {
+                    GroupKind::Capture(_) => {
+                        groups[group.index - 1].span.end = position + 1;
+                        Matcher::CaptureGroup(group.index, inner)
+                    }
+                    GroupKind::NonCapture => Matcher::Group(inner),
+                    GroupKind::Atomic => Matcher::Atomic(inner),
+                    GroupKind::Lookahead { negative } => Matcher::Lookahead { negative, inner },
+                    GroupKind::Lookbehind { negative } => {
+                        Matcher::new_lookbehind(negative, inner, group.position)?
+                    }
+                }
+            }
+            Token::Repeat {
+                min,
+                max,
+                possessive,
+            } => {
+                let repeated = matchers.pop();
+                let matcher = repeat(repeated, min, max, possessive, position, &mut warnings)?;
+                matchers.push(matcher);
+                continue;
+            }
+        };
+        matchers.push(matcher);
+    }
+
+    if let Some(group) = open.last() {
+        return Err(Error::syntax(
+            "Unterminated group",
+            group.position,
+            "close it with a `Token::Close`",
+        ));
+    }
+    branches.push(matchers);
+    Ok(Ast::new(Matcher::sequence(branches), groups, warnings))
+}
+
+fn class_item(
+    builder: &PatternBuilder,
+    item: ClassItem,
+    position: usize,
+) -> Result<SingleCharacterMatcher> {
+    let not = |class| SingleCharacterMatcher::NegativeGroup(vec![class]);
+    let matcher = match item {
+        ClassItem::Char(ch) => SingleCharacterMatcher::Literal(ch),
+        ClassItem::Range(low, high) if low <= high => SingleCharacterMatcher::Range(low, high),
+        ClassItem::Range(..) => {
+            return Err(Error::syntax(
+                "Range out of order",
+                position,
+                "write the smaller character first, e.g. `a-z`",
+            ))
+        }
+        ClassItem::Digit => SingleCharacterMatcher::Digit,
+        ClassItem::NotDigit => not(SingleCharacterMatcher::Digit),
+        ClassItem::Word => SingleCharacterMatcher::Alphanumeric,
+        ClassItem::NotWord => not(SingleCharacterMatcher::Alphanumeric),
+        ClassItem::Whitespace => SingleCharacterMatcher::Whitespace,
+        ClassItem::NotWhitespace => not(SingleCharacterMatcher::Whitespace),
+        ClassItem::Named(name) => {
+            SingleCharacterMatcher::new_bracket_class(builder, &name, position)?
+        }
+    };
+    Ok(match builder.flags.contains(Flags::UNICODE) {
+        true => matcher.unicode(),
+        false => matcher,
+    })
+}
+
+/// Wraps the matcher before a [`Token::Repeat`] at `position` in a repetition,
+/// if it can be repeated.
+fn repeat(
+    repeated: Option<Matcher>,
+    min: usize,
+    max: Option<usize>,
+    possessive: bool,
+    position: usize,
+    warnings: &mut Vec<Warning>,
+) -> Result<Matcher> {
+    if max.is_some_and(|max| min > max) {
+        return Err(Error::syntax(
+            "Repetition bounds out of order",
+            position,
+            "give a `max` of at least `min`",
+        ));
+    }
+    let what = match repeated {
+        Some(
+            matcher @ (Matcher::SingleCharacter(_)
+            | Matcher::Grapheme(_)
+            | Matcher::Folded { .. }
+            | Matcher::Group(_)
+            | Matcher::CaptureGroup(..)
+            | Matcher::Atomic(_)),
+        ) => {
+            let bounds = ((min > 0).then_some(min), max);
+            return Ok(Matcher::new_repeat(
+                matcher, bounds, possessive, position, warnings,
+            ));
+        }
+        Some(
+            Matcher::StartOfString
+            | Matcher::EndOfString
+            | Matcher::StartOfLine
+            | Matcher::EndOfLine
+            | Matcher::StartOfText
+            | Matcher::EndOfText
+            | Matcher::EndOfTextOrNewline,
+        ) => "an anchor",
+        Some(Matcher::Backreference { .. }) => "a backreference",
+        Some(Matcher::Lookahead { .. }) => "a lookahead",
+        Some(Matcher::Lookbehind { .. }) => "a lookbehind",
+        Some(Matcher::Repeat { .. } | Matcher::Alternation(_)) | None => {
+            return Err(Error::syntax(
+                "Nothing to repeat",
+                position,
+                "put the `Token::Repeat` right after what it repeats",
+            ))
+        }
+    };
+    Err(Error::syntax(
+        format!("Repeating {what} is not supported"),
+        position,
+        "repeat it by hand",
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ClassItem, GroupKind, Token};
+    use crate::{Error, Flags, Pattern, PatternBuilder, WarningKind};
+
+    #[test]
+    fn build_from_tokens() {
+        let tokens = [
+            Token::Start,
+            Token::Open(GroupKind::Capture(Some(String::from("word")))),
+            Token::Class {
+                items: vec![ClassItem::Range('a', 'z'), ClassItem::Char('.')],
+                negated: false,
+            },
+            Token::Repeat {
+                min: 1,
+                max: None,
+                possessive: false,
+            },
+            Token::Close,
+            Token::Literal('+'),
+            Token::NamedBackreference(String::from("word")),
+            Token::Open(GroupKind::Lookahead { negative: true }),
+            Token::Any,
+            Token::Close,
+        ];
+        let pattern = Pattern::from_tokens(tokens).expect("Tokens are correct");
+        let text = Pattern::new(r"^(?<word>[a-z.]+)\+\k<word>(?!.)").expect("Pattern is correct");
+        for haystack in ["ab+ab", "a.+a.", "ab+abc", "ab+a", "a+b"] {
+            assert_eq!(pattern.find(haystack), text.find(haystack), "{haystack}");
+        }
+        assert_eq!(pattern.groups()[0].span(), 1..5);
+
+        let tokens = [
+            Token::Literal('a'),
+            Token::Alternation,
+            Token::Open(GroupKind::NonCapture),
+            Token::Literal('b'),
+            Token::Alternation,
+            Token::Close,
+            Token::Literal('c'),
+        ];
+        let pattern = Pattern::from_tokens(tokens).expect("Tokens are correct");
+        let text = Pattern::new("a|(?:b|)c").expect("Pattern is correct");
+        for haystack in ["a", "bc", "c", "b"] {
+            assert_eq!(pattern.find(haystack), text.find(haystack), "{haystack}");
+        }
+    }
+
+    #[test]
+    fn token_errors() {
+        let error = |tokens: &[Token]| match Pattern::from_tokens(tokens.to_vec()) {
+            Err(Error::Syntax {
+                message, position, ..
+            }) => (message, position),
+            other => panic!("Expected a syntax error for {tokens:?}, got {other:?}"),
+        };
+        let plus = Token::Repeat {
+            min: 1,
+            max: None,
+            possessive: false,
+        };
+        let capture = Token::Open(GroupKind::Capture(None));
+
+        assert_eq!(error(std::slice::from_ref(&plus)).0, "Nothing to repeat");
+        let lookahead = Token::Open(GroupKind::Lookahead { negative: false });
+        assert_eq!(
+            error(&[
+                Token::Any,
+                lookahead,
+                Token::Any,
+                Token::Close,
+                plus.clone()
+            ]),
+            (String::from("Repeating a lookahead is not supported"), 4)
+        );
+        let repeated = [Token::Any, capture.clone(), Token::Any, Token::Close, plus];
+        assert!(Pattern::from_tokens(repeated).is_ok_and(|p| p.test("abc")));
+        assert_eq!(error(&[Token::Any, capture]).1, 1);
+        assert_eq!(error(&[Token::Close]).0, "Unmatched `)`");
+        assert_eq!(
+            error(&[
+                Token::Open(GroupKind::Lookbehind { negative: false }),
+                Token::Any,
+                Token::Repeat {
+                    min: 0,
+                    max: Some(1),
+                    possessive: false
+                },
+                Token::Close
+            ])
+            .0,
+            "Lookbehind must have a fixed width"
+        );
+        assert!(matches!(
+            PatternBuilder::new()
+                .size_limit(2)
+                .build_tokens([Token::Any, Token::Any, Token::Any]),
+            Err(Error::LimitExceeded { position: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn tokens_with_flags() {
+        let pattern = PatternBuilder::new()
+            .flags(Flags::CASE_INSENSITIVE)
+            .build_tokens([
+                Token::Literal('a'),
+                Token::Class {
+                    items: vec![ClassItem::Range('x', 'z')],
+                    negated: false,
+                },
+            ])
+            .expect("Tokens are correct");
+        assert!(pattern.test("AY"));
+
+        let pattern = PatternBuilder::new()
+            .unicode(true)
+            .build_tokens([Token::Class {
+                items: vec![ClassItem::NotDigit],
+                negated: false,
+            }])
+            .expect("Tokens are correct");
+        assert!(!pattern.test("٣"));
+
+        let pattern = PatternBuilder::new()
+            .graphemes(true)
+            .build_tokens([Token::Start, Token::Any, Token::End])
+            .expect("Tokens are correct");
+        assert!(pattern.test("e\u{301}"));
+    }
+
+    #[test]
+    fn token_warnings() {
+        let pattern = Pattern::from_tokens([
+            Token::Any,
+            Token::Class {
+                items: vec![ClassItem::Word, ClassItem::NotWord],
+                negated: true,
+            },
+        ])
+        .expect("Tokens are correct");
+        assert_eq!(pattern.warnings()[0].kind(), WarningKind::EmptyClass);
+        assert_eq!(pattern.warnings()[0].position(), 1);
+    }
+}
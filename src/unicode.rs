@@ -0,0 +1,235 @@
+//! Unicode properties for `\p{...}`, general categories and scripts, see
+//! [`Property`], normalization, see [`nfc`], grapheme clusters, see
+//! [`grapheme_end`], and full case folding, see [`case_fold`]. Characters are looked
+//! up in the tables of [`tables`], generated from the Unicode Character Database.
+
+mod folding;
+mod normalization;
+mod segmentation;
+mod tables;
+
+pub(crate) use folding::{case_fold, folding_to, has_expansion};
+pub use normalization::nfc;
+pub(crate) use segmentation::grapheme_end;
+
+use tables::{GENERAL_CATEGORIES, SCRIPTS, SCRIPT_NAMES, UNKNOWN_SCRIPT};
+use GeneralCategory::*;
+
+/// Looks `code` up in a table of sorted, disjoint ranges of code points.
+fn lookup<T: Copy>(table: &[(u32, u32, T)], code: u32) -> Option<T> {
+    let at = table.partition_point(|(_, end, _)| *end < code);
+    match table.get(at) {
+        Some((start, _, value)) if *start <= code => Some(*value),
+        _ => None,
+    }
+}
+
+/// The general category of a character, like `Lu` for uppercase letters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum GeneralCategory {
+    Lu,
+    Ll,
+    Lt,
+    Lm,
+    Lo,
+    Mn,
+    Mc,
+    Me,
+    Nd,
+    Nl,
+    No,
+    Pc,
+    Pd,
+    Ps,
+    Pe,
+    Pi,
+    Pf,
+    Po,
+    Sm,
+    Sc,
+    Sk,
+    So,
+    Zs,
+    Zl,
+    Zp,
+    Cc,
+    Cf,
+    Cs,
+    Co,
+    Cn,
+}
+
+impl GeneralCategory {
+    pub(crate) fn of(ch: char) -> Self {
+        lookup(GENERAL_CATEGORIES, u32::from(ch)).unwrap_or(Self::Cn)
+    }
+
+    fn bit(self) -> u32 {
+        1 << self as u32
+    }
+}
+
+/// Whether `ch` is a decimal digit of any script, of category `Nd`.
+pub(crate) fn is_digit(ch: char) -> bool {
+    GeneralCategory::of(ch) == Nd
+}
+
+/// Whether `ch` is a word character of any script: a letter, a mark, a decimal digit
+/// or connector punctuation like `_`.
+pub(crate) fn is_word(ch: char) -> bool {
+    ch.is_alphabetic() || matches!(GeneralCategory::of(ch), Mn | Mc | Me | Nd | Pc)
+}
+
+/// The properties `\p{...}` knows: their names, abbreviation first, and the
+/// categories they are made of.
+const PROPERTIES: &[(&[&str], &[GeneralCategory])] = &[
+    (&["L", "Letter"], &[Lu, Ll, Lt, Lm, Lo]),
+    (&["LC", "Cased_Letter"], &[Lu, Ll, Lt]),
+    (&["Lu", "Uppercase_Letter"], &[Lu]),
+    (&["Ll", "Lowercase_Letter"], &[Ll]),
+    (&["Lt", "Titlecase_Letter"], &[Lt]),
+    (&["Lm", "Modifier_Letter"], &[Lm]),
+    (&["Lo", "Other_Letter"], &[Lo]),
+    (&["M", "Mark", "Combining_Mark"], &[Mn, Mc, Me]),
+    (&["Mn", "Nonspacing_Mark"], &[Mn]),
+    (&["Mc", "Spacing_Mark"], &[Mc]),
+    (&["Me", "Enclosing_Mark"], &[Me]),
+    (&["N", "Number"], &[Nd, Nl, No]),
+    (&["Nd", "Decimal_Number", "digit"], &[Nd]),
+    (&["Nl", "Letter_Number"], &[Nl]),
+    (&["No", "Other_Number"], &[No]),
+    (
+        &["P", "Punctuation", "punct"],
+        &[Pc, Pd, Ps, Pe, Pi, Pf, Po],
+    ),
+    (&["Pc", "Connector_Punctuation"], &[Pc]),
+    (&["Pd", "Dash_Punctuation"], &[Pd]),
+    (&["Ps", "Open_Punctuation"], &[Ps]),
+    (&["Pe", "Close_Punctuation"], &[Pe]),
+    (&["Pi", "Initial_Punctuation"], &[Pi]),
+    (&["Pf", "Final_Punctuation"], &[Pf]),
+    (&["Po", "Other_Punctuation"], &[Po]),
+    (&["S", "Symbol"], &[Sm, Sc, Sk, So]),
+    (&["Sm", "Math_Symbol"], &[Sm]),
+    (&["Sc", "Currency_Symbol"], &[Sc]),
+    (&["Sk", "Modifier_Symbol"], &[Sk]),
+    (&["So", "Other_Symbol"], &[So]),
+    (&["Z", "Separator"], &[Zs, Zl, Zp]),
+    (&["Zs", "Space_Separator"], &[Zs]),
+    (&["Zl", "Line_Separator"], &[Zl]),
+    (&["Zp", "Paragraph_Separator"], &[Zp]),
+    (&["C", "Other"], &[Cc, Cf, Cs, Co, Cn]),
+    (&["Cc", "Control", "cntrl"], &[Cc]),
+    (&["Cf", "Format"], &[Cf]),
+    (&["Cs", "Surrogate"], &[Cs]),
+    (&["Co", "Private_Use"], &[Co]),
+    (&["Cn", "Unassigned"], &[Cn]),
+];
+
+/// The script of a character, as an index into [`SCRIPT_NAMES`].
+fn script_of(ch: char) -> u8 {
+    lookup(SCRIPTS, u32::from(ch)).unwrap_or(UNKNOWN_SCRIPT)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Characters {
+    /// One bit for each [`GeneralCategory`] in the property.
+    Categories(u32),
+    /// The index of a script in [`SCRIPT_NAMES`].
+    Script(u8),
+}
+
+/// A Unicode property: the characters of one or more general categories, or of a
+/// script.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Property {
+    /// The name of the property, like `Lu` or `Greek`.
+    name: &'static str,
+    characters: Characters,
+}
+
+impl Property {
+    /// Looks up a property by any of its names, general categories first. Like in
+    /// the Unicode Character Database, case and `_` are ignored, so
+    /// `uppercase_letter` is `Lu` and `old_italic` is `Old_Italic`.
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        let loose = |name: &str| -> String {
+            name.chars()
+                .filter(|ch| *ch != '_')
+                .map(|ch| ch.to_ascii_lowercase())
+                .collect()
+        };
+        let wanted = loose(name);
+        let category = PROPERTIES
+            .iter()
+            .find(|(names, _)| names.iter().any(|name| loose(name) == wanted));
+        if let Some((names, categories)) = category {
+            return Some(Self {
+                name: names[0],
+                characters: Characters::Categories(
+                    categories.iter().fold(0, |bits, c| bits | c.bit()),
+                ),
+            });
+        }
+        let script = SCRIPT_NAMES
+            .iter()
+            .position(|(short, long)| loose(short) == wanted || loose(long) == wanted)?;
+        Some(Self {
+            name: SCRIPT_NAMES[script].1,
+            characters: Characters::Script(script as u8),
+        })
+    }
+
+    pub(crate) fn name(self) -> &'static str {
+        self.name
+    }
+
+    /// Whether this is a script, rather than general categories.
+    pub(crate) fn is_script(self) -> bool {
+        matches!(self.characters, Characters::Script(_))
+    }
+
+    pub(crate) fn test(self, ch: char) -> bool {
+        match self.characters {
+            Characters::Categories(bits) => bits & GeneralCategory::of(ch).bit() != 0,
+            Characters::Script(script) => script_of(ch) == script,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{GeneralCategory, Property};
+
+    #[test]
+    fn general_categories() {
+        assert_eq!(GeneralCategory::of('a'), GeneralCategory::Ll);
+        assert_eq!(GeneralCategory::of('Ж'), GeneralCategory::Lu);
+        assert_eq!(GeneralCategory::of('٣'), GeneralCategory::Nd);
+        assert_eq!(GeneralCategory::of('¿'), GeneralCategory::Po);
+        assert_eq!(GeneralCategory::of('\u{0378}'), GeneralCategory::Cn);
+        assert_eq!(GeneralCategory::of('\u{10FFFF}'), GeneralCategory::Cn);
+
+        let letter = Property::from_name("letter").expect("L is a property");
+        assert_eq!(letter.name(), "L");
+        assert!(letter.test('é') && letter.test('中') && !letter.test('1'));
+        assert_eq!(Property::from_name("Punct"), Property::from_name("P"));
+        assert_eq!(
+            Property::from_name("decimal_number"),
+            Property::from_name("Nd")
+        );
+        assert_eq!(Property::from_name("Klingon"), None);
+    }
+
+    #[test]
+    fn scripts() {
+        let greek = Property::from_name("greek").expect("Greek is a script");
+        assert_eq!((greek.name(), greek.is_script()), ("Greek", true));
+        assert!(greek.test('λ') && greek.test('Ω') && !greek.test('a'));
+        assert_eq!(Property::from_name("Cyrl"), Property::from_name("Cyrillic"));
+        assert!(Property::from_name("Cyrillic").is_some_and(|p| p.test('ж')));
+        assert!(Property::from_name("Common").is_some_and(|p| p.test('1')));
+        let unknown = Property::from_name("Unknown").expect("Unknown is a script");
+        assert!(unknown.test('\u{0378}') && !unknown.test('a'));
+    }
+}
@@ -0,0 +1,66 @@
+//! Full case folding, see [`case_fold`].
+
+use super::tables::CASE_FOLDS;
+
+/// The full case folding of `ch`, or `None` if it folds to itself. Some characters
+/// fold to several, like `ß` to `ss`. With `turkic`, `I` folds to the dotless `ı`
+/// and `İ` to `i`, as in Turkish and Azerbaijani.
+pub(crate) fn case_fold(ch: char, turkic: bool) -> Option<&'static str> {
+    match ch {
+        'I' if turkic => Some("ı"),
+        'İ' if turkic => Some("i"),
+        _ => {
+            let at = CASE_FOLDS
+                .binary_search_by_key(&u32::from(ch), |(code, _)| *code)
+                .ok()?;
+            Some(CASE_FOLDS[at].1)
+        }
+    }
+}
+
+/// The characters whose full case folding starts with `first`, with or without the
+/// Turkic foldings.
+pub(crate) fn folding_to(first: char) -> impl Iterator<Item = char> {
+    let turkic = [('I', 'ı'), ('İ', 'i')]
+        .into_iter()
+        .filter(move |(_, folded)| *folded == first)
+        .map(|(ch, _)| ch);
+    CASE_FOLDS
+        .iter()
+        .filter(move |(_, folded)| folded.starts_with(first))
+        .filter_map(|(code, _)| char::from_u32(*code))
+        .chain(turkic)
+}
+
+/// Whether folded `text` contains the case folding of a character that folds to
+/// several, so that text folding to it may have fewer characters.
+pub(crate) fn has_expansion(text: &str) -> bool {
+    CASE_FOLDS
+        .iter()
+        .any(|(_, folded)| folded.chars().nth(1).is_some() && text.contains(folded))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{case_fold, folding_to, has_expansion};
+
+    #[test]
+    fn full_case_folding() {
+        assert_eq!(case_fold('A', false), Some("a"));
+        assert_eq!(case_fold('a', false), None);
+        assert_eq!(case_fold('ß', false), Some("ss"));
+        assert_eq!(case_fold('ﬁ', false), Some("fi"));
+        assert_eq!(case_fold('İ', false), Some("i\u{307}"));
+        assert_eq!(case_fold('I', false), Some("i"));
+        assert_eq!(case_fold('I', true), Some("ı"));
+        assert_eq!(case_fold('İ', true), Some("i"));
+        assert_eq!(case_fold('ı', true), None);
+
+        let to_s: Vec<char> = folding_to('s').collect();
+        assert!(['S', 'ß', 'ſ', 'ẞ'].iter().all(|ch| to_s.contains(ch)));
+        assert!(folding_to('i').any(|ch| ch == 'İ'));
+        assert!(folding_to('ı').any(|ch| ch == 'I'));
+        assert!(has_expansion("strasse") && has_expansion("xfiy"));
+        assert!(!has_expansion("abc"));
+    }
+}
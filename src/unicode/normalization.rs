@@ -0,0 +1,153 @@
+//! Canonical normalization, see [`nfc`].
+
+use std::borrow::Cow;
+
+use super::lookup;
+use super::tables::{COMBINING_CLASSES, COMPOSITIONS, DECOMPOSITIONS};
+
+/// The first Hangul syllable, the syllables are all combinations of a leading
+/// consonant, a vowel and an optional trailing consonant in that order.
+const SYLLABLE_BASE: u32 = 0xAC00;
+const LEADING_BASE: u32 = 0x1100;
+const VOWEL_BASE: u32 = 0x1161;
+/// One before the first trailing consonant, for syllables without one.
+const TRAILING_BASE: u32 = 0x11A7;
+const LEADING_COUNT: u32 = 19;
+const VOWEL_COUNT: u32 = 21;
+const TRAILING_COUNT: u32 = 28;
+const SYLLABLE_COUNT: u32 = LEADING_COUNT * VOWEL_COUNT * TRAILING_COUNT;
+
+fn combining_class(ch: char) -> u8 {
+    lookup(COMBINING_CLASSES, u32::from(ch)).unwrap_or(0)
+}
+
+/// Pushes the full canonical decomposition of `ch` to `chars`.
+fn decompose(ch: char, chars: &mut Vec<char>) {
+    let code = u32::from(ch);
+    if let Some(index) = code.checked_sub(SYLLABLE_BASE) {
+        if index < SYLLABLE_COUNT {
+            let leading = LEADING_BASE + index / (VOWEL_COUNT * TRAILING_COUNT);
+            let vowel = VOWEL_BASE + index % (VOWEL_COUNT * TRAILING_COUNT) / TRAILING_COUNT;
+            let trailing = TRAILING_BASE + index % TRAILING_COUNT;
+            let jamo = [leading, vowel]
+                .into_iter()
+                .chain((trailing != TRAILING_BASE).then_some(trailing));
+            chars.extend(jamo.filter_map(char::from_u32));
+            return;
+        }
+    }
+    match DECOMPOSITIONS.binary_search_by_key(&code, |(code, _)| *code) {
+        Ok(at) => {
+            for part in DECOMPOSITIONS[at].1 {
+                decompose(*part, chars);
+            }
+        }
+        Err(_) => chars.push(ch),
+    }
+}
+
+/// The character `first` and `second` compose into, if any.
+fn compose(first: char, second: char) -> Option<char> {
+    let (first, second) = (u32::from(first), u32::from(second));
+    let leading = first.wrapping_sub(LEADING_BASE);
+    let vowel = second.wrapping_sub(VOWEL_BASE);
+    if leading < LEADING_COUNT && vowel < VOWEL_COUNT {
+        let syllable = (leading * VOWEL_COUNT + vowel) * TRAILING_COUNT;
+        return char::from_u32(SYLLABLE_BASE + syllable);
+    }
+    let syllable = first.wrapping_sub(SYLLABLE_BASE);
+    let trailing = second.wrapping_sub(TRAILING_BASE);
+    if syllable < SYLLABLE_COUNT
+        && syllable % TRAILING_COUNT == 0
+        && (1..TRAILING_COUNT).contains(&trailing)
+    {
+        return char::from_u32(first + trailing);
+    }
+    let at = COMPOSITIONS
+        .binary_search_by_key(&(first, second), |(first, second, _)| (*first, *second))
+        .ok()?;
+    Some(COMPOSITIONS[at].2)
+}
+
+/// Normalizes `text` to NFC, Normalization Form C: characters are decomposed, their
+/// combining marks put in canonical order, and then composed again wherever
+/// possible. Text that looks the same then is the same, whether it was typed with
+/// precomposed characters like `é` or with combining marks like `e\u{301}`.
+///
+/// Text that is already normalized, like all ASCII text, is returned as is.
+pub fn nfc(text: &str) -> Cow<'_, str> {
+    // No character before the combining marks changes, or combines with another
+    if text.chars().all(|ch| ch < '\u{300}') {
+        return Cow::Borrowed(text);
+    }
+
+    let mut chars = Vec::with_capacity(text.len());
+    for ch in text.chars() {
+        decompose(ch, &mut chars);
+    }
+    let mut start = 0;
+    while start < chars.len() {
+        let run = chars[start..]
+            .iter()
+            .take_while(|ch| combining_class(**ch) != 0)
+            .count();
+        // Stable, so marks of the same class keep their order
+        chars[start..start + run].sort_by_key(|ch| combining_class(*ch));
+        start += run.max(1);
+    }
+
+    let mut composed: Vec<char> = Vec::with_capacity(chars.len());
+    // The last starter, the character marks after it can compose with
+    let mut starter = None;
+    // The class of the last character kept after the starter, 0 if there is none
+    let mut last_class = 0;
+    for ch in chars {
+        let class = combining_class(ch);
+        if let Some(at) = starter {
+            // A mark of the same or a higher class in between blocks the composition
+            let blocked = last_class != 0 && last_class >= class;
+            if let Some(composite) = compose(composed[at], ch).filter(|_| !blocked) {
+                composed[at] = composite;
+                continue;
+            }
+        }
+        if class == 0 {
+            starter = Some(composed.len());
+            last_class = 0;
+        } else {
+            last_class = class;
+        }
+        composed.push(ch);
+    }
+
+    let composed: String = composed.into_iter().collect();
+    if composed == text {
+        Cow::Borrowed(text)
+    } else {
+        Cow::Owned(composed)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::nfc;
+
+    #[test]
+    fn normalize() {
+        assert_eq!(nfc("abc"), "abc");
+        assert_eq!(nfc("cafe\u{301}"), "café");
+        assert_eq!(nfc("café"), "café");
+        // Marks are reordered by class before composing
+        assert_eq!(nfc("a\u{323}\u{302}"), "\u{1EAD}");
+        assert_eq!(nfc("a\u{302}\u{323}"), "\u{1EAD}");
+        // A mark of the same class blocks the second one
+        assert_eq!(nfc("a\u{308}\u{301}"), "\u{E4}\u{301}");
+        // Singletons and excluded characters stay decomposed
+        assert_eq!(nfc("\u{212B}"), "\u{C5}");
+        assert_eq!(nfc("\u{958}"), "\u{915}\u{93C}");
+        // Hangul syllables compose from their letters
+        assert_eq!(nfc("\u{1100}\u{1161}\u{11A8}"), "\u{AC01}");
+        assert_eq!(nfc("\u{AC00}\u{11A8}"), "\u{AC01}");
+        assert_eq!(nfc("\u{AC01}"), "\u{AC01}");
+    }
+}
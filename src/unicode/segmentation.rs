@@ -0,0 +1,101 @@
+//! Grapheme clusters, what users see as a single character, see [`grapheme_end`].
+
+use super::lookup;
+use super::tables::GRAPHEME_BREAKS;
+use GraphemeBreak::*;
+
+/// The grapheme cluster break property of a character, which decides whether a
+/// cluster can continue with it, with the extended pictographic characters apart.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum GraphemeBreak {
+    Cr,
+    Lf,
+    Control,
+    Extend,
+    Zwj,
+    RegionalIndicator,
+    Prepend,
+    SpacingMark,
+    /// Hangul leading consonants, vowels, trailing consonants, and syllables without
+    /// and with a trailing consonant.
+    L,
+    V,
+    T,
+    Lv,
+    Lvt,
+    Pictographic,
+    Other,
+}
+
+impl GraphemeBreak {
+    fn of(ch: char) -> Self {
+        lookup(GRAPHEME_BREAKS, u32::from(ch)).unwrap_or(Other)
+    }
+}
+
+/// The end of the extended grapheme cluster starting at byte offset `start` of
+/// `text`, following the rules of Unicode Standard Annex #29. `start` is taken to
+/// be the start of a cluster, so only what comes after it is looked at.
+pub(crate) fn grapheme_end(text: &str, start: usize) -> usize {
+    let mut chars = text[start..].char_indices();
+    let Some((_, first)) = chars.next() else {
+        return start;
+    };
+    let mut previous = GraphemeBreak::of(first);
+    // Whether the cluster ends in a pictographic character and extending characters,
+    // or in those and a zero-width joiner, which joins another pictographic character
+    let mut emoji = previous == Pictographic;
+    let mut emoji_joiner = false;
+    // Regional indicators, making up flags, pair up from the start of the cluster
+    let mut regional = usize::from(previous == RegionalIndicator);
+    for (offset, ch) in chars {
+        let next = GraphemeBreak::of(ch);
+        let joined = match (previous, next) {
+            (Cr, Lf) => true,
+            (Cr | Lf | Control, _) | (_, Cr | Lf | Control) => false,
+            (L, L | V | Lv | Lvt) | (Lv | V, V | T) | (Lvt | T, T) => true,
+            (_, Extend | Zwj | SpacingMark) | (Prepend, _) => true,
+            (Zwj, Pictographic) => emoji_joiner,
+            (RegionalIndicator, RegionalIndicator) => regional % 2 == 1,
+            _ => false,
+        };
+        if !joined {
+            return start + offset;
+        }
+        emoji_joiner = emoji && next == Zwj;
+        emoji = next == Pictographic || (emoji && next == Extend);
+        regional = match next {
+            RegionalIndicator => regional + 1,
+            _ => 0,
+        };
+        previous = next;
+    }
+    text.len()
+}
+
+#[cfg(test)]
+mod test {
+    use super::grapheme_end;
+
+    #[test]
+    fn grapheme_clusters() {
+        let ends = |text: &str| {
+            let mut ends = Vec::new();
+            let mut at = 0;
+            while at < text.len() {
+                at = grapheme_end(text, at);
+                ends.push(at);
+            }
+            ends
+        };
+        assert_eq!(ends("ab"), [1, 2]);
+        assert_eq!(ends("e\u{301}x"), [3, 4]);
+        assert_eq!(ends("\r\n\n"), [2, 3]);
+        // Hangul letters, a flag and a half, and a family joined by ZWJ
+        assert_eq!(ends("\u{1100}\u{1161}\u{11A8}"), [9]);
+        assert_eq!(ends("🇫🇷🇩"), [8, 12]);
+        assert_eq!(ends("👨\u{200D}👩\u{200D}👧!"), [18, 19]);
+        assert_eq!(ends("👍🏽"), [8]);
+        assert_eq!(ends("a\u{200D}👍"), [4, 8]);
+    }
+}
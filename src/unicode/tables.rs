@@ -0,0 +1,10772 @@
+//! Tables generated from the Unicode Character Database, version 14.0.0. Don't edit
+//! them by hand.
+
+use super::segmentation::GraphemeBreak::{self, *};
+use super::GeneralCategory::{self, *};
+
+/// The general category of every assigned character but the surrogates, as
+/// sorted, disjoint ranges of code points. Characters not in any range are `Cn`.
+pub(super) const GENERAL_CATEGORIES: &[(u32, u32, GeneralCategory)] = &[
+    (0x0, 0x1F, Cc),
+    (0x20, 0x20, Zs),
+    (0x21, 0x23, Po),
+    (0x24, 0x24, Sc),
+    (0x25, 0x27, Po),
+    (0x28, 0x28, Ps),
+    (0x29, 0x29, Pe),
+    (0x2A, 0x2A, Po),
+    (0x2B, 0x2B, Sm),
+    (0x2C, 0x2C, Po),
+    (0x2D, 0x2D, Pd),
+    (0x2E, 0x2F, Po),
+    (0x30, 0x39, Nd),
+    (0x3A, 0x3B, Po),
+    (0x3C, 0x3E, Sm),
+    (0x3F, 0x40, Po),
+    (0x41, 0x5A, Lu),
+    (0x5B, 0x5B, Ps),
+    (0x5C, 0x5C, Po),
+    (0x5D, 0x5D, Pe),
+    (0x5E, 0x5E, Sk),
+    (0x5F, 0x5F, Pc),
+    (0x60, 0x60, Sk),
+    (0x61, 0x7A, Ll),
+    (0x7B, 0x7B, Ps),
+    (0x7C, 0x7C, Sm),
+    (0x7D, 0x7D, Pe),
+    (0x7E, 0x7E, Sm),
+    (0x7F, 0x9F, Cc),
+    (0xA0, 0xA0, Zs),
+    (0xA1, 0xA1, Po),
+    (0xA2, 0xA5, Sc),
+    (0xA6, 0xA6, So),
+    (0xA7, 0xA7, Po),
+    (0xA8, 0xA8, Sk),
+    (0xA9, 0xA9, So),
+    (0xAA, 0xAA, Lo),
+    (0xAB, 0xAB, Pi),
+    (0xAC, 0xAC, Sm),
+    (0xAD, 0xAD, Cf),
+    (0xAE, 0xAE, So),
+    (0xAF, 0xAF, Sk),
+    (0xB0, 0xB0, So),
+    (0xB1, 0xB1, Sm),
+    (0xB2, 0xB3, No),
+    (0xB4, 0xB4, Sk),
+    (0xB5, 0xB5, Ll),
+    (0xB6, 0xB7, Po),
+    (0xB8, 0xB8, Sk),
+    (0xB9, 0xB9, No),
+    (0xBA, 0xBA, Lo),
+    (0xBB, 0xBB, Pf),
+    (0xBC, 0xBE, No),
+    (0xBF, 0xBF, Po),
+    (0xC0, 0xD6, Lu),
+    (0xD7, 0xD7, Sm),
+    (0xD8, 0xDE, Lu),
+    (0xDF, 0xF6, Ll),
+    (0xF7, 0xF7, Sm),
+    (0xF8, 0xFF, Ll),
+    (0x100, 0x100, Lu),
+    (0x101, 0x101, Ll),
+    (0x102, 0x102, Lu),
+    (0x103, 0x103, Ll),
+    (0x104, 0x104, Lu),
+    (0x105, 0x105, Ll),
+    (0x106, 0x106, Lu),
+    (0x107, 0x107, Ll),
+    (0x108, 0x108, Lu),
+    (0x109, 0x109, Ll),
+    (0x10A, 0x10A, Lu),
+    (0x10B, 0x10B, Ll),
+    (0x10C, 0x10C, Lu),
+    (0x10D, 0x10D, Ll),
+    (0x10E, 0x10E, Lu),
+    (0x10F, 0x10F, Ll),
+    (0x110, 0x110, Lu),
+    (0x111, 0x111, Ll),
+    (0x112, 0x112, Lu),
+    (0x113, 0x113, Ll),
+    (0x114, 0x114, Lu),
+    (0x115, 0x115, Ll),
+    (0x116, 0x116, Lu),
+    (0x117, 0x117, Ll),
+    (0x118, 0x118, Lu),
+    (0x119, 0x119, Ll),
+    (0x11A, 0x11A, Lu),
+    (0x11B, 0x11B, Ll),
+    (0x11C, 0x11C, Lu),
+    (0x11D, 0x11D, Ll),
+    (0x11E, 0x11E, Lu),
+    (0x11F, 0x11F, Ll),
+    (0x120, 0x120, Lu),
+    (0x121, 0x121, Ll),
+    (0x122, 0x122, Lu),
+    (0x123, 0x123, Ll),
+    (0x124, 0x124, Lu),
+    (0x125, 0x125, Ll),
+    (0x126, 0x126, Lu),
+    (0x127, 0x127, Ll),
+    (0x128, 0x128, Lu),
+    (0x129, 0x129, Ll),
+    (0x12A, 0x12A, Lu),
+    (0x12B, 0x12B, Ll),
+    (0x12C, 0x12C, Lu),
+    (0x12D, 0x12D, Ll),
+    (0x12E, 0x12E, Lu),
+    (0x12F, 0x12F, Ll),
+    (0x130, 0x130, Lu),
+    (0x131, 0x131, Ll),
+    (0x132, 0x132, Lu),
+    (0x133, 0x133, Ll),
+    (0x134, 0x134, Lu),
+    (0x135, 0x135, Ll),
+    (0x136, 0x136, Lu),
+    (0x137, 0x138, Ll),
+    (0x139, 0x139, Lu),
+    (0x13A, 0x13A, Ll),
+    (0x13B, 0x13B, Lu),
+    (0x13C, 0x13C, Ll),
+    (0x13D, 0x13D, Lu),
+    (0x13E, 0x13E, Ll),
+    (0x13F, 0x13F, Lu),
+    (0x140, 0x140, Ll),
+    (0x141, 0x141, Lu),
+    (0x142, 0x142, Ll),
+    (0x143, 0x143, Lu),
+    (0x144, 0x144, Ll),
+    (0x145, 0x145, Lu),
+    (0x146, 0x146, Ll),
+    (0x147, 0x147, Lu),
+    (0x148, 0x149, Ll),
+    (0x14A, 0x14A, Lu),
+    (0x14B, 0x14B, Ll),
+    (0x14C, 0x14C, Lu),
+    (0x14D, 0x14D, Ll),
+    (0x14E, 0x14E, Lu),
+    (0x14F, 0x14F, Ll),
+    (0x150, 0x150, Lu),
+    (0x151, 0x151, Ll),
+    (0x152, 0x152, Lu),
+    (0x153, 0x153, Ll),
+    (0x154, 0x154, Lu),
+    (0x155, 0x155, Ll),
+    (0x156, 0x156, Lu),
+    (0x157, 0x157, Ll),
+    (0x158, 0x158, Lu),
+    (0x159, 0x159, Ll),
+    (0x15A, 0x15A, Lu),
+    (0x15B, 0x15B, Ll),
+    (0x15C, 0x15C, Lu),
+    (0x15D, 0x15D, Ll),
+    (0x15E, 0x15E, Lu),
+    (0x15F, 0x15F, Ll),
+    (0x160, 0x160, Lu),
+    (0x161, 0x161, Ll),
+    (0x162, 0x162, Lu),
+    (0x163, 0x163, Ll),
+    (0x164, 0x164, Lu),
+    (0x165, 0x165, Ll),
+    (0x166, 0x166, Lu),
+    (0x167, 0x167, Ll),
+    (0x168, 0x168, Lu),
+    (0x169, 0x169, Ll),
+    (0x16A, 0x16A, Lu),
+    (0x16B, 0x16B, Ll),
+    (0x16C, 0x16C, Lu),
+    (0x16D, 0x16D, Ll),
+    (0x16E, 0x16E, Lu),
+    (0x16F, 0x16F, Ll),
+    (0x170, 0x170, Lu),
+    (0x171, 0x171, Ll),
+    (0x172, 0x172, Lu),
+    (0x173, 0x173, Ll),
+    (0x174, 0x174, Lu),
+    (0x175, 0x175, Ll),
+    (0x176, 0x176, Lu),
+    (0x177, 0x177, Ll),
+    (0x178, 0x179, Lu),
+    (0x17A, 0x17A, Ll),
+    (0x17B, 0x17B, Lu),
+    (0x17C, 0x17C, Ll),
+    (0x17D, 0x17D, Lu),
+    (0x17E, 0x180, Ll),
+    (0x181, 0x182, Lu),
+    (0x183, 0x183, Ll),
+    (0x184, 0x184, Lu),
+    (0x185, 0x185, Ll),
+    (0x186, 0x187, Lu),
+    (0x188, 0x188, Ll),
+    (0x189, 0x18B, Lu),
+    (0x18C, 0x18D, Ll),
+    (0x18E, 0x191, Lu),
+    (0x192, 0x192, Ll),
+    (0x193, 0x194, Lu),
+    (0x195, 0x195, Ll),
+    (0x196, 0x198, Lu),
+    (0x199, 0x19B, Ll),
+    (0x19C, 0x19D, Lu),
+    (0x19E, 0x19E, Ll),
+    (0x19F, 0x1A0, Lu),
+    (0x1A1, 0x1A1, Ll),
+    (0x1A2, 0x1A2, Lu),
+    (0x1A3, 0x1A3, Ll),
+    (0x1A4, 0x1A4, Lu),
+    (0x1A5, 0x1A5, Ll),
+    (0x1A6, 0x1A7, Lu),
+    (0x1A8, 0x1A8, Ll),
+    (0x1A9, 0x1A9, Lu),
+    (0x1AA, 0x1AB, Ll),
+    (0x1AC, 0x1AC, Lu),
+    (0x1AD, 0x1AD, Ll),
+    (0x1AE, 0x1AF, Lu),
+    (0x1B0, 0x1B0, Ll),
+    (0x1B1, 0x1B3, Lu),
+    (0x1B4, 0x1B4, Ll),
+    (0x1B5, 0x1B5, Lu),
+    (0x1B6, 0x1B6, Ll),
+    (0x1B7, 0x1B8, Lu),
+    (0x1B9, 0x1BA, Ll),
+    (0x1BB, 0x1BB, Lo),
+    (0x1BC, 0x1BC, Lu),
+    (0x1BD, 0x1BF, Ll),
+    (0x1C0, 0x1C3, Lo),
+    (0x1C4, 0x1C4, Lu),
+    (0x1C5, 0x1C5, Lt),
+    (0x1C6, 0x1C6, Ll),
+    (0x1C7, 0x1C7, Lu),
+    (0x1C8, 0x1C8, Lt),
+    (0x1C9, 0x1C9, Ll),
+    (0x1CA, 0x1CA, Lu),
+    (0x1CB, 0x1CB, Lt),
+    (0x1CC, 0x1CC, Ll),
+    (0x1CD, 0x1CD, Lu),
+    (0x1CE, 0x1CE, Ll),
+    (0x1CF, 0x1CF, Lu),
+    (0x1D0, 0x1D0, Ll),
+    (0x1D1, 0x1D1, Lu),
+    (0x1D2, 0x1D2, Ll),
+    (0x1D3, 0x1D3, Lu),
+    (0x1D4, 0x1D4, Ll),
+    (0x1D5, 0x1D5, Lu),
+    (0x1D6, 0x1D6, Ll),
+    (0x1D7, 0x1D7, Lu),
+    (0x1D8, 0x1D8, Ll),
+    (0x1D9, 0x1D9, Lu),
+    (0x1DA, 0x1DA, Ll),
+    (0x1DB, 0x1DB, Lu),
+    (0x1DC, 0x1DD, Ll),
+    (0x1DE, 0x1DE, Lu),
+    (0x1DF, 0x1DF, Ll),
+    (0x1E0, 0x1E0, Lu),
+    (0x1E1, 0x1E1, Ll),
+    (0x1E2, 0x1E2, Lu),
+    (0x1E3, 0x1E3, Ll),
+    (0x1E4, 0x1E4, Lu),
+    (0x1E5, 0x1E5, Ll),
+    (0x1E6, 0x1E6, Lu),
+    (0x1E7, 0x1E7, Ll),
+    (0x1E8, 0x1E8, Lu),
+    (0x1E9, 0x1E9, Ll),
+    (0x1EA, 0x1EA, Lu),
+    (0x1EB, 0x1EB, Ll),
+    (0x1EC, 0x1EC, Lu),
+    (0x1ED, 0x1ED, Ll),
+    (0x1EE, 0x1EE, Lu),
+    (0x1EF, 0x1F0, Ll),
+    (0x1F1, 0x1F1, Lu),
+    (0x1F2, 0x1F2, Lt),
+    (0x1F3, 0x1F3, Ll),
+    (0x1F4, 0x1F4, Lu),
+    (0x1F5, 0x1F5, Ll),
+    (0x1F6, 0x1F8, Lu),
+    (0x1F9, 0x1F9, Ll),
+    (0x1FA, 0x1FA, Lu),
+    (0x1FB, 0x1FB, Ll),
+    (0x1FC, 0x1FC, Lu),
+    (0x1FD, 0x1FD, Ll),
+    (0x1FE, 0x1FE, Lu),
+    (0x1FF, 0x1FF, Ll),
+    (0x200, 0x200, Lu),
+    (0x201, 0x201, Ll),
+    (0x202, 0x202, Lu),
+    (0x203, 0x203, Ll),
+    (0x204, 0x204, Lu),
+    (0x205, 0x205, Ll),
+    (0x206, 0x206, Lu),
+    (0x207, 0x207, Ll),
+    (0x208, 0x208, Lu),
+    (0x209, 0x209, Ll),
+    (0x20A, 0x20A, Lu),
+    (0x20B, 0x20B, Ll),
+    (0x20C, 0x20C, Lu),
+    (0x20D, 0x20D, Ll),
+    (0x20E, 0x20E, Lu),
+    (0x20F, 0x20F, Ll),
+    (0x210, 0x210, Lu),
+    (0x211, 0x211, Ll),
+    (0x212, 0x212, Lu),
+    (0x213, 0x213, Ll),
+    (0x214, 0x214, Lu),
+    (0x215, 0x215, Ll),
+    (0x216, 0x216, Lu),
+    (0x217, 0x217, Ll),
+    (0x218, 0x218, Lu),
+    (0x219, 0x219, Ll),
+    (0x21A, 0x21A, Lu),
+    (0x21B, 0x21B, Ll),
+    (0x21C, 0x21C, Lu),
+    (0x21D, 0x21D, Ll),
+    (0x21E, 0x21E, Lu),
+    (0x21F, 0x21F, Ll),
+    (0x220, 0x220, Lu),
+    (0x221, 0x221, Ll),
+    (0x222, 0x222, Lu),
+    (0x223, 0x223, Ll),
+    (0x224, 0x224, Lu),
+    (0x225, 0x225, Ll),
+    (0x226, 0x226, Lu),
+    (0x227, 0x227, Ll),
+    (0x228, 0x228, Lu),
+    (0x229, 0x229, Ll),
+    (0x22A, 0x22A, Lu),
+    (0x22B, 0x22B, Ll),
+    (0x22C, 0x22C, Lu),
+    (0x22D, 0x22D, Ll),
+    (0x22E, 0x22E, Lu),
+    (0x22F, 0x22F, Ll),
+    (0x230, 0x230, Lu),
+    (0x231, 0x231, Ll),
+    (0x232, 0x232, Lu),
+    (0x233, 0x239, Ll),
+    (0x23A, 0x23B, Lu),
+    (0x23C, 0x23C, Ll),
+    (0x23D, 0x23E, Lu),
+    (0x23F, 0x240, Ll),
+    (0x241, 0x241, Lu),
+    (0x242, 0x242, Ll),
+    (0x243, 0x246, Lu),
+    (0x247, 0x247, Ll),
+    (0x248, 0x248, Lu),
+    (0x249, 0x249, Ll),
+    (0x24A, 0x24A, Lu),
+    (0x24B, 0x24B, Ll),
+    (0x24C, 0x24C, Lu),
+    (0x24D, 0x24D, Ll),
+    (0x24E, 0x24E, Lu),
+    (0x24F, 0x293, Ll),
+    (0x294, 0x294, Lo),
+    (0x295, 0x2AF, Ll),
+    (0x2B0, 0x2C1, Lm),
+    (0x2C2, 0x2C5, Sk),
+    (0x2C6, 0x2D1, Lm),
+    (0x2D2, 0x2DF, Sk),
+    (0x2E0, 0x2E4, Lm),
+    (0x2E5, 0x2EB, Sk),
+    (0x2EC, 0x2EC, Lm),
+    (0x2ED, 0x2ED, Sk),
+    (0x2EE, 0x2EE, Lm),
+    (0x2EF, 0x2FF, Sk),
+    (0x300, 0x36F, Mn),
+    (0x370, 0x370, Lu),
+    (0x371, 0x371, Ll),
+    (0x372, 0x372, Lu),
+    (0x373, 0x373, Ll),
+    (0x374, 0x374, Lm),
+    (0x375, 0x375, Sk),
+    (0x376, 0x376, Lu),
+    (0x377, 0x377, Ll),
+    (0x37A, 0x37A, Lm),
+    (0x37B, 0x37D, Ll),
+    (0x37E, 0x37E, Po),
+    (0x37F, 0x37F, Lu),
+    (0x384, 0x385, Sk),
+    (0x386, 0x386, Lu),
+    (0x387, 0x387, Po),
+    (0x388, 0x38A, Lu),
+    (0x38C, 0x38C, Lu),
+    (0x38E, 0x38F, Lu),
+    (0x390, 0x390, Ll),
+    (0x391, 0x3A1, Lu),
+    (0x3A3, 0x3AB, Lu),
+    (0x3AC, 0x3CE, Ll),
+    (0x3CF, 0x3CF, Lu),
+    (0x3D0, 0x3D1, Ll),
+    (0x3D2, 0x3D4, Lu),
+    (0x3D5, 0x3D7, Ll),
+    (0x3D8, 0x3D8, Lu),
+    (0x3D9, 0x3D9, Ll),
+    (0x3DA, 0x3DA, Lu),
+    (0x3DB, 0x3DB, Ll),
+    (0x3DC, 0x3DC, Lu),
+    (0x3DD, 0x3DD, Ll),
+    (0x3DE, 0x3DE, Lu),
+    (0x3DF, 0x3DF, Ll),
+    (0x3E0, 0x3E0, Lu),
+    (0x3E1, 0x3E1, Ll),
+    (0x3E2, 0x3E2, Lu),
+    (0x3E3, 0x3E3, Ll),
+    (0x3E4, 0x3E4, Lu),
+    (0x3E5, 0x3E5, Ll),
+    (0x3E6, 0x3E6, Lu),
+    (0x3E7, 0x3E7, Ll),
+    (0x3E8, 0x3E8, Lu),
+    (0x3E9, 0x3E9, Ll),
+    (0x3EA, 0x3EA, Lu),
+    (0x3EB, 0x3EB, Ll),
+    (0x3EC, 0x3EC, Lu),
+    (0x3ED, 0x3ED, Ll),
+    (0x3EE, 0x3EE, Lu),
+    (0x3EF, 0x3F3, Ll),
+    (0x3F4, 0x3F4, Lu),
+    (0x3F5, 0x3F5, Ll),
+    (0x3F6, 0x3F6, Sm),
+    (0x3F7, 0x3F7, Lu),
+    (0x3F8, 0x3F8, Ll),
+    (0x3F9, 0x3FA, Lu),
+    (0x3FB, 0x3FC, Ll),
+    (0x3FD, 0x42F, Lu),
+    (0x430, 0x45F, Ll),
+    (0x460, 0x460, Lu),
+    (0x461, 0x461, Ll),
+    (0x462, 0x462, Lu),
+    (0x463, 0x463, Ll),
+    (0x464, 0x464, Lu),
+    (0x465, 0x465, Ll),
+    (0x466, 0x466, Lu),
+    (0x467, 0x467, Ll),
+    (0x468, 0x468, Lu),
+    (0x469, 0x469, Ll),
+    (0x46A, 0x46A, Lu),
+    (0x46B, 0x46B, Ll),
+    (0x46C, 0x46C, Lu),
+    (0x46D, 0x46D, Ll),
+    (0x46E, 0x46E, Lu),
+    (0x46F, 0x46F, Ll),
+    (0x470, 0x470, Lu),
+    (0x471, 0x471, Ll),
+    (0x472, 0x472, Lu),
+    (0x473, 0x473, Ll),
+    (0x474, 0x474, Lu),
+    (0x475, 0x475, Ll),
+    (0x476, 0x476, Lu),
+    (0x477, 0x477, Ll),
+    (0x478, 0x478, Lu),
+    (0x479, 0x479, Ll),
+    (0x47A, 0x47A, Lu),
+    (0x47B, 0x47B, Ll),
+    (0x47C, 0x47C, Lu),
+    (0x47D, 0x47D, Ll),
+    (0x47E, 0x47E, Lu),
+    (0x47F, 0x47F, Ll),
+    (0x480, 0x480, Lu),
+    (0x481, 0x481, Ll),
+    (0x482, 0x482, So),
+    (0x483, 0x487, Mn),
+    (0x488, 0x489, Me),
+    (0x48A, 0x48A, Lu),
+    (0x48B, 0x48B, Ll),
+    (0x48C, 0x48C, Lu),
+    (0x48D, 0x48D, Ll),
+    (0x48E, 0x48E, Lu),
+    (0x48F, 0x48F, Ll),
+    (0x490, 0x490, Lu),
+    (0x491, 0x491, Ll),
+    (0x492, 0x492, Lu),
+    (0x493, 0x493, Ll),
+    (0x494, 0x494, Lu),
+    (0x495, 0x495, Ll),
+    (0x496, 0x496, Lu),
+    (0x497, 0x497, Ll),
+    (0x498, 0x498, Lu),
+    (0x499, 0x499, Ll),
+    (0x49A, 0x49A, Lu),
+    (0x49B, 0x49B, Ll),
+    (0x49C, 0x49C, Lu),
+    (0x49D, 0x49D, Ll),
+    (0x49E, 0x49E, Lu),
+    (0x49F, 0x49F, Ll),
+    (0x4A0, 0x4A0, Lu),
+    (0x4A1, 0x4A1, Ll),
+    (0x4A2, 0x4A2, Lu),
+    (0x4A3, 0x4A3, Ll),
+    (0x4A4, 0x4A4, Lu),
+    (0x4A5, 0x4A5, Ll),
+    (0x4A6, 0x4A6, Lu),
+    (0x4A7, 0x4A7, Ll),
+    (0x4A8, 0x4A8, Lu),
+    (0x4A9, 0x4A9, Ll),
+    (0x4AA, 0x4AA, Lu),
+    (0x4AB, 0x4AB, Ll),
+    (0x4AC, 0x4AC, Lu),
+    (0x4AD, 0x4AD, Ll),
+    (0x4AE, 0x4AE, Lu),
+    (0x4AF, 0x4AF, Ll),
+    (0x4B0, 0x4B0, Lu),
+    (0x4B1, 0x4B1, Ll),
+    (0x4B2, 0x4B2, Lu),
+    (0x4B3, 0x4B3, Ll),
+    (0x4B4, 0x4B4, Lu),
+    (0x4B5, 0x4B5, Ll),
+    (0x4B6, 0x4B6, Lu),
+    (0x4B7, 0x4B7, Ll),
+    (0x4B8, 0x4B8, Lu),
+    (0x4B9, 0x4B9, Ll),
+    (0x4BA, 0x4BA, Lu),
+    (0x4BB, 0x4BB, Ll),
+    (0x4BC, 0x4BC, Lu),
+    (0x4BD, 0x4BD, Ll),
+    (0x4BE, 0x4BE, Lu),
+    (0x4BF, 0x4BF, Ll),
+    (0x4C0, 0x4C1, Lu),
+    (0x4C2, 0x4C2, Ll),
+    (0x4C3, 0x4C3, Lu),
+    (0x4C4, 0x4C4, Ll),
+    (0x4C5, 0x4C5, Lu),
+    (0x4C6, 0x4C6, Ll),
+    (0x4C7, 0x4C7, Lu),
+    (0x4C8, 0x4C8, Ll),
+    (0x4C9, 0x4C9, Lu),
+    (0x4CA, 0x4CA, Ll),
+    (0x4CB, 0x4CB, Lu),
+    (0x4CC, 0x4CC, Ll),
+    (0x4CD, 0x4CD, Lu),
+    (0x4CE, 0x4CF, Ll),
+    (0x4D0, 0x4D0, Lu),
+    (0x4D1, 0x4D1, Ll),
+    (0x4D2, 0x4D2, Lu),
+    (0x4D3, 0x4D3, Ll),
+    (0x4D4, 0x4D4, Lu),
+    (0x4D5, 0x4D5, Ll),
+    (0x4D6, 0x4D6, Lu),
+    (0x4D7, 0x4D7, Ll),
+    (0x4D8, 0x4D8, Lu),
+    (0x4D9, 0x4D9, Ll),
+    (0x4DA, 0x4DA, Lu),
+    (0x4DB, 0x4DB, Ll),
+    (0x4DC, 0x4DC, Lu),
+    (0x4DD, 0x4DD, Ll),
+    (0x4DE, 0x4DE, Lu),
+    (0x4DF, 0x4DF, Ll),
+    (0x4E0, 0x4E0, Lu),
+    (0x4E1, 0x4E1, Ll),
+    (0x4E2, 0x4E2, Lu),
+    (0x4E3, 0x4E3, Ll),
+    (0x4E4, 0x4E4, Lu),
+    (0x4E5, 0x4E5, Ll),
+    (0x4E6, 0x4E6, Lu),
+    (0x4E7, 0x4E7, Ll),
+    (0x4E8, 0x4E8, Lu),
+    (0x4E9, 0x4E9, Ll),
+    (0x4EA, 0x4EA, Lu),
+    (0x4EB, 0x4EB, Ll),
+    (0x4EC, 0x4EC, Lu),
+    (0x4ED, 0x4ED, Ll),
+    (0x4EE, 0x4EE, Lu),
+    (0x4EF, 0x4EF, Ll),
+    (0x4F0, 0x4F0, Lu),
+    (0x4F1, 0x4F1, Ll),
+    (0x4F2, 0x4F2, Lu),
+    (0x4F3, 0x4F3, Ll),
+    (0x4F4, 0x4F4, Lu),
+    (0x4F5, 0x4F5, Ll),
+    (0x4F6, 0x4F6, Lu),
+    (0x4F7, 0x4F7, Ll),
+    (0x4F8, 0x4F8, Lu),
+    (0x4F9, 0x4F9, Ll),
+    (0x4FA, 0x4FA, Lu),
+    (0x4FB, 0x4FB, Ll),
+    (0x4FC, 0x4FC, Lu),
+    (0x4FD, 0x4FD, Ll),
+    (0x4FE, 0x4FE, Lu),
+    (0x4FF, 0x4FF, Ll),
+    (0x500, 0x500, Lu),
+    (0x501, 0x501, Ll),
+    (0x502, 0x502, Lu),
+    (0x503, 0x503, Ll),
+    (0x504, 0x504, Lu),
+    (0x505, 0x505, Ll),
+    (0x506, 0x506, Lu),
+    (0x507, 0x507, Ll),
+    (0x508, 0x508, Lu),
+    (0x509, 0x509, Ll),
+    (0x50A, 0x50A, Lu),
+    (0x50B, 0x50B, Ll),
+    (0x50C, 0x50C, Lu),
+    (0x50D, 0x50D, Ll),
+    (0x50E, 0x50E, Lu),
+    (0x50F, 0x50F, Ll),
+    (0x510, 0x510, Lu),
+    (0x511, 0x511, Ll),
+    (0x512, 0x512, Lu),
+    (0x513, 0x513, Ll),
+    (0x514, 0x514, Lu),
+    (0x515, 0x515, Ll),
+    (0x516, 0x516, Lu),
+    (0x517, 0x517, Ll),
+    (0x518, 0x518, Lu),
+    (0x519, 0x519, Ll),
+    (0x51A, 0x51A, Lu),
+    (0x51B, 0x51B, Ll),
+    (0x51C, 0x51C, Lu),
+    (0x51D, 0x51D, Ll),
+    (0x51E, 0x51E, Lu),
+    (0x51F, 0x51F, Ll),
+    (0x520, 0x520, Lu),
+    (0x521, 0x521, Ll),
+    (0x522, 0x522, Lu),
+    (0x523, 0x523, Ll),
+    (0x524, 0x524, Lu),
+    (0x525, 0x525, Ll),
+    (0x526, 0x526, Lu),
+    (0x527, 0x527, Ll),
+    (0x528, 0x528, Lu),
+    (0x529, 0x529, Ll),
+    (0x52A, 0x52A, Lu),
+    (0x52B, 0x52B, Ll),
+    (0x52C, 0x52C, Lu),
+    (0x52D, 0x52D, Ll),
+    (0x52E, 0x52E, Lu),
+    (0x52F, 0x52F, Ll),
+    (0x531, 0x556, Lu),
+    (0x559, 0x559, Lm),
+    (0x55A, 0x55F, Po),
+    (0x560, 0x588, Ll),
+    (0x589, 0x589, Po),
+    (0x58A, 0x58A, Pd),
+    (0x58D, 0x58E, So),
+    (0x58F, 0x58F, Sc),
+    (0x591, 0x5BD, Mn),
+    (0x5BE, 0x5BE, Pd),
+    (0x5BF, 0x5BF, Mn),
+    (0x5C0, 0x5C0, Po),
+    (0x5C1, 0x5C2, Mn),
+    (0x5C3, 0x5C3, Po),
+    (0x5C4, 0x5C5, Mn),
+    (0x5C6, 0x5C6, Po),
+    (0x5C7, 0x5C7, Mn),
+    (0x5D0, 0x5EA, Lo),
+    (0x5EF, 0x5F2, Lo),
+    (0x5F3, 0x5F4, Po),
+    (0x600, 0x605, Cf),
+    (0x606, 0x608, Sm),
+    (0x609, 0x60A, Po),
+    (0x60B, 0x60B, Sc),
+    (0x60C, 0x60D, Po),
+    (0x60E, 0x60F, So),
+    (0x610, 0x61A, Mn),
+    (0x61B, 0x61B, Po),
+    (0x61C, 0x61C, Cf),
+    (0x61D, 0x61F, Po),
+    (0x620, 0x63F, Lo),
+    (0x640, 0x640, Lm),
+    (0x641, 0x64A, Lo),
+    (0x64B, 0x65F, Mn),
+    (0x660, 0x669, Nd),
+    (0x66A, 0x66D, Po),
+    (0x66E, 0x66F, Lo),
+    (0x670, 0x670, Mn),
+    (0x671, 0x6D3, Lo),
+    (0x6D4, 0x6D4, Po),
+    (0x6D5, 0x6D5, Lo),
+    (0x6D6, 0x6DC, Mn),
+    (0x6DD, 0x6DD, Cf),
+    (0x6DE, 0x6DE, So),
+    (0x6DF, 0x6E4, Mn),
+    (0x6E5, 0x6E6, Lm),
+    (0x6E7, 0x6E8, Mn),
+    (0x6E9, 0x6E9, So),
+    (0x6EA, 0x6ED, Mn),
+    (0x6EE, 0x6EF, Lo),
+    (0x6F0, 0x6F9, Nd),
+    (0x6FA, 0x6FC, Lo),
+    (0x6FD, 0x6FE, So),
+    (0x6FF, 0x6FF, Lo),
+    (0x700, 0x70D, Po),
+    (0x70F, 0x70F, Cf),
+    (0x710, 0x710, Lo),
+    (0x711, 0x711, Mn),
+    (0x712, 0x72F, Lo),
+    (0x730, 0x74A, Mn),
+    (0x74D, 0x7A5, Lo),
+    (0x7A6, 0x7B0, Mn),
+    (0x7B1, 0x7B1, Lo),
+    (0x7C0, 0x7C9, Nd),
+    (0x7CA, 0x7EA, Lo),
+    (0x7EB, 0x7F3, Mn),
+    (0x7F4, 0x7F5, Lm),
+    (0x7F6, 0x7F6, So),
+    (0x7F7, 0x7F9, Po),
+    (0x7FA, 0x7FA, Lm),
+    (0x7FD, 0x7FD, Mn),
+    (0x7FE, 0x7FF, Sc),
+    (0x800, 0x815, Lo),
+    (0x816, 0x819, Mn),
+    (0x81A, 0x81A, Lm),
+    (0x81B, 0x823, Mn),
+    (0x824, 0x824, Lm),
+    (0x825, 0x827, Mn),
+    (0x828, 0x828, Lm),
+    (0x829, 0x82D, Mn),
+    (0x830, 0x83E, Po),
+    (0x840, 0x858, Lo),
+    (0x859, 0x85B, Mn),
+    (0x85E, 0x85E, Po),
+    (0x860, 0x86A, Lo),
+    (0x870, 0x887, Lo),
+    (0x888, 0x888, Sk),
+    (0x889, 0x88E, Lo),
+    (0x890, 0x891, Cf),
+    (0x898, 0x89F, Mn),
+    (0x8A0, 0x8C8, Lo),
+    (0x8C9, 0x8C9, Lm),
+    (0x8CA, 0x8E1, Mn),
+    (0x8E2, 0x8E2, Cf),
+    (0x8E3, 0x902, Mn),
+    (0x903, 0x903, Mc),
+    (0x904, 0x939, Lo),
+    (0x93A, 0x93A, Mn),
+    (0x93B, 0x93B, Mc),
+    (0x93C, 0x93C, Mn),
+    (0x93D, 0x93D, Lo),
+    (0x93E, 0x940, Mc),
+    (0x941, 0x948, Mn),
+    (0x949, 0x94C, Mc),
+    (0x94D, 0x94D, Mn),
+    (0x94E, 0x94F, Mc),
+    (0x950, 0x950, Lo),
+    (0x951, 0x957, Mn),
+    (0x958, 0x961, Lo),
+    (0x962, 0x963, Mn),
+    (0x964, 0x965, Po),
+    (0x966, 0x96F, Nd),
+    (0x970, 0x970, Po),
+    (0x971, 0x971, Lm),
+    (0x972, 0x980, Lo),
+    (0x981, 0x981, Mn),
+    (0x982, 0x983, Mc),
+    (0x985, 0x98C, Lo),
+    (0x98F, 0x990, Lo),
+    (0x993, 0x9A8, Lo),
+    (0x9AA, 0x9B0, Lo),
+    (0x9B2, 0x9B2, Lo),
+    (0x9B6, 0x9B9, Lo),
+    (0x9BC, 0x9BC, Mn),
+    (0x9BD, 0x9BD, Lo),
+    (0x9BE, 0x9C0, Mc),
+    (0x9C1, 0x9C4, Mn),
+    (0x9C7, 0x9C8, Mc),
+    (0x9CB, 0x9CC, Mc),
+    (0x9CD, 0x9CD, Mn),
+    (0x9CE, 0x9CE, Lo),
+    (0x9D7, 0x9D7, Mc),
+    (0x9DC, 0x9DD, Lo),
+    (0x9DF, 0x9E1, Lo),
+    (0x9E2, 0x9E3, Mn),
+    (0x9E6, 0x9EF, Nd),
+    (0x9F0, 0x9F1, Lo),
+    (0x9F2, 0x9F3, Sc),
+    (0x9F4, 0x9F9, No),
+    (0x9FA, 0x9FA, So),
+    (0x9FB, 0x9FB, Sc),
+    (0x9FC, 0x9FC, Lo),
+    (0x9FD, 0x9FD, Po),
+    (0x9FE, 0x9FE, Mn),
+    (0xA01, 0xA02, Mn),
+    (0xA03, 0xA03, Mc),
+    (0xA05, 0xA0A, Lo),
+    (0xA0F, 0xA10, Lo),
+    (0xA13, 0xA28, Lo),
+    (0xA2A, 0xA30, Lo),
+    (0xA32, 0xA33, Lo),
+    (0xA35, 0xA36, Lo),
+    (0xA38, 0xA39, Lo),
+    (0xA3C, 0xA3C, Mn),
+    (0xA3E, 0xA40, Mc),
+    (0xA41, 0xA42, Mn),
+    (0xA47, 0xA48, Mn),
+    (0xA4B, 0xA4D, Mn),
+    (0xA51, 0xA51, Mn),
+    (0xA59, 0xA5C, Lo),
+    (0xA5E, 0xA5E, Lo),
+    (0xA66, 0xA6F, Nd),
+    (0xA70, 0xA71, Mn),
+    (0xA72, 0xA74, Lo),
+    (0xA75, 0xA75, Mn),
+    (0xA76, 0xA76, Po),
+    (0xA81, 0xA82, Mn),
+    (0xA83, 0xA83, Mc),
+    (0xA85, 0xA8D, Lo),
+    (0xA8F, 0xA91, Lo),
+    (0xA93, 0xAA8, Lo),
+    (0xAAA, 0xAB0, Lo),
+    (0xAB2, 0xAB3, Lo),
+    (0xAB5, 0xAB9, Lo),
+    (0xABC, 0xABC, Mn),
+    (0xABD, 0xABD, Lo),
+    (0xABE, 0xAC0, Mc),
+    (0xAC1, 0xAC5, Mn),
+    (0xAC7, 0xAC8, Mn),
+    (0xAC9, 0xAC9, Mc),
+    (0xACB, 0xACC, Mc),
+    (0xACD, 0xACD, Mn),
+    (0xAD0, 0xAD0, Lo),
+    (0xAE0, 0xAE1, Lo),
+    (0xAE2, 0xAE3, Mn),
+    (0xAE6, 0xAEF, Nd),
+    (0xAF0, 0xAF0, Po),
+    (0xAF1, 0xAF1, Sc),
+    (0xAF9, 0xAF9, Lo),
+    (0xAFA, 0xAFF, Mn),
+    (0xB01, 0xB01, Mn),
+    (0xB02, 0xB03, Mc),
+    (0xB05, 0xB0C, Lo),
+    (0xB0F, 0xB10, Lo),
+    (0xB13, 0xB28, Lo),
+    (0xB2A, 0xB30, Lo),
+    (0xB32, 0xB33, Lo),
+    (0xB35, 0xB39, Lo),
+    (0xB3C, 0xB3C, Mn),
+    (0xB3D, 0xB3D, Lo),
+    (0xB3E, 0xB3E, Mc),
+    (0xB3F, 0xB3F, Mn),
+    (0xB40, 0xB40, Mc),
+    (0xB41, 0xB44, Mn),
+    (0xB47, 0xB48, Mc),
+    (0xB4B, 0xB4C, Mc),
+    (0xB4D, 0xB4D, Mn),
+    (0xB55, 0xB56, Mn),
+    (0xB57, 0xB57, Mc),
+    (0xB5C, 0xB5D, Lo),
+    (0xB5F, 0xB61, Lo),
+    (0xB62, 0xB63, Mn),
+    (0xB66, 0xB6F, Nd),
+    (0xB70, 0xB70, So),
+    (0xB71, 0xB71, Lo),
+    (0xB72, 0xB77, No),
+    (0xB82, 0xB82, Mn),
+    (0xB83, 0xB83, Lo),
+    (0xB85, 0xB8A, Lo),
+    (0xB8E, 0xB90, Lo),
+    (0xB92, 0xB95, Lo),
+    (0xB99, 0xB9A, Lo),
+    (0xB9C, 0xB9C, Lo),
+    (0xB9E, 0xB9F, Lo),
+    (0xBA3, 0xBA4, Lo),
+    (0xBA8, 0xBAA, Lo),
+    (0xBAE, 0xBB9, Lo),
+    (0xBBE, 0xBBF, Mc),
+    (0xBC0, 0xBC0, Mn),
+    (0xBC1, 0xBC2, Mc),
+    (0xBC6, 0xBC8, Mc),
+    (0xBCA, 0xBCC, Mc),
+    (0xBCD, 0xBCD, Mn),
+    (0xBD0, 0xBD0, Lo),
+    (0xBD7, 0xBD7, Mc),
+    (0xBE6, 0xBEF, Nd),
+    (0xBF0, 0xBF2, No),
+    (0xBF3, 0xBF8, So),
+    (0xBF9, 0xBF9, Sc),
+    (0xBFA, 0xBFA, So),
+    (0xC00, 0xC00, Mn),
+    (0xC01, 0xC03, Mc),
+    (0xC04, 0xC04, Mn),
+    (0xC05, 0xC0C, Lo),
+    (0xC0E, 0xC10, Lo),
+    (0xC12, 0xC28, Lo),
+    (0xC2A, 0xC39, Lo),
+    (0xC3C, 0xC3C, Mn),
+    (0xC3D, 0xC3D, Lo),
+    (0xC3E, 0xC40, Mn),
+    (0xC41, 0xC44, Mc),
+    (0xC46, 0xC48, Mn),
+    (0xC4A, 0xC4D, Mn),
+    (0xC55, 0xC56, Mn),
+    (0xC58, 0xC5A, Lo),
+    (0xC5D, 0xC5D, Lo),
+    (0xC60, 0xC61, Lo),
+    (0xC62, 0xC63, Mn),
+    (0xC66, 0xC6F, Nd),
+    (0xC77, 0xC77, Po),
+    (0xC78, 0xC7E, No),
+    (0xC7F, 0xC7F, So),
+    (0xC80, 0xC80, Lo),
+    (0xC81, 0xC81, Mn),
+    (0xC82, 0xC83, Mc),
+    (0xC84, 0xC84, Po),
+    (0xC85, 0xC8C, Lo),
+    (0xC8E, 0xC90, Lo),
+    (0xC92, 0xCA8, Lo),
+    (0xCAA, 0xCB3, Lo),
+    (0xCB5, 0xCB9, Lo),
+    (0xCBC, 0xCBC, Mn),
+    (0xCBD, 0xCBD, Lo),
+    (0xCBE, 0xCBE, Mc),
+    (0xCBF, 0xCBF, Mn),
+    (0xCC0, 0xCC4, Mc),
+    (0xCC6, 0xCC6, Mn),
+    (0xCC7, 0xCC8, Mc),
+    (0xCCA, 0xCCB, Mc),
+    (0xCCC, 0xCCD, Mn),
+    (0xCD5, 0xCD6, Mc),
+    (0xCDD, 0xCDE, Lo),
+    (0xCE0, 0xCE1, Lo),
+    (0xCE2, 0xCE3, Mn),
+    (0xCE6, 0xCEF, Nd),
+    (0xCF1, 0xCF2, Lo),
+    (0xD00, 0xD01, Mn),
+    (0xD02, 0xD03, Mc),
+    (0xD04, 0xD0C, Lo),
+    (0xD0E, 0xD10, Lo),
+    (0xD12, 0xD3A, Lo),
+    (0xD3B, 0xD3C, Mn),
+    (0xD3D, 0xD3D, Lo),
+    (0xD3E, 0xD40, Mc),
+    (0xD41, 0xD44, Mn),
+    (0xD46, 0xD48, Mc),
+    (0xD4A, 0xD4C, Mc),
+    (0xD4D, 0xD4D, Mn),
+    (0xD4E, 0xD4E, Lo),
+    (0xD4F, 0xD4F, So),
+    (0xD54, 0xD56, Lo),
+    (0xD57, 0xD57, Mc),
+    (0xD58, 0xD5E, No),
+    (0xD5F, 0xD61, Lo),
+    (0xD62, 0xD63, Mn),
+    (0xD66, 0xD6F, Nd),
+    (0xD70, 0xD78, No),
+    (0xD79, 0xD79, So),
+    (0xD7A, 0xD7F, Lo),
+    (0xD81, 0xD81, Mn),
+    (0xD82, 0xD83, Mc),
+    (0xD85, 0xD96, Lo),
+    (0xD9A, 0xDB1, Lo),
+    (0xDB3, 0xDBB, Lo),
+    (0xDBD, 0xDBD, Lo),
+    (0xDC0, 0xDC6, Lo),
+    (0xDCA, 0xDCA, Mn),
+    (0xDCF, 0xDD1, Mc),
+    (0xDD2, 0xDD4, Mn),
+    (0xDD6, 0xDD6, Mn),
+    (0xDD8, 0xDDF, Mc),
+    (0xDE6, 0xDEF, Nd),
+    (0xDF2, 0xDF3, Mc),
+    (0xDF4, 0xDF4, Po),
+    (0xE01, 0xE30, Lo),
+    (0xE31, 0xE31, Mn),
+    (0xE32, 0xE33, Lo),
+    (0xE34, 0xE3A, Mn),
+    (0xE3F, 0xE3F, Sc),
+    (0xE40, 0xE45, Lo),
+    (0xE46, 0xE46, Lm),
+    (0xE47, 0xE4E, Mn),
+    (0xE4F, 0xE4F, Po),
+    (0xE50, 0xE59, Nd),
+    (0xE5A, 0xE5B, Po),
+    (0xE81, 0xE82, Lo),
+    (0xE84, 0xE84, Lo),
+    (0xE86, 0xE8A, Lo),
+    (0xE8C, 0xEA3, Lo),
+    (0xEA5, 0xEA5, Lo),
+    (0xEA7, 0xEB0, Lo),
+    (0xEB1, 0xEB1, Mn),
+    (0xEB2, 0xEB3, Lo),
+    (0xEB4, 0xEBC, Mn),
+    (0xEBD, 0xEBD, Lo),
+    (0xEC0, 0xEC4, Lo),
+    (0xEC6, 0xEC6, Lm),
+    (0xEC8, 0xECD, Mn),
+    (0xED0, 0xED9, Nd),
+    (0xEDC, 0xEDF, Lo),
+    (0xF00, 0xF00, Lo),
+    (0xF01, 0xF03, So),
+    (0xF04, 0xF12, Po),
+    (0xF13, 0xF13, So),
+    (0xF14, 0xF14, Po),
+    (0xF15, 0xF17, So),
+    (0xF18, 0xF19, Mn),
+    (0xF1A, 0xF1F, So),
+    (0xF20, 0xF29, Nd),
+    (0xF2A, 0xF33, No),
+    (0xF34, 0xF34, So),
+    (0xF35, 0xF35, Mn),
+    (0xF36, 0xF36, So),
+    (0xF37, 0xF37, Mn),
+    (0xF38, 0xF38, So),
+    (0xF39, 0xF39, Mn),
+    (0xF3A, 0xF3A, Ps),
+    (0xF3B, 0xF3B, Pe),
+    (0xF3C, 0xF3C, Ps),
+    (0xF3D, 0xF3D, Pe),
+    (0xF3E, 0xF3F, Mc),
+    (0xF40, 0xF47, Lo),
+    (0xF49, 0xF6C, Lo),
+    (0xF71, 0xF7E, Mn),
+    (0xF7F, 0xF7F, Mc),
+    (0xF80, 0xF84, Mn),
+    (0xF85, 0xF85, Po),
+    (0xF86, 0xF87, Mn),
+    (0xF88, 0xF8C, Lo),
+    (0xF8D, 0xF97, Mn),
+    (0xF99, 0xFBC, Mn),
+    (0xFBE, 0xFC5, So),
+    (0xFC6, 0xFC6, Mn),
+    (0xFC7, 0xFCC, So),
+    (0xFCE, 0xFCF, So),
+    (0xFD0, 0xFD4, Po),
+    (0xFD5, 0xFD8, So),
+    (0xFD9, 0xFDA, Po),
+    (0x1000, 0x102A, Lo),
+    (0x102B, 0x102C, Mc),
+    (0x102D, 0x1030, Mn),
+    (0x1031, 0x1031, Mc),
+    (0x1032, 0x1037, Mn),
+    (0x1038, 0x1038, Mc),
+    (0x1039, 0x103A, Mn),
+    (0x103B, 0x103C, Mc),
+    (0x103D, 0x103E, Mn),
+    (0x103F, 0x103F, Lo),
+    (0x1040, 0x1049, Nd),
+    (0x104A, 0x104F, Po),
+    (0x1050, 0x1055, Lo),
+    (0x1056, 0x1057, Mc),
+    (0x1058, 0x1059, Mn),
+    (0x105A, 0x105D, Lo),
+    (0x105E, 0x1060, Mn),
+    (0x1061, 0x1061, Lo),
+    (0x1062, 0x1064, Mc),
+    (0x1065, 0x1066, Lo),
+    (0x1067, 0x106D, Mc),
+    (0x106E, 0x1070, Lo),
+    (0x1071, 0x1074, Mn),
+    (0x1075, 0x1081, Lo),
+    (0x1082, 0x1082, Mn),
+    (0x1083, 0x1084, Mc),
+    (0x1085, 0x1086, Mn),
+    (0x1087, 0x108C, Mc),
+    (0x108D, 0x108D, Mn),
+    (0x108E, 0x108E, Lo),
+    (0x108F, 0x108F, Mc),
+    (0x1090, 0x1099, Nd),
+    (0x109A, 0x109C, Mc),
+    (0x109D, 0x109D, Mn),
+    (0x109E, 0x109F, So),
+    (0x10A0, 0x10C5, Lu),
+    (0x10C7, 0x10C7, Lu),
+    (0x10CD, 0x10CD, Lu),
+    (0x10D0, 0x10FA, Ll),
+    (0x10FB, 0x10FB, Po),
+    (0x10FC, 0x10FC, Lm),
+    (0x10FD, 0x10FF, Ll),
+    (0x1100, 0x1248, Lo),
+    (0x124A, 0x124D, Lo),
+    (0x1250, 0x1256, Lo),
+    (0x1258, 0x1258, Lo),
+    (0x125A, 0x125D, Lo),
+    (0x1260, 0x1288, Lo),
+    (0x128A, 0x128D, Lo),
+    (0x1290, 0x12B0, Lo),
+    (0x12B2, 0x12B5, Lo),
+    (0x12B8, 0x12BE, Lo),
+    (0x12C0, 0x12C0, Lo),
+    (0x12C2, 0x12C5, Lo),
+    (0x12C8, 0x12D6, Lo),
+    (0x12D8, 0x1310, Lo),
+    (0x1312, 0x1315, Lo),
+    (0x1318, 0x135A, Lo),
+    (0x135D, 0x135F, Mn),
+    (0x1360, 0x1368, Po),
+    (0x1369, 0x137C, No),
+    (0x1380, 0x138F, Lo),
+    (0x1390, 0x1399, So),
+    (0x13A0, 0x13F5, Lu),
+    (0x13F8, 0x13FD, Ll),
+    (0x1400, 0x1400, Pd),
+    (0x1401, 0x166C, Lo),
+    (0x166D, 0x166D, So),
+    (0x166E, 0x166E, Po),
+    (0x166F, 0x167F, Lo),
+    (0x1680, 0x1680, Zs),
+    (0x1681, 0x169A, Lo),
+    (0x169B, 0x169B, Ps),
+    (0x169C, 0x169C, Pe),
+    (0x16A0, 0x16EA, Lo),
+    (0x16EB, 0x16ED, Po),
+    (0x16EE, 0x16F0, Nl),
+    (0x16F1, 0x16F8, Lo),
+    (0x1700, 0x1711, Lo),
+    (0x1712, 0x1714, Mn),
+    (0x1715, 0x1715, Mc),
+    (0x171F, 0x1731, Lo),
+    (0x1732, 0x1733, Mn),
+    (0x1734, 0x1734, Mc),
+    (0x1735, 0x1736, Po),
+    (0x1740, 0x1751, Lo),
+    (0x1752, 0x1753, Mn),
+    (0x1760, 0x176C, Lo),
+    (0x176E, 0x1770, Lo),
+    (0x1772, 0x1773, Mn),
+    (0x1780, 0x17B3, Lo),
+    (0x17B4, 0x17B5, Mn),
+    (0x17B6, 0x17B6, Mc),
+    (0x17B7, 0x17BD, Mn),
+    (0x17BE, 0x17C5, Mc),
+    (0x17C6, 0x17C6, Mn),
+    (0x17C7, 0x17C8, Mc),
+    (0x17C9, 0x17D3, Mn),
+    (0x17D4, 0x17D6, Po),
+    (0x17D7, 0x17D7, Lm),
+    (0x17D8, 0x17DA, Po),
+    (0x17DB, 0x17DB, Sc),
+    (0x17DC, 0x17DC, Lo),
+    (0x17DD, 0x17DD, Mn),
+    (0x17E0, 0x17E9, Nd),
+    (0x17F0, 0x17F9, No),
+    (0x1800, 0x1805, Po),
+    (0x1806, 0x1806, Pd),
+    (0x1807, 0x180A, Po),
+    (0x180B, 0x180D, Mn),
+    (0x180E, 0x180E, Cf),
+    (0x180F, 0x180F, Mn),
+    (0x1810, 0x1819, Nd),
+    (0x1820, 0x1842, Lo),
+    (0x1843, 0x1843, Lm),
+    (0x1844, 0x1878, Lo),
+    (0x1880, 0x1884, Lo),
+    (0x1885, 0x1886, Mn),
+    (0x1887, 0x18A8, Lo),
+    (0x18A9, 0x18A9, Mn),
+    (0x18AA, 0x18AA, Lo),
+    (0x18B0, 0x18F5, Lo),
+    (0x1900, 0x191E, Lo),
+    (0x1920, 0x1922, Mn),
+    (0x1923, 0x1926, Mc),
+    (0x1927, 0x1928, Mn),
+    (0x1929, 0x192B, Mc),
+    (0x1930, 0x1931, Mc),
+    (0x1932, 0x1932, Mn),
+    (0x1933, 0x1938, Mc),
+    (0x1939, 0x193B, Mn),
+    (0x1940, 0x1940, So),
+    (0x1944, 0x1945, Po),
+    (0x1946, 0x194F, Nd),
+    (0x1950, 0x196D, Lo),
+    (0x1970, 0x1974, Lo),
+    (0x1980, 0x19AB, Lo),
+    (0x19B0, 0x19C9, Lo),
+    (0x19D0, 0x19D9, Nd),
+    (0x19DA, 0x19DA, No),
+    (0x19DE, 0x19FF, So),
+    (0x1A00, 0x1A16, Lo),
+    (0x1A17, 0x1A18, Mn),
+    (0x1A19, 0x1A1A, Mc),
+    (0x1A1B, 0x1A1B, Mn),
+    (0x1A1E, 0x1A1F, Po),
+    (0x1A20, 0x1A54, Lo),
+    (0x1A55, 0x1A55, Mc),
+    (0x1A56, 0x1A56, Mn),
+    (0x1A57, 0x1A57, Mc),
+    (0x1A58, 0x1A5E, Mn),
+    (0x1A60, 0x1A60, Mn),
+    (0x1A61, 0x1A61, Mc),
+    (0x1A62, 0x1A62, Mn),
+    (0x1A63, 0x1A64, Mc),
+    (0x1A65, 0x1A6C, Mn),
+    (0x1A6D, 0x1A72, Mc),
+    (0x1A73, 0x1A7C, Mn),
+    (0x1A7F, 0x1A7F, Mn),
+    (0x1A80, 0x1A89, Nd),
+    (0x1A90, 0x1A99, Nd),
+    (0x1AA0, 0x1AA6, Po),
+    (0x1AA7, 0x1AA7, Lm),
+    (0x1AA8, 0x1AAD, Po),
+    (0x1AB0, 0x1ABD, Mn),
+    (0x1ABE, 0x1ABE, Me),
+    (0x1ABF, 0x1ACE, Mn),
+    (0x1B00, 0x1B03, Mn),
+    (0x1B04, 0x1B04, Mc),
+    (0x1B05, 0x1B33, Lo),
+    (0x1B34, 0x1B34, Mn),
+    (0x1B35, 0x1B35, Mc),
+    (0x1B36, 0x1B3A, Mn),
+    (0x1B3B, 0x1B3B, Mc),
+    (0x1B3C, 0x1B3C, Mn),
+    (0x1B3D, 0x1B41, Mc),
+    (0x1B42, 0x1B42, Mn),
+    (0x1B43, 0x1B44, Mc),
+    (0x1B45, 0x1B4C, Lo),
+    (0x1B50, 0x1B59, Nd),
+    (0x1B5A, 0x1B60, Po),
+    (0x1B61, 0x1B6A, So),
+    (0x1B6B, 0x1B73, Mn),
+    (0x1B74, 0x1B7C, So),
+    (0x1B7D, 0x1B7E, Po),
+    (0x1B80, 0x1B81, Mn),
+    (0x1B82, 0x1B82, Mc),
+    (0x1B83, 0x1BA0, Lo),
+    (0x1BA1, 0x1BA1, Mc),
+    (0x1BA2, 0x1BA5, Mn),
+    (0x1BA6, 0x1BA7, Mc),
+    (0x1BA8, 0x1BA9, Mn),
+    (0x1BAA, 0x1BAA, Mc),
+    (0x1BAB, 0x1BAD, Mn),
+    (0x1BAE, 0x1BAF, Lo),
+    (0x1BB0, 0x1BB9, Nd),
+    (0x1BBA, 0x1BE5, Lo),
+    (0x1BE6, 0x1BE6, Mn),
+    (0x1BE7, 0x1BE7, Mc),
+    (0x1BE8, 0x1BE9, Mn),
+    (0x1BEA, 0x1BEC, Mc),
+    (0x1BED, 0x1BED, Mn),
+    (0x1BEE, 0x1BEE, Mc),
+    (0x1BEF, 0x1BF1, Mn),
+    (0x1BF2, 0x1BF3, Mc),
+    (0x1BFC, 0x1BFF, Po),
+    (0x1C00, 0x1C23, Lo),
+    (0x1C24, 0x1C2B, Mc),
+    (0x1C2C, 0x1C33, Mn),
+    (0x1C34, 0x1C35, Mc),
+    (0x1C36, 0x1C37, Mn),
+    (0x1C3B, 0x1C3F, Po),
+    (0x1C40, 0x1C49, Nd),
+    (0x1C4D, 0x1C4F, Lo),
+    (0x1C50, 0x1C59, Nd),
+    (0x1C5A, 0x1C77, Lo),
+    (0x1C78, 0x1C7D, Lm),
+    (0x1C7E, 0x1C7F, Po),
+    (0x1C80, 0x1C88, Ll),
+    (0x1C90, 0x1CBA, Lu),
+    (0x1CBD, 0x1CBF, Lu),
+    (0x1CC0, 0x1CC7, Po),
+    (0x1CD0, 0x1CD2, Mn),
+    (0x1CD3, 0x1CD3, Po),
+    (0x1CD4, 0x1CE0, Mn),
+    (0x1CE1, 0x1CE1, Mc),
+    (0x1CE2, 0x1CE8, Mn),
+    (0x1CE9, 0x1CEC, Lo),
+    (0x1CED, 0x1CED, Mn),
+    (0x1CEE, 0x1CF3, Lo),
+    (0x1CF4, 0x1CF4, Mn),
+    (0x1CF5, 0x1CF6, Lo),
+    (0x1CF7, 0x1CF7, Mc),
+    (0x1CF8, 0x1CF9, Mn),
+    (0x1CFA, 0x1CFA, Lo),
+    (0x1D00, 0x1D2B, Ll),
+    (0x1D2C, 0x1D6A, Lm),
+    (0x1D6B, 0x1D77, Ll),
+    (0x1D78, 0x1D78, Lm),
+    (0x1D79, 0x1D9A, Ll),
+    (0x1D9B, 0x1DBF, Lm),
+    (0x1DC0, 0x1DFF, Mn),
+    (0x1E00, 0x1E00, Lu),
+    (0x1E01, 0x1E01, Ll),
+    (0x1E02, 0x1E02, Lu),
+    (0x1E03, 0x1E03, Ll),
+    (0x1E04, 0x1E04, Lu),
+    (0x1E05, 0x1E05, Ll),
+    (0x1E06, 0x1E06, Lu),
+    (0x1E07, 0x1E07, Ll),
+    (0x1E08, 0x1E08, Lu),
+    (0x1E09, 0x1E09, Ll),
+    (0x1E0A, 0x1E0A, Lu),
+    (0x1E0B, 0x1E0B, Ll),
+    (0x1E0C, 0x1E0C, Lu),
+    (0x1E0D, 0x1E0D, Ll),
+    (0x1E0E, 0x1E0E, Lu),
+    (0x1E0F, 0x1E0F, Ll),
+    (0x1E10, 0x1E10, Lu),
+    (0x1E11, 0x1E11, Ll),
+    (0x1E12, 0x1E12, Lu),
+    (0x1E13, 0x1E13, Ll),
+    (0x1E14, 0x1E14, Lu),
+    (0x1E15, 0x1E15, Ll),
+    (0x1E16, 0x1E16, Lu),
+    (0x1E17, 0x1E17, Ll),
+    (0x1E18, 0x1E18, Lu),
+    (0x1E19, 0x1E19, Ll),
+    (0x1E1A, 0x1E1A, Lu),
+    (0x1E1B, 0x1E1B, Ll),
+    (0x1E1C, 0x1E1C, Lu),
+    (0x1E1D, 0x1E1D, Ll),
+    (0x1E1E, 0x1E1E, Lu),
+    (0x1E1F, 0x1E1F, Ll),
+    (0x1E20, 0x1E20, Lu),
+    (0x1E21, 0x1E21, Ll),
+    (0x1E22, 0x1E22, Lu),
+    (0x1E23, 0x1E23, Ll),
+    (0x1E24, 0x1E24, Lu),
+    (0x1E25, 0x1E25, Ll),
+    (0x1E26, 0x1E26, Lu),
+    (0x1E27, 0x1E27, Ll),
+    (0x1E28, 0x1E28, Lu),
+    (0x1E29, 0x1E29, Ll),
+    (0x1E2A, 0x1E2A, Lu),
+    (0x1E2B, 0x1E2B, Ll),
+    (0x1E2C, 0x1E2C, Lu),
+    (0x1E2D, 0x1E2D, Ll),
+    (0x1E2E, 0x1E2E, Lu),
+    (0x1E2F, 0x1E2F, Ll),
+    (0x1E30, 0x1E30, Lu),
+    (0x1E31, 0x1E31, Ll),
+    (0x1E32, 0x1E32, Lu),
+    (0x1E33, 0x1E33, Ll),
+    (0x1E34, 0x1E34, Lu),
+    (0x1E35, 0x1E35, Ll),
+    (0x1E36, 0x1E36, Lu),
+    (0x1E37, 0x1E37, Ll),
+    (0x1E38, 0x1E38, Lu),
+    (0x1E39, 0x1E39, Ll),
+    (0x1E3A, 0x1E3A, Lu),
+    (0x1E3B, 0x1E3B, Ll),
+    (0x1E3C, 0x1E3C, Lu),
+    (0x1E3D, 0x1E3D, Ll),
+    (0x1E3E, 0x1E3E, Lu),
+    (0x1E3F, 0x1E3F, Ll),
+    (0x1E40, 0x1E40, Lu),
+    (0x1E41, 0x1E41, Ll),
+    (0x1E42, 0x1E42, Lu),
+    (0x1E43, 0x1E43, Ll),
+    (0x1E44, 0x1E44, Lu),
+    (0x1E45, 0x1E45, Ll),
+    (0x1E46, 0x1E46, Lu),
+    (0x1E47, 0x1E47, Ll),
+    (0x1E48, 0x1E48, Lu),
+    (0x1E49, 0x1E49, Ll),
+    (0x1E4A, 0x1E4A, Lu),
+    (0x1E4B, 0x1E4B, Ll),
+    (0x1E4C, 0x1E4C, Lu),
+    (0x1E4D, 0x1E4D, Ll),
+    (0x1E4E, 0x1E4E, Lu),
+    (0x1E4F, 0x1E4F, Ll),
+    (0x1E50, 0x1E50, Lu),
+    (0x1E51, 0x1E51, Ll),
+    (0x1E52, 0x1E52, Lu),
+    (0x1E53, 0x1E53, Ll),
+    (0x1E54, 0x1E54, Lu),
+    (0x1E55, 0x1E55, Ll),
+    (0x1E56, 0x1E56, Lu),
+    (0x1E57, 0x1E57, Ll),
+    (0x1E58, 0x1E58, Lu),
+    (0x1E59, 0x1E59, Ll),
+    (0x1E5A, 0x1E5A, Lu),
+    (0x1E5B, 0x1E5B, Ll),
+    (0x1E5C, 0x1E5C, Lu),
+    (0x1E5D, 0x1E5D, Ll),
+    (0x1E5E, 0x1E5E, Lu),
+    (0x1E5F, 0x1E5F, Ll),
+    (0x1E60, 0x1E60, Lu),
+    (0x1E61, 0x1E61, Ll),
+    (0x1E62, 0x1E62, Lu),
+    (0x1E63, 0x1E63, Ll),
+    (0x1E64, 0x1E64, Lu),
+    (0x1E65, 0x1E65, Ll),
+    (0x1E66, 0x1E66, Lu),
+    (0x1E67, 0x1E67, Ll),
+    (0x1E68, 0x1E68, Lu),
+    (0x1E69, 0x1E69, Ll),
+    (0x1E6A, 0x1E6A, Lu),
+    (0x1E6B, 0x1E6B, Ll),
+    (0x1E6C, 0x1E6C, Lu),
+    (0x1E6D, 0x1E6D, Ll),
+    (0x1E6E, 0x1E6E, Lu),
+    (0x1E6F, 0x1E6F, Ll),
+    (0x1E70, 0x1E70, Lu),
+    (0x1E71, 0x1E71, Ll),
+    (0x1E72, 0x1E72, Lu),
+    (0x1E73, 0x1E73, Ll),
+    (0x1E74, 0x1E74, Lu),
+    (0x1E75, 0x1E75, Ll),
+    (0x1E76, 0x1E76, Lu),
+    (0x1E77, 0x1E77, Ll),
+    (0x1E78, 0x1E78, Lu),
+    (0x1E79, 0x1E79, Ll),
+    (0x1E7A, 0x1E7A, Lu),
+    (0x1E7B, 0x1E7B, Ll),
+    (0x1E7C, 0x1E7C, Lu),
+    (0x1E7D, 0x1E7D, Ll),
+    (0x1E7E, 0x1E7E, Lu),
+    (0x1E7F, 0x1E7F, Ll),
+    (0x1E80, 0x1E80, Lu),
+    (0x1E81, 0x1E81, Ll),
+    (0x1E82, 0x1E82, Lu),
+    (0x1E83, 0x1E83, Ll),
+    (0x1E84, 0x1E84, Lu),
+    (0x1E85, 0x1E85, Ll),
+    (0x1E86, 0x1E86, Lu),
+    (0x1E87, 0x1E87, Ll),
+    (0x1E88, 0x1E88, Lu),
+    (0x1E89, 0x1E89, Ll),
+    (0x1E8A, 0x1E8A, Lu),
+    (0x1E8B, 0x1E8B, Ll),
+    (0x1E8C, 0x1E8C, Lu),
+    (0x1E8D, 0x1E8D, Ll),
+    (0x1E8E, 0x1E8E, Lu),
+    (0x1E8F, 0x1E8F, Ll),
+    (0x1E90, 0x1E90, Lu),
+    (0x1E91, 0x1E91, Ll),
+    (0x1E92, 0x1E92, Lu),
+    (0x1E93, 0x1E93, Ll),
+    (0x1E94, 0x1E94, Lu),
+    (0x1E95, 0x1E9D, Ll),
+    (0x1E9E, 0x1E9E, Lu),
+    (0x1E9F, 0x1E9F, Ll),
+    (0x1EA0, 0x1EA0, Lu),
+    (0x1EA1, 0x1EA1, Ll),
+    (0x1EA2, 0x1EA2, Lu),
+    (0x1EA3, 0x1EA3, Ll),
+    (0x1EA4, 0x1EA4, Lu),
+    (0x1EA5, 0x1EA5, Ll),
+    (0x1EA6, 0x1EA6, Lu),
+    (0x1EA7, 0x1EA7, Ll),
+    (0x1EA8, 0x1EA8, Lu),
+    (0x1EA9, 0x1EA9, Ll),
+    (0x1EAA, 0x1EAA, Lu),
+    (0x1EAB, 0x1EAB, Ll),
+    (0x1EAC, 0x1EAC, Lu),
+    (0x1EAD, 0x1EAD, Ll),
+    (0x1EAE, 0x1EAE, Lu),
+    (0x1EAF, 0x1EAF, Ll),
+    (0x1EB0, 0x1EB0, Lu),
+    (0x1EB1, 0x1EB1, Ll),
+    (0x1EB2, 0x1EB2, Lu),
+    (0x1EB3, 0x1EB3, Ll),
+    (0x1EB4, 0x1EB4, Lu),
+    (0x1EB5, 0x1EB5, Ll),
+    (0x1EB6, 0x1EB6, Lu),
+    (0x1EB7, 0x1EB7, Ll),
+    (0x1EB8, 0x1EB8, Lu),
+    (0x1EB9, 0x1EB9, Ll),
+    (0x1EBA, 0x1EBA, Lu),
+    (0x1EBB, 0x1EBB, Ll),
+    (0x1EBC, 0x1EBC, Lu),
+    (0x1EBD, 0x1EBD, Ll),
+    (0x1EBE, 0x1EBE, Lu),
+    (0x1EBF, 0x1EBF, Ll),
+    (0x1EC0, 0x1EC0, Lu),
+    (0x1EC1, 0x1EC1, Ll),
+    (0x1EC2, 0x1EC2, Lu),
+    (0x1EC3, 0x1EC3, Ll),
+    (0x1EC4, 0x1EC4, Lu),
+    (0x1EC5, 0x1EC5, Ll),
+    (0x1EC6, 0x1EC6, Lu),
+    (0x1EC7, 0x1EC7, Ll),
+    (0x1EC8, 0x1EC8, Lu),
+    (0x1EC9, 0x1EC9, Ll),
+    (0x1ECA, 0x1ECA, Lu),
+    (0x1ECB, 0x1ECB, Ll),
+    (0x1ECC, 0x1ECC, Lu),
+    (0x1ECD, 0x1ECD, Ll),
+    (0x1ECE, 0x1ECE, Lu),
+    (0x1ECF, 0x1ECF, Ll),
+    (0x1ED0, 0x1ED0, Lu),
+    (0x1ED1, 0x1ED1, Ll),
+    (0x1ED2, 0x1ED2, Lu),
+    (0x1ED3, 0x1ED3, Ll),
+    (0x1ED4, 0x1ED4, Lu),
+    (0x1ED5, 0x1ED5, Ll),
+    (0x1ED6, 0x1ED6, Lu),
+    (0x1ED7, 0x1ED7, Ll),
+    (0x1ED8, 0x1ED8, Lu),
+    (0x1ED9, 0x1ED9, Ll),
+    (0x1EDA, 0x1EDA, Lu),
+    (0x1EDB, 0x1EDB, Ll),
+    (0x1EDC, 0x1EDC, Lu),
+    (0x1EDD, 0x1EDD, Ll),
+    (0x1EDE, 0x1EDE, Lu),
+    (0x1EDF, 0x1EDF, Ll),
+    (0x1EE0, 0x1EE0, Lu),
+    (0x1EE1, 0x1EE1, Ll),
+    (0x1EE2, 0x1EE2, Lu),
+    (0x1EE3, 0x1EE3, Ll),
+    (0x1EE4, 0x1EE4, Lu),
+    (0x1EE5, 0x1EE5, Ll),
+    (0x1EE6, 0x1EE6, Lu),
+    (0x1EE7, 0x1EE7, Ll),
+    (0x1EE8, 0x1EE8, Lu),
+    (0x1EE9, 0x1EE9, Ll),
+    (0x1EEA, 0x1EEA, Lu),
+    (0x1EEB, 0x1EEB, Ll),
+    (0x1EEC, 0x1EEC, Lu),
+    (0x1EED, 0x1EED, Ll),
+    (0x1EEE, 0x1EEE, Lu),
+    (0x1EEF, 0x1EEF, Ll),
+    (0x1EF0, 0x1EF0, Lu),
+    (0x1EF1, 0x1EF1, Ll),
+    (0x1EF2, 0x1EF2, Lu),
+    (0x1EF3, 0x1EF3, Ll),
+    (0x1EF4, 0x1EF4, Lu),
+    (0x1EF5, 0x1EF5, Ll),
+    (0x1EF6, 0x1EF6, Lu),
+    (0x1EF7, 0x1EF7, Ll),
+    (0x1EF8, 0x1EF8, Lu),
+    (0x1EF9, 0x1EF9, Ll),
+    (0x1EFA, 0x1EFA, Lu),
+    (0x1EFB, 0x1EFB, Ll),
+    (0x1EFC, 0x1EFC, Lu),
+    (0x1EFD, 0x1EFD, Ll),
+    (0x1EFE, 0x1EFE, Lu),
+    (0x1EFF, 0x1F07, Ll),
+    (0x1F08, 0x1F0F, Lu),
+    (0x1F10, 0x1F15, Ll),
+    (0x1F18, 0x1F1D, Lu),
+    (0x1F20, 0x1F27, Ll),
+    (0x1F28, 0x1F2F, Lu),
+    (0x1F30, 0x1F37, Ll),
+    (0x1F38, 0x1F3F, Lu),
+    (0x1F40, 0x1F45, Ll),
+    (0x1F48, 0x1F4D, Lu),
+    (0x1F50, 0x1F57, Ll),
+    (0x1F59, 0x1F59, Lu),
+    (0x1F5B, 0x1F5B, Lu),
+    (0x1F5D, 0x1F5D, Lu),
+    (0x1F5F, 0x1F5F, Lu),
+    (0x1F60, 0x1F67, Ll),
+    (0x1F68, 0x1F6F, Lu),
+    (0x1F70, 0x1F7D, Ll),
+    (0x1F80, 0x1F87, Ll),
+    (0x1F88, 0x1F8F, Lt),
+    (0x1F90, 0x1F97, Ll),
+    (0x1F98, 0x1F9F, Lt),
+    (0x1FA0, 0x1FA7, Ll),
+    (0x1FA8, 0x1FAF, Lt),
+    (0x1FB0, 0x1FB4, Ll),
+    (0x1FB6, 0x1FB7, Ll),
+    (0x1FB8, 0x1FBB, Lu),
+    (0x1FBC, 0x1FBC, Lt),
+    (0x1FBD, 0x1FBD, Sk),
+    (0x1FBE, 0x1FBE, Ll),
+    (0x1FBF, 0x1FC1, Sk),
+    (0x1FC2, 0x1FC4, Ll),
+    (0x1FC6, 0x1FC7, Ll),
+    (0x1FC8, 0x1FCB, Lu),
+    (0x1FCC, 0x1FCC, Lt),
+    (0x1FCD, 0x1FCF, Sk),
+    (0x1FD0, 0x1FD3, Ll),
+    (0x1FD6, 0x1FD7, Ll),
+    (0x1FD8, 0x1FDB, Lu),
+    (0x1FDD, 0x1FDF, Sk),
+    (0x1FE0, 0x1FE7, Ll),
+    (0x1FE8, 0x1FEC, Lu),
+    (0x1FED, 0x1FEF, Sk),
+    (0x1FF2, 0x1FF4, Ll),
+    (0x1FF6, 0x1FF7, Ll),
+    (0x1FF8, 0x1FFB, Lu),
+    (0x1FFC, 0x1FFC, Lt),
+    (0x1FFD, 0x1FFE, Sk),
+    (0x2000, 0x200A, Zs),
+    (0x200B, 0x200F, Cf),
+    (0x2010, 0x2015, Pd),
+    (0x2016, 0x2017, Po),
+    (0x2018, 0x2018, Pi),
+    (0x2019, 0x2019, Pf),
+    (0x201A, 0x201A, Ps),
+    (0x201B, 0x201C, Pi),
+    (0x201D, 0x201D, Pf),
+    (0x201E, 0x201E, Ps),
+    (0x201F, 0x201F, Pi),
+    (0x2020, 0x2027, Po),
+    (0x2028, 0x2028, Zl),
+    (0x2029, 0x2029, Zp),
+    (0x202A, 0x202E, Cf),
+    (0x202F, 0x202F, Zs),
+    (0x2030, 0x2038, Po),
+    (0x2039, 0x2039, Pi),
+    (0x203A, 0x203A, Pf),
+    (0x203B, 0x203E, Po),
+    (0x203F, 0x2040, Pc),
+    (0x2041, 0x2043, Po),
+    (0x2044, 0x2044, Sm),
+    (0x2045, 0x2045, Ps),
+    (0x2046, 0x2046, Pe),
+    (0x2047, 0x2051, Po),
+    (0x2052, 0x2052, Sm),
+    (0x2053, 0x2053, Po),
+    (0x2054, 0x2054, Pc),
+    (0x2055, 0x205E, Po),
+    (0x205F, 0x205F, Zs),
+    (0x2060, 0x2064, Cf),
+    (0x2066, 0x206F, Cf),
+    (0x2070, 0x2070, No),
+    (0x2071, 0x2071, Lm),
+    (0x2074, 0x2079, No),
+    (0x207A, 0x207C, Sm),
+    (0x207D, 0x207D, Ps),
+    (0x207E, 0x207E, Pe),
+    (0x207F, 0x207F, Lm),
+    (0x2080, 0x2089, No),
+    (0x208A, 0x208C, Sm),
+    (0x208D, 0x208D, Ps),
+    (0x208E, 0x208E, Pe),
+    (0x2090, 0x209C, Lm),
+    (0x20A0, 0x20C0, Sc),
+    (0x20D0, 0x20DC, Mn),
+    (0x20DD, 0x20E0, Me),
+    (0x20E1, 0x20E1, Mn),
+    (0x20E2, 0x20E4, Me),
+    (0x20E5, 0x20F0, Mn),
+    (0x2100, 0x2101, So),
+    (0x2102, 0x2102, Lu),
+    (0x2103, 0x2106, So),
+    (0x2107, 0x2107, Lu),
+    (0x2108, 0x2109, So),
+    (0x210A, 0x210A, Ll),
+    (0x210B, 0x210D, Lu),
+    (0x210E, 0x210F, Ll),
+    (0x2110, 0x2112, Lu),
+    (0x2113, 0x2113, Ll),
+    (0x2114, 0x2114, So),
+    (0x2115, 0x2115, Lu),
+    (0x2116, 0x2117, So),
+    (0x2118, 0x2118, Sm),
+    (0x2119, 0x211D, Lu),
+    (0x211E, 0x2123, So),
+    (0x2124, 0x2124, Lu),
+    (0x2125, 0x2125, So),
+    (0x2126, 0x2126, Lu),
+    (0x2127, 0x2127, So),
+    (0x2128, 0x2128, Lu),
+    (0x2129, 0x2129, So),
+    (0x212A, 0x212D, Lu),
+    (0x212E, 0x212E, So),
+    (0x212F, 0x212F, Ll),
+    (0x2130, 0x2133, Lu),
+    (0x2134, 0x2134, Ll),
+    (0x2135, 0x2138, Lo),
+    (0x2139, 0x2139, Ll),
+    (0x213A, 0x213B, So),
+    (0x213C, 0x213D, Ll),
+    (0x213E, 0x213F, Lu),
+    (0x2140, 0x2144, Sm),
+    (0x2145, 0x2145, Lu),
+    (0x2146, 0x2149, Ll),
+    (0x214A, 0x214A, So),
+    (0x214B, 0x214B, Sm),
+    (0x214C, 0x214D, So),
+    (0x214E, 0x214E, Ll),
+    (0x214F, 0x214F, So),
+    (0x2150, 0x215F, No),
+    (0x2160, 0x2182, Nl),
+    (0x2183, 0x2183, Lu),
+    (0x2184, 0x2184, Ll),
+    (0x2185, 0x2188, Nl),
+    (0x2189, 0x2189, No),
+    (0x218A, 0x218B, So),
+    (0x2190, 0x2194, Sm),
+    (0x2195, 0x2199, So),
+    (0x219A, 0x219B, Sm),
+    (0x219C, 0x219F, So),
+    (0x21A0, 0x21A0, Sm),
+    (0x21A1, 0x21A2, So),
+    (0x21A3, 0x21A3, Sm),
+    (0x21A4, 0x21A5, So),
+    (0x21A6, 0x21A6, Sm),
+    (0x21A7, 0x21AD, So),
+    (0x21AE, 0x21AE, Sm),
+    (0x21AF, 0x21CD, So),
+    (0x21CE, 0x21CF, Sm),
+    (0x21D0, 0x21D1, So),
+    (0x21D2, 0x21D2, Sm),
+    (0x21D3, 0x21D3, So),
+    (0x21D4, 0x21D4, Sm),
+    (0x21D5, 0x21F3, So),
+    (0x21F4, 0x22FF, Sm),
+    (0x2300, 0x2307, So),
+    (0x2308, 0x2308, Ps),
+    (0x2309, 0x2309, Pe),
+    (0x230A, 0x230A, Ps),
+    (0x230B, 0x230B, Pe),
+    (0x230C, 0x231F, So),
+    (0x2320, 0x2321, Sm),
+    (0x2322, 0x2328, So),
+    (0x2329, 0x2329, Ps),
+    (0x232A, 0x232A, Pe),
+    (0x232B, 0x237B, So),
+    (0x237C, 0x237C, Sm),
+    (0x237D, 0x239A, So),
+    (0x239B, 0x23B3, Sm),
+    (0x23B4, 0x23DB, So),
+    (0x23DC, 0x23E1, Sm),
+    (0x23E2, 0x2426, So),
+    (0x2440, 0x244A, So),
+    (0x2460, 0x249B, No),
+    (0x249C, 0x24E9, So),
+    (0x24EA, 0x24FF, No),
+    (0x2500, 0x25B6, So),
+    (0x25B7, 0x25B7, Sm),
+    (0x25B8, 0x25C0, So),
+    (0x25C1, 0x25C1, Sm),
+    (0x25C2, 0x25F7, So),
+    (0x25F8, 0x25FF, Sm),
+    (0x2600, 0x266E, So),
+    (0x266F, 0x266F, Sm),
+    (0x2670, 0x2767, So),
+    (0x2768, 0x2768, Ps),
+    (0x2769, 0x2769, Pe),
+    (0x276A, 0x276A, Ps),
+    (0x276B, 0x276B, Pe),
+    (0x276C, 0x276C, Ps),
+    (0x276D, 0x276D, Pe),
+    (0x276E, 0x276E, Ps),
+    (0x276F, 0x276F, Pe),
+    (0x2770, 0x2770, Ps),
+    (0x2771, 0x2771, Pe),
+    (0x2772, 0x2772, Ps),
+    (0x2773, 0x2773, Pe),
+    (0x2774, 0x2774, Ps),
+    (0x2775, 0x2775, Pe),
+    (0x2776, 0x2793, No),
+    (0x2794, 0x27BF, So),
+    (0x27C0, 0x27C4, Sm),
+    (0x27C5, 0x27C5, Ps),
+    (0x27C6, 0x27C6, Pe),
+    (0x27C7, 0x27E5, Sm),
+    (0x27E6, 0x27E6, Ps),
+    (0x27E7, 0x27E7, Pe),
+    (0x27E8, 0x27E8, Ps),
+    (0x27E9, 0x27E9, Pe),
+    (0x27EA, 0x27EA, Ps),
+    (0x27EB, 0x27EB, Pe),
+    (0x27EC, 0x27EC, Ps),
+    (0x27ED, 0x27ED, Pe),
+    (0x27EE, 0x27EE, Ps),
+    (0x27EF, 0x27EF, Pe),
+    (0x27F0, 0x27FF, Sm),
+    (0x2800, 0x28FF, So),
+    (0x2900, 0x2982, Sm),
+    (0x2983, 0x2983, Ps),
+    (0x2984, 0x2984, Pe),
+    (0x2985, 0x2985, Ps),
+    (0x2986, 0x2986, Pe),
+    (0x2987, 0x2987, Ps),
+    (0x2988, 0x2988, Pe),
+    (0x2989, 0x2989, Ps),
+    (0x298A, 0x298A, Pe),
+    (0x298B, 0x298B, Ps),
+    (0x298C, 0x298C, Pe),
+    (0x298D, 0x298D, Ps),
+    (0x298E, 0x298E, Pe),
+    (0x298F, 0x298F, Ps),
+    (0x2990, 0x2990, Pe),
+    (0x2991, 0x2991, Ps),
+    (0x2992, 0x2992, Pe),
+    (0x2993, 0x2993, Ps),
+    (0x2994, 0x2994, Pe),
+    (0x2995, 0x2995, Ps),
+    (0x2996, 0x2996, Pe),
+    (0x2997, 0x2997, Ps),
+    (0x2998, 0x2998, Pe),
+    (0x2999, 0x29D7, Sm),
+    (0x29D8, 0x29D8, Ps),
+    (0x29D9, 0x29D9, Pe),
+    (0x29DA, 0x29DA, Ps),
+    (0x29DB, 0x29DB, Pe),
+    (0x29DC, 0x29FB, Sm),
+    (0x29FC, 0x29FC, Ps),
+    (0x29FD, 0x29FD, Pe),
+    (0x29FE, 0x2AFF, Sm),
+    (0x2B00, 0x2B2F, So),
+    (0x2B30, 0x2B44, Sm),
+    (0x2B45, 0x2B46, So),
+    (0x2B47, 0x2B4C, Sm),
+    (0x2B4D, 0x2B73, So),
+    (0x2B76, 0x2B95, So),
+    (0x2B97, 0x2BFF, So),
+    (0x2C00, 0x2C2F, Lu),
+    (0x2C30, 0x2C5F, Ll),
+    (0x2C60, 0x2C60, Lu),
+    (0x2C61, 0x2C61, Ll),
+    (0x2C62, 0x2C64, Lu),
+    (0x2C65, 0x2C66, Ll),
+    (0x2C67, 0x2C67, Lu),
+    (0x2C68, 0x2C68, Ll),
+    (0x2C69, 0x2C69, Lu),
+    (0x2C6A, 0x2C6A, Ll),
+    (0x2C6B, 0x2C6B, Lu),
+    (0x2C6C, 0x2C6C, Ll),
+    (0x2C6D, 0x2C70, Lu),
+    (0x2C71, 0x2C71, Ll),
+    (0x2C72, 0x2C72, Lu),
+    (0x2C73, 0x2C74, Ll),
+    (0x2C75, 0x2C75, Lu),
+    (0x2C76, 0x2C7B, Ll),
+    (0x2C7C, 0x2C7D, Lm),
+    (0x2C7E, 0x2C80, Lu),
+    (0x2C81, 0x2C81, Ll),
+    (0x2C82, 0x2C82, Lu),
+    (0x2C83, 0x2C83, Ll),
+    (0x2C84, 0x2C84, Lu),
+    (0x2C85, 0x2C85, Ll),
+    (0x2C86, 0x2C86, Lu),
+    (0x2C87, 0x2C87, Ll),
+    (0x2C88, 0x2C88, Lu),
+    (0x2C89, 0x2C89, Ll),
+    (0x2C8A, 0x2C8A, Lu),
+    (0x2C8B, 0x2C8B, Ll),
+    (0x2C8C, 0x2C8C, Lu),
+    (0x2C8D, 0x2C8D, Ll),
+    (0x2C8E, 0x2C8E, Lu),
+    (0x2C8F, 0x2C8F, Ll),
+    (0x2C90, 0x2C90, Lu),
+    (0x2C91, 0x2C91, Ll),
+    (0x2C92, 0x2C92, Lu),
+    (0x2C93, 0x2C93, Ll),
+    (0x2C94, 0x2C94, Lu),
+    (0x2C95, 0x2C95, Ll),
+    (0x2C96, 0x2C96, Lu),
+    (0x2C97, 0x2C97, Ll),
+    (0x2C98, 0x2C98, Lu),
+    (0x2C99, 0x2C99, Ll),
+    (0x2C9A, 0x2C9A, Lu),
+    (0x2C9B, 0x2C9B, Ll),
+    (0x2C9C, 0x2C9C, Lu),
+    (0x2C9D, 0x2C9D, Ll),
+    (0x2C9E, 0x2C9E, Lu),
+    (0x2C9F, 0x2C9F, Ll),
+    (0x2CA0, 0x2CA0, Lu),
+    (0x2CA1, 0x2CA1, Ll),
+    (0x2CA2, 0x2CA2, Lu),
+    (0x2CA3, 0x2CA3, Ll),
+    (0x2CA4, 0x2CA4, Lu),
+    (0x2CA5, 0x2CA5, Ll),
+    (0x2CA6, 0x2CA6, Lu),
+    (0x2CA7, 0x2CA7, Ll),
+    (0x2CA8, 0x2CA8, Lu),
+    (0x2CA9, 0x2CA9, Ll),
+    (0x2CAA, 0x2CAA, Lu),
+    (0x2CAB, 0x2CAB, Ll),
+    (0x2CAC, 0x2CAC, Lu),
+    (0x2CAD, 0x2CAD, Ll),
+    (0x2CAE, 0x2CAE, Lu),
+    (0x2CAF, 0x2CAF, Ll),
+    (0x2CB0, 0x2CB0, Lu),
+    (0x2CB1, 0x2CB1, Ll),
+    (0x2CB2, 0x2CB2, Lu),
+    (0x2CB3, 0x2CB3, Ll),
+    (0x2CB4, 0x2CB4, Lu),
+    (0x2CB5, 0x2CB5, Ll),
+    (0x2CB6, 0x2CB6, Lu),
+    (0x2CB7, 0x2CB7, Ll),
+    (0x2CB8, 0x2CB8, Lu),
+    (0x2CB9, 0x2CB9, Ll),
+    (0x2CBA, 0x2CBA, Lu),
+    (0x2CBB, 0x2CBB, Ll),
+    (0x2CBC, 0x2CBC, Lu),
+    (0x2CBD, 0x2CBD, Ll),
+    (0x2CBE, 0x2CBE, Lu),
+    (0x2CBF, 0x2CBF, Ll),
+    (0x2CC0, 0x2CC0, Lu),
+    (0x2CC1, 0x2CC1, Ll),
+    (0x2CC2, 0x2CC2, Lu),
+    (0x2CC3, 0x2CC3, Ll),
+    (0x2CC4, 0x2CC4, Lu),
+    (0x2CC5, 0x2CC5, Ll),
+    (0x2CC6, 0x2CC6, Lu),
+    (0x2CC7, 0x2CC7, Ll),
+    (0x2CC8, 0x2CC8, Lu),
+    (0x2CC9, 0x2CC9, Ll),
+    (0x2CCA, 0x2CCA, Lu),
+    (0x2CCB, 0x2CCB, Ll),
+    (0x2CCC, 0x2CCC, Lu),
+    (0x2CCD, 0x2CCD, Ll),
+    (0x2CCE, 0x2CCE, Lu),
+    (0x2CCF, 0x2CCF, Ll),
+    (0x2CD0, 0x2CD0, Lu),
+    (0x2CD1, 0x2CD1, Ll),
+    (0x2CD2, 0x2CD2, Lu),
+    (0x2CD3, 0x2CD3, Ll),
+    (0x2CD4, 0x2CD4, Lu),
+    (0x2CD5, 0x2CD5, Ll),
+    (0x2CD6, 0x2CD6, Lu),
+    (0x2CD7, 0x2CD7, Ll),
+    (0x2CD8, 0x2CD8, Lu),
+    (0x2CD9, 0x2CD9, Ll),
+    (0x2CDA, 0x2CDA, Lu),
+    (0x2CDB, 0x2CDB, Ll),
+    (0x2CDC, 0x2CDC, Lu),
+    (0x2CDD, 0x2CDD, Ll),
+    (0x2CDE, 0x2CDE, Lu),
+    (0x2CDF, 0x2CDF, Ll),
+    (0x2CE0, 0x2CE0, Lu),
+    (0x2CE1, 0x2CE1, Ll),
+    (0x2CE2, 0x2CE2, Lu),
+    (0x2CE3, 0x2CE4, Ll),
+    (0x2CE5, 0x2CEA, So),
+    (0x2CEB, 0x2CEB, Lu),
+    (0x2CEC, 0x2CEC, Ll),
+    (0x2CED, 0x2CED, Lu),
+    (0x2CEE, 0x2CEE, Ll),
+    (0x2CEF, 0x2CF1, Mn),
+    (0x2CF2, 0x2CF2, Lu),
+    (0x2CF3, 0x2CF3, Ll),
+    (0x2CF9, 0x2CFC, Po),
+    (0x2CFD, 0x2CFD, No),
+    (0x2CFE, 0x2CFF, Po),
+    (0x2D00, 0x2D25, Ll),
+    (0x2D27, 0x2D27, Ll),
+    (0x2D2D, 0x2D2D, Ll),
+    (0x2D30, 0x2D67, Lo),
+    (0x2D6F, 0x2D6F, Lm),
+    (0x2D70, 0x2D70, Po),
+    (0x2D7F, 0x2D7F, Mn),
+    (0x2D80, 0x2D96, Lo),
+    (0x2DA0, 0x2DA6, Lo),
+    (0x2DA8, 0x2DAE, Lo),
+    (0x2DB0, 0x2DB6, Lo),
+    (0x2DB8, 0x2DBE, Lo),
+    (0x2DC0, 0x2DC6, Lo),
+    (0x2DC8, 0x2DCE, Lo),
+    (0x2DD0, 0x2DD6, Lo),
+    (0x2DD8, 0x2DDE, Lo),
+    (0x2DE0, 0x2DFF, Mn),
+    (0x2E00, 0x2E01, Po),
+    (0x2E02, 0x2E02, Pi),
+    (0x2E03, 0x2E03, Pf),
+    (0x2E04, 0x2E04, Pi),
+    (0x2E05, 0x2E05, Pf),
+    (0x2E06, 0x2E08, Po),
+    (0x2E09, 0x2E09, Pi),
+    (0x2E0A, 0x2E0A, Pf),
+    (0x2E0B, 0x2E0B, Po),
+    (0x2E0C, 0x2E0C, Pi),
+    (0x2E0D, 0x2E0D, Pf),
+    (0x2E0E, 0x2E16, Po),
+    (0x2E17, 0x2E17, Pd),
+    (0x2E18, 0x2E19, Po),
+    (0x2E1A, 0x2E1A, Pd),
+    (0x2E1B, 0x2E1B, Po),
+    (0x2E1C, 0x2E1C, Pi),
+    (0x2E1D, 0x2E1D, Pf),
+    (0x2E1E, 0x2E1F, Po),
+    (0x2E20, 0x2E20, Pi),
+    (0x2E21, 0x2E21, Pf),
+    (0x2E22, 0x2E22, Ps),
+    (0x2E23, 0x2E23, Pe),
+    (0x2E24, 0x2E24, Ps),
+    (0x2E25, 0x2E25, Pe),
+    (0x2E26, 0x2E26, Ps),
+    (0x2E27, 0x2E27, Pe),
+    (0x2E28, 0x2E28, Ps),
+    (0x2E29, 0x2E29, Pe),
+    (0x2E2A, 0x2E2E, Po),
+    (0x2E2F, 0x2E2F, Lm),
+    (0x2E30, 0x2E39, Po),
+    (0x2E3A, 0x2E3B, Pd),
+    (0x2E3C, 0x2E3F, Po),
+    (0x2E40, 0x2E40, Pd),
+    (0x2E41, 0x2E41, Po),
+    (0x2E42, 0x2E42, Ps),
+    (0x2E43, 0x2E4F, Po),
+    (0x2E50, 0x2E51, So),
+    (0x2E52, 0x2E54, Po),
+    (0x2E55, 0x2E55, Ps),
+    (0x2E56, 0x2E56, Pe),
+    (0x2E57, 0x2E57, Ps),
+    (0x2E58, 0x2E58, Pe),
+    (0x2E59, 0x2E59, Ps),
+    (0x2E5A, 0x2E5A, Pe),
+    (0x2E5B, 0x2E5B, Ps),
+    (0x2E5C, 0x2E5C, Pe),
+    (0x2E5D, 0x2E5D, Pd),
+    (0x2E80, 0x2E99, So),
+    (0x2E9B, 0x2EF3, So),
+    (0x2F00, 0x2FD5, So),
+    (0x2FF0, 0x2FFB, So),
+    (0x3000, 0x3000, Zs),
+    (0x3001, 0x3003, Po),
+    (0x3004, 0x3004, So),
+    (0x3005, 0x3005, Lm),
+    (0x3006, 0x3006, Lo),
+    (0x3007, 0x3007, Nl),
+    (0x3008, 0x3008, Ps),
+    (0x3009, 0x3009, Pe),
+    (0x300A, 0x300A, Ps),
+    (0x300B, 0x300B, Pe),
+    (0x300C, 0x300C, Ps),
+    (0x300D, 0x300D, Pe),
+    (0x300E, 0x300E, Ps),
+    (0x300F, 0x300F, Pe),
+    (0x3010, 0x3010, Ps),
+    (0x3011, 0x3011, Pe),
+    (0x3012, 0x3013, So),
+    (0x3014, 0x3014, Ps),
+    (0x3015, 0x3015, Pe),
+    (0x3016, 0x3016, Ps),
+    (0x3017, 0x3017, Pe),
+    (0x3018, 0x3018, Ps),
+    (0x3019, 0x3019, Pe),
+    (0x301A, 0x301A, Ps),
+    (0x301B, 0x301B, Pe),
+    (0x301C, 0x301C, Pd),
+    (0x301D, 0x301D, Ps),
+    (0x301E, 0x301F, Pe),
+    (0x3020, 0x3020, So),
+    (0x3021, 0x3029, Nl),
+    (0x302A, 0x302D, Mn),
+    (0x302E, 0x302F, Mc),
+    (0x3030, 0x3030, Pd),
+    (0x3031, 0x3035, Lm),
+    (0x3036, 0x3037, So),
+    (0x3038, 0x303A, Nl),
+    (0x303B, 0x303B, Lm),
+    (0x303C, 0x303C, Lo),
+    (0x303D, 0x303D, Po),
+    (0x303E, 0x303F, So),
+    (0x3041, 0x3096, Lo),
+    (0x3099, 0x309A, Mn),
+    (0x309B, 0x309C, Sk),
+    (0x309D, 0x309E, Lm),
+    (0x309F, 0x309F, Lo),
+    (0x30A0, 0x30A0, Pd),
+    (0x30A1, 0x30FA, Lo),
+    (0x30FB, 0x30FB, Po),
+    (0x30FC, 0x30FE, Lm),
+    (0x30FF, 0x30FF, Lo),
+    (0x3105, 0x312F, Lo),
+    (0x3131, 0x318E, Lo),
+    (0x3190, 0x3191, So),
+    (0x3192, 0x3195, No),
+    (0x3196, 0x319F, So),
+    (0x31A0, 0x31BF, Lo),
+    (0x31C0, 0x31E3, So),
+    (0x31F0, 0x31FF, Lo),
+    (0x3200, 0x321E, So),
+    (0x3220, 0x3229, No),
+    (0x322A, 0x3247, So),
+    (0x3248, 0x324F, No),
+    (0x3250, 0x3250, So),
+    (0x3251, 0x325F, No),
+    (0x3260, 0x327F, So),
+    (0x3280, 0x3289, No),
+    (0x328A, 0x32B0, So),
+    (0x32B1, 0x32BF, No),
+    (0x32C0, 0x33FF, So),
+    (0x3400, 0x4DBF, Lo),
+    (0x4DC0, 0x4DFF, So),
+    (0x4E00, 0xA014, Lo),
+    (0xA015, 0xA015, Lm),
+    (0xA016, 0xA48C, Lo),
+    (0xA490, 0xA4C6, So),
+    (0xA4D0, 0xA4F7, Lo),
+    (0xA4F8, 0xA4FD, Lm),
+    (0xA4FE, 0xA4FF, Po),
+    (0xA500, 0xA60B, Lo),
+    (0xA60C, 0xA60C, Lm),
+    (0xA60D, 0xA60F, Po),
+    (0xA610, 0xA61F, Lo),
+    (0xA620, 0xA629, Nd),
+    (0xA62A, 0xA62B, Lo),
+    (0xA640, 0xA640, Lu),
+    (0xA641, 0xA641, Ll),
+    (0xA642, 0xA642, Lu),
+    (0xA643, 0xA643, Ll),
+    (0xA644, 0xA644, Lu),
+    (0xA645, 0xA645, Ll),
+    (0xA646, 0xA646, Lu),
+    (0xA647, 0xA647, Ll),
+    (0xA648, 0xA648, Lu),
+    (0xA649, 0xA649, Ll),
+    (0xA64A, 0xA64A, Lu),
+    (0xA64B, 0xA64B, Ll),
+    (0xA64C, 0xA64C, Lu),
+    (0xA64D, 0xA64D, Ll),
+    (0xA64E, 0xA64E, Lu),
+    (0xA64F, 0xA64F, Ll),
+    (0xA650, 0xA650, Lu),
+    (0xA651, 0xA651, Ll),
+    (0xA652, 0xA652, Lu),
+    (0xA653, 0xA653, Ll),
+    (0xA654, 0xA654, Lu),
+    (0xA655, 0xA655, Ll),
+    (0xA656, 0xA656, Lu),
+    (0xA657, 0xA657, Ll),
+    (0xA658, 0xA658, Lu),
+    (0xA659, 0xA659, Ll),
+    (0xA65A, 0xA65A, Lu),
+    (0xA65B, 0xA65B, Ll),
+    (0xA65C, 0xA65C, Lu),
+    (0xA65D, 0xA65D, Ll),
+    (0xA65E, 0xA65E, Lu),
+    (0xA65F, 0xA65F, Ll),
+    (0xA660, 0xA660, Lu),
+    (0xA661, 0xA661, Ll),
+    (0xA662, 0xA662, Lu),
+    (0xA663, 0xA663, Ll),
+    (0xA664, 0xA664, Lu),
+    (0xA665, 0xA665, Ll),
+    (0xA666, 0xA666, Lu),
+    (0xA667, 0xA667, Ll),
+    (0xA668, 0xA668, Lu),
+    (0xA669, 0xA669, Ll),
+    (0xA66A, 0xA66A, Lu),
+    (0xA66B, 0xA66B, Ll),
+    (0xA66C, 0xA66C, Lu),
+    (0xA66D, 0xA66D, Ll),
+    (0xA66E, 0xA66E, Lo),
+    (0xA66F, 0xA66F, Mn),
+    (0xA670, 0xA672, Me),
+    (0xA673, 0xA673, Po),
+    (0xA674, 0xA67D, Mn),
+    (0xA67E, 0xA67E, Po),
+    (0xA67F, 0xA67F, Lm),
+    (0xA680, 0xA680, Lu),
+    (0xA681, 0xA681, Ll),
+    (0xA682, 0xA682, Lu),
+    (0xA683, 0xA683, Ll),
+    (0xA684, 0xA684, Lu),
+    (0xA685, 0xA685, Ll),
+    (0xA686, 0xA686, Lu),
+    (0xA687, 0xA687, Ll),
+    (0xA688, 0xA688, Lu),
+    (0xA689, 0xA689, Ll),
+    (0xA68A, 0xA68A, Lu),
+    (0xA68B, 0xA68B, Ll),
+    (0xA68C, 0xA68C, Lu),
+    (0xA68D, 0xA68D, Ll),
+    (0xA68E, 0xA68E, Lu),
+    (0xA68F, 0xA68F, Ll),
+    (0xA690, 0xA690, Lu),
+    (0xA691, 0xA691, Ll),
+    (0xA692, 0xA692, Lu),
+    (0xA693, 0xA693, Ll),
+    (0xA694, 0xA694, Lu),
+    (0xA695, 0xA695, Ll),
+    (0xA696, 0xA696, Lu),
+    (0xA697, 0xA697, Ll),
+    (0xA698, 0xA698, Lu),
+    (0xA699, 0xA699, Ll),
+    (0xA69A, 0xA69A, Lu),
+    (0xA69B, 0xA69B, Ll),
+    (0xA69C, 0xA69D, Lm),
+    (0xA69E, 0xA69F, Mn),
+    (0xA6A0, 0xA6E5, Lo),
+    (0xA6E6, 0xA6EF, Nl),
+    (0xA6F0, 0xA6F1, Mn),
+    (0xA6F2, 0xA6F7, Po),
+    (0xA700, 0xA716, Sk),
+    (0xA717, 0xA71F, Lm),
+    (0xA720, 0xA721, Sk),
+    (0xA722, 0xA722, Lu),
+    (0xA723, 0xA723, Ll),
+    (0xA724, 0xA724, Lu),
+    (0xA725, 0xA725, Ll),
+    (0xA726, 0xA726, Lu),
+    (0xA727, 0xA727, Ll),
+    (0xA728, 0xA728, Lu),
+    (0xA729, 0xA729, Ll),
+    (0xA72A, 0xA72A, Lu),
+    (0xA72B, 0xA72B, Ll),
+    (0xA72C, 0xA72C, Lu),
+    (0xA72D, 0xA72D, Ll),
+    (0xA72E, 0xA72E, Lu),
+    (0xA72F, 0xA731, Ll),
+    (0xA732, 0xA732, Lu),
+    (0xA733, 0xA733, Ll),
+    (0xA734, 0xA734, Lu),
+    (0xA735, 0xA735, Ll),
+    (0xA736, 0xA736, Lu),
+    (0xA737, 0xA737, Ll),
+    (0xA738, 0xA738, Lu),
+    (0xA739, 0xA739, Ll),
+    (0xA73A, 0xA73A, Lu),
+    (0xA73B, 0xA73B, Ll),
+    (0xA73C, 0xA73C, Lu),
+    (0xA73D, 0xA73D, Ll),
+    (0xA73E, 0xA73E, Lu),
+    (0xA73F, 0xA73F, Ll),
+    (0xA740, 0xA740, Lu),
+    (0xA741, 0xA741, Ll),
+    (0xA742, 0xA742, Lu),
+    (0xA743, 0xA743, Ll),
+    (0xA744, 0xA744, Lu),
+    (0xA745, 0xA745, Ll),
+    (0xA746, 0xA746, Lu),
+    (0xA747, 0xA747, Ll),
+    (0xA748, 0xA748, Lu),
+    (0xA749, 0xA749, Ll),
+    (0xA74A, 0xA74A, Lu),
+    (0xA74B, 0xA74B, Ll),
+    (0xA74C, 0xA74C, Lu),
+    (0xA74D, 0xA74D, Ll),
+    (0xA74E, 0xA74E, Lu),
+    (0xA74F, 0xA74F, Ll),
+    (0xA750, 0xA750, Lu),
+    (0xA751, 0xA751, Ll),
+    (0xA752, 0xA752, Lu),
+    (0xA753, 0xA753, Ll),
+    (0xA754, 0xA754, Lu),
+    (0xA755, 0xA755, Ll),
+    (0xA756, 0xA756, Lu),
+    (0xA757, 0xA757, Ll),
+    (0xA758, 0xA758, Lu),
+    (0xA759, 0xA759, Ll),
+    (0xA75A, 0xA75A, Lu),
+    (0xA75B, 0xA75B, Ll),
+    (0xA75C, 0xA75C, Lu),
+    (0xA75D, 0xA75D, Ll),
+    (0xA75E, 0xA75E, Lu),
+    (0xA75F, 0xA75F, Ll),
+    (0xA760, 0xA760, Lu),
+    (0xA761, 0xA761, Ll),
+    (0xA762, 0xA762, Lu),
+    (0xA763, 0xA763, Ll),
+    (0xA764, 0xA764, Lu),
+    (0xA765, 0xA765, Ll),
+    (0xA766, 0xA766, Lu),
+    (0xA767, 0xA767, Ll),
+    (0xA768, 0xA768, Lu),
+    (0xA769, 0xA769, Ll),
+    (0xA76A, 0xA76A, Lu),
+    (0xA76B, 0xA76B, Ll),
+    (0xA76C, 0xA76C, Lu),
+    (0xA76D, 0xA76D, Ll),
+    (0xA76E, 0xA76E, Lu),
+    (0xA76F, 0xA76F, Ll),
+    (0xA770, 0xA770, Lm),
+    (0xA771, 0xA778, Ll),
+    (0xA779, 0xA779, Lu),
+    (0xA77A, 0xA77A, Ll),
+    (0xA77B, 0xA77B, Lu),
+    (0xA77C, 0xA77C, Ll),
+    (0xA77D, 0xA77E, Lu),
+    (0xA77F, 0xA77F, Ll),
+    (0xA780, 0xA780, Lu),
+    (0xA781, 0xA781, Ll),
+    (0xA782, 0xA782, Lu),
+    (0xA783, 0xA783, Ll),
+    (0xA784, 0xA784, Lu),
+    (0xA785, 0xA785, Ll),
+    (0xA786, 0xA786, Lu),
+    (0xA787, 0xA787, Ll),
+    (0xA788, 0xA788, Lm),
+    (0xA789, 0xA78A, Sk),
+    (0xA78B, 0xA78B, Lu),
+    (0xA78C, 0xA78C, Ll),
+    (0xA78D, 0xA78D, Lu),
+    (0xA78E, 0xA78E, Ll),
+    (0xA78F, 0xA78F, Lo),
+    (0xA790, 0xA790, Lu),
+    (0xA791, 0xA791, Ll),
+    (0xA792, 0xA792, Lu),
+    (0xA793, 0xA795, Ll),
+    (0xA796, 0xA796, Lu),
+    (0xA797, 0xA797, Ll),
+    (0xA798, 0xA798, Lu),
+    (0xA799, 0xA799, Ll),
+    (0xA79A, 0xA79A, Lu),
+    (0xA79B, 0xA79B, Ll),
+    (0xA79C, 0xA79C, Lu),
+    (0xA79D, 0xA79D, Ll),
+    (0xA79E, 0xA79E, Lu),
+    (0xA79F, 0xA79F, Ll),
+    (0xA7A0, 0xA7A0, Lu),
+    (0xA7A1, 0xA7A1, Ll),
+    (0xA7A2, 0xA7A2, Lu),
+    (0xA7A3, 0xA7A3, Ll),
+    (0xA7A4, 0xA7A4, Lu),
+    (0xA7A5, 0xA7A5, Ll),
+    (0xA7A6, 0xA7A6, Lu),
+    (0xA7A7, 0xA7A7, Ll),
+    (0xA7A8, 0xA7A8, Lu),
+    (0xA7A9, 0xA7A9, Ll),
+    (0xA7AA, 0xA7AE, Lu),
+    (0xA7AF, 0xA7AF, Ll),
+    (0xA7B0, 0xA7B4, Lu),
+    (0xA7B5, 0xA7B5, Ll),
+    (0xA7B6, 0xA7B6, Lu),
+    (0xA7B7, 0xA7B7, Ll),
+    (0xA7B8, 0xA7B8, Lu),
+    (0xA7B9, 0xA7B9, Ll),
+    (0xA7BA, 0xA7BA, Lu),
+    (0xA7BB, 0xA7BB, Ll),
+    (0xA7BC, 0xA7BC, Lu),
+    (0xA7BD, 0xA7BD, Ll),
+    (0xA7BE, 0xA7BE, Lu),
+    (0xA7BF, 0xA7BF, Ll),
+    (0xA7C0, 0xA7C0, Lu),
+    (0xA7C1, 0xA7C1, Ll),
+    (0xA7C2, 0xA7C2, Lu),
+    (0xA7C3, 0xA7C3, Ll),
+    (0xA7C4, 0xA7C7, Lu),
+    (0xA7C8, 0xA7C8, Ll),
+    (0xA7C9, 0xA7C9, Lu),
+    (0xA7CA, 0xA7CA, Ll),
+    (0xA7D0, 0xA7D0, Lu),
+    (0xA7D1, 0xA7D1, Ll),
+    (0xA7D3, 0xA7D3, Ll),
+    (0xA7D5, 0xA7D5, Ll),
+    (0xA7D6, 0xA7D6, Lu),
+    (0xA7D7, 0xA7D7, Ll),
+    (0xA7D8, 0xA7D8, Lu),
+    (0xA7D9, 0xA7D9, Ll),
+    (0xA7F2, 0xA7F4, Lm),
+    (0xA7F5, 0xA7F5, Lu),
+    (0xA7F6, 0xA7F6, Ll),
+    (0xA7F7, 0xA7F7, Lo),
+    (0xA7F8, 0xA7F9, Lm),
+    (0xA7FA, 0xA7FA, Ll),
+    (0xA7FB, 0xA801, Lo),
+    (0xA802, 0xA802, Mn),
+    (0xA803, 0xA805, Lo),
+    (0xA806, 0xA806, Mn),
+    (0xA807, 0xA80A, Lo),
+    (0xA80B, 0xA80B, Mn),
+    (0xA80C, 0xA822, Lo),
+    (0xA823, 0xA824, Mc),
+    (0xA825, 0xA826, Mn),
+    (0xA827, 0xA827, Mc),
+    (0xA828, 0xA82B, So),
+    (0xA82C, 0xA82C, Mn),
+    (0xA830, 0xA835, No),
+    (0xA836, 0xA837, So),
+    (0xA838, 0xA838, Sc),
+    (0xA839, 0xA839, So),
+    (0xA840, 0xA873, Lo),
+    (0xA874, 0xA877, Po),
+    (0xA880, 0xA881, Mc),
+    (0xA882, 0xA8B3, Lo),
+    (0xA8B4, 0xA8C3, Mc),
+    (0xA8C4, 0xA8C5, Mn),
+    (0xA8CE, 0xA8CF, Po),
+    (0xA8D0, 0xA8D9, Nd),
+    (0xA8E0, 0xA8F1, Mn),
+    (0xA8F2, 0xA8F7, Lo),
+    (0xA8F8, 0xA8FA, Po),
+    (0xA8FB, 0xA8FB, Lo),
+    (0xA8FC, 0xA8FC, Po),
+    (0xA8FD, 0xA8FE, Lo),
+    (0xA8FF, 0xA8FF, Mn),
+    (0xA900, 0xA909, Nd),
+    (0xA90A, 0xA925, Lo),
+    (0xA926, 0xA92D, Mn),
+    (0xA92E, 0xA92F, Po),
+    (0xA930, 0xA946, Lo),
+    (0xA947, 0xA951, Mn),
+    (0xA952, 0xA953, Mc),
+    (0xA95F, 0xA95F, Po),
+    (0xA960, 0xA97C, Lo),
+    (0xA980, 0xA982, Mn),
+    (0xA983, 0xA983, Mc),
+    (0xA984, 0xA9B2, Lo),
+    (0xA9B3, 0xA9B3, Mn),
+    (0xA9B4, 0xA9B5, Mc),
+    (0xA9B6, 0xA9B9, Mn),
+    (0xA9BA, 0xA9BB, Mc),
+    (0xA9BC, 0xA9BD, Mn),
+    (0xA9BE, 0xA9C0, Mc),
+    (0xA9C1, 0xA9CD, Po),
+    (0xA9CF, 0xA9CF, Lm),
+    (0xA9D0, 0xA9D9, Nd),
+    (0xA9DE, 0xA9DF, Po),
+    (0xA9E0, 0xA9E4, Lo),
+    (0xA9E5, 0xA9E5, Mn),
+    (0xA9E6, 0xA9E6, Lm),
+    (0xA9E7, 0xA9EF, Lo),
+    (0xA9F0, 0xA9F9, Nd),
+    (0xA9FA, 0xA9FE, Lo),
+    (0xAA00, 0xAA28, Lo),
+    (0xAA29, 0xAA2E, Mn),
+    (0xAA2F, 0xAA30, Mc),
+    (0xAA31, 0xAA32, Mn),
+    (0xAA33, 0xAA34, Mc),
+    (0xAA35, 0xAA36, Mn),
+    (0xAA40, 0xAA42, Lo),
+    (0xAA43, 0xAA43, Mn),
+    (0xAA44, 0xAA4B, Lo),
+    (0xAA4C, 0xAA4C, Mn),
+    (0xAA4D, 0xAA4D, Mc),
+    (0xAA50, 0xAA59, Nd),
+    (0xAA5C, 0xAA5F, Po),
+    (0xAA60, 0xAA6F, Lo),
+    (0xAA70, 0xAA70, Lm),
+    (0xAA71, 0xAA76, Lo),
+    (0xAA77, 0xAA79, So),
+    (0xAA7A, 0xAA7A, Lo),
+    (0xAA7B, 0xAA7B, Mc),
+    (0xAA7C, 0xAA7C, Mn),
+    (0xAA7D, 0xAA7D, Mc),
+    (0xAA7E, 0xAAAF, Lo),
+    (0xAAB0, 0xAAB0, Mn),
+    (0xAAB1, 0xAAB1, Lo),
+    (0xAAB2, 0xAAB4, Mn),
+    (0xAAB5, 0xAAB6, Lo),
+    (0xAAB7, 0xAAB8, Mn),
+    (0xAAB9, 0xAABD, Lo),
+    (0xAABE, 0xAABF, Mn),
+    (0xAAC0, 0xAAC0, Lo),
+    (0xAAC1, 0xAAC1, Mn),
+    (0xAAC2, 0xAAC2, Lo),
+    (0xAADB, 0xAADC, Lo),
+    (0xAADD, 0xAADD, Lm),
+    (0xAADE, 0xAADF, Po),
+    (0xAAE0, 0xAAEA, Lo),
+    (0xAAEB, 0xAAEB, Mc),
+    (0xAAEC, 0xAAED, Mn),
+    (0xAAEE, 0xAAEF, Mc),
+    (0xAAF0, 0xAAF1, Po),
+    (0xAAF2, 0xAAF2, Lo),
+    (0xAAF3, 0xAAF4, Lm),
+    (0xAAF5, 0xAAF5, Mc),
+    (0xAAF6, 0xAAF6, Mn),
+    (0xAB01, 0xAB06, Lo),
+    (0xAB09, 0xAB0E, Lo),
+    (0xAB11, 0xAB16, Lo),
+    (0xAB20, 0xAB26, Lo),
+    (0xAB28, 0xAB2E, Lo),
+    (0xAB30, 0xAB5A, Ll),
+    (0xAB5B, 0xAB5B, Sk),
+    (0xAB5C, 0xAB5F, Lm),
+    (0xAB60, 0xAB68, Ll),
+    (0xAB69, 0xAB69, Lm),
+    (0xAB6A, 0xAB6B, Sk),
+    (0xAB70, 0xABBF, Ll),
+    (0xABC0, 0xABE2, Lo),
+    (0xABE3, 0xABE4, Mc),
+    (0xABE5, 0xABE5, Mn),
+    (0xABE6, 0xABE7, Mc),
+    (0xABE8, 0xABE8, Mn),
+    (0xABE9, 0xABEA, Mc),
+    (0xABEB, 0xABEB, Po),
+    (0xABEC, 0xABEC, Mc),
+    (0xABED, 0xABED, Mn),
+    (0xABF0, 0xABF9, Nd),
+    (0xAC00, 0xD7A3, Lo),
+    (0xD7B0, 0xD7C6, Lo),
+    (0xD7CB, 0xD7FB, Lo),
+    (0xE000, 0xF8FF, Co),
+    (0xF900, 0xFA6D, Lo),
+    (0xFA70, 0xFAD9, Lo),
+    (0xFB00, 0xFB06, Ll),
+    (0xFB13, 0xFB17, Ll),
+    (0xFB1D, 0xFB1D, Lo),
+    (0xFB1E, 0xFB1E, Mn),
+    (0xFB1F, 0xFB28, Lo),
+    (0xFB29, 0xFB29, Sm),
+    (0xFB2A, 0xFB36, Lo),
+    (0xFB38, 0xFB3C, Lo),
+    (0xFB3E, 0xFB3E, Lo),
+    (0xFB40, 0xFB41, Lo),
+    (0xFB43, 0xFB44, Lo),
+    (0xFB46, 0xFBB1, Lo),
+    (0xFBB2, 0xFBC2, Sk),
+    (0xFBD3, 0xFD3D, Lo),
+    (0xFD3E, 0xFD3E, Pe),
+    (0xFD3F, 0xFD3F, Ps),
+    (0xFD40, 0xFD4F, So),
+    (0xFD50, 0xFD8F, Lo),
+    (0xFD92, 0xFDC7, Lo),
+    (0xFDCF, 0xFDCF, So),
+    (0xFDF0, 0xFDFB, Lo),
+    (0xFDFC, 0xFDFC, Sc),
+    (0xFDFD, 0xFDFF, So),
+    (0xFE00, 0xFE0F, Mn),
+    (0xFE10, 0xFE16, Po),
+    (0xFE17, 0xFE17, Ps),
+    (0xFE18, 0xFE18, Pe),
+    (0xFE19, 0xFE19, Po),
+    (0xFE20, 0xFE2F, Mn),
+    (0xFE30, 0xFE30, Po),
+    (0xFE31, 0xFE32, Pd),
+    (0xFE33, 0xFE34, Pc),
+    (0xFE35, 0xFE35, Ps),
+    (0xFE36, 0xFE36, Pe),
+    (0xFE37, 0xFE37, Ps),
+    (0xFE38, 0xFE38, Pe),
+    (0xFE39, 0xFE39, Ps),
+    (0xFE3A, 0xFE3A, Pe),
+    (0xFE3B, 0xFE3B, Ps),
+    (0xFE3C, 0xFE3C, Pe),
+    (0xFE3D, 0xFE3D, Ps),
+    (0xFE3E, 0xFE3E, Pe),
+    (0xFE3F, 0xFE3F, Ps),
+    (0xFE40, 0xFE40, Pe),
+    (0xFE41, 0xFE41, Ps),
+    (0xFE42, 0xFE42, Pe),
+    (0xFE43, 0xFE43, Ps),
+    (0xFE44, 0xFE44, Pe),
+    (0xFE45, 0xFE46, Po),
+    (0xFE47, 0xFE47, Ps),
+    (0xFE48, 0xFE48, Pe),
+    (0xFE49, 0xFE4C, Po),
+    (0xFE4D, 0xFE4F, Pc),
+    (0xFE50, 0xFE52, Po),
+    (0xFE54, 0xFE57, Po),
+    (0xFE58, 0xFE58, Pd),
+    (0xFE59, 0xFE59, Ps),
+    (0xFE5A, 0xFE5A, Pe),
+    (0xFE5B, 0xFE5B, Ps),
+    (0xFE5C, 0xFE5C, Pe),
+    (0xFE5D, 0xFE5D, Ps),
+    (0xFE5E, 0xFE5E, Pe),
+    (0xFE5F, 0xFE61, Po),
+    (0xFE62, 0xFE62, Sm),
+    (0xFE63, 0xFE63, Pd),
+    (0xFE64, 0xFE66, Sm),
+    (0xFE68, 0xFE68, Po),
+    (0xFE69, 0xFE69, Sc),
+    (0xFE6A, 0xFE6B, Po),
+    (0xFE70, 0xFE74, Lo),
+    (0xFE76, 0xFEFC, Lo),
+    (0xFEFF, 0xFEFF, Cf),
+    (0xFF01, 0xFF03, Po),
+    (0xFF04, 0xFF04, Sc),
+    (0xFF05, 0xFF07, Po),
+    (0xFF08, 0xFF08, Ps),
+    (0xFF09, 0xFF09, Pe),
+    (0xFF0A, 0xFF0A, Po),
+    (0xFF0B, 0xFF0B, Sm),
+    (0xFF0C, 0xFF0C, Po),
+    (0xFF0D, 0xFF0D, Pd),
+    (0xFF0E, 0xFF0F, Po),
+    (0xFF10, 0xFF19, Nd),
+    (0xFF1A, 0xFF1B, Po),
+    (0xFF1C, 0xFF1E, Sm),
+    (0xFF1F, 0xFF20, Po),
+    (0xFF21, 0xFF3A, Lu),
+    (0xFF3B, 0xFF3B, Ps),
+    (0xFF3C, 0xFF3C, Po),
+    (0xFF3D, 0xFF3D, Pe),
+    (0xFF3E, 0xFF3E, Sk),
+    (0xFF3F, 0xFF3F, Pc),
+    (0xFF40, 0xFF40, Sk),
+    (0xFF41, 0xFF5A, Ll),
+    (0xFF5B, 0xFF5B, Ps),
+    (0xFF5C, 0xFF5C, Sm),
+    (0xFF5D, 0xFF5D, Pe),
+    (0xFF5E, 0xFF5E, Sm),
+    (0xFF5F, 0xFF5F, Ps),
+    (0xFF60, 0xFF60, Pe),
+    (0xFF61, 0xFF61, Po),
+    (0xFF62, 0xFF62, Ps),
+    (0xFF63, 0xFF63, Pe),
+    (0xFF64, 0xFF65, Po),
+    (0xFF66, 0xFF6F, Lo),
+    (0xFF70, 0xFF70, Lm),
+    (0xFF71, 0xFF9D, Lo),
+    (0xFF9E, 0xFF9F, Lm),
+    (0xFFA0, 0xFFBE, Lo),
+    (0xFFC2, 0xFFC7, Lo),
+    (0xFFCA, 0xFFCF, Lo),
+    (0xFFD2, 0xFFD7, Lo),
+    (0xFFDA, 0xFFDC, Lo),
+    (0xFFE0, 0xFFE1, Sc),
+    (0xFFE2, 0xFFE2, Sm),
+    (0xFFE3, 0xFFE3, Sk),
+    (0xFFE4, 0xFFE4, So),
+    (0xFFE5, 0xFFE6, Sc),
+    (0xFFE8, 0xFFE8, So),
+    (0xFFE9, 0xFFEC, Sm),
+    (0xFFED, 0xFFEE, So),
+    (0xFFF9, 0xFFFB, Cf),
+    (0xFFFC, 0xFFFD, So),
+    (0x10000, 0x1000B, Lo),
+    (0x1000D, 0x10026, Lo),
+    (0x10028, 0x1003A, Lo),
+    (0x1003C, 0x1003D, Lo),
+    (0x1003F, 0x1004D, Lo),
+    (0x10050, 0x1005D, Lo),
+    (0x10080, 0x100FA, Lo),
+    (0x10100, 0x10102, Po),
+    (0x10107, 0x10133, No),
+    (0x10137, 0x1013F, So),
+    (0x10140, 0x10174, Nl),
+    (0x10175, 0x10178, No),
+    (0x10179, 0x10189, So),
+    (0x1018A, 0x1018B, No),
+    (0x1018C, 0x1018E, So),
+    (0x10190, 0x1019C, So),
+    (0x101A0, 0x101A0, So),
+    (0x101D0, 0x101FC, So),
+    (0x101FD, 0x101FD, Mn),
+    (0x10280, 0x1029C, Lo),
+    (0x102A0, 0x102D0, Lo),
+    (0x102E0, 0x102E0, Mn),
+    (0x102E1, 0x102FB, No),
+    (0x10300, 0x1031F, Lo),
+    (0x10320, 0x10323, No),
+    (0x1032D, 0x10340, Lo),
+    (0x10341, 0x10341, Nl),
+    (0x10342, 0x10349, Lo),
+    (0x1034A, 0x1034A, Nl),
+    (0x10350, 0x10375, Lo),
+    (0x10376, 0x1037A, Mn),
+    (0x10380, 0x1039D, Lo),
+    (0x1039F, 0x1039F, Po),
+    (0x103A0, 0x103C3, Lo),
+    (0x103C8, 0x103CF, Lo),
+    (0x103D0, 0x103D0, Po),
+    (0x103D1, 0x103D5, Nl),
+    (0x10400, 0x10427, Lu),
+    (0x10428, 0x1044F, Ll),
+    (0x10450, 0x1049D, Lo),
+    (0x104A0, 0x104A9, Nd),
+    (0x104B0, 0x104D3, Lu),
+    (0x104D8, 0x104FB, Ll),
+    (0x10500, 0x10527, Lo),
+    (0x10530, 0x10563, Lo),
+    (0x1056F, 0x1056F, Po),
+    (0x10570, 0x1057A, Lu),
+    (0x1057C, 0x1058A, Lu),
+    (0x1058C, 0x10592, Lu),
+    (0x10594, 0x10595, Lu),
+    (0x10597, 0x105A1, Ll),
+    (0x105A3, 0x105B1, Ll),
+    (0x105B3, 0x105B9, Ll),
+    (0x105BB, 0x105BC, Ll),
+    (0x10600, 0x10736, Lo),
+    (0x10740, 0x10755, Lo),
+    (0x10760, 0x10767, Lo),
+    (0x10780, 0x10785, Lm),
+    (0x10787, 0x107B0, Lm),
+    (0x107B2, 0x107BA, Lm),
+    (0x10800, 0x10805, Lo),
+    (0x10808, 0x10808, Lo),
+    (0x1080A, 0x10835, Lo),
+    (0x10837, 0x10838, Lo),
+    (0x1083C, 0x1083C, Lo),
+    (0x1083F, 0x10855, Lo),
+    (0x10857, 0x10857, Po),
+    (0x10858, 0x1085F, No),
+    (0x10860, 0x10876, Lo),
+    (0x10877, 0x10878, So),
+    (0x10879, 0x1087F, No),
+    (0x10880, 0x1089E, Lo),
+    (0x108A7, 0x108AF, No),
+    (0x108E0, 0x108F2, Lo),
+    (0x108F4, 0x108F5, Lo),
+    (0x108FB, 0x108FF, No),
+    (0x10900, 0x10915, Lo),
+    (0x10916, 0x1091B, No),
+    (0x1091F, 0x1091F, Po),
+    (0x10920, 0x10939, Lo),
+    (0x1093F, 0x1093F, Po),
+    (0x10980, 0x109B7, Lo),
+    (0x109BC, 0x109BD, No),
+    (0x109BE, 0x109BF, Lo),
+    (0x109C0, 0x109CF, No),
+    (0x109D2, 0x109FF, No),
+    (0x10A00, 0x10A00, Lo),
+    (0x10A01, 0x10A03, Mn),
+    (0x10A05, 0x10A06, Mn),
+    (0x10A0C, 0x10A0F, Mn),
+    (0x10A10, 0x10A13, Lo),
+    (0x10A15, 0x10A17, Lo),
+    (0x10A19, 0x10A35, Lo),
+    (0x10A38, 0x10A3A, Mn),
+    (0x10A3F, 0x10A3F, Mn),
+    (0x10A40, 0x10A48, No),
+    (0x10A50, 0x10A58, Po),
+    (0x10A60, 0x10A7C, Lo),
+    (0x10A7D, 0x10A7E, No),
+    (0x10A7F, 0x10A7F, Po),
+    (0x10A80, 0x10A9C, Lo),
+    (0x10A9D, 0x10A9F, No),
+    (0x10AC0, 0x10AC7, Lo),
+    (0x10AC8, 0x10AC8, So),
+    (0x10AC9, 0x10AE4, Lo),
+    (0x10AE5, 0x10AE6, Mn),
+    (0x10AEB, 0x10AEF, No),
+    (0x10AF0, 0x10AF6, Po),
+    (0x10B00, 0x10B35, Lo),
+    (0x10B39, 0x10B3F, Po),
+    (0x10B40, 0x10B55, Lo),
+    (0x10B58, 0x10B5F, No),
+    (0x10B60, 0x10B72, Lo),
+    (0x10B78, 0x10B7F, No),
+    (0x10B80, 0x10B91, Lo),
+    (0x10B99, 0x10B9C, Po),
+    (0x10BA9, 0x10BAF, No),
+    (0x10C00, 0x10C48, Lo),
+    (0x10C80, 0x10CB2, Lu),
+    (0x10CC0, 0x10CF2, Ll),
+    (0x10CFA, 0x10CFF, No),
+    (0x10D00, 0x10D23, Lo),
+    (0x10D24, 0x10D27, Mn),
+    (0x10D30, 0x10D39, Nd),
+    (0x10E60, 0x10E7E, No),
+    (0x10E80, 0x10EA9, Lo),
+    (0x10EAB, 0x10EAC, Mn),
+    (0x10EAD, 0x10EAD, Pd),
+    (0x10EB0, 0x10EB1, Lo),
+    (0x10F00, 0x10F1C, Lo),
+    (0x10F1D, 0x10F26, No),
+    (0x10F27, 0x10F27, Lo),
+    (0x10F30, 0x10F45, Lo),
+    (0x10F46, 0x10F50, Mn),
+    (0x10F51, 0x10F54, No),
+    (0x10F55, 0x10F59, Po),
+    (0x10F70, 0x10F81, Lo),
+    (0x10F82, 0x10F85, Mn),
+    (0x10F86, 0x10F89, Po),
+    (0x10FB0, 0x10FC4, Lo),
+    (0x10FC5, 0x10FCB, No),
+    (0x10FE0, 0x10FF6, Lo),
+    (0x11000, 0x11000, Mc),
+    (0x11001, 0x11001, Mn),
+    (0x11002, 0x11002, Mc),
+    (0x11003, 0x11037, Lo),
+    (0x11038, 0x11046, Mn),
+    (0x11047, 0x1104D, Po),
+    (0x11052, 0x11065, No),
+    (0x11066, 0x1106F, Nd),
+    (0x11070, 0x11070, Mn),
+    (0x11071, 0x11072, Lo),
+    (0x11073, 0x11074, Mn),
+    (0x11075, 0x11075, Lo),
+    (0x1107F, 0x11081, Mn),
+    (0x11082, 0x11082, Mc),
+    (0x11083, 0x110AF, Lo),
+    (0x110B0, 0x110B2, Mc),
+    (0x110B3, 0x110B6, Mn),
+    (0x110B7, 0x110B8, Mc),
+    (0x110B9, 0x110BA, Mn),
+    (0x110BB, 0x110BC, Po),
+    (0x110BD, 0x110BD, Cf),
+    (0x110BE, 0x110C1, Po),
+    (0x110C2, 0x110C2, Mn),
+    (0x110CD, 0x110CD, Cf),
+    (0x110D0, 0x110E8, Lo),
+    (0x110F0, 0x110F9, Nd),
+    (0x11100, 0x11102, Mn),
+    (0x11103, 0x11126, Lo),
+    (0x11127, 0x1112B, Mn),
+    (0x1112C, 0x1112C, Mc),
+    (0x1112D, 0x11134, Mn),
+    (0x11136, 0x1113F, Nd),
+    (0x11140, 0x11143, Po),
+    (0x11144, 0x11144, Lo),
+    (0x11145, 0x11146, Mc),
+    (0x11147, 0x11147, Lo),
+    (0x11150, 0x11172, Lo),
+    (0x11173, 0x11173, Mn),
+    (0x11174, 0x11175, Po),
+    (0x11176, 0x11176, Lo),
+    (0x11180, 0x11181, Mn),
+    (0x11182, 0x11182, Mc),
+    (0x11183, 0x111B2, Lo),
+    (0x111B3, 0x111B5, Mc),
+    (0x111B6, 0x111BE, Mn),
+    (0x111BF, 0x111C0, Mc),
+    (0x111C1, 0x111C4, Lo),
+    (0x111C5, 0x111C8, Po),
+    (0x111C9, 0x111CC, Mn),
+    (0x111CD, 0x111CD, Po),
+    (0x111CE, 0x111CE, Mc),
+    (0x111CF, 0x111CF, Mn),
+    (0x111D0, 0x111D9, Nd),
+    (0x111DA, 0x111DA, Lo),
+    (0x111DB, 0x111DB, Po),
+    (0x111DC, 0x111DC, Lo),
+    (0x111DD, 0x111DF, Po),
+    (0x111E1, 0x111F4, No),
+    (0x11200, 0x11211, Lo),
+    (0x11213, 0x1122B, Lo),
+    (0x1122C, 0x1122E, Mc),
+    (0x1122F, 0x11231, Mn),
+    (0x11232, 0x11233, Mc),
+    (0x11234, 0x11234, Mn),
+    (0x11235, 0x11235, Mc),
+    (0x11236, 0x11237, Mn),
+    (0x11238, 0x1123D, Po),
+    (0x1123E, 0x1123E, Mn),
+    (0x11280, 0x11286, Lo),
+    (0x11288, 0x11288, Lo),
+    (0x1128A, 0x1128D, Lo),
+    (0x1128F, 0x1129D, Lo),
+    (0x1129F, 0x112A8, Lo),
+    (0x112A9, 0x112A9, Po),
+    (0x112B0, 0x112DE, Lo),
+    (0x112DF, 0x112DF, Mn),
+    (0x112E0, 0x112E2, Mc),
+    (0x112E3, 0x112EA, Mn),
+    (0x112F0, 0x112F9, Nd),
+    (0x11300, 0x11301, Mn),
+    (0x11302, 0x11303, Mc),
+    (0x11305, 0x1130C, Lo),
+    (0x1130F, 0x11310, Lo),
+    (0x11313, 0x11328, Lo),
+    (0x1132A, 0x11330, Lo),
+    (0x11332, 0x11333, Lo),
+    (0x11335, 0x11339, Lo),
+    (0x1133B, 0x1133C, Mn),
+    (0x1133D, 0x1133D, Lo),
+    (0x1133E, 0x1133F, Mc),
+    (0x11340, 0x11340, Mn),
+    (0x11341, 0x11344, Mc),
+    (0x11347, 0x11348, Mc),
+    (0x1134B, 0x1134D, Mc),
+    (0x11350, 0x11350, Lo),
+    (0x11357, 0x11357, Mc),
+    (0x1135D, 0x11361, Lo),
+    (0x11362, 0x11363, Mc),
+    (0x11366, 0x1136C, Mn),
+    (0x11370, 0x11374, Mn),
+    (0x11400, 0x11434, Lo),
+    (0x11435, 0x11437, Mc),
+    (0x11438, 0x1143F, Mn),
+    (0x11440, 0x11441, Mc),
+    (0x11442, 0x11444, Mn),
+    (0x11445, 0x11445, Mc),
+    (0x11446, 0x11446, Mn),
+    (0x11447, 0x1144A, Lo),
+    (0x1144B, 0x1144F, Po),
+    (0x11450, 0x11459, Nd),
+    (0x1145A, 0x1145B, Po),
+    (0x1145D, 0x1145D, Po),
+    (0x1145E, 0x1145E, Mn),
+    (0x1145F, 0x11461, Lo),
+    (0x11480, 0x114AF, Lo),
+    (0x114B0, 0x114B2, Mc),
+    (0x114B3, 0x114B8, Mn),
+    (0x114B9, 0x114B9, Mc),
+    (0x114BA, 0x114BA, Mn),
+    (0x114BB, 0x114BE, Mc),
+    (0x114BF, 0x114C0, Mn),
+    (0x114C1, 0x114C1, Mc),
+    (0x114C2, 0x114C3, Mn),
+    (0x114C4, 0x114C5, Lo),
+    (0x114C6, 0x114C6, Po),
+    (0x114C7, 0x114C7, Lo),
+    (0x114D0, 0x114D9, Nd),
+    (0x11580, 0x115AE, Lo),
+    (0x115AF, 0x115B1, Mc),
+    (0x115B2, 0x115B5, Mn),
+    (0x115B8, 0x115BB, Mc),
+    (0x115BC, 0x115BD, Mn),
+    (0x115BE, 0x115BE, Mc),
+    (0x115BF, 0x115C0, Mn),
+    (0x115C1, 0x115D7, Po),
+    (0x115D8, 0x115DB, Lo),
+    (0x115DC, 0x115DD, Mn),
+    (0x11600, 0x1162F, Lo),
+    (0x11630, 0x11632, Mc),
+    (0x11633, 0x1163A, Mn),
+    (0x1163B, 0x1163C, Mc),
+    (0x1163D, 0x1163D, Mn),
+    (0x1163E, 0x1163E, Mc),
+    (0x1163F, 0x11640, Mn),
+    (0x11641, 0x11643, Po),
+    (0x11644, 0x11644, Lo),
+    (0x11650, 0x11659, Nd),
+    (0x11660, 0x1166C, Po),
+    (0x11680, 0x116AA, Lo),
+    (0x116AB, 0x116AB, Mn),
+    (0x116AC, 0x116AC, Mc),
+    (0x116AD, 0x116AD, Mn),
+    (0x116AE, 0x116AF, Mc),
+    (0x116B0, 0x116B5, Mn),
+    (0x116B6, 0x116B6, Mc),
+    (0x116B7, 0x116B7, Mn),
+    (0x116B8, 0x116B8, Lo),
+    (0x116B9, 0x116B9, Po),
+    (0x116C0, 0x116C9, Nd),
+    (0x11700, 0x1171A, Lo),
+    (0x1171D, 0x1171F, Mn),
+    (0x11720, 0x11721, Mc),
+    (0x11722, 0x11725, Mn),
+    (0x11726, 0x11726, Mc),
+    (0x11727, 0x1172B, Mn),
+    (0x11730, 0x11739, Nd),
+    (0x1173A, 0x1173B, No),
+    (0x1173C, 0x1173E, Po),
+    (0x1173F, 0x1173F, So),
+    (0x11740, 0x11746, Lo),
+    (0x11800, 0x1182B, Lo),
+    (0x1182C, 0x1182E, Mc),
+    (0x1182F, 0x11837, Mn),
+    (0x11838, 0x11838, Mc),
+    (0x11839, 0x1183A, Mn),
+    (0x1183B, 0x1183B, Po),
+    (0x118A0, 0x118BF, Lu),
+    (0x118C0, 0x118DF, Ll),
+    (0x118E0, 0x118E9, Nd),
+    (0x118EA, 0x118F2, No),
+    (0x118FF, 0x11906, Lo),
+    (0x11909, 0x11909, Lo),
+    (0x1190C, 0x11913, Lo),
+    (0x11915, 0x11916, Lo),
+    (0x11918, 0x1192F, Lo),
+    (0x11930, 0x11935, Mc),
+    (0x11937, 0x11938, Mc),
+    (0x1193B, 0x1193C, Mn),
+    (0x1193D, 0x1193D, Mc),
+    (0x1193E, 0x1193E, Mn),
+    (0x1193F, 0x1193F, Lo),
+    (0x11940, 0x11940, Mc),
+    (0x11941, 0x11941, Lo),
+    (0x11942, 0x11942, Mc),
+    (0x11943, 0x11943, Mn),
+    (0x11944, 0x11946, Po),
+    (0x11950, 0x11959, Nd),
+    (0x119A0, 0x119A7, Lo),
+    (0x119AA, 0x119D0, Lo),
+    (0x119D1, 0x119D3, Mc),
+    (0x119D4, 0x119D7, Mn),
+    (0x119DA, 0x119DB, Mn),
+    (0x119DC, 0x119DF, Mc),
+    (0x119E0, 0x119E0, Mn),
+    (0x119E1, 0x119E1, Lo),
+    (0x119E2, 0x119E2, Po),
+    (0x119E3, 0x119E3, Lo),
+    (0x119E4, 0x119E4, Mc),
+    (0x11A00, 0x11A00, Lo),
+    (0x11A01, 0x11A0A, Mn),
+    (0x11A0B, 0x11A32, Lo),
+    (0x11A33, 0x11A38, Mn),
+    (0x11A39, 0x11A39, Mc),
+    (0x11A3A, 0x11A3A, Lo),
+    (0x11A3B, 0x11A3E, Mn),
+    (0x11A3F, 0x11A46, Po),
+    (0x11A47, 0x11A47, Mn),
+    (0x11A50, 0x11A50, Lo),
+    (0x11A51, 0x11A56, Mn),
+    (0x11A57, 0x11A58, Mc),
+    (0x11A59, 0x11A5B, Mn),
+    (0x11A5C, 0x11A89, Lo),
+    (0x11A8A, 0x11A96, Mn),
+    (0x11A97, 0x11A97, Mc),
+    (0x11A98, 0x11A99, Mn),
+    (0x11A9A, 0x11A9C, Po),
+    (0x11A9D, 0x11A9D, Lo),
+    (0x11A9E, 0x11AA2, Po),
+    (0x11AB0, 0x11AF8, Lo),
+    (0x11C00, 0x11C08, Lo),
+    (0x11C0A, 0x11C2E, Lo),
+    (0x11C2F, 0x11C2F, Mc),
+    (0x11C30, 0x11C36, Mn),
+    (0x11C38, 0x11C3D, Mn),
+    (0x11C3E, 0x11C3E, Mc),
+    (0x11C3F, 0x11C3F, Mn),
+    (0x11C40, 0x11C40, Lo),
+    (0x11C41, 0x11C45, Po),
+    (0x11C50, 0x11C59, Nd),
+    (0x11C5A, 0x11C6C, No),
+    (0x11C70, 0x11C71, Po),
+    (0x11C72, 0x11C8F, Lo),
+    (0x11C92, 0x11CA7, Mn),
+    (0x11CA9, 0x11CA9, Mc),
+    (0x11CAA, 0x11CB0, Mn),
+    (0x11CB1, 0x11CB1, Mc),
+    (0x11CB2, 0x11CB3, Mn),
+    (0x11CB4, 0x11CB4, Mc),
+    (0x11CB5, 0x11CB6, Mn),
+    (0x11D00, 0x11D06, Lo),
+    (0x11D08, 0x11D09, Lo),
+    (0x11D0B, 0x11D30, Lo),
+    (0x11D31, 0x11D36, Mn),
+    (0x11D3A, 0x11D3A, Mn),
+    (0x11D3C, 0x11D3D, Mn),
+    (0x11D3F, 0x11D45, Mn),
+    (0x11D46, 0x11D46, Lo),
+    (0x11D47, 0x11D47, Mn),
+    (0x11D50, 0x11D59, Nd),
+    (0x11D60, 0x11D65, Lo),
+    (0x11D67, 0x11D68, Lo),
+    (0x11D6A, 0x11D89, Lo),
+    (0x11D8A, 0x11D8E, Mc),
+    (0x11D90, 0x11D91, Mn),
+    (0x11D93, 0x11D94, Mc),
+    (0x11D95, 0x11D95, Mn),
+    (0x11D96, 0x11D96, Mc),
+    (0x11D97, 0x11D97, Mn),
+    (0x11D98, 0x11D98, Lo),
+    (0x11DA0, 0x11DA9, Nd),
+    (0x11EE0, 0x11EF2, Lo),
+    (0x11EF3, 0x11EF4, Mn),
+    (0x11EF5, 0x11EF6, Mc),
+    (0x11EF7, 0x11EF8, Po),
+    (0x11FB0, 0x11FB0, Lo),
+    (0x11FC0, 0x11FD4, No),
+    (0x11FD5, 0x11FDC, So),
+    (0x11FDD, 0x11FE0, Sc),
+    (0x11FE1, 0x11FF1, So),
+    (0x11FFF, 0x11FFF, Po),
+    (0x12000, 0x12399, Lo),
+    (0x12400, 0x1246E, Nl),
+    (0x12470, 0x12474, Po),
+    (0x12480, 0x12543, Lo),
+    (0x12F90, 0x12FF0, Lo),
+    (0x12FF1, 0x12FF2, Po),
+    (0x13000, 0x1342E, Lo),
+    (0x13430, 0x13438, Cf),
+    (0x14400, 0x14646, Lo),
+    (0x16800, 0x16A38, Lo),
+    (0x16A40, 0x16A5E, Lo),
+    (0x16A60, 0x16A69, Nd),
+    (0x16A6E, 0x16A6F, Po),
+    (0x16A70, 0x16ABE, Lo),
+    (0x16AC0, 0x16AC9, Nd),
+    (0x16AD0, 0x16AED, Lo),
+    (0x16AF0, 0x16AF4, Mn),
+    (0x16AF5, 0x16AF5, Po),
+    (0x16B00, 0x16B2F, Lo),
+    (0x16B30, 0x16B36, Mn),
+    (0x16B37, 0x16B3B, Po),
+    (0x16B3C, 0x16B3F, So),
+    (0x16B40, 0x16B43, Lm),
+    (0x16B44, 0x16B44, Po),
+    (0x16B45, 0x16B45, So),
+    (0x16B50, 0x16B59, Nd),
+    (0x16B5B, 0x16B61, No),
+    (0x16B63, 0x16B77, Lo),
+    (0x16B7D, 0x16B8F, Lo),
+    (0x16E40, 0x16E5F, Lu),
+    (0x16E60, 0x16E7F, Ll),
+    (0x16E80, 0x16E96, No),
+    (0x16E97, 0x16E9A, Po),
+    (0x16F00, 0x16F4A, Lo),
+    (0x16F4F, 0x16F4F, Mn),
+    (0x16F50, 0x16F50, Lo),
+    (0x16F51, 0x16F87, Mc),
+    (0x16F8F, 0x16F92, Mn),
+    (0x16F93, 0x16F9F, Lm),
+    (0x16FE0, 0x16FE1, Lm),
+    (0x16FE2, 0x16FE2, Po),
+    (0x16FE3, 0x16FE3, Lm),
+    (0x16FE4, 0x16FE4, Mn),
+    (0x16FF0, 0x16FF1, Mc),
+    (0x17000, 0x187F7, Lo),
+    (0x18800, 0x18CD5, Lo),
+    (0x18D00, 0x18D08, Lo),
+    (0x1AFF0, 0x1AFF3, Lm),
+    (0x1AFF5, 0x1AFFB, Lm),
+    (0x1AFFD, 0x1AFFE, Lm),
+    (0x1B000, 0x1B122, Lo),
+    (0x1B150, 0x1B152, Lo),
+    (0x1B164, 0x1B167, Lo),
+    (0x1B170, 0x1B2FB, Lo),
+    (0x1BC00, 0x1BC6A, Lo),
+    (0x1BC70, 0x1BC7C, Lo),
+    (0x1BC80, 0x1BC88, Lo),
+    (0x1BC90, 0x1BC99, Lo),
+    (0x1BC9C, 0x1BC9C, So),
+    (0x1BC9D, 0x1BC9E, Mn),
+    (0x1BC9F, 0x1BC9F, Po),
+    (0x1BCA0, 0x1BCA3, Cf),
+    (0x1CF00, 0x1CF2D, Mn),
+    (0x1CF30, 0x1CF46, Mn),
+    (0x1CF50, 0x1CFC3, So),
+    (0x1D000, 0x1D0F5, So),
+    (0x1D100, 0x1D126, So),
+    (0x1D129, 0x1D164, So),
+    (0x1D165, 0x1D166, Mc),
+    (0x1D167, 0x1D169, Mn),
+    (0x1D16A, 0x1D16C, So),
+    (0x1D16D, 0x1D172, Mc),
+    (0x1D173, 0x1D17A, Cf),
+    (0x1D17B, 0x1D182, Mn),
+    (0x1D183, 0x1D184, So),
+    (0x1D185, 0x1D18B, Mn),
+    (0x1D18C, 0x1D1A9, So),
+    (0x1D1AA, 0x1D1AD, Mn),
+    (0x1D1AE, 0x1D1EA, So),
+    (0x1D200, 0x1D241, So),
+    (0x1D242, 0x1D244, Mn),
+    (0x1D245, 0x1D245, So),
+    (0x1D2E0, 0x1D2F3, No),
+    (0x1D300, 0x1D356, So),
+    (0x1D360, 0x1D378, No),
+    (0x1D400, 0x1D419, Lu),
+    (0x1D41A, 0x1D433, Ll),
+    (0x1D434, 0x1D44D, Lu),
+    (0x1D44E, 0x1D454, Ll),
+    (0x1D456, 0x1D467, Ll),
+    (0x1D468, 0x1D481, Lu),
+    (0x1D482, 0x1D49B, Ll),
+    (0x1D49C, 0x1D49C, Lu),
+    (0x1D49E, 0x1D49F, Lu),
+    (0x1D4A2, 0x1D4A2, Lu),
+    (0x1D4A5, 0x1D4A6, Lu),
+    (0x1D4A9, 0x1D4AC, Lu),
+    (0x1D4AE, 0x1D4B5, Lu),
+    (0x1D4B6, 0x1D4B9, Ll),
+    (0x1D4BB, 0x1D4BB, Ll),
+    (0x1D4BD, 0x1D4C3, Ll),
+    (0x1D4C5, 0x1D4CF, Ll),
+    (0x1D4D0, 0x1D4E9, Lu),
+    (0x1D4EA, 0x1D503, Ll),
+    (0x1D504, 0x1D505, Lu),
+    (0x1D507, 0x1D50A, Lu),
+    (0x1D50D, 0x1D514, Lu),
+    (0x1D516, 0x1D51C, Lu),
+    (0x1D51E, 0x1D537, Ll),
+    (0x1D538, 0x1D539, Lu),
+    (0x1D53B, 0x1D53E, Lu),
+    (0x1D540, 0x1D544, Lu),
+    (0x1D546, 0x1D546, Lu),
+    (0x1D54A, 0x1D550, Lu),
+    (0x1D552, 0x1D56B, Ll),
+    (0x1D56C, 0x1D585, Lu),
+    (0x1D586, 0x1D59F, Ll),
+    (0x1D5A0, 0x1D5B9, Lu),
+    (0x1D5BA, 0x1D5D3, Ll),
+    (0x1D5D4, 0x1D5ED, Lu),
+    (0x1D5EE, 0x1D607, Ll),
+    (0x1D608, 0x1D621, Lu),
+    (0x1D622, 0x1D63B, Ll),
+    (0x1D63C, 0x1D655, Lu),
+    (0x1D656, 0x1D66F, Ll),
+    (0x1D670, 0x1D689, Lu),
+    (0x1D68A, 0x1D6A5, Ll),
+    (0x1D6A8, 0x1D6C0, Lu),
+    (0x1D6C1, 0x1D6C1, Sm),
+    (0x1D6C2, 0x1D6DA, Ll),
+    (0x1D6DB, 0x1D6DB, Sm),
+    (0x1D6DC, 0x1D6E1, Ll),
+    (0x1D6E2, 0x1D6FA, Lu),
+    (0x1D6FB, 0x1D6FB, Sm),
+    (0x1D6FC, 0x1D714, Ll),
+    (0x1D715, 0x1D715, Sm),
+    (0x1D716, 0x1D71B, Ll),
+    (0x1D71C, 0x1D734, Lu),
+    (0x1D735, 0x1D735, Sm),
+    (0x1D736, 0x1D74E, Ll),
+    (0x1D74F, 0x1D74F, Sm),
+    (0x1D750, 0x1D755, Ll),
+    (0x1D756, 0x1D76E, Lu),
+    (0x1D76F, 0x1D76F, Sm),
+    (0x1D770, 0x1D788, Ll),
+    (0x1D789, 0x1D789, Sm),
+    (0x1D78A, 0x1D78F, Ll),
+    (0x1D790, 0x1D7A8, Lu),
+    (0x1D7A9, 0x1D7A9, Sm),
+    (0x1D7AA, 0x1D7C2, Ll),
+    (0x1D7C3, 0x1D7C3, Sm),
+    (0x1D7C4, 0x1D7C9, Ll),
+    (0x1D7CA, 0x1D7CA, Lu),
+    (0x1D7CB, 0x1D7CB, Ll),
+    (0x1D7CE, 0x1D7FF, Nd),
+    (0x1D800, 0x1D9FF, So),
+    (0x1DA00, 0x1DA36, Mn),
+    (0x1DA37, 0x1DA3A, So),
+    (0x1DA3B, 0x1DA6C, Mn),
+    (0x1DA6D, 0x1DA74, So),
+    (0x1DA75, 0x1DA75, Mn),
+    (0x1DA76, 0x1DA83, So),
+    (0x1DA84, 0x1DA84, Mn),
+    (0x1DA85, 0x1DA86, So),
+    (0x1DA87, 0x1DA8B, Po),
+    (0x1DA9B, 0x1DA9F, Mn),
+    (0x1DAA1, 0x1DAAF, Mn),
+    (0x1DF00, 0x1DF09, Ll),
+    (0x1DF0A, 0x1DF0A, Lo),
+    (0x1DF0B, 0x1DF1E, Ll),
+    (0x1E000, 0x1E006, Mn),
+    (0x1E008, 0x1E018, Mn),
+    (0x1E01B, 0x1E021, Mn),
+    (0x1E023, 0x1E024, Mn),
+    (0x1E026, 0x1E02A, Mn),
+    (0x1E100, 0x1E12C, Lo),
+    (0x1E130, 0x1E136, Mn),
+    (0x1E137, 0x1E13D, Lm),
+    (0x1E140, 0x1E149, Nd),
+    (0x1E14E, 0x1E14E, Lo),
+    (0x1E14F, 0x1E14F, So),
+    (0x1E290, 0x1E2AD, Lo),
+    (0x1E2AE, 0x1E2AE, Mn),
+    (0x1E2C0, 0x1E2EB, Lo),
+    (0x1E2EC, 0x1E2EF, Mn),
+    (0x1E2F0, 0x1E2F9, Nd),
+    (0x1E2FF, 0x1E2FF, Sc),
+    (0x1E7E0, 0x1E7E6, Lo),
+    (0x1E7E8, 0x1E7EB, Lo),
+    (0x1E7ED, 0x1E7EE, Lo),
+    (0x1E7F0, 0x1E7FE, Lo),
+    (0x1E800, 0x1E8C4, Lo),
+    (0x1E8C7, 0x1E8CF, No),
+    (0x1E8D0, 0x1E8D6, Mn),
+    (0x1E900, 0x1E921, Lu),
+    (0x1E922, 0x1E943, Ll),
+    (0x1E944, 0x1E94A, Mn),
+    (0x1E94B, 0x1E94B, Lm),
+    (0x1E950, 0x1E959, Nd),
+    (0x1E95E, 0x1E95F, Po),
+    (0x1EC71, 0x1ECAB, No),
+    (0x1ECAC, 0x1ECAC, So),
+    (0x1ECAD, 0x1ECAF, No),
+    (0x1ECB0, 0x1ECB0, Sc),
+    (0x1ECB1, 0x1ECB4, No),
+    (0x1ED01, 0x1ED2D, No),
+    (0x1ED2E, 0x1ED2E, So),
+    (0x1ED2F, 0x1ED3D, No),
+    (0x1EE00, 0x1EE03, Lo),
+    (0x1EE05, 0x1EE1F, Lo),
+    (0x1EE21, 0x1EE22, Lo),
+    (0x1EE24, 0x1EE24, Lo),
+    (0x1EE27, 0x1EE27, Lo),
+    (0x1EE29, 0x1EE32, Lo),
+    (0x1EE34, 0x1EE37, Lo),
+    (0x1EE39, 0x1EE39, Lo),
+    (0x1EE3B, 0x1EE3B, Lo),
+    (0x1EE42, 0x1EE42, Lo),
+    (0x1EE47, 0x1EE47, Lo),
+    (0x1EE49, 0x1EE49, Lo),
+    (0x1EE4B, 0x1EE4B, Lo),
+    (0x1EE4D, 0x1EE4F, Lo),
+    (0x1EE51, 0x1EE52, Lo),
+    (0x1EE54, 0x1EE54, Lo),
+    (0x1EE57, 0x1EE57, Lo),
+    (0x1EE59, 0x1EE59, Lo),
+    (0x1EE5B, 0x1EE5B, Lo),
+    (0x1EE5D, 0x1EE5D, Lo),
+    (0x1EE5F, 0x1EE5F, Lo),
+    (0x1EE61, 0x1EE62, Lo),
+    (0x1EE64, 0x1EE64, Lo),
+    (0x1EE67, 0x1EE6A, Lo),
+    (0x1EE6C, 0x1EE72, Lo),
+    (0x1EE74, 0x1EE77, Lo),
+    (0x1EE79, 0x1EE7C, Lo),
+    (0x1EE7E, 0x1EE7E, Lo),
+    (0x1EE80, 0x1EE89, Lo),
+    (0x1EE8B, 0x1EE9B, Lo),
+    (0x1EEA1, 0x1EEA3, Lo),
+    (0x1EEA5, 0x1EEA9, Lo),
+    (0x1EEAB, 0x1EEBB, Lo),
+    (0x1EEF0, 0x1EEF1, Sm),
+    (0x1F000, 0x1F02B, So),
+    (0x1F030, 0x1F093, So),
+    (0x1F0A0, 0x1F0AE, So),
+    (0x1F0B1, 0x1F0BF, So),
+    (0x1F0C1, 0x1F0CF, So),
+    (0x1F0D1, 0x1F0F5, So),
+    (0x1F100, 0x1F10C, No),
+    (0x1F10D, 0x1F1AD, So),
+    (0x1F1E6, 0x1F202, So),
+    (0x1F210, 0x1F23B, So),
+    (0x1F240, 0x1F248, So),
+    (0x1F250, 0x1F251, So),
+    (0x1F260, 0x1F265, So),
+    (0x1F300, 0x1F3FA, So),
+    (0x1F3FB, 0x1F3FF, Sk),
+    (0x1F400, 0x1F6D7, So),
+    (0x1F6DD, 0x1F6EC, So),
+    (0x1F6F0, 0x1F6FC, So),
+    (0x1F700, 0x1F773, So),
+    (0x1F780, 0x1F7D8, So),
+    (0x1F7E0, 0x1F7EB, So),
+    (0x1F7F0, 0x1F7F0, So),
+    (0x1F800, 0x1F80B, So),
+    (0x1F810, 0x1F847, So),
+    (0x1F850, 0x1F859, So),
+    (0x1F860, 0x1F887, So),
+    (0x1F890, 0x1F8AD, So),
+    (0x1F8B0, 0x1F8B1, So),
+    (0x1F900, 0x1FA53, So),
+    (0x1FA60, 0x1FA6D, So),
+    (0x1FA70, 0x1FA74, So),
+    (0x1FA78, 0x1FA7C, So),
+    (0x1FA80, 0x1FA86, So),
+    (0x1FA90, 0x1FAAC, So),
+    (0x1FAB0, 0x1FABA, So),
+    (0x1FAC0, 0x1FAC5, So),
+    (0x1FAD0, 0x1FAD9, So),
+    (0x1FAE0, 0x1FAE7, So),
+    (0x1FAF0, 0x1FAF6, So),
+    (0x1FB00, 0x1FB92, So),
+    (0x1FB94, 0x1FBCA, So),
+    (0x1FBF0, 0x1FBF9, Nd),
+    (0x20000, 0x2A6DF, Lo),
+    (0x2A700, 0x2B738, Lo),
+    (0x2B740, 0x2B81D, Lo),
+    (0x2B820, 0x2CEA1, Lo),
+    (0x2CEB0, 0x2EBE0, Lo),
+    (0x2F800, 0x2FA1D, Lo),
+    (0x30000, 0x3134A, Lo),
+    (0xE0001, 0xE0001, Cf),
+    (0xE0020, 0xE007F, Cf),
+    (0xE0100, 0xE01EF, Mn),
+    (0xF0000, 0xFFFFD, Co),
+    (0x100000, 0x10FFFD, Co),
+];
+
+/// The scripts, as their abbreviation and long name, sorted by long name.
+pub(super) const SCRIPT_NAMES: &[(&str, &str)] = &[
+    ("Adlm", "Adlam"),
+    ("Ahom", "Ahom"),
+    ("Hluw", "Anatolian_Hieroglyphs"),
+    ("Arab", "Arabic"),
+    ("Armn", "Armenian"),
+    ("Avst", "Avestan"),
+    ("Bali", "Balinese"),
+    ("Bamu", "Bamum"),
+    ("Bass", "Bassa_Vah"),
+    ("Batk", "Batak"),
+    ("Beng", "Bengali"),
+    ("Bhks", "Bhaiksuki"),
+    ("Bopo", "Bopomofo"),
+    ("Brah", "Brahmi"),
+    ("Brai", "Braille"),
+    ("Bugi", "Buginese"),
+    ("Buhd", "Buhid"),
+    ("Cans", "Canadian_Aboriginal"),
+    ("Cari", "Carian"),
+    ("Aghb", "Caucasian_Albanian"),
+    ("Cakm", "Chakma"),
+    ("Cham", "Cham"),
+    ("Cher", "Cherokee"),
+    ("Chrs", "Chorasmian"),
+    ("Zyyy", "Common"),
+    ("Copt", "Coptic"),
+    ("Xsux", "Cuneiform"),
+    ("Cprt", "Cypriot"),
+    ("Cpmn", "Cypro_Minoan"),
+    ("Cyrl", "Cyrillic"),
+    ("Dsrt", "Deseret"),
+    ("Deva", "Devanagari"),
+    ("Diak", "Dives_Akuru"),
+    ("Dogr", "Dogra"),
+    ("Dupl", "Duployan"),
+    ("Egyp", "Egyptian_Hieroglyphs"),
+    ("Elba", "Elbasan"),
+    ("Elym", "Elymaic"),
+    ("Ethi", "Ethiopic"),
+    ("Geor", "Georgian"),
+    ("Glag", "Glagolitic"),
+    ("Goth", "Gothic"),
+    ("Gran", "Grantha"),
+    ("Grek", "Greek"),
+    ("Gujr", "Gujarati"),
+    ("Gong", "Gunjala_Gondi"),
+    ("Guru", "Gurmukhi"),
+    ("Hani", "Han"),
+    ("Hang", "Hangul"),
+    ("Rohg", "Hanifi_Rohingya"),
+    ("Hano", "Hanunoo"),
+    ("Hatr", "Hatran"),
+    ("Hebr", "Hebrew"),
+    ("Hira", "Hiragana"),
+    ("Armi", "Imperial_Aramaic"),
+    ("Zinh", "Inherited"),
+    ("Phli", "Inscriptional_Pahlavi"),
+    ("Prti", "Inscriptional_Parthian"),
+    ("Java", "Javanese"),
+    ("Kthi", "Kaithi"),
+    ("Knda", "Kannada"),
+    ("Kana", "Katakana"),
+    ("Kali", "Kayah_Li"),
+    ("Khar", "Kharoshthi"),
+    ("Kits", "Khitan_Small_Script"),
+    ("Khmr", "Khmer"),
+    ("Khoj", "Khojki"),
+    ("Sind", "Khudawadi"),
+    ("Laoo", "Lao"),
+    ("Latn", "Latin"),
+    ("Lepc", "Lepcha"),
+    ("Limb", "Limbu"),
+    ("Lina", "Linear_A"),
+    ("Linb", "Linear_B"),
+    ("Lisu", "Lisu"),
+    ("Lyci", "Lycian"),
+    ("Lydi", "Lydian"),
+    ("Mahj", "Mahajani"),
+    ("Maka", "Makasar"),
+    ("Mlym", "Malayalam"),
+    ("Mand", "Mandaic"),
+    ("Mani", "Manichaean"),
+    ("Marc", "Marchen"),
+    ("Gonm", "Masaram_Gondi"),
+    ("Medf", "Medefaidrin"),
+    ("Mtei", "Meetei_Mayek"),
+    ("Mend", "Mende_Kikakui"),
+    ("Merc", "Meroitic_Cursive"),
+    ("Mero", "Meroitic_Hieroglyphs"),
+    ("Plrd", "Miao"),
+    ("Modi", "Modi"),
+    ("Mong", "Mongolian"),
+    ("Mroo", "Mro"),
+    ("Mult", "Multani"),
+    ("Mymr", "Myanmar"),
+    ("Nbat", "Nabataean"),
+    ("Nand", "Nandinagari"),
+    ("Talu", "New_Tai_Lue"),
+    ("Newa", "Newa"),
+    ("Nkoo", "Nko"),
+    ("Nshu", "Nushu"),
+    ("Hmnp", "Nyiakeng_Puachue_Hmong"),
+    ("Ogam", "Ogham"),
+    ("Olck", "Ol_Chiki"),
+    ("Hung", "Old_Hungarian"),
+    ("Ital", "Old_Italic"),
+    ("Narb", "Old_North_Arabian"),
+    ("Perm", "Old_Permic"),
+    ("Xpeo", "Old_Persian"),
+    ("Sogo", "Old_Sogdian"),
+    ("Sarb", "Old_South_Arabian"),
+    ("Orkh", "Old_Turkic"),
+    ("Ougr", "Old_Uyghur"),
+    ("Orya", "Oriya"),
+    ("Osge", "Osage"),
+    ("Osma", "Osmanya"),
+    ("Hmng", "Pahawh_Hmong"),
+    ("Palm", "Palmyrene"),
+    ("Pauc", "Pau_Cin_Hau"),
+    ("Phag", "Phags_Pa"),
+    ("Phnx", "Phoenician"),
+    ("Phlp", "Psalter_Pahlavi"),
+    ("Rjng", "Rejang"),
+    ("Runr", "Runic"),
+    ("Samr", "Samaritan"),
+    ("Saur", "Saurashtra"),
+    ("Shrd", "Sharada"),
+    ("Shaw", "Shavian"),
+    ("Sidd", "Siddham"),
+    ("Sgnw", "SignWriting"),
+    ("Sinh", "Sinhala"),
+    ("Sogd", "Sogdian"),
+    ("Sora", "Sora_Sompeng"),
+    ("Soyo", "Soyombo"),
+    ("Sund", "Sundanese"),
+    ("Sylo", "Syloti_Nagri"),
+    ("Syrc", "Syriac"),
+    ("Tglg", "Tagalog"),
+    ("Tagb", "Tagbanwa"),
+    ("Tale", "Tai_Le"),
+    ("Lana", "Tai_Tham"),
+    ("Tavt", "Tai_Viet"),
+    ("Takr", "Takri"),
+    ("Taml", "Tamil"),
+    ("Tnsa", "Tangsa"),
+    ("Tang", "Tangut"),
+    ("Telu", "Telugu"),
+    ("Thaa", "Thaana"),
+    ("Thai", "Thai"),
+    ("Tibt", "Tibetan"),
+    ("Tfng", "Tifinagh"),
+    ("Tirh", "Tirhuta"),
+    ("Toto", "Toto"),
+    ("Ugar", "Ugaritic"),
+    ("Zzzz", "Unknown"),
+    ("Vaii", "Vai"),
+    ("Vith", "Vithkuqi"),
+    ("Wcho", "Wancho"),
+    ("Wara", "Warang_Citi"),
+    ("Yezi", "Yezidi"),
+    ("Yiii", "Yi"),
+    ("Zanb", "Zanabazar_Square"),
+];
+
+/// The index of the `Unknown` script in [`SCRIPT_NAMES`], that of unassigned
+/// characters.
+pub(super) const UNKNOWN_SCRIPT: u8 = 154;
+
+/// The script of every character, as an index into [`SCRIPT_NAMES`], by sorted,
+/// disjoint ranges of code points. Characters not in any range are `Unknown`.
+pub(super) const SCRIPTS: &[(u32, u32, u8)] = &[
+    (0x0, 0x40, 24),         // Common
+    (0x41, 0x5A, 69),        // Latin
+    (0x5B, 0x60, 24),        // Common
+    (0x61, 0x7A, 69),        // Latin
+    (0x7B, 0xA9, 24),        // Common
+    (0xAA, 0xAA, 69),        // Latin
+    (0xAB, 0xB9, 24),        // Common
+    (0xBA, 0xBA, 69),        // Latin
+    (0xBB, 0xBF, 24),        // Common
+    (0xC0, 0xD6, 69),        // Latin
+    (0xD7, 0xD7, 24),        // Common
+    (0xD8, 0xF6, 69),        // Latin
+    (0xF7, 0xF7, 24),        // Common
+    (0xF8, 0x2B8, 69),       // Latin
+    (0x2B9, 0x2DF, 24),      // Common
+    (0x2E0, 0x2E4, 69),      // Latin
+    (0x2E5, 0x2E9, 24),      // Common
+    (0x2EA, 0x2EB, 12),      // Bopomofo
+    (0x2EC, 0x2FF, 24),      // Common
+    (0x300, 0x36F, 55),      // Inherited
+    (0x370, 0x373, 43),      // Greek
+    (0x374, 0x374, 24),      // Common
+    (0x375, 0x377, 43),      // Greek
+    (0x37A, 0x37D, 43),      // Greek
+    (0x37E, 0x37E, 24),      // Common
+    (0x37F, 0x37F, 43),      // Greek
+    (0x384, 0x384, 43),      // Greek
+    (0x385, 0x385, 24),      // Common
+    (0x386, 0x386, 43),      // Greek
+    (0x387, 0x387, 24),      // Common
+    (0x388, 0x38A, 43),      // Greek
+    (0x38C, 0x38C, 43),      // Greek
+    (0x38E, 0x3A1, 43),      // Greek
+    (0x3A3, 0x3E1, 43),      // Greek
+    (0x3E2, 0x3EF, 25),      // Coptic
+    (0x3F0, 0x3FF, 43),      // Greek
+    (0x400, 0x484, 29),      // Cyrillic
+    (0x485, 0x486, 55),      // Inherited
+    (0x487, 0x52F, 29),      // Cyrillic
+    (0x531, 0x556, 4),       // Armenian
+    (0x559, 0x58A, 4),       // Armenian
+    (0x58D, 0x58F, 4),       // Armenian
+    (0x591, 0x5C7, 52),      // Hebrew
+    (0x5D0, 0x5EA, 52),      // Hebrew
+    (0x5EF, 0x5F4, 52),      // Hebrew
+    (0x600, 0x604, 3),       // Arabic
+    (0x605, 0x605, 24),      // Common
+    (0x606, 0x60B, 3),       // Arabic
+    (0x60C, 0x60C, 24),      // Common
+    (0x60D, 0x61A, 3),       // Arabic
+    (0x61B, 0x61B, 24),      // Common
+    (0x61C, 0x61E, 3),       // Arabic
+    (0x61F, 0x61F, 24),      // Common
+    (0x620, 0x63F, 3),       // Arabic
+    (0x640, 0x640, 24),      // Common
+    (0x641, 0x64A, 3),       // Arabic
+    (0x64B, 0x655, 55),      // Inherited
+    (0x656, 0x66F, 3),       // Arabic
+    (0x670, 0x670, 55),      // Inherited
+    (0x671, 0x6DC, 3),       // Arabic
+    (0x6DD, 0x6DD, 24),      // Common
+    (0x6DE, 0x6FF, 3),       // Arabic
+    (0x700, 0x70D, 136),     // Syriac
+    (0x70F, 0x74A, 136),     // Syriac
+    (0x74D, 0x74F, 136),     // Syriac
+    (0x750, 0x77F, 3),       // Arabic
+    (0x780, 0x7B1, 147),     // Thaana
+    (0x7C0, 0x7FA, 99),      // Nko
+    (0x7FD, 0x7FF, 99),      // Nko
+    (0x800, 0x82D, 124),     // Samaritan
+    (0x830, 0x83E, 124),     // Samaritan
+    (0x840, 0x85B, 80),      // Mandaic
+    (0x85E, 0x85E, 80),      // Mandaic
+    (0x860, 0x86A, 136),     // Syriac
+    (0x870, 0x88E, 3),       // Arabic
+    (0x890, 0x891, 3),       // Arabic
+    (0x898, 0x8E1, 3),       // Arabic
+    (0x8E2, 0x8E2, 24),      // Common
+    (0x8E3, 0x8FF, 3),       // Arabic
+    (0x900, 0x950, 31),      // Devanagari
+    (0x951, 0x954, 55),      // Inherited
+    (0x955, 0x963, 31),      // Devanagari
+    (0x964, 0x965, 24),      // Common
+    (0x966, 0x97F, 31),      // Devanagari
+    (0x980, 0x983, 10),      // Bengali
+    (0x985, 0x98C, 10),      // Bengali
+    (0x98F, 0x990, 10),      // Bengali
+    (0x993, 0x9A8, 10),      // Bengali
+    (0x9AA, 0x9B0, 10),      // Bengali
+    (0x9B2, 0x9B2, 10),      // Bengali
+    (0x9B6, 0x9B9, 10),      // Bengali
+    (0x9BC, 0x9C4, 10),      // Bengali
+    (0x9C7, 0x9C8, 10),      // Bengali
+    (0x9CB, 0x9CE, 10),      // Bengali
+    (0x9D7, 0x9D7, 10),      // Bengali
+    (0x9DC, 0x9DD, 10),      // Bengali
+    (0x9DF, 0x9E3, 10),      // Bengali
+    (0x9E6, 0x9FE, 10),      // Bengali
+    (0xA01, 0xA03, 46),      // Gurmukhi
+    (0xA05, 0xA0A, 46),      // Gurmukhi
+    (0xA0F, 0xA10, 46),      // Gurmukhi
+    (0xA13, 0xA28, 46),      // Gurmukhi
+    (0xA2A, 0xA30, 46),      // Gurmukhi
+    (0xA32, 0xA33, 46),      // Gurmukhi
+    (0xA35, 0xA36, 46),      // Gurmukhi
+    (0xA38, 0xA39, 46),      // Gurmukhi
+    (0xA3C, 0xA3C, 46),      // Gurmukhi
+    (0xA3E, 0xA42, 46),      // Gurmukhi
+    (0xA47, 0xA48, 46),      // Gurmukhi
+    (0xA4B, 0xA4D, 46),      // Gurmukhi
+    (0xA51, 0xA51, 46),      // Gurmukhi
+    (0xA59, 0xA5C, 46),      // Gurmukhi
+    (0xA5E, 0xA5E, 46),      // Gurmukhi
+    (0xA66, 0xA76, 46),      // Gurmukhi
+    (0xA81, 0xA83, 44),      // Gujarati
+    (0xA85, 0xA8D, 44),      // Gujarati
+    (0xA8F, 0xA91, 44),      // Gujarati
+    (0xA93, 0xAA8, 44),      // Gujarati
+    (0xAAA, 0xAB0, 44),      // Gujarati
+    (0xAB2, 0xAB3, 44),      // Gujarati
+    (0xAB5, 0xAB9, 44),      // Gujarati
+    (0xABC, 0xAC5, 44),      // Gujarati
+    (0xAC7, 0xAC9, 44),      // Gujarati
+    (0xACB, 0xACD, 44),      // Gujarati
+    (0xAD0, 0xAD0, 44),      // Gujarati
+    (0xAE0, 0xAE3, 44),      // Gujarati
+    (0xAE6, 0xAF1, 44),      // Gujarati
+    (0xAF9, 0xAFF, 44),      // Gujarati
+    (0xB01, 0xB03, 113),     // Oriya
+    (0xB05, 0xB0C, 113),     // Oriya
+    (0xB0F, 0xB10, 113),     // Oriya
+    (0xB13, 0xB28, 113),     // Oriya
+    (0xB2A, 0xB30, 113),     // Oriya
+    (0xB32, 0xB33, 113),     // Oriya
+    (0xB35, 0xB39, 113),     // Oriya
+    (0xB3C, 0xB44, 113),     // Oriya
+    (0xB47, 0xB48, 113),     // Oriya
+    (0xB4B, 0xB4D, 113),     // Oriya
+    (0xB55, 0xB57, 113),     // Oriya
+    (0xB5C, 0xB5D, 113),     // Oriya
+    (0xB5F, 0xB63, 113),     // Oriya
+    (0xB66, 0xB77, 113),     // Oriya
+    (0xB82, 0xB83, 143),     // Tamil
+    (0xB85, 0xB8A, 143),     // Tamil
+    (0xB8E, 0xB90, 143),     // Tamil
+    (0xB92, 0xB95, 143),     // Tamil
+    (0xB99, 0xB9A, 143),     // Tamil
+    (0xB9C, 0xB9C, 143),     // Tamil
+    (0xB9E, 0xB9F, 143),     // Tamil
+    (0xBA3, 0xBA4, 143),     // Tamil
+    (0xBA8, 0xBAA, 143),     // Tamil
+    (0xBAE, 0xBB9, 143),     // Tamil
+    (0xBBE, 0xBC2, 143),     // Tamil
+    (0xBC6, 0xBC8, 143),     // Tamil
+    (0xBCA, 0xBCD, 143),     // Tamil
+    (0xBD0, 0xBD0, 143),     // Tamil
+    (0xBD7, 0xBD7, 143),     // Tamil
+    (0xBE6, 0xBFA, 143),     // Tamil
+    (0xC00, 0xC0C, 146),     // Telugu
+    (0xC0E, 0xC10, 146),     // Telugu
+    (0xC12, 0xC28, 146),     // Telugu
+    (0xC2A, 0xC39, 146),     // Telugu
+    (0xC3C, 0xC44, 146),     // Telugu
+    (0xC46, 0xC48, 146),     // Telugu
+    (0xC4A, 0xC4D, 146),     // Telugu
+    (0xC55, 0xC56, 146),     // Telugu
+    (0xC58, 0xC5A, 146),     // Telugu
+    (0xC5D, 0xC5D, 146),     // Telugu
+    (0xC60, 0xC63, 146),     // Telugu
+    (0xC66, 0xC6F, 146),     // Telugu
+    (0xC77, 0xC7F, 146),     // Telugu
+    (0xC80, 0xC8C, 60),      // Kannada
+    (0xC8E, 0xC90, 60),      // Kannada
+    (0xC92, 0xCA8, 60),      // Kannada
+    (0xCAA, 0xCB3, 60),      // Kannada
+    (0xCB5, 0xCB9, 60),      // Kannada
+    (0xCBC, 0xCC4, 60),      // Kannada
+    (0xCC6, 0xCC8, 60),      // Kannada
+    (0xCCA, 0xCCD, 60),      // Kannada
+    (0xCD5, 0xCD6, 60),      // Kannada
+    (0xCDD, 0xCDE, 60),      // Kannada
+    (0xCE0, 0xCE3, 60),      // Kannada
+    (0xCE6, 0xCEF, 60),      // Kannada
+    (0xCF1, 0xCF2, 60),      // Kannada
+    (0xD00, 0xD0C, 79),      // Malayalam
+    (0xD0E, 0xD10, 79),      // Malayalam
+    (0xD12, 0xD44, 79),      // Malayalam
+    (0xD46, 0xD48, 79),      // Malayalam
+    (0xD4A, 0xD4F, 79),      // Malayalam
+    (0xD54, 0xD63, 79),      // Malayalam
+    (0xD66, 0xD7F, 79),      // Malayalam
+    (0xD81, 0xD83, 130),     // Sinhala
+    (0xD85, 0xD96, 130),     // Sinhala
+    (0xD9A, 0xDB1, 130),     // Sinhala
+    (0xDB3, 0xDBB, 130),     // Sinhala
+    (0xDBD, 0xDBD, 130),     // Sinhala
+    (0xDC0, 0xDC6, 130),     // Sinhala
+    (0xDCA, 0xDCA, 130),     // Sinhala
+    (0xDCF, 0xDD4, 130),     // Sinhala
+    (0xDD6, 0xDD6, 130),     // Sinhala
+    (0xDD8, 0xDDF, 130),     // Sinhala
+    (0xDE6, 0xDEF, 130),     // Sinhala
+    (0xDF2, 0xDF4, 130),     // Sinhala
+    (0xE01, 0xE3A, 148),     // Thai
+    (0xE3F, 0xE3F, 24),      // Common
+    (0xE40, 0xE5B, 148),     // Thai
+    (0xE81, 0xE82, 68),      // Lao
+    (0xE84, 0xE84, 68),      // Lao
+    (0xE86, 0xE8A, 68),      // Lao
+    (0xE8C, 0xEA3, 68),      // Lao
+    (0xEA5, 0xEA5, 68),      // Lao
+    (0xEA7, 0xEBD, 68),      // Lao
+    (0xEC0, 0xEC4, 68),      // Lao
+    (0xEC6, 0xEC6, 68),      // Lao
+    (0xEC8, 0xECD, 68),      // Lao
+    (0xED0, 0xED9, 68),      // Lao
+    (0xEDC, 0xEDF, 68),      // Lao
+    (0xF00, 0xF47, 149),     // Tibetan
+    (0xF49, 0xF6C, 149),     // Tibetan
+    (0xF71, 0xF97, 149),     // Tibetan
+    (0xF99, 0xFBC, 149),     // Tibetan
+    (0xFBE, 0xFCC, 149),     // Tibetan
+    (0xFCE, 0xFD4, 149),     // Tibetan
+    (0xFD5, 0xFD8, 24),      // Common
+    (0xFD9, 0xFDA, 149),     // Tibetan
+    (0x1000, 0x109F, 94),    // Myanmar
+    (0x10A0, 0x10C5, 39),    // Georgian
+    (0x10C7, 0x10C7, 39),    // Georgian
+    (0x10CD, 0x10CD, 39),    // Georgian
+    (0x10D0, 0x10FA, 39),    // Georgian
+    (0x10FB, 0x10FB, 24),    // Common
+    (0x10FC, 0x10FF, 39),    // Georgian
+    (0x1100, 0x11FF, 48),    // Hangul
+    (0x1200, 0x1248, 38),    // Ethiopic
+    (0x124A, 0x124D, 38),    // Ethiopic
+    (0x1250, 0x1256, 38),    // Ethiopic
+    (0x1258, 0x1258, 38),    // Ethiopic
+    (0x125A, 0x125D, 38),    // Ethiopic
+    (0x1260, 0x1288, 38),    // Ethiopic
+    (0x128A, 0x128D, 38),    // Ethiopic
+    (0x1290, 0x12B0, 38),    // Ethiopic
+    (0x12B2, 0x12B5, 38),    // Ethiopic
+    (0x12B8, 0x12BE, 38),    // Ethiopic
+    (0x12C0, 0x12C0, 38),    // Ethiopic
+    (0x12C2, 0x12C5, 38),    // Ethiopic
+    (0x12C8, 0x12D6, 38),    // Ethiopic
+    (0x12D8, 0x1310, 38),    // Ethiopic
+    (0x1312, 0x1315, 38),    // Ethiopic
+    (0x1318, 0x135A, 38),    // Ethiopic
+    (0x135D, 0x137C, 38),    // Ethiopic
+    (0x1380, 0x1399, 38),    // Ethiopic
+    (0x13A0, 0x13F5, 22),    // Cherokee
+    (0x13F8, 0x13FD, 22),    // Cherokee
+    (0x1400, 0x167F, 17),    // Canadian_Aboriginal
+    (0x1680, 0x169C, 102),   // Ogham
+    (0x16A0, 0x16EA, 123),   // Runic
+    (0x16EB, 0x16ED, 24),    // Common
+    (0x16EE, 0x16F8, 123),   // Runic
+    (0x1700, 0x1715, 137),   // Tagalog
+    (0x171F, 0x171F, 137),   // Tagalog
+    (0x1720, 0x1734, 50),    // Hanunoo
+    (0x1735, 0x1736, 24),    // Common
+    (0x1740, 0x1753, 16),    // Buhid
+    (0x1760, 0x176C, 138),   // Tagbanwa
+    (0x176E, 0x1770, 138),   // Tagbanwa
+    (0x1772, 0x1773, 138),   // Tagbanwa
+    (0x1780, 0x17DD, 65),    // Khmer
+    (0x17E0, 0x17E9, 65),    // Khmer
+    (0x17F0, 0x17F9, 65),    // Khmer
+    (0x1800, 0x1801, 91),    // Mongolian
+    (0x1802, 0x1803, 24),    // Common
+    (0x1804, 0x1804, 91),    // Mongolian
+    (0x1805, 0x1805, 24),    // Common
+    (0x1806, 0x1819, 91),    // Mongolian
+    (0x1820, 0x1878, 91),    // Mongolian
+    (0x1880, 0x18AA, 91),    // Mongolian
+    (0x18B0, 0x18F5, 17),    // Canadian_Aboriginal
+    (0x1900, 0x191E, 71),    // Limbu
+    (0x1920, 0x192B, 71),    // Limbu
+    (0x1930, 0x193B, 71),    // Limbu
+    (0x1940, 0x1940, 71),    // Limbu
+    (0x1944, 0x194F, 71),    // Limbu
+    (0x1950, 0x196D, 139),   // Tai_Le
+    (0x1970, 0x1974, 139),   // Tai_Le
+    (0x1980, 0x19AB, 97),    // New_Tai_Lue
+    (0x19B0, 0x19C9, 97),    // New_Tai_Lue
+    (0x19D0, 0x19DA, 97),    // New_Tai_Lue
+    (0x19DE, 0x19DF, 97),    // New_Tai_Lue
+    (0x19E0, 0x19FF, 65),    // Khmer
+    (0x1A00, 0x1A1B, 15),    // Buginese
+    (0x1A1E, 0x1A1F, 15),    // Buginese
+    (0x1A20, 0x1A5E, 140),   // Tai_Tham
+    (0x1A60, 0x1A7C, 140),   // Tai_Tham
+    (0x1A7F, 0x1A89, 140),   // Tai_Tham
+    (0x1A90, 0x1A99, 140),   // Tai_Tham
+    (0x1AA0, 0x1AAD, 140),   // Tai_Tham
+    (0x1AB0, 0x1ACE, 55),    // Inherited
+    (0x1B00, 0x1B4C, 6),     // Balinese
+    (0x1B50, 0x1B7E, 6),     // Balinese
+    (0x1B80, 0x1BBF, 134),   // Sundanese
+    (0x1BC0, 0x1BF3, 9),     // Batak
+    (0x1BFC, 0x1BFF, 9),     // Batak
+    (0x1C00, 0x1C37, 70),    // Lepcha
+    (0x1C3B, 0x1C49, 70),    // Lepcha
+    (0x1C4D, 0x1C4F, 70),    // Lepcha
+    (0x1C50, 0x1C7F, 103),   // Ol_Chiki
+    (0x1C80, 0x1C88, 29),    // Cyrillic
+    (0x1C90, 0x1CBA, 39),    // Georgian
+    (0x1CBD, 0x1CBF, 39),    // Georgian
+    (0x1CC0, 0x1CC7, 134),   // Sundanese
+    (0x1CD0, 0x1CD2, 55),    // Inherited
+    (0x1CD3, 0x1CD3, 24),    // Common
+    (0x1CD4, 0x1CE0, 55),    // Inherited
+    (0x1CE1, 0x1CE1, 24),    // Common
+    (0x1CE2, 0x1CE8, 55),    // Inherited
+    (0x1CE9, 0x1CEC, 24),    // Common
+    (0x1CED, 0x1CED, 55),    // Inherited
+    (0x1CEE, 0x1CF3, 24),    // Common
+    (0x1CF4, 0x1CF4, 55),    // Inherited
+    (0x1CF5, 0x1CF7, 24),    // Common
+    (0x1CF8, 0x1CF9, 55),    // Inherited
+    (0x1CFA, 0x1CFA, 24),    // Common
+    (0x1D00, 0x1D25, 69),    // Latin
+    (0x1D26, 0x1D2A, 43),    // Greek
+    (0x1D2B, 0x1D2B, 29),    // Cyrillic
+    (0x1D2C, 0x1D5C, 69),    // Latin
+    (0x1D5D, 0x1D61, 43),    // Greek
+    (0x1D62, 0x1D65, 69),    // Latin
+    (0x1D66, 0x1D6A, 43),    // Greek
+    (0x1D6B, 0x1D77, 69),    // Latin
+    (0x1D78, 0x1D78, 29),    // Cyrillic
+    (0x1D79, 0x1DBE, 69),    // Latin
+    (0x1DBF, 0x1DBF, 43),    // Greek
+    (0x1DC0, 0x1DFF, 55),    // Inherited
+    (0x1E00, 0x1EFF, 69),    // Latin
+    (0x1F00, 0x1F15, 43),    // Greek
+    (0x1F18, 0x1F1D, 43),    // Greek
+    (0x1F20, 0x1F45, 43),    // Greek
+    (0x1F48, 0x1F4D, 43),    // Greek
+    (0x1F50, 0x1F57, 43),    // Greek
+    (0x1F59, 0x1F59, 43),    // Greek
+    (0x1F5B, 0x1F5B, 43),    // Greek
+    (0x1F5D, 0x1F5D, 43),    // Greek
+    (0x1F5F, 0x1F7D, 43),    // Greek
+    (0x1F80, 0x1FB4, 43),    // Greek
+    (0x1FB6, 0x1FC4, 43),    // Greek
+    (0x1FC6, 0x1FD3, 43),    // Greek
+    (0x1FD6, 0x1FDB, 43),    // Greek
+    (0x1FDD, 0x1FEF, 43),    // Greek
+    (0x1FF2, 0x1FF4, 43),    // Greek
+    (0x1FF6, 0x1FFE, 43),    // Greek
+    (0x2000, 0x200B, 24),    // Common
+    (0x200C, 0x200D, 55),    // Inherited
+    (0x200E, 0x2064, 24),    // Common
+    (0x2066, 0x2070, 24),    // Common
+    (0x2071, 0x2071, 69),    // Latin
+    (0x2074, 0x207E, 24),    // Common
+    (0x207F, 0x207F, 69),    // Latin
+    (0x2080, 0x208E, 24),    // Common
+    (0x2090, 0x209C, 69),    // Latin
+    (0x20A0, 0x20C0, 24),    // Common
+    (0x20D0, 0x20F0, 55),    // Inherited
+    (0x2100, 0x2125, 24),    // Common
+    (0x2126, 0x2126, 43),    // Greek
+    (0x2127, 0x2129, 24),    // Common
+    (0x212A, 0x212B, 69),    // Latin
+    (0x212C, 0x2131, 24),    // Common
+    (0x2132, 0x2132, 69),    // Latin
+    (0x2133, 0x214D, 24),    // Common
+    (0x214E, 0x214E, 69),    // Latin
+    (0x214F, 0x215F, 24),    // Common
+    (0x2160, 0x2188, 69),    // Latin
+    (0x2189, 0x218B, 24),    // Common
+    (0x2190, 0x2426, 24),    // Common
+    (0x2440, 0x244A, 24),    // Common
+    (0x2460, 0x27FF, 24),    // Common
+    (0x2800, 0x28FF, 14),    // Braille
+    (0x2900, 0x2B73, 24),    // Common
+    (0x2B76, 0x2B95, 24),    // Common
+    (0x2B97, 0x2BFF, 24),    // Common
+    (0x2C00, 0x2C5F, 40),    // Glagolitic
+    (0x2C60, 0x2C7F, 69),    // Latin
+    (0x2C80, 0x2CF3, 25),    // Coptic
+    (0x2CF9, 0x2CFF, 25),    // Coptic
+    (0x2D00, 0x2D25, 39),    // Georgian
+    (0x2D27, 0x2D27, 39),    // Georgian
+    (0x2D2D, 0x2D2D, 39),    // Georgian
+    (0x2D30, 0x2D67, 150),   // Tifinagh
+    (0x2D6F, 0x2D70, 150),   // Tifinagh
+    (0x2D7F, 0x2D7F, 150),   // Tifinagh
+    (0x2D80, 0x2D96, 38),    // Ethiopic
+    (0x2DA0, 0x2DA6, 38),    // Ethiopic
+    (0x2DA8, 0x2DAE, 38),    // Ethiopic
+    (0x2DB0, 0x2DB6, 38),    // Ethiopic
+    (0x2DB8, 0x2DBE, 38),    // Ethiopic
+    (0x2DC0, 0x2DC6, 38),    // Ethiopic
+    (0x2DC8, 0x2DCE, 38),    // Ethiopic
+    (0x2DD0, 0x2DD6, 38),    // Ethiopic
+    (0x2DD8, 0x2DDE, 38),    // Ethiopic
+    (0x2DE0, 0x2DFF, 29),    // Cyrillic
+    (0x2E00, 0x2E5D, 24),    // Common
+    (0x2E80, 0x2E99, 47),    // Han
+    (0x2E9B, 0x2EF3, 47),    // Han
+    (0x2F00, 0x2FD5, 47),    // Han
+    (0x2FF0, 0x2FFB, 24),    // Common
+    (0x3000, 0x3004, 24),    // Common
+    (0x3005, 0x3005, 47),    // Han
+    (0x3006, 0x3006, 24),    // Common
+    (0x3007, 0x3007, 47),    // Han
+    (0x3008, 0x3020, 24),    // Common
+    (0x3021, 0x3029, 47),    // Han
+    (0x302A, 0x302D, 55),    // Inherited
+    (0x302E, 0x302F, 48),    // Hangul
+    (0x3030, 0x3037, 24),    // Common
+    (0x3038, 0x303B, 47),    // Han
+    (0x303C, 0x303F, 24),    // Common
+    (0x3041, 0x3096, 53),    // Hiragana
+    (0x3099, 0x309A, 55),    // Inherited
+    (0x309B, 0x309C, 24),    // Common
+    (0x309D, 0x309F, 53),    // Hiragana
+    (0x30A0, 0x30A0, 24),    // Common
+    (0x30A1, 0x30FA, 61),    // Katakana
+    (0x30FB, 0x30FC, 24),    // Common
+    (0x30FD, 0x30FF, 61),    // Katakana
+    (0x3105, 0x312F, 12),    // Bopomofo
+    (0x3131, 0x318E, 48),    // Hangul
+    (0x3190, 0x319F, 24),    // Common
+    (0x31A0, 0x31BF, 12),    // Bopomofo
+    (0x31C0, 0x31E3, 24),    // Common
+    (0x31F0, 0x31FF, 61),    // Katakana
+    (0x3200, 0x321E, 48),    // Hangul
+    (0x3220, 0x325F, 24),    // Common
+    (0x3260, 0x327E, 48),    // Hangul
+    (0x327F, 0x32CF, 24),    // Common
+    (0x32D0, 0x32FE, 61),    // Katakana
+    (0x32FF, 0x32FF, 24),    // Common
+    (0x3300, 0x3357, 61),    // Katakana
+    (0x3358, 0x33FF, 24),    // Common
+    (0x3400, 0x4DBF, 47),    // Han
+    (0x4DC0, 0x4DFF, 24),    // Common
+    (0x4E00, 0x9FFF, 47),    // Han
+    (0xA000, 0xA48C, 160),   // Yi
+    (0xA490, 0xA4C6, 160),   // Yi
+    (0xA4D0, 0xA4FF, 74),    // Lisu
+    (0xA500, 0xA62B, 155),   // Vai
+    (0xA640, 0xA69F, 29),    // Cyrillic
+    (0xA6A0, 0xA6F7, 7),     // Bamum
+    (0xA700, 0xA721, 24),    // Common
+    (0xA722, 0xA787, 69),    // Latin
+    (0xA788, 0xA78A, 24),    // Common
+    (0xA78B, 0xA7CA, 69),    // Latin
+    (0xA7D0, 0xA7D1, 69),    // Latin
+    (0xA7D3, 0xA7D3, 69),    // Latin
+    (0xA7D5, 0xA7D9, 69),    // Latin
+    (0xA7F2, 0xA7FF, 69),    // Latin
+    (0xA800, 0xA82C, 135),   // Syloti_Nagri
+    (0xA830, 0xA839, 24),    // Common
+    (0xA840, 0xA877, 119),   // Phags_Pa
+    (0xA880, 0xA8C5, 125),   // Saurashtra
+    (0xA8CE, 0xA8D9, 125),   // Saurashtra
+    (0xA8E0, 0xA8FF, 31),    // Devanagari
+    (0xA900, 0xA92D, 62),    // Kayah_Li
+    (0xA92E, 0xA92E, 24),    // Common
+    (0xA92F, 0xA92F, 62),    // Kayah_Li
+    (0xA930, 0xA953, 122),   // Rejang
+    (0xA95F, 0xA95F, 122),   // Rejang
+    (0xA960, 0xA97C, 48),    // Hangul
+    (0xA980, 0xA9CD, 58),    // Javanese
+    (0xA9CF, 0xA9CF, 24),    // Common
+    (0xA9D0, 0xA9D9, 58),    // Javanese
+    (0xA9DE, 0xA9DF, 58),    // Javanese
+    (0xA9E0, 0xA9FE, 94),    // Myanmar
+    (0xAA00, 0xAA36, 21),    // Cham
+    (0xAA40, 0xAA4D, 21),    // Cham
+    (0xAA50, 0xAA59, 21),    // Cham
+    (0xAA5C, 0xAA5F, 21),    // Cham
+    (0xAA60, 0xAA7F, 94),    // Myanmar
+    (0xAA80, 0xAAC2, 141),   // Tai_Viet
+    (0xAADB, 0xAADF, 141),   // Tai_Viet
+    (0xAAE0, 0xAAF6, 85),    // Meetei_Mayek
+    (0xAB01, 0xAB06, 38),    // Ethiopic
+    (0xAB09, 0xAB0E, 38),    // Ethiopic
+    (0xAB11, 0xAB16, 38),    // Ethiopic
+    (0xAB20, 0xAB26, 38),    // Ethiopic
+    (0xAB28, 0xAB2E, 38),    // Ethiopic
+    (0xAB30, 0xAB5A, 69),    // Latin
+    (0xAB5B, 0xAB5B, 24),    // Common
+    (0xAB5C, 0xAB64, 69),    // Latin
+    (0xAB65, 0xAB65, 43),    // Greek
+    (0xAB66, 0xAB69, 69),    // Latin
+    (0xAB6A, 0xAB6B, 24),    // Common
+    (0xAB70, 0xABBF, 22),    // Cherokee
+    (0xABC0, 0xABED, 85),    // Meetei_Mayek
+    (0xABF0, 0xABF9, 85),    // Meetei_Mayek
+    (0xAC00, 0xD7A3, 48),    // Hangul
+    (0xD7B0, 0xD7C6, 48),    // Hangul
+    (0xD7CB, 0xD7FB, 48),    // Hangul
+    (0xF900, 0xFA6D, 47),    // Han
+    (0xFA70, 0xFAD9, 47),    // Han
+    (0xFB00, 0xFB06, 69),    // Latin
+    (0xFB13, 0xFB17, 4),     // Armenian
+    (0xFB1D, 0xFB36, 52),    // Hebrew
+    (0xFB38, 0xFB3C, 52),    // Hebrew
+    (0xFB3E, 0xFB3E, 52),    // Hebrew
+    (0xFB40, 0xFB41, 52),    // Hebrew
+    (0xFB43, 0xFB44, 52),    // Hebrew
+    (0xFB46, 0xFB4F, 52),    // Hebrew
+    (0xFB50, 0xFBC2, 3),     // Arabic
+    (0xFBD3, 0xFD3D, 3),     // Arabic
+    (0xFD3E, 0xFD3F, 24),    // Common
+    (0xFD40, 0xFD8F, 3),     // Arabic
+    (0xFD92, 0xFDC7, 3),     // Arabic
+    (0xFDCF, 0xFDCF, 3),     // Arabic
+    (0xFDF0, 0xFDFF, 3),     // Arabic
+    (0xFE00, 0xFE0F, 55),    // Inherited
+    (0xFE10, 0xFE19, 24),    // Common
+    (0xFE20, 0xFE2D, 55),    // Inherited
+    (0xFE2E, 0xFE2F, 29),    // Cyrillic
+    (0xFE30, 0xFE52, 24),    // Common
+    (0xFE54, 0xFE66, 24),    // Common
+    (0xFE68, 0xFE6B, 24),    // Common
+    (0xFE70, 0xFE74, 3),     // Arabic
+    (0xFE76, 0xFEFC, 3),     // Arabic
+    (0xFEFF, 0xFEFF, 24),    // Common
+    (0xFF01, 0xFF20, 24),    // Common
+    (0xFF21, 0xFF3A, 69),    // Latin
+    (0xFF3B, 0xFF40, 24),    // Common
+    (0xFF41, 0xFF5A, 69),    // Latin
+    (0xFF5B, 0xFF65, 24),    // Common
+    (0xFF66, 0xFF6F, 61),    // Katakana
+    (0xFF70, 0xFF70, 24),    // Common
+    (0xFF71, 0xFF9D, 61),    // Katakana
+    (0xFF9E, 0xFF9F, 24),    // Common
+    (0xFFA0, 0xFFBE, 48),    // Hangul
+    (0xFFC2, 0xFFC7, 48),    // Hangul
+    (0xFFCA, 0xFFCF, 48),    // Hangul
+    (0xFFD2, 0xFFD7, 48),    // Hangul
+    (0xFFDA, 0xFFDC, 48),    // Hangul
+    (0xFFE0, 0xFFE6, 24),    // Common
+    (0xFFE8, 0xFFEE, 24),    // Common
+    (0xFFF9, 0xFFFD, 24),    // Common
+    (0x10000, 0x1000B, 73),  // Linear_B
+    (0x1000D, 0x10026, 73),  // Linear_B
+    (0x10028, 0x1003A, 73),  // Linear_B
+    (0x1003C, 0x1003D, 73),  // Linear_B
+    (0x1003F, 0x1004D, 73),  // Linear_B
+    (0x10050, 0x1005D, 73),  // Linear_B
+    (0x10080, 0x100FA, 73),  // Linear_B
+    (0x10100, 0x10102, 24),  // Common
+    (0x10107, 0x10133, 24),  // Common
+    (0x10137, 0x1013F, 24),  // Common
+    (0x10140, 0x1018E, 43),  // Greek
+    (0x10190, 0x1019C, 24),  // Common
+    (0x101A0, 0x101A0, 43),  // Greek
+    (0x101D0, 0x101FC, 24),  // Common
+    (0x101FD, 0x101FD, 55),  // Inherited
+    (0x10280, 0x1029C, 75),  // Lycian
+    (0x102A0, 0x102D0, 18),  // Carian
+    (0x102E0, 0x102E0, 55),  // Inherited
+    (0x102E1, 0x102FB, 24),  // Common
+    (0x10300, 0x10323, 105), // Old_Italic
+    (0x1032D, 0x1032F, 105), // Old_Italic
+    (0x10330, 0x1034A, 41),  // Gothic
+    (0x10350, 0x1037A, 107), // Old_Permic
+    (0x10380, 0x1039D, 153), // Ugaritic
+    (0x1039F, 0x1039F, 153), // Ugaritic
+    (0x103A0, 0x103C3, 108), // Old_Persian
+    (0x103C8, 0x103D5, 108), // Old_Persian
+    (0x10400, 0x1044F, 30),  // Deseret
+    (0x10450, 0x1047F, 127), // Shavian
+    (0x10480, 0x1049D, 115), // Osmanya
+    (0x104A0, 0x104A9, 115), // Osmanya
+    (0x104B0, 0x104D3, 114), // Osage
+    (0x104D8, 0x104FB, 114), // Osage
+    (0x10500, 0x10527, 36),  // Elbasan
+    (0x10530, 0x10563, 19),  // Caucasian_Albanian
+    (0x1056F, 0x1056F, 19),  // Caucasian_Albanian
+    (0x10570, 0x1057A, 156), // Vithkuqi
+    (0x1057C, 0x1058A, 156), // Vithkuqi
+    (0x1058C, 0x10592, 156), // Vithkuqi
+    (0x10594, 0x10595, 156), // Vithkuqi
+    (0x10597, 0x105A1, 156), // Vithkuqi
+    (0x105A3, 0x105B1, 156), // Vithkuqi
+    (0x105B3, 0x105B9, 156), // Vithkuqi
+    (0x105BB, 0x105BC, 156), // Vithkuqi
+    (0x10600, 0x10736, 72),  // Linear_A
+    (0x10740, 0x10755, 72),  // Linear_A
+    (0x10760, 0x10767, 72),  // Linear_A
+    (0x10780, 0x10785, 69),  // Latin
+    (0x10787, 0x107B0, 69),  // Latin
+    (0x107B2, 0x107BA, 69),  // Latin
+    (0x10800, 0x10805, 27),  // Cypriot
+    (0x10808, 0x10808, 27),  // Cypriot
+    (0x1080A, 0x10835, 27),  // Cypriot
+    (0x10837, 0x10838, 27),  // Cypriot
+    (0x1083C, 0x1083C, 27),  // Cypriot
+    (0x1083F, 0x1083F, 27),  // Cypriot
+    (0x10840, 0x10855, 54),  // Imperial_Aramaic
+    (0x10857, 0x1085F, 54),  // Imperial_Aramaic
+    (0x10860, 0x1087F, 117), // Palmyrene
+    (0x10880, 0x1089E, 95),  // Nabataean
+    (0x108A7, 0x108AF, 95),  // Nabataean
+    (0x108E0, 0x108F2, 51),  // Hatran
+    (0x108F4, 0x108F5, 51),  // Hatran
+    (0x108FB, 0x108FF, 51),  // Hatran
+    (0x10900, 0x1091B, 120), // Phoenician
+    (0x1091F, 0x1091F, 120), // Phoenician
+    (0x10920, 0x10939, 76),  // Lydian
+    (0x1093F, 0x1093F, 76),  // Lydian
+    (0x10980, 0x1099F, 88),  // Meroitic_Hieroglyphs
+    (0x109A0, 0x109B7, 87),  // Meroitic_Cursive
+    (0x109BC, 0x109CF, 87),  // Meroitic_Cursive
+    (0x109D2, 0x109FF, 87),  // Meroitic_Cursive
+    (0x10A00, 0x10A03, 63),  // Kharoshthi
+    (0x10A05, 0x10A06, 63),  // Kharoshthi
+    (0x10A0C, 0x10A13, 63),  // Kharoshthi
+    (0x10A15, 0x10A17, 63),  // Kharoshthi
+    (0x10A19, 0x10A35, 63),  // Kharoshthi
+    (0x10A38, 0x10A3A, 63),  // Kharoshthi
+    (0x10A3F, 0x10A48, 63),  // Kharoshthi
+    (0x10A50, 0x10A58, 63),  // Kharoshthi
+    (0x10A60, 0x10A7F, 110), // Old_South_Arabian
+    (0x10A80, 0x10A9F, 106), // Old_North_Arabian
+    (0x10AC0, 0x10AE6, 81),  // Manichaean
+    (0x10AEB, 0x10AF6, 81),  // Manichaean
+    (0x10B00, 0x10B35, 5),   // Avestan
+    (0x10B39, 0x10B3F, 5),   // Avestan
+    (0x10B40, 0x10B55, 57),  // Inscriptional_Parthian
+    (0x10B58, 0x10B5F, 57),  // Inscriptional_Parthian
+    (0x10B60, 0x10B72, 56),  // Inscriptional_Pahlavi
+    (0x10B78, 0x10B7F, 56),  // Inscriptional_Pahlavi
+    (0x10B80, 0x10B91, 121), // Psalter_Pahlavi
+    (0x10B99, 0x10B9C, 121), // Psalter_Pahlavi
+    (0x10BA9, 0x10BAF, 121), // Psalter_Pahlavi
+    (0x10C00, 0x10C48, 111), // Old_Turkic
+    (0x10C80, 0x10CB2, 104), // Old_Hungarian
+    (0x10CC0, 0x10CF2, 104), // Old_Hungarian
+    (0x10CFA, 0x10CFF, 104), // Old_Hungarian
+    (0x10D00, 0x10D27, 49),  // Hanifi_Rohingya
+    (0x10D30, 0x10D39, 49),  // Hanifi_Rohingya
+    (0x10E60, 0x10E7E, 3),   // Arabic
+    (0x10E80, 0x10EA9, 159), // Yezidi
+    (0x10EAB, 0x10EAD, 159), // Yezidi
+    (0x10EB0, 0x10EB1, 159), // Yezidi
+    (0x10F00, 0x10F27, 109), // Old_Sogdian
+    (0x10F30, 0x10F59, 131), // Sogdian
+    (0x10F70, 0x10F89, 112), // Old_Uyghur
+    (0x10FB0, 0x10FCB, 23),  // Chorasmian
+    (0x10FE0, 0x10FF6, 37),  // Elymaic
+    (0x11000, 0x1104D, 13),  // Brahmi
+    (0x11052, 0x11075, 13),  // Brahmi
+    (0x1107F, 0x1107F, 13),  // Brahmi
+    (0x11080, 0x110C2, 59),  // Kaithi
+    (0x110CD, 0x110CD, 59),  // Kaithi
+    (0x110D0, 0x110E8, 132), // Sora_Sompeng
+    (0x110F0, 0x110F9, 132), // Sora_Sompeng
+    (0x11100, 0x11134, 20),  // Chakma
+    (0x11136, 0x11147, 20),  // Chakma
+    (0x11150, 0x11176, 77),  // Mahajani
+    (0x11180, 0x111DF, 126), // Sharada
+    (0x111E1, 0x111F4, 130), // Sinhala
+    (0x11200, 0x11211, 66),  // Khojki
+    (0x11213, 0x1123E, 66),  // Khojki
+    (0x11280, 0x11286, 93),  // Multani
+    (0x11288, 0x11288, 93),  // Multani
+    (0x1128A, 0x1128D, 93),  // Multani
+    (0x1128F, 0x1129D, 93),  // Multani
+    (0x1129F, 0x112A9, 93),  // Multani
+    (0x112B0, 0x112EA, 67),  // Khudawadi
+    (0x112F0, 0x112F9, 67),  // Khudawadi
+    (0x11300, 0x11303, 42),  // Grantha
+    (0x11305, 0x1130C, 42),  // Grantha
+    (0x1130F, 0x11310, 42),  // Grantha
+    (0x11313, 0x11328, 42),  // Grantha
+    (0x1132A, 0x11330, 42),  // Grantha
+    (0x11332, 0x11333, 42),  // Grantha
+    (0x11335, 0x11339, 42),  // Grantha
+    (0x1133B, 0x1133B, 55),  // Inherited
+    (0x1133C, 0x11344, 42),  // Grantha
+    (0x11347, 0x11348, 42),  // Grantha
+    (0x1134B, 0x1134D, 42),  // Grantha
+    (0x11350, 0x11350, 42),  // Grantha
+    (0x11357, 0x11357, 42),  // Grantha
+    (0x1135D, 0x11363, 42),  // Grantha
+    (0x11366, 0x1136C, 42),  // Grantha
+    (0x11370, 0x11374, 42),  // Grantha
+    (0x11400, 0x1145B, 98),  // Newa
+    (0x1145D, 0x11461, 98),  // Newa
+    (0x11480, 0x114C7, 151), // Tirhuta
+    (0x114D0, 0x114D9, 151), // Tirhuta
+    (0x11580, 0x115B5, 128), // Siddham
+    (0x115B8, 0x115DD, 128), // Siddham
+    (0x11600, 0x11644, 90),  // Modi
+    (0x11650, 0x11659, 90),  // Modi
+    (0x11660, 0x1166C, 91),  // Mongolian
+    (0x11680, 0x116B9, 142), // Takri
+    (0x116C0, 0x116C9, 142), // Takri
+    (0x11700, 0x1171A, 1),   // Ahom
+    (0x1171D, 0x1172B, 1),   // Ahom
+    (0x11730, 0x11746, 1),   // Ahom
+    (0x11800, 0x1183B, 33),  // Dogra
+    (0x118A0, 0x118F2, 158), // Warang_Citi
+    (0x118FF, 0x118FF, 158), // Warang_Citi
+    (0x11900, 0x11906, 32),  // Dives_Akuru
+    (0x11909, 0x11909, 32),  // Dives_Akuru
+    (0x1190C, 0x11913, 32),  // Dives_Akuru
+    (0x11915, 0x11916, 32),  // Dives_Akuru
+    (0x11918, 0x11935, 32),  // Dives_Akuru
+    (0x11937, 0x11938, 32),  // Dives_Akuru
+    (0x1193B, 0x11946, 32),  // Dives_Akuru
+    (0x11950, 0x11959, 32),  // Dives_Akuru
+    (0x119A0, 0x119A7, 96),  // Nandinagari
+    (0x119AA, 0x119D7, 96),  // Nandinagari
+    (0x119DA, 0x119E4, 96),  // Nandinagari
+    (0x11A00, 0x11A47, 161), // Zanabazar_Square
+    (0x11A50, 0x11AA2, 133), // Soyombo
+    (0x11AB0, 0x11ABF, 17),  // Canadian_Aboriginal
+    (0x11AC0, 0x11AF8, 118), // Pau_Cin_Hau
+    (0x11C00, 0x11C08, 11),  // Bhaiksuki
+    (0x11C0A, 0x11C36, 11),  // Bhaiksuki
+    (0x11C38, 0x11C45, 11),  // Bhaiksuki
+    (0x11C50, 0x11C6C, 11),  // Bhaiksuki
+    (0x11C70, 0x11C8F, 82),  // Marchen
+    (0x11C92, 0x11CA7, 82),  // Marchen
+    (0x11CA9, 0x11CB6, 82),  // Marchen
+    (0x11D00, 0x11D06, 83),  // Masaram_Gondi
+    (0x11D08, 0x11D09, 83),  // Masaram_Gondi
+    (0x11D0B, 0x11D36, 83),  // Masaram_Gondi
+    (0x11D3A, 0x11D3A, 83),  // Masaram_Gondi
+    (0x11D3C, 0x11D3D, 83),  // Masaram_Gondi
+    (0x11D3F, 0x11D47, 83),  // Masaram_Gondi
+    (0x11D50, 0x11D59, 83),  // Masaram_Gondi
+    (0x11D60, 0x11D65, 45),  // Gunjala_Gondi
+    (0x11D67, 0x11D68, 45),  // Gunjala_Gondi
+    (0x11D6A, 0x11D8E, 45),  // Gunjala_Gondi
+    (0x11D90, 0x11D91, 45),  // Gunjala_Gondi
+    (0x11D93, 0x11D98, 45),  // Gunjala_Gondi
+    (0x11DA0, 0x11DA9, 45),  // Gunjala_Gondi
+    (0x11EE0, 0x11EF8, 78),  // Makasar
+    (0x11FB0, 0x11FB0, 74),  // Lisu
+    (0x11FC0, 0x11FF1, 143), // Tamil
+    (0x11FFF, 0x11FFF, 143), // Tamil
+    (0x12000, 0x12399, 26),  // Cuneiform
+    (0x12400, 0x1246E, 26),  // Cuneiform
+    (0x12470, 0x12474, 26),  // Cuneiform
+    (0x12480, 0x12543, 26),  // Cuneiform
+    (0x12F90, 0x12FF2, 28),  // Cypro_Minoan
+    (0x13000, 0x1342E, 35),  // Egyptian_Hieroglyphs
+    (0x13430, 0x13438, 35),  // Egyptian_Hieroglyphs
+    (0x14400, 0x14646, 2),   // Anatolian_Hieroglyphs
+    (0x16800, 0x16A38, 7),   // Bamum
+    (0x16A40, 0x16A5E, 92),  // Mro
+    (0x16A60, 0x16A69, 92),  // Mro
+    (0x16A6E, 0x16A6F, 92),  // Mro
+    (0x16A70, 0x16ABE, 144), // Tangsa
+    (0x16AC0, 0x16AC9, 144), // Tangsa
+    (0x16AD0, 0x16AED, 8),   // Bassa_Vah
+    (0x16AF0, 0x16AF5, 8),   // Bassa_Vah
+    (0x16B00, 0x16B45, 116), // Pahawh_Hmong
+    (0x16B50, 0x16B59, 116), // Pahawh_Hmong
+    (0x16B5B, 0x16B61, 116), // Pahawh_Hmong
+    (0x16B63, 0x16B77, 116), // Pahawh_Hmong
+    (0x16B7D, 0x16B8F, 116), // Pahawh_Hmong
+    (0x16E40, 0x16E9A, 84),  // Medefaidrin
+    (0x16F00, 0x16F4A, 89),  // Miao
+    (0x16F4F, 0x16F87, 89),  // Miao
+    (0x16F8F, 0x16F9F, 89),  // Miao
+    (0x16FE0, 0x16FE0, 145), // Tangut
+    (0x16FE1, 0x16FE1, 100), // Nushu
+    (0x16FE2, 0x16FE3, 47),  // Han
+    (0x16FE4, 0x16FE4, 64),  // Khitan_Small_Script
+    (0x16FF0, 0x16FF1, 47),  // Han
+    (0x17000, 0x187F7, 145), // Tangut
+    (0x18800, 0x18AFF, 145), // Tangut
+    (0x18B00, 0x18CD5, 64),  // Khitan_Small_Script
+    (0x18D00, 0x18D08, 145), // Tangut
+    (0x1AFF0, 0x1AFF3, 61),  // Katakana
+    (0x1AFF5, 0x1AFFB, 61),  // Katakana
+    (0x1AFFD, 0x1AFFE, 61),  // Katakana
+    (0x1B000, 0x1B000, 61),  // Katakana
+    (0x1B001, 0x1B11F, 53),  // Hiragana
+    (0x1B120, 0x1B122, 61),  // Katakana
+    (0x1B150, 0x1B152, 53),  // Hiragana
+    (0x1B164, 0x1B167, 61),  // Katakana
+    (0x1B170, 0x1B2FB, 100), // Nushu
+    (0x1BC00, 0x1BC6A, 34),  // Duployan
+    (0x1BC70, 0x1BC7C, 34),  // Duployan
+    (0x1BC80, 0x1BC88, 34),  // Duployan
+    (0x1BC90, 0x1BC99, 34),  // Duployan
+    (0x1BC9C, 0x1BC9F, 34),  // Duployan
+    (0x1BCA0, 0x1BCA3, 24),  // Common
+    (0x1CF00, 0x1CF2D, 55),  // Inherited
+    (0x1CF30, 0x1CF46, 55),  // Inherited
+    (0x1CF50, 0x1CFC3, 24),  // Common
+    (0x1D000, 0x1D0F5, 24),  // Common
+    (0x1D100, 0x1D126, 24),  // Common
+    (0x1D129, 0x1D166, 24),  // Common
+    (0x1D167, 0x1D169, 55),  // Inherited
+    (0x1D16A, 0x1D17A, 24),  // Common
+    (0x1D17B, 0x1D182, 55),  // Inherited
+    (0x1D183, 0x1D184, 24),  // Common
+    (0x1D185, 0x1D18B, 55),  // Inherited
+    (0x1D18C, 0x1D1A9, 24),  // Common
+    (0x1D1AA, 0x1D1AD, 55),  // Inherited
+    (0x1D1AE, 0x1D1EA, 24),  // Common
+    (0x1D200, 0x1D245, 43),  // Greek
+    (0x1D2E0, 0x1D2F3, 24),  // Common
+    (0x1D300, 0x1D356, 24),  // Common
+    (0x1D360, 0x1D378, 24),  // Common
+    (0x1D400, 0x1D454, 24),  // Common
+    (0x1D456, 0x1D49C, 24),  // Common
+    (0x1D49E, 0x1D49F, 24),  // Common
+    (0x1D4A2, 0x1D4A2, 24),  // Common
+    (0x1D4A5, 0x1D4A6, 24),  // Common
+    (0x1D4A9, 0x1D4AC, 24),  // Common
+    (0x1D4AE, 0x1D4B9, 24),  // Common
+    (0x1D4BB, 0x1D4BB, 24),  // Common
+    (0x1D4BD, 0x1D4C3, 24),  // Common
+    (0x1D4C5, 0x1D505, 24),  // Common
+    (0x1D507, 0x1D50A, 24),  // Common
+    (0x1D50D, 0x1D514, 24),  // Common
+    (0x1D516, 0x1D51C, 24),  // Common
+    (0x1D51E, 0x1D539, 24),  // Common
+    (0x1D53B, 0x1D53E, 24),  // Common
+    (0x1D540, 0x1D544, 24),  // Common
+    (0x1D546, 0x1D546, 24),  // Common
+    (0x1D54A, 0x1D550, 24),  // Common
+    (0x1D552, 0x1D6A5, 24),  // Common
+    (0x1D6A8, 0x1D7CB, 24),  // Common
+    (0x1D7CE, 0x1D7FF, 24),  // Common
+    (0x1D800, 0x1DA8B, 129), // SignWriting
+    (0x1DA9B, 0x1DA9F, 129), // SignWriting
+    (0x1DAA1, 0x1DAAF, 129), // SignWriting
+    (0x1DF00, 0x1DF1E, 69),  // Latin
+    (0x1E000, 0x1E006, 40),  // Glagolitic
+    (0x1E008, 0x1E018, 40),  // Glagolitic
+    (0x1E01B, 0x1E021, 40),  // Glagolitic
+    (0x1E023, 0x1E024, 40),  // Glagolitic
+    (0x1E026, 0x1E02A, 40),  // Glagolitic
+    (0x1E100, 0x1E12C, 101), // Nyiakeng_Puachue_Hmong
+    (0x1E130, 0x1E13D, 101), // Nyiakeng_Puachue_Hmong
+    (0x1E140, 0x1E149, 101), // Nyiakeng_Puachue_Hmong
+    (0x1E14E, 0x1E14F, 101), // Nyiakeng_Puachue_Hmong
+    (0x1E290, 0x1E2AE, 152), // Toto
+    (0x1E2C0, 0x1E2F9, 157), // Wancho
+    (0x1E2FF, 0x1E2FF, 157), // Wancho
+    (0x1E7E0, 0x1E7E6, 38),  // Ethiopic
+    (0x1E7E8, 0x1E7EB, 38),  // Ethiopic
+    (0x1E7ED, 0x1E7EE, 38),  // Ethiopic
+    (0x1E7F0, 0x1E7FE, 38),  // Ethiopic
+    (0x1E800, 0x1E8C4, 86),  // Mende_Kikakui
+    (0x1E8C7, 0x1E8D6, 86),  // Mende_Kikakui
+    (0x1E900, 0x1E94B, 0),   // Adlam
+    (0x1E950, 0x1E959, 0),   // Adlam
+    (0x1E95E, 0x1E95F, 0),   // Adlam
+    (0x1EC71, 0x1ECB4, 24),  // Common
+    (0x1ED01, 0x1ED3D, 24),  // Common
+    (0x1EE00, 0x1EE03, 3),   // Arabic
+    (0x1EE05, 0x1EE1F, 3),   // Arabic
+    (0x1EE21, 0x1EE22, 3),   // Arabic
+    (0x1EE24, 0x1EE24, 3),   // Arabic
+    (0x1EE27, 0x1EE27, 3),   // Arabic
+    (0x1EE29, 0x1EE32, 3),   // Arabic
+    (0x1EE34, 0x1EE37, 3),   // Arabic
+    (0x1EE39, 0x1EE39, 3),   // Arabic
+    (0x1EE3B, 0x1EE3B, 3),   // Arabic
+    (0x1EE42, 0x1EE42, 3),   // Arabic
+    (0x1EE47, 0x1EE47, 3),   // Arabic
+    (0x1EE49, 0x1EE49, 3),   // Arabic
+    (0x1EE4B, 0x1EE4B, 3),   // Arabic
+    (0x1EE4D, 0x1EE4F, 3),   // Arabic
+    (0x1EE51, 0x1EE52, 3),   // Arabic
+    (0x1EE54, 0x1EE54, 3),   // Arabic
+    (0x1EE57, 0x1EE57, 3),   // Arabic
+    (0x1EE59, 0x1EE59, 3),   // Arabic
+    (0x1EE5B, 0x1EE5B, 3),   // Arabic
+    (0x1EE5D, 0x1EE5D, 3),   // Arabic
+    (0x1EE5F, 0x1EE5F, 3),   // Arabic
+    (0x1EE61, 0x1EE62, 3),   // Arabic
+    (0x1EE64, 0x1EE64, 3),   // Arabic
+    (0x1EE67, 0x1EE6A, 3),   // Arabic
+    (0x1EE6C, 0x1EE72, 3),   // Arabic
+    (0x1EE74, 0x1EE77, 3),   // Arabic
+    (0x1EE79, 0x1EE7C, 3),   // Arabic
+    (0x1EE7E, 0x1EE7E, 3),   // Arabic
+    (0x1EE80, 0x1EE89, 3),   // Arabic
+    (0x1EE8B, 0x1EE9B, 3),   // Arabic
+    (0x1EEA1, 0x1EEA3, 3),   // Arabic
+    (0x1EEA5, 0x1EEA9, 3),   // Arabic
+    (0x1EEAB, 0x1EEBB, 3),   // Arabic
+    (0x1EEF0, 0x1EEF1, 3),   // Arabic
+    (0x1F000, 0x1F02B, 24),  // Common
+    (0x1F030, 0x1F093, 24),  // Common
+    (0x1F0A0, 0x1F0AE, 24),  // Common
+    (0x1F0B1, 0x1F0BF, 24),  // Common
+    (0x1F0C1, 0x1F0CF, 24),  // Common
+    (0x1F0D1, 0x1F0F5, 24),  // Common
+    (0x1F100, 0x1F1AD, 24),  // Common
+    (0x1F1E6, 0x1F1FF, 24),  // Common
+    (0x1F200, 0x1F200, 53),  // Hiragana
+    (0x1F201, 0x1F202, 24),  // Common
+    (0x1F210, 0x1F23B, 24),  // Common
+    (0x1F240, 0x1F248, 24),  // Common
+    (0x1F250, 0x1F251, 24),  // Common
+    (0x1F260, 0x1F265, 24),  // Common
+    (0x1F300, 0x1F6D7, 24),  // Common
+    (0x1F6DD, 0x1F6EC, 24),  // Common
+    (0x1F6F0, 0x1F6FC, 24),  // Common
+    (0x1F700, 0x1F773, 24),  // Common
+    (0x1F780, 0x1F7D8, 24),  // Common
+    (0x1F7E0, 0x1F7EB, 24),  // Common
+    (0x1F7F0, 0x1F7F0, 24),  // Common
+    (0x1F800, 0x1F80B, 24),  // Common
+    (0x1F810, 0x1F847, 24),  // Common
+    (0x1F850, 0x1F859, 24),  // Common
+    (0x1F860, 0x1F887, 24),  // Common
+    (0x1F890, 0x1F8AD, 24),  // Common
+    (0x1F8B0, 0x1F8B1, 24),  // Common
+    (0x1F900, 0x1FA53, 24),  // Common
+    (0x1FA60, 0x1FA6D, 24),  // Common
+    (0x1FA70, 0x1FA74, 24),  // Common
+    (0x1FA78, 0x1FA7C, 24),  // Common
+    (0x1FA80, 0x1FA86, 24),  // Common
+    (0x1FA90, 0x1FAAC, 24),  // Common
+    (0x1FAB0, 0x1FABA, 24),  // Common
+    (0x1FAC0, 0x1FAC5, 24),  // Common
+    (0x1FAD0, 0x1FAD9, 24),  // Common
+    (0x1FAE0, 0x1FAE7, 24),  // Common
+    (0x1FAF0, 0x1FAF6, 24),  // Common
+    (0x1FB00, 0x1FB92, 24),  // Common
+    (0x1FB94, 0x1FBCA, 24),  // Common
+    (0x1FBF0, 0x1FBF9, 24),  // Common
+    (0x20000, 0x2A6DF, 47),  // Han
+    (0x2A700, 0x2B738, 47),  // Han
+    (0x2B740, 0x2B81D, 47),  // Han
+    (0x2B820, 0x2CEA1, 47),  // Han
+    (0x2CEB0, 0x2EBE0, 47),  // Han
+    (0x2F800, 0x2FA1D, 47),  // Han
+    (0x30000, 0x3134A, 47),  // Han
+    (0xE0001, 0xE0001, 24),  // Common
+    (0xE0020, 0xE007F, 24),  // Common
+    (0xE0100, 0xE01EF, 55),  // Inherited
+];
+
+/// The canonical combining class of every character that has one, as sorted,
+/// disjoint ranges of code points. Characters not in any range have class 0.
+pub(super) const COMBINING_CLASSES: &[(u32, u32, u8)] = &[
+    (0x300, 0x314, 230),
+    (0x315, 0x315, 232),
+    (0x316, 0x319, 220),
+    (0x31A, 0x31A, 232),
+    (0x31B, 0x31B, 216),
+    (0x31C, 0x320, 220),
+    (0x321, 0x322, 202),
+    (0x323, 0x326, 220),
+    (0x327, 0x328, 202),
+    (0x329, 0x333, 220),
+    (0x334, 0x338, 1),
+    (0x339, 0x33C, 220),
+    (0x33D, 0x344, 230),
+    (0x345, 0x345, 240),
+    (0x346, 0x346, 230),
+    (0x347, 0x349, 220),
+    (0x34A, 0x34C, 230),
+    (0x34D, 0x34E, 220),
+    (0x350, 0x352, 230),
+    (0x353, 0x356, 220),
+    (0x357, 0x357, 230),
+    (0x358, 0x358, 232),
+    (0x359, 0x35A, 220),
+    (0x35B, 0x35B, 230),
+    (0x35C, 0x35C, 233),
+    (0x35D, 0x35E, 234),
+    (0x35F, 0x35F, 233),
+    (0x360, 0x361, 234),
+    (0x362, 0x362, 233),
+    (0x363, 0x36F, 230),
+    (0x483, 0x487, 230),
+    (0x591, 0x591, 220),
+    (0x592, 0x595, 230),
+    (0x596, 0x596, 220),
+    (0x597, 0x599, 230),
+    (0x59A, 0x59A, 222),
+    (0x59B, 0x59B, 220),
+    (0x59C, 0x5A1, 230),
+    (0x5A2, 0x5A7, 220),
+    (0x5A8, 0x5A9, 230),
+    (0x5AA, 0x5AA, 220),
+    (0x5AB, 0x5AC, 230),
+    (0x5AD, 0x5AD, 222),
+    (0x5AE, 0x5AE, 228),
+    (0x5AF, 0x5AF, 230),
+    (0x5B0, 0x5B0, 10),
+    (0x5B1, 0x5B1, 11),
+    (0x5B2, 0x5B2, 12),
+    (0x5B3, 0x5B3, 13),
+    (0x5B4, 0x5B4, 14),
+    (0x5B5, 0x5B5, 15),
+    (0x5B6, 0x5B6, 16),
+    (0x5B7, 0x5B7, 17),
+    (0x5B8, 0x5B8, 18),
+    (0x5B9, 0x5BA, 19),
+    (0x5BB, 0x5BB, 20),
+    (0x5BC, 0x5BC, 21),
+    (0x5BD, 0x5BD, 22),
+    (0x5BF, 0x5BF, 23),
+    (0x5C1, 0x5C1, 24),
+    (0x5C2, 0x5C2, 25),
+    (0x5C4, 0x5C4, 230),
+    (0x5C5, 0x5C5, 220),
+    (0x5C7, 0x5C7, 18),
+    (0x610, 0x617, 230),
+    (0x618, 0x618, 30),
+    (0x619, 0x619, 31),
+    (0x61A, 0x61A, 32),
+    (0x64B, 0x64B, 27),
+    (0x64C, 0x64C, 28),
+    (0x64D, 0x64D, 29),
+    (0x64E, 0x64E, 30),
+    (0x64F, 0x64F, 31),
+    (0x650, 0x650, 32),
+    (0x651, 0x651, 33),
+    (0x652, 0x652, 34),
+    (0x653, 0x654, 230),
+    (0x655, 0x656, 220),
+    (0x657, 0x65B, 230),
+    (0x65C, 0x65C, 220),
+    (0x65D, 0x65E, 230),
+    (0x65F, 0x65F, 220),
+    (0x670, 0x670, 35),
+    (0x6D6, 0x6DC, 230),
+    (0x6DF, 0x6E2, 230),
+    (0x6E3, 0x6E3, 220),
+    (0x6E4, 0x6E4, 230),
+    (0x6E7, 0x6E8, 230),
+    (0x6EA, 0x6EA, 220),
+    (0x6EB, 0x6EC, 230),
+    (0x6ED, 0x6ED, 220),
+    (0x711, 0x711, 36),
+    (0x730, 0x730, 230),
+    (0x731, 0x731, 220),
+    (0x732, 0x733, 230),
+    (0x734, 0x734, 220),
+    (0x735, 0x736, 230),
+    (0x737, 0x739, 220),
+    (0x73A, 0x73A, 230),
+    (0x73B, 0x73C, 220),
+    (0x73D, 0x73D, 230),
+    (0x73E, 0x73E, 220),
+    (0x73F, 0x741, 230),
+    (0x742, 0x742, 220),
+    (0x743, 0x743, 230),
+    (0x744, 0x744, 220),
+    (0x745, 0x745, 230),
+    (0x746, 0x746, 220),
+    (0x747, 0x747, 230),
+    (0x748, 0x748, 220),
+    (0x749, 0x74A, 230),
+    (0x7EB, 0x7F1, 230),
+    (0x7F2, 0x7F2, 220),
+    (0x7F3, 0x7F3, 230),
+    (0x7FD, 0x7FD, 220),
+    (0x816, 0x819, 230),
+    (0x81B, 0x823, 230),
+    (0x825, 0x827, 230),
+    (0x829, 0x82D, 230),
+    (0x859, 0x85B, 220),
+    (0x898, 0x898, 230),
+    (0x899, 0x89B, 220),
+    (0x89C, 0x89F, 230),
+    (0x8CA, 0x8CE, 230),
+    (0x8CF, 0x8D3, 220),
+    (0x8D4, 0x8E1, 230),
+    (0x8E3, 0x8E3, 220),
+    (0x8E4, 0x8E5, 230),
+    (0x8E6, 0x8E6, 220),
+    (0x8E7, 0x8E8, 230),
+    (0x8E9, 0x8E9, 220),
+    (0x8EA, 0x8EC, 230),
+    (0x8ED, 0x8EF, 220),
+    (0x8F0, 0x8F0, 27),
+    (0x8F1, 0x8F1, 28),
+    (0x8F2, 0x8F2, 29),
+    (0x8F3, 0x8F5, 230),
+    (0x8F6, 0x8F6, 220),
+    (0x8F7, 0x8F8, 230),
+    (0x8F9, 0x8FA, 220),
+    (0x8FB, 0x8FF, 230),
+    (0x93C, 0x93C, 7),
+    (0x94D, 0x94D, 9),
+    (0x951, 0x951, 230),
+    (0x952, 0x952, 220),
+    (0x953, 0x954, 230),
+    (0x9BC, 0x9BC, 7),
+    (0x9CD, 0x9CD, 9),
+    (0x9FE, 0x9FE, 230),
+    (0xA3C, 0xA3C, 7),
+    (0xA4D, 0xA4D, 9),
+    (0xABC, 0xABC, 7),
+    (0xACD, 0xACD, 9),
+    (0xB3C, 0xB3C, 7),
+    (0xB4D, 0xB4D, 9),
+    (0xBCD, 0xBCD, 9),
+    (0xC3C, 0xC3C, 7),
+    (0xC4D, 0xC4D, 9),
+    (0xC55, 0xC55, 84),
+    (0xC56, 0xC56, 91),
+    (0xCBC, 0xCBC, 7),
+    (0xCCD, 0xCCD, 9),
+    (0xD3B, 0xD3C, 9),
+    (0xD4D, 0xD4D, 9),
+    (0xDCA, 0xDCA, 9),
+    (0xE38, 0xE39, 103),
+    (0xE3A, 0xE3A, 9),
+    (0xE48, 0xE4B, 107),
+    (0xEB8, 0xEB9, 118),
+    (0xEBA, 0xEBA, 9),
+    (0xEC8, 0xECB, 122),
+    (0xF18, 0xF19, 220),
+    (0xF35, 0xF35, 220),
+    (0xF37, 0xF37, 220),
+    (0xF39, 0xF39, 216),
+    (0xF71, 0xF71, 129),
+    (0xF72, 0xF72, 130),
+    (0xF74, 0xF74, 132),
+    (0xF7A, 0xF7D, 130),
+    (0xF80, 0xF80, 130),
+    (0xF82, 0xF83, 230),
+    (0xF84, 0xF84, 9),
+    (0xF86, 0xF87, 230),
+    (0xFC6, 0xFC6, 220),
+    (0x1037, 0x1037, 7),
+    (0x1039, 0x103A, 9),
+    (0x108D, 0x108D, 220),
+    (0x135D, 0x135F, 230),
+    (0x1714, 0x1715, 9),
+    (0x1734, 0x1734, 9),
+    (0x17D2, 0x17D2, 9),
+    (0x17DD, 0x17DD, 230),
+    (0x18A9, 0x18A9, 228),
+    (0x1939, 0x1939, 222),
+    (0x193A, 0x193A, 230),
+    (0x193B, 0x193B, 220),
+    (0x1A17, 0x1A17, 230),
+    (0x1A18, 0x1A18, 220),
+    (0x1A60, 0x1A60, 9),
+    (0x1A75, 0x1A7C, 230),
+    (0x1A7F, 0x1A7F, 220),
+    (0x1AB0, 0x1AB4, 230),
+    (0x1AB5, 0x1ABA, 220),
+    (0x1ABB, 0x1ABC, 230),
+    (0x1ABD, 0x1ABD, 220),
+    (0x1ABF, 0x1AC0, 220),
+    (0x1AC1, 0x1AC2, 230),
+    (0x1AC3, 0x1AC4, 220),
+    (0x1AC5, 0x1AC9, 230),
+    (0x1ACA, 0x1ACA, 220),
+    (0x1ACB, 0x1ACE, 230),
+    (0x1B34, 0x1B34, 7),
+    (0x1B44, 0x1B44, 9),
+    (0x1B6B, 0x1B6B, 230),
+    (0x1B6C, 0x1B6C, 220),
+    (0x1B6D, 0x1B73, 230),
+    (0x1BAA, 0x1BAB, 9),
+    (0x1BE6, 0x1BE6, 7),
+    (0x1BF2, 0x1BF3, 9),
+    (0x1C37, 0x1C37, 7),
+    (0x1CD0, 0x1CD2, 230),
+    (0x1CD4, 0x1CD4, 1),
+    (0x1CD5, 0x1CD9, 220),
+    (0x1CDA, 0x1CDB, 230),
+    (0x1CDC, 0x1CDF, 220),
+    (0x1CE0, 0x1CE0, 230),
+    (0x1CE2, 0x1CE8, 1),
+    (0x1CED, 0x1CED, 220),
+    (0x1CF4, 0x1CF4, 230),
+    (0x1CF8, 0x1CF9, 230),
+    (0x1DC0, 0x1DC1, 230),
+    (0x1DC2, 0x1DC2, 220),
+    (0x1DC3, 0x1DC9, 230),
+    (0x1DCA, 0x1DCA, 220),
+    (0x1DCB, 0x1DCC, 230),
+    (0x1DCD, 0x1DCD, 234),
+    (0x1DCE, 0x1DCE, 214),
+    (0x1DCF, 0x1DCF, 220),
+    (0x1DD0, 0x1DD0, 202),
+    (0x1DD1, 0x1DF5, 230),
+    (0x1DF6, 0x1DF6, 232),
+    (0x1DF7, 0x1DF8, 228),
+    (0x1DF9, 0x1DF9, 220),
+    (0x1DFA, 0x1DFA, 218),
+    (0x1DFB, 0x1DFB, 230),
+    (0x1DFC, 0x1DFC, 233),
+    (0x1DFD, 0x1DFD, 220),
+    (0x1DFE, 0x1DFE, 230),
+    (0x1DFF, 0x1DFF, 220),
+    (0x20D0, 0x20D1, 230),
+    (0x20D2, 0x20D3, 1),
+    (0x20D4, 0x20D7, 230),
+    (0x20D8, 0x20DA, 1),
+    (0x20DB, 0x20DC, 230),
+    (0x20E1, 0x20E1, 230),
+    (0x20E5, 0x20E6, 1),
+    (0x20E7, 0x20E7, 230),
+    (0x20E8, 0x20E8, 220),
+    (0x20E9, 0x20E9, 230),
+    (0x20EA, 0x20EB, 1),
+    (0x20EC, 0x20EF, 220),
+    (0x20F0, 0x20F0, 230),
+    (0x2CEF, 0x2CF1, 230),
+    (0x2D7F, 0x2D7F, 9),
+    (0x2DE0, 0x2DFF, 230),
+    (0x302A, 0x302A, 218),
+    (0x302B, 0x302B, 228),
+    (0x302C, 0x302C, 232),
+    (0x302D, 0x302D, 222),
+    (0x302E, 0x302F, 224),
+    (0x3099, 0x309A, 8),
+    (0xA66F, 0xA66F, 230),
+    (0xA674, 0xA67D, 230),
+    (0xA69E, 0xA69F, 230),
+    (0xA6F0, 0xA6F1, 230),
+    (0xA806, 0xA806, 9),
+    (0xA82C, 0xA82C, 9),
+    (0xA8C4, 0xA8C4, 9),
+    (0xA8E0, 0xA8F1, 230),
+    (0xA92B, 0xA92D, 220),
+    (0xA953, 0xA953, 9),
+    (0xA9B3, 0xA9B3, 7),
+    (0xA9C0, 0xA9C0, 9),
+    (0xAAB0, 0xAAB0, 230),
+    (0xAAB2, 0xAAB3, 230),
+    (0xAAB4, 0xAAB4, 220),
+    (0xAAB7, 0xAAB8, 230),
+    (0xAABE, 0xAABF, 230),
+    (0xAAC1, 0xAAC1, 230),
+    (0xAAF6, 0xAAF6, 9),
+    (0xABED, 0xABED, 9),
+    (0xFB1E, 0xFB1E, 26),
+    (0xFE20, 0xFE26, 230),
+    (0xFE27, 0xFE2D, 220),
+    (0xFE2E, 0xFE2F, 230),
+    (0x101FD, 0x101FD, 220),
+    (0x102E0, 0x102E0, 220),
+    (0x10376, 0x1037A, 230),
+    (0x10A0D, 0x10A0D, 220),
+    (0x10A0F, 0x10A0F, 230),
+    (0x10A38, 0x10A38, 230),
+    (0x10A39, 0x10A39, 1),
+    (0x10A3A, 0x10A3A, 220),
+    (0x10A3F, 0x10A3F, 9),
+    (0x10AE5, 0x10AE5, 230),
+    (0x10AE6, 0x10AE6, 220),
+    (0x10D24, 0x10D27, 230),
+    (0x10EAB, 0x10EAC, 230),
+    (0x10F46, 0x10F47, 220),
+    (0x10F48, 0x10F4A, 230),
+    (0x10F4B, 0x10F4B, 220),
+    (0x10F4C, 0x10F4C, 230),
+    (0x10F4D, 0x10F50, 220),
+    (0x10F82, 0x10F82, 230),
+    (0x10F83, 0x10F83, 220),
+    (0x10F84, 0x10F84, 230),
+    (0x10F85, 0x10F85, 220),
+    (0x11046, 0x11046, 9),
+    (0x11070, 0x11070, 9),
+    (0x1107F, 0x1107F, 9),
+    (0x110B9, 0x110B9, 9),
+    (0x110BA, 0x110BA, 7),
+    (0x11100, 0x11102, 230),
+    (0x11133, 0x11134, 9),
+    (0x11173, 0x11173, 7),
+    (0x111C0, 0x111C0, 9),
+    (0x111CA, 0x111CA, 7),
+    (0x11235, 0x11235, 9),
+    (0x11236, 0x11236, 7),
+    (0x112E9, 0x112E9, 7),
+    (0x112EA, 0x112EA, 9),
+    (0x1133B, 0x1133C, 7),
+    (0x1134D, 0x1134D, 9),
+    (0x11366, 0x1136C, 230),
+    (0x11370, 0x11374, 230),
+    (0x11442, 0x11442, 9),
+    (0x11446, 0x11446, 7),
+    (0x1145E, 0x1145E, 230),
+    (0x114C2, 0x114C2, 9),
+    (0x114C3, 0x114C3, 7),
+    (0x115BF, 0x115BF, 9),
+    (0x115C0, 0x115C0, 7),
+    (0x1163F, 0x1163F, 9),
+    (0x116B6, 0x116B6, 9),
+    (0x116B7, 0x116B7, 7),
+    (0x1172B, 0x1172B, 9),
+    (0x11839, 0x11839, 9),
+    (0x1183A, 0x1183A, 7),
+    (0x1193D, 0x1193E, 9),
+    (0x11943, 0x11943, 7),
+    (0x119E0, 0x119E0, 9),
+    (0x11A34, 0x11A34, 9),
+    (0x11A47, 0x11A47, 9),
+    (0x11A99, 0x11A99, 9),
+    (0x11C3F, 0x11C3F, 9),
+    (0x11D42, 0x11D42, 7),
+    (0x11D44, 0x11D45, 9),
+    (0x11D97, 0x11D97, 9),
+    (0x16AF0, 0x16AF4, 1),
+    (0x16B30, 0x16B36, 230),
+    (0x16FF0, 0x16FF1, 6),
+    (0x1BC9E, 0x1BC9E, 1),
+    (0x1D165, 0x1D166, 216),
+    (0x1D167, 0x1D169, 1),
+    (0x1D16D, 0x1D16D, 226),
+    (0x1D16E, 0x1D172, 216),
+    (0x1D17B, 0x1D182, 220),
+    (0x1D185, 0x1D189, 230),
+    (0x1D18A, 0x1D18B, 220),
+    (0x1D1AA, 0x1D1AD, 230),
+    (0x1D242, 0x1D244, 230),
+    (0x1E000, 0x1E006, 230),
+    (0x1E008, 0x1E018, 230),
+    (0x1E01B, 0x1E021, 230),
+    (0x1E023, 0x1E024, 230),
+    (0x1E026, 0x1E02A, 230),
+    (0x1E130, 0x1E136, 230),
+    (0x1E2AE, 0x1E2AE, 230),
+    (0x1E2EC, 0x1E2EF, 230),
+    (0x1E8D0, 0x1E8D6, 220),
+    (0x1E944, 0x1E949, 230),
+    (0x1E94A, 0x1E94A, 7),
+];
+
+/// The canonical decomposition of every character that has one, a single level
+/// deep, sorted by code point. Hangul syllables are left out, they decompose
+/// algorithmically.
+pub(super) const DECOMPOSITIONS: &[(u32, &[char])] = &[
+    (0xC0, &['\u{41}', '\u{300}']),
+    (0xC1, &['\u{41}', '\u{301}']),
+    (0xC2, &['\u{41}', '\u{302}']),
+    (0xC3, &['\u{41}', '\u{303}']),
+    (0xC4, &['\u{41}', '\u{308}']),
+    (0xC5, &['\u{41}', '\u{30A}']),
+    (0xC7, &['\u{43}', '\u{327}']),
+    (0xC8, &['\u{45}', '\u{300}']),
+    (0xC9, &['\u{45}', '\u{301}']),
+    (0xCA, &['\u{45}', '\u{302}']),
+    (0xCB, &['\u{45}', '\u{308}']),
+    (0xCC, &['\u{49}', '\u{300}']),
+    (0xCD, &['\u{49}', '\u{301}']),
+    (0xCE, &['\u{49}', '\u{302}']),
+    (0xCF, &['\u{49}', '\u{308}']),
+    (0xD1, &['\u{4E}', '\u{303}']),
+    (0xD2, &['\u{4F}', '\u{300}']),
+    (0xD3, &['\u{4F}', '\u{301}']),
+    (0xD4, &['\u{4F}', '\u{302}']),
+    (0xD5, &['\u{4F}', '\u{303}']),
+    (0xD6, &['\u{4F}', '\u{308}']),
+    (0xD9, &['\u{55}', '\u{300}']),
+    (0xDA, &['\u{55}', '\u{301}']),
+    (0xDB, &['\u{55}', '\u{302}']),
+    (0xDC, &['\u{55}', '\u{308}']),
+    (0xDD, &['\u{59}', '\u{301}']),
+    (0xE0, &['\u{61}', '\u{300}']),
+    (0xE1, &['\u{61}', '\u{301}']),
+    (0xE2, &['\u{61}', '\u{302}']),
+    (0xE3, &['\u{61}', '\u{303}']),
+    (0xE4, &['\u{61}', '\u{308}']),
+    (0xE5, &['\u{61}', '\u{30A}']),
+    (0xE7, &['\u{63}', '\u{327}']),
+    (0xE8, &['\u{65}', '\u{300}']),
+    (0xE9, &['\u{65}', '\u{301}']),
+    (0xEA, &['\u{65}', '\u{302}']),
+    (0xEB, &['\u{65}', '\u{308}']),
+    (0xEC, &['\u{69}', '\u{300}']),
+    (0xED, &['\u{69}', '\u{301}']),
+    (0xEE, &['\u{69}', '\u{302}']),
+    (0xEF, &['\u{69}', '\u{308}']),
+    (0xF1, &['\u{6E}', '\u{303}']),
+    (0xF2, &['\u{6F}', '\u{300}']),
+    (0xF3, &['\u{6F}', '\u{301}']),
+    (0xF4, &['\u{6F}', '\u{302}']),
+    (0xF5, &['\u{6F}', '\u{303}']),
+    (0xF6, &['\u{6F}', '\u{308}']),
+    (0xF9, &['\u{75}', '\u{300}']),
+    (0xFA, &['\u{75}', '\u{301}']),
+    (0xFB, &['\u{75}', '\u{302}']),
+    (0xFC, &['\u{75}', '\u{308}']),
+    (0xFD, &['\u{79}', '\u{301}']),
+    (0xFF, &['\u{79}', '\u{308}']),
+    (0x100, &['\u{41}', '\u{304}']),
+    (0x101, &['\u{61}', '\u{304}']),
+    (0x102, &['\u{41}', '\u{306}']),
+    (0x103, &['\u{61}', '\u{306}']),
+    (0x104, &['\u{41}', '\u{328}']),
+    (0x105, &['\u{61}', '\u{328}']),
+    (0x106, &['\u{43}', '\u{301}']),
+    (0x107, &['\u{63}', '\u{301}']),
+    (0x108, &['\u{43}', '\u{302}']),
+    (0x109, &['\u{63}', '\u{302}']),
+    (0x10A, &['\u{43}', '\u{307}']),
+    (0x10B, &['\u{63}', '\u{307}']),
+    (0x10C, &['\u{43}', '\u{30C}']),
+    (0x10D, &['\u{63}', '\u{30C}']),
+    (0x10E, &['\u{44}', '\u{30C}']),
+    (0x10F, &['\u{64}', '\u{30C}']),
+    (0x112, &['\u{45}', '\u{304}']),
+    (0x113, &['\u{65}', '\u{304}']),
+    (0x114, &['\u{45}', '\u{306}']),
+    (0x115, &['\u{65}', '\u{306}']),
+    (0x116, &['\u{45}', '\u{307}']),
+    (0x117, &['\u{65}', '\u{307}']),
+    (0x118, &['\u{45}', '\u{328}']),
+    (0x119, &['\u{65}', '\u{328}']),
+    (0x11A, &['\u{45}', '\u{30C}']),
+    (0x11B, &['\u{65}', '\u{30C}']),
+    (0x11C, &['\u{47}', '\u{302}']),
+    (0x11D, &['\u{67}', '\u{302}']),
+    (0x11E, &['\u{47}', '\u{306}']),
+    (0x11F, &['\u{67}', '\u{306}']),
+    (0x120, &['\u{47}', '\u{307}']),
+    (0x121, &['\u{67}', '\u{307}']),
+    (0x122, &['\u{47}', '\u{327}']),
+    (0x123, &['\u{67}', '\u{327}']),
+    (0x124, &['\u{48}', '\u{302}']),
+    (0x125, &['\u{68}', '\u{302}']),
+    (0x128, &['\u{49}', '\u{303}']),
+    (0x129, &['\u{69}', '\u{303}']),
+    (0x12A, &['\u{49}', '\u{304}']),
+    (0x12B, &['\u{69}', '\u{304}']),
+    (0x12C, &['\u{49}', '\u{306}']),
+    (0x12D, &['\u{69}', '\u{306}']),
+    (0x12E, &['\u{49}', '\u{328}']),
+    (0x12F, &['\u{69}', '\u{328}']),
+    (0x130, &['\u{49}', '\u{307}']),
+    (0x134, &['\u{4A}', '\u{302}']),
+    (0x135, &['\u{6A}', '\u{302}']),
+    (0x136, &['\u{4B}', '\u{327}']),
+    (0x137, &['\u{6B}', '\u{327}']),
+    (0x139, &['\u{4C}', '\u{301}']),
+    (0x13A, &['\u{6C}', '\u{301}']),
+    (0x13B, &['\u{4C}', '\u{327}']),
+    (0x13C, &['\u{6C}', '\u{327}']),
+    (0x13D, &['\u{4C}', '\u{30C}']),
+    (0x13E, &['\u{6C}', '\u{30C}']),
+    (0x143, &['\u{4E}', '\u{301}']),
+    (0x144, &['\u{6E}', '\u{301}']),
+    (0x145, &['\u{4E}', '\u{327}']),
+    (0x146, &['\u{6E}', '\u{327}']),
+    (0x147, &['\u{4E}', '\u{30C}']),
+    (0x148, &['\u{6E}', '\u{30C}']),
+    (0x14C, &['\u{4F}', '\u{304}']),
+    (0x14D, &['\u{6F}', '\u{304}']),
+    (0x14E, &['\u{4F}', '\u{306}']),
+    (0x14F, &['\u{6F}', '\u{306}']),
+    (0x150, &['\u{4F}', '\u{30B}']),
+    (0x151, &['\u{6F}', '\u{30B}']),
+    (0x154, &['\u{52}', '\u{301}']),
+    (0x155, &['\u{72}', '\u{301}']),
+    (0x156, &['\u{52}', '\u{327}']),
+    (0x157, &['\u{72}', '\u{327}']),
+    (0x158, &['\u{52}', '\u{30C}']),
+    (0x159, &['\u{72}', '\u{30C}']),
+    (0x15A, &['\u{53}', '\u{301}']),
+    (0x15B, &['\u{73}', '\u{301}']),
+    (0x15C, &['\u{53}', '\u{302}']),
+    (0x15D, &['\u{73}', '\u{302}']),
+    (0x15E, &['\u{53}', '\u{327}']),
+    (0x15F, &['\u{73}', '\u{327}']),
+    (0x160, &['\u{53}', '\u{30C}']),
+    (0x161, &['\u{73}', '\u{30C}']),
+    (0x162, &['\u{54}', '\u{327}']),
+    (0x163, &['\u{74}', '\u{327}']),
+    (0x164, &['\u{54}', '\u{30C}']),
+    (0x165, &['\u{74}', '\u{30C}']),
+    (0x168, &['\u{55}', '\u{303}']),
+    (0x169, &['\u{75}', '\u{303}']),
+    (0x16A, &['\u{55}', '\u{304}']),
+    (0x16B, &['\u{75}', '\u{304}']),
+    (0x16C, &['\u{55}', '\u{306}']),
+    (0x16D, &['\u{75}', '\u{306}']),
+    (0x16E, &['\u{55}', '\u{30A}']),
+    (0x16F, &['\u{75}', '\u{30A}']),
+    (0x170, &['\u{55}', '\u{30B}']),
+    (0x171, &['\u{75}', '\u{30B}']),
+    (0x172, &['\u{55}', '\u{328}']),
+    (0x173, &['\u{75}', '\u{328}']),
+    (0x174, &['\u{57}', '\u{302}']),
+    (0x175, &['\u{77}', '\u{302}']),
+    (0x176, &['\u{59}', '\u{302}']),
+    (0x177, &['\u{79}', '\u{302}']),
+    (0x178, &['\u{59}', '\u{308}']),
+    (0x179, &['\u{5A}', '\u{301}']),
+    (0x17A, &['\u{7A}', '\u{301}']),
+    (0x17B, &['\u{5A}', '\u{307}']),
+    (0x17C, &['\u{7A}', '\u{307}']),
+    (0x17D, &['\u{5A}', '\u{30C}']),
+    (0x17E, &['\u{7A}', '\u{30C}']),
+    (0x1A0, &['\u{4F}', '\u{31B}']),
+    (0x1A1, &['\u{6F}', '\u{31B}']),
+    (0x1AF, &['\u{55}', '\u{31B}']),
+    (0x1B0, &['\u{75}', '\u{31B}']),
+    (0x1CD, &['\u{41}', '\u{30C}']),
+    (0x1CE, &['\u{61}', '\u{30C}']),
+    (0x1CF, &['\u{49}', '\u{30C}']),
+    (0x1D0, &['\u{69}', '\u{30C}']),
+    (0x1D1, &['\u{4F}', '\u{30C}']),
+    (0x1D2, &['\u{6F}', '\u{30C}']),
+    (0x1D3, &['\u{55}', '\u{30C}']),
+    (0x1D4, &['\u{75}', '\u{30C}']),
+    (0x1D5, &['\u{DC}', '\u{304}']),
+    (0x1D6, &['\u{FC}', '\u{304}']),
+    (0x1D7, &['\u{DC}', '\u{301}']),
+    (0x1D8, &['\u{FC}', '\u{301}']),
+    (0x1D9, &['\u{DC}', '\u{30C}']),
+    (0x1DA, &['\u{FC}', '\u{30C}']),
+    (0x1DB, &['\u{DC}', '\u{300}']),
+    (0x1DC, &['\u{FC}', '\u{300}']),
+    (0x1DE, &['\u{C4}', '\u{304}']),
+    (0x1DF, &['\u{E4}', '\u{304}']),
+    (0x1E0, &['\u{226}', '\u{304}']),
+    (0x1E1, &['\u{227}', '\u{304}']),
+    (0x1E2, &['\u{C6}', '\u{304}']),
+    (0x1E3, &['\u{E6}', '\u{304}']),
+    (0x1E6, &['\u{47}', '\u{30C}']),
+    (0x1E7, &['\u{67}', '\u{30C}']),
+    (0x1E8, &['\u{4B}', '\u{30C}']),
+    (0x1E9, &['\u{6B}', '\u{30C}']),
+    (0x1EA, &['\u{4F}', '\u{328}']),
+    (0x1EB, &['\u{6F}', '\u{328}']),
+    (0x1EC, &['\u{1EA}', '\u{304}']),
+    (0x1ED, &['\u{1EB}', '\u{304}']),
+    (0x1EE, &['\u{1B7}', '\u{30C}']),
+    (0x1EF, &['\u{292}', '\u{30C}']),
+    (0x1F0, &['\u{6A}', '\u{30C}']),
+    (0x1F4, &['\u{47}', '\u{301}']),
+    (0x1F5, &['\u{67}', '\u{301}']),
+    (0x1F8, &['\u{4E}', '\u{300}']),
+    (0x1F9, &['\u{6E}', '\u{300}']),
+    (0x1FA, &['\u{C5}', '\u{301}']),
+    (0x1FB, &['\u{E5}', '\u{301}']),
+    (0x1FC, &['\u{C6}', '\u{301}']),
+    (0x1FD, &['\u{E6}', '\u{301}']),
+    (0x1FE, &['\u{D8}', '\u{301}']),
+    (0x1FF, &['\u{F8}', '\u{301}']),
+    (0x200, &['\u{41}', '\u{30F}']),
+    (0x201, &['\u{61}', '\u{30F}']),
+    (0x202, &['\u{41}', '\u{311}']),
+    (0x203, &['\u{61}', '\u{311}']),
+    (0x204, &['\u{45}', '\u{30F}']),
+    (0x205, &['\u{65}', '\u{30F}']),
+    (0x206, &['\u{45}', '\u{311}']),
+    (0x207, &['\u{65}', '\u{311}']),
+    (0x208, &['\u{49}', '\u{30F}']),
+    (0x209, &['\u{69}', '\u{30F}']),
+    (0x20A, &['\u{49}', '\u{311}']),
+    (0x20B, &['\u{69}', '\u{311}']),
+    (0x20C, &['\u{4F}', '\u{30F}']),
+    (0x20D, &['\u{6F}', '\u{30F}']),
+    (0x20E, &['\u{4F}', '\u{311}']),
+    (0x20F, &['\u{6F}', '\u{311}']),
+    (0x210, &['\u{52}', '\u{30F}']),
+    (0x211, &['\u{72}', '\u{30F}']),
+    (0x212, &['\u{52}', '\u{311}']),
+    (0x213, &['\u{72}', '\u{311}']),
+    (0x214, &['\u{55}', '\u{30F}']),
+    (0x215, &['\u{75}', '\u{30F}']),
+    (0x216, &['\u{55}', '\u{311}']),
+    (0x217, &['\u{75}', '\u{311}']),
+    (0x218, &['\u{53}', '\u{326}']),
+    (0x219, &['\u{73}', '\u{326}']),
+    (0x21A, &['\u{54}', '\u{326}']),
+    (0x21B, &['\u{74}', '\u{326}']),
+    (0x21E, &['\u{48}', '\u{30C}']),
+    (0x21F, &['\u{68}', '\u{30C}']),
+    (0x226, &['\u{41}', '\u{307}']),
+    (0x227, &['\u{61}', '\u{307}']),
+    (0x228, &['\u{45}', '\u{327}']),
+    (0x229, &['\u{65}', '\u{327}']),
+    (0x22A, &['\u{D6}', '\u{304}']),
+    (0x22B, &['\u{F6}', '\u{304}']),
+    (0x22C, &['\u{D5}', '\u{304}']),
+    (0x22D, &['\u{F5}', '\u{304}']),
+    (0x22E, &['\u{4F}', '\u{307}']),
+    (0x22F, &['\u{6F}', '\u{307}']),
+    (0x230, &['\u{22E}', '\u{304}']),
+    (0x231, &['\u{22F}', '\u{304}']),
+    (0x232, &['\u{59}', '\u{304}']),
+    (0x233, &['\u{79}', '\u{304}']),
+    (0x340, &['\u{300}']),
+    (0x341, &['\u{301}']),
+    (0x343, &['\u{313}']),
+    (0x344, &['\u{308}', '\u{301}']),
+    (0x374, &['\u{2B9}']),
+    (0x37E, &['\u{3B}']),
+    (0x385, &['\u{A8}', '\u{301}']),
+    (0x386, &['\u{391}', '\u{301}']),
+    (0x387, &['\u{B7}']),
+    (0x388, &['\u{395}', '\u{301}']),
+    (0x389, &['\u{397}', '\u{301}']),
+    (0x38A, &['\u{399}', '\u{301}']),
+    (0x38C, &['\u{39F}', '\u{301}']),
+    (0x38E, &['\u{3A5}', '\u{301}']),
+    (0x38F, &['\u{3A9}', '\u{301}']),
+    (0x390, &['\u{3CA}', '\u{301}']),
+    (0x3AA, &['\u{399}', '\u{308}']),
+    (0x3AB, &['\u{3A5}', '\u{308}']),
+    (0x3AC, &['\u{3B1}', '\u{301}']),
+    (0x3AD, &['\u{3B5}', '\u{301}']),
+    (0x3AE, &['\u{3B7}', '\u{301}']),
+    (0x3AF, &['\u{3B9}', '\u{301}']),
+    (0x3B0, &['\u{3CB}', '\u{301}']),
+    (0x3CA, &['\u{3B9}', '\u{308}']),
+    (0x3CB, &['\u{3C5}', '\u{308}']),
+    (0x3CC, &['\u{3BF}', '\u{301}']),
+    (0x3CD, &['\u{3C5}', '\u{301}']),
+    (0x3CE, &['\u{3C9}', '\u{301}']),
+    (0x3D3, &['\u{3D2}', '\u{301}']),
+    (0x3D4, &['\u{3D2}', '\u{308}']),
+    (0x400, &['\u{415}', '\u{300}']),
+    (0x401, &['\u{415}', '\u{308}']),
+    (0x403, &['\u{413}', '\u{301}']),
+    (0x407, &['\u{406}', '\u{308}']),
+    (0x40C, &['\u{41A}', '\u{301}']),
+    (0x40D, &['\u{418}', '\u{300}']),
+    (0x40E, &['\u{423}', '\u{306}']),
+    (0x419, &['\u{418}', '\u{306}']),
+    (0x439, &['\u{438}', '\u{306}']),
+    (0x450, &['\u{435}', '\u{300}']),
+    (0x451, &['\u{435}', '\u{308}']),
+    (0x453, &['\u{433}', '\u{301}']),
+    (0x457, &['\u{456}', '\u{308}']),
+    (0x45C, &['\u{43A}', '\u{301}']),
+    (0x45D, &['\u{438}', '\u{300}']),
+    (0x45E, &['\u{443}', '\u{306}']),
+    (0x476, &['\u{474}', '\u{30F}']),
+    (0x477, &['\u{475}', '\u{30F}']),
+    (0x4C1, &['\u{416}', '\u{306}']),
+    (0x4C2, &['\u{436}', '\u{306}']),
+    (0x4D0, &['\u{410}', '\u{306}']),
+    (0x4D1, &['\u{430}', '\u{306}']),
+    (0x4D2, &['\u{410}', '\u{308}']),
+    (0x4D3, &['\u{430}', '\u{308}']),
+    (0x4D6, &['\u{415}', '\u{306}']),
+    (0x4D7, &['\u{435}', '\u{306}']),
+    (0x4DA, &['\u{4D8}', '\u{308}']),
+    (0x4DB, &['\u{4D9}', '\u{308}']),
+    (0x4DC, &['\u{416}', '\u{308}']),
+    (0x4DD, &['\u{436}', '\u{308}']),
+    (0x4DE, &['\u{417}', '\u{308}']),
+    (0x4DF, &['\u{437}', '\u{308}']),
+    (0x4E2, &['\u{418}', '\u{304}']),
+    (0x4E3, &['\u{438}', '\u{304}']),
+    (0x4E4, &['\u{418}', '\u{308}']),
+    (0x4E5, &['\u{438}', '\u{308}']),
+    (0x4E6, &['\u{41E}', '\u{308}']),
+    (0x4E7, &['\u{43E}', '\u{308}']),
+    (0x4EA, &['\u{4E8}', '\u{308}']),
+    (0x4EB, &['\u{4E9}', '\u{308}']),
+    (0x4EC, &['\u{42D}', '\u{308}']),
+    (0x4ED, &['\u{44D}', '\u{308}']),
+    (0x4EE, &['\u{423}', '\u{304}']),
+    (0x4EF, &['\u{443}', '\u{304}']),
+    (0x4F0, &['\u{423}', '\u{308}']),
+    (0x4F1, &['\u{443}', '\u{308}']),
+    (0x4F2, &['\u{423}', '\u{30B}']),
+    (0x4F3, &['\u{443}', '\u{30B}']),
+    (0x4F4, &['\u{427}', '\u{308}']),
+    (0x4F5, &['\u{447}', '\u{308}']),
+    (0x4F8, &['\u{42B}', '\u{308}']),
+    (0x4F9, &['\u{44B}', '\u{308}']),
+    (0x622, &['\u{627}', '\u{653}']),
+    (0x623, &['\u{627}', '\u{654}']),
+    (0x624, &['\u{648}', '\u{654}']),
+    (0x625, &['\u{627}', '\u{655}']),
+    (0x626, &['\u{64A}', '\u{654}']),
+    (0x6C0, &['\u{6D5}', '\u{654}']),
+    (0x6C2, &['\u{6C1}', '\u{654}']),
+    (0x6D3, &['\u{6D2}', '\u{654}']),
+    (0x929, &['\u{928}', '\u{93C}']),
+    (0x931, &['\u{930}', '\u{93C}']),
+    (0x934, &['\u{933}', '\u{93C}']),
+    (0x958, &['\u{915}', '\u{93C}']),
+    (0x959, &['\u{916}', '\u{93C}']),
+    (0x95A, &['\u{917}', '\u{93C}']),
+    (0x95B, &['\u{91C}', '\u{93C}']),
+    (0x95C, &['\u{921}', '\u{93C}']),
+    (0x95D, &['\u{922}', '\u{93C}']),
+    (0x95E, &['\u{92B}', '\u{93C}']),
+    (0x95F, &['\u{92F}', '\u{93C}']),
+    (0x9CB, &['\u{9C7}', '\u{9BE}']),
+    (0x9CC, &['\u{9C7}', '\u{9D7}']),
+    (0x9DC, &['\u{9A1}', '\u{9BC}']),
+    (0x9DD, &['\u{9A2}', '\u{9BC}']),
+    (0x9DF, &['\u{9AF}', '\u{9BC}']),
+    (0xA33, &['\u{A32}', '\u{A3C}']),
+    (0xA36, &['\u{A38}', '\u{A3C}']),
+    (0xA59, &['\u{A16}', '\u{A3C}']),
+    (0xA5A, &['\u{A17}', '\u{A3C}']),
+    (0xA5B, &['\u{A1C}', '\u{A3C}']),
+    (0xA5E, &['\u{A2B}', '\u{A3C}']),
+    (0xB48, &['\u{B47}', '\u{B56}']),
+    (0xB4B, &['\u{B47}', '\u{B3E}']),
+    (0xB4C, &['\u{B47}', '\u{B57}']),
+    (0xB5C, &['\u{B21}', '\u{B3C}']),
+    (0xB5D, &['\u{B22}', '\u{B3C}']),
+    (0xB94, &['\u{B92}', '\u{BD7}']),
+    (0xBCA, &['\u{BC6}', '\u{BBE}']),
+    (0xBCB, &['\u{BC7}', '\u{BBE}']),
+    (0xBCC, &['\u{BC6}', '\u{BD7}']),
+    (0xC48, &['\u{C46}', '\u{C56}']),
+    (0xCC0, &['\u{CBF}', '\u{CD5}']),
+    (0xCC7, &['\u{CC6}', '\u{CD5}']),
+    (0xCC8, &['\u{CC6}', '\u{CD6}']),
+    (0xCCA, &['\u{CC6}', '\u{CC2}']),
+    (0xCCB, &['\u{CCA}', '\u{CD5}']),
+    (0xD4A, &['\u{D46}', '\u{D3E}']),
+    (0xD4B, &['\u{D47}', '\u{D3E}']),
+    (0xD4C, &['\u{D46}', '\u{D57}']),
+    (0xDDA, &['\u{DD9}', '\u{DCA}']),
+    (0xDDC, &['\u{DD9}', '\u{DCF}']),
+    (0xDDD, &['\u{DDC}', '\u{DCA}']),
+    (0xDDE, &['\u{DD9}', '\u{DDF}']),
+    (0xF43, &['\u{F42}', '\u{FB7}']),
+    (0xF4D, &['\u{F4C}', '\u{FB7}']),
+    (0xF52, &['\u{F51}', '\u{FB7}']),
+    (0xF57, &['\u{F56}', '\u{FB7}']),
+    (0xF5C, &['\u{F5B}', '\u{FB7}']),
+    (0xF69, &['\u{F40}', '\u{FB5}']),
+    (0xF73, &['\u{F71}', '\u{F72}']),
+    (0xF75, &['\u{F71}', '\u{F74}']),
+    (0xF76, &['\u{FB2}', '\u{F80}']),
+    (0xF78, &['\u{FB3}', '\u{F80}']),
+    (0xF81, &['\u{F71}', '\u{F80}']),
+    (0xF93, &['\u{F92}', '\u{FB7}']),
+    (0xF9D, &['\u{F9C}', '\u{FB7}']),
+    (0xFA2, &['\u{FA1}', '\u{FB7}']),
+    (0xFA7, &['\u{FA6}', '\u{FB7}']),
+    (0xFAC, &['\u{FAB}', '\u{FB7}']),
+    (0xFB9, &['\u{F90}', '\u{FB5}']),
+    (0x1026, &['\u{1025}', '\u{102E}']),
+    (0x1B06, &['\u{1B05}', '\u{1B35}']),
+    (0x1B08, &['\u{1B07}', '\u{1B35}']),
+    (0x1B0A, &['\u{1B09}', '\u{1B35}']),
+    (0x1B0C, &['\u{1B0B}', '\u{1B35}']),
+    (0x1B0E, &['\u{1B0D}', '\u{1B35}']),
+    (0x1B12, &['\u{1B11}', '\u{1B35}']),
+    (0x1B3B, &['\u{1B3A}', '\u{1B35}']),
+    (0x1B3D, &['\u{1B3C}', '\u{1B35}']),
+    (0x1B40, &['\u{1B3E}', '\u{1B35}']),
+    (0x1B41, &['\u{1B3F}', '\u{1B35}']),
+    (0x1B43, &['\u{1B42}', '\u{1B35}']),
+    (0x1E00, &['\u{41}', '\u{325}']),
+    (0x1E01, &['\u{61}', '\u{325}']),
+    (0x1E02, &['\u{42}', '\u{307}']),
+    (0x1E03, &['\u{62}', '\u{307}']),
+    (0x1E04, &['\u{42}', '\u{323}']),
+    (0x1E05, &['\u{62}', '\u{323}']),
+    (0x1E06, &['\u{42}', '\u{331}']),
+    (0x1E07, &['\u{62}', '\u{331}']),
+    (0x1E08, &['\u{C7}', '\u{301}']),
+    (0x1E09, &['\u{E7}', '\u{301}']),
+    (0x1E0A, &['\u{44}', '\u{307}']),
+    (0x1E0B, &['\u{64}', '\u{307}']),
+    (0x1E0C, &['\u{44}', '\u{323}']),
+    (0x1E0D, &['\u{64}', '\u{323}']),
+    (0x1E0E, &['\u{44}', '\u{331}']),
+    (0x1E0F, &['\u{64}', '\u{331}']),
+    (0x1E10, &['\u{44}', '\u{327}']),
+    (0x1E11, &['\u{64}', '\u{327}']),
+    (0x1E12, &['\u{44}', '\u{32D}']),
+    (0x1E13, &['\u{64}', '\u{32D}']),
+    (0x1E14, &['\u{112}', '\u{300}']),
+    (0x1E15, &['\u{113}', '\u{300}']),
+    (0x1E16, &['\u{112}', '\u{301}']),
+    (0x1E17, &['\u{113}', '\u{301}']),
+    (0x1E18, &['\u{45}', '\u{32D}']),
+    (0x1E19, &['\u{65}', '\u{32D}']),
+    (0x1E1A, &['\u{45}', '\u{330}']),
+    (0x1E1B, &['\u{65}', '\u{330}']),
+    (0x1E1C, &['\u{228}', '\u{306}']),
+    (0x1E1D, &['\u{229}', '\u{306}']),
+    (0x1E1E, &['\u{46}', '\u{307}']),
+    (0x1E1F, &['\u{66}', '\u{307}']),
+    (0x1E20, &['\u{47}', '\u{304}']),
+    (0x1E21, &['\u{67}', '\u{304}']),
+    (0x1E22, &['\u{48}', '\u{307}']),
+    (0x1E23, &['\u{68}', '\u{307}']),
+    (0x1E24, &['\u{48}', '\u{323}']),
+    (0x1E25, &['\u{68}', '\u{323}']),
+    (0x1E26, &['\u{48}', '\u{308}']),
+    (0x1E27, &['\u{68}', '\u{308}']),
+    (0x1E28, &['\u{48}', '\u{327}']),
+    (0x1E29, &['\u{68}', '\u{327}']),
+    (0x1E2A, &['\u{48}', '\u{32E}']),
+    (0x1E2B, &['\u{68}', '\u{32E}']),
+    (0x1E2C, &['\u{49}', '\u{330}']),
+    (0x1E2D, &['\u{69}', '\u{330}']),
+    (0x1E2E, &['\u{CF}', '\u{301}']),
+    (0x1E2F, &['\u{EF}', '\u{301}']),
+    (0x1E30, &['\u{4B}', '\u{301}']),
+    (0x1E31, &['\u{6B}', '\u{301}']),
+    (0x1E32, &['\u{4B}', '\u{323}']),
+    (0x1E33, &['\u{6B}', '\u{323}']),
+    (0x1E34, &['\u{4B}', '\u{331}']),
+    (0x1E35, &['\u{6B}', '\u{331}']),
+    (0x1E36, &['\u{4C}', '\u{323}']),
+    (0x1E37, &['\u{6C}', '\u{323}']),
+    (0x1E38, &['\u{1E36}', '\u{304}']),
+    (0x1E39, &['\u{1E37}', '\u{304}']),
+    (0x1E3A, &['\u{4C}', '\u{331}']),
+    (0x1E3B, &['\u{6C}', '\u{331}']),
+    (0x1E3C, &['\u{4C}', '\u{32D}']),
+    (0x1E3D, &['\u{6C}', '\u{32D}']),
+    (0x1E3E, &['\u{4D}', '\u{301}']),
+    (0x1E3F, &['\u{6D}', '\u{301}']),
+    (0x1E40, &['\u{4D}', '\u{307}']),
+    (0x1E41, &['\u{6D}', '\u{307}']),
+    (0x1E42, &['\u{4D}', '\u{323}']),
+    (0x1E43, &['\u{6D}', '\u{323}']),
+    (0x1E44, &['\u{4E}', '\u{307}']),
+    (0x1E45, &['\u{6E}', '\u{307}']),
+    (0x1E46, &['\u{4E}', '\u{323}']),
+    (0x1E47, &['\u{6E}', '\u{323}']),
+    (0x1E48, &['\u{4E}', '\u{331}']),
+    (0x1E49, &['\u{6E}', '\u{331}']),
+    (0x1E4A, &['\u{4E}', '\u{32D}']),
+    (0x1E4B, &['\u{6E}', '\u{32D}']),
+    (0x1E4C, &['\u{D5}', '\u{301}']),
+    (0x1E4D, &['\u{F5}', '\u{301}']),
+    (0x1E4E, &['\u{D5}', '\u{308}']),
+    (0x1E4F, &['\u{F5}', '\u{308}']),
+    (0x1E50, &['\u{14C}', '\u{300}']),
+    (0x1E51, &['\u{14D}', '\u{300}']),
+    (0x1E52, &['\u{14C}', '\u{301}']),
+    (0x1E53, &['\u{14D}', '\u{301}']),
+    (0x1E54, &['\u{50}', '\u{301}']),
+    (0x1E55, &['\u{70}', '\u{301}']),
+    (0x1E56, &['\u{50}', '\u{307}']),
+    (0x1E57, &['\u{70}', '\u{307}']),
+    (0x1E58, &['\u{52}', '\u{307}']),
+    (0x1E59, &['\u{72}', '\u{307}']),
+    (0x1E5A, &['\u{52}', '\u{323}']),
+    (0x1E5B, &['\u{72}', '\u{323}']),
+    (0x1E5C, &['\u{1E5A}', '\u{304}']),
+    (0x1E5D, &['\u{1E5B}', '\u{304}']),
+    (0x1E5E, &['\u{52}', '\u{331}']),
+    (0x1E5F, &['\u{72}', '\u{331}']),
+    (0x1E60, &['\u{53}', '\u{307}']),
+    (0x1E61, &['\u{73}', '\u{307}']),
+    (0x1E62, &['\u{53}', '\u{323}']),
+    (0x1E63, &['\u{73}', '\u{323}']),
+    (0x1E64, &['\u{15A}', '\u{307}']),
+    (0x1E65, &['\u{15B}', '\u{307}']),
+    (0x1E66, &['\u{160}', '\u{307}']),
+    (0x1E67, &['\u{161}', '\u{307}']),
+    (0x1E68, &['\u{1E62}', '\u{307}']),
+    (0x1E69, &['\u{1E63}', '\u{307}']),
+    (0x1E6A, &['\u{54}', '\u{307}']),
+    (0x1E6B, &['\u{74}', '\u{307}']),
+    (0x1E6C, &['\u{54}', '\u{323}']),
+    (0x1E6D, &['\u{74}', '\u{323}']),
+    (0x1E6E, &['\u{54}', '\u{331}']),
+    (0x1E6F, &['\u{74}', '\u{331}']),
+    (0x1E70, &['\u{54}', '\u{32D}']),
+    (0x1E71, &['\u{74}', '\u{32D}']),
+    (0x1E72, &['\u{55}', '\u{324}']),
+    (0x1E73, &['\u{75}', '\u{324}']),
+    (0x1E74, &['\u{55}', '\u{330}']),
+    (0x1E75, &['\u{75}', '\u{330}']),
+    (0x1E76, &['\u{55}', '\u{32D}']),
+    (0x1E77, &['\u{75}', '\u{32D}']),
+    (0x1E78, &['\u{168}', '\u{301}']),
+    (0x1E79, &['\u{169}', '\u{301}']),
+    (0x1E7A, &['\u{16A}', '\u{308}']),
+    (0x1E7B, &['\u{16B}', '\u{308}']),
+    (0x1E7C, &['\u{56}', '\u{303}']),
+    (0x1E7D, &['\u{76}', '\u{303}']),
+    (0x1E7E, &['\u{56}', '\u{323}']),
+    (0x1E7F, &['\u{76}', '\u{323}']),
+    (0x1E80, &['\u{57}', '\u{300}']),
+    (0x1E81, &['\u{77}', '\u{300}']),
+    (0x1E82, &['\u{57}', '\u{301}']),
+    (0x1E83, &['\u{77}', '\u{301}']),
+    (0x1E84, &['\u{57}', '\u{308}']),
+    (0x1E85, &['\u{77}', '\u{308}']),
+    (0x1E86, &['\u{57}', '\u{307}']),
+    (0x1E87, &['\u{77}', '\u{307}']),
+    (0x1E88, &['\u{57}', '\u{323}']),
+    (0x1E89, &['\u{77}', '\u{323}']),
+    (0x1E8A, &['\u{58}', '\u{307}']),
+    (0x1E8B, &['\u{78}', '\u{307}']),
+    (0x1E8C, &['\u{58}', '\u{308}']),
+    (0x1E8D, &['\u{78}', '\u{308}']),
+    (0x1E8E, &['\u{59}', '\u{307}']),
+    (0x1E8F, &['\u{79}', '\u{307}']),
+    (0x1E90, &['\u{5A}', '\u{302}']),
+    (0x1E91, &['\u{7A}', '\u{302}']),
+    (0x1E92, &['\u{5A}', '\u{323}']),
+    (0x1E93, &['\u{7A}', '\u{323}']),
+    (0x1E94, &['\u{5A}', '\u{331}']),
+    (0x1E95, &['\u{7A}', '\u{331}']),
+    (0x1E96, &['\u{68}', '\u{331}']),
+    (0x1E97, &['\u{74}', '\u{308}']),
+    (0x1E98, &['\u{77}', '\u{30A}']),
+    (0x1E99, &['\u{79}', '\u{30A}']),
+    (0x1E9B, &['\u{17F}', '\u{307}']),
+    (0x1EA0, &['\u{41}', '\u{323}']),
+    (0x1EA1, &['\u{61}', '\u{323}']),
+    (0x1EA2, &['\u{41}', '\u{309}']),
+    (0x1EA3, &['\u{61}', '\u{309}']),
+    (0x1EA4, &['\u{C2}', '\u{301}']),
+    (0x1EA5, &['\u{E2}', '\u{301}']),
+    (0x1EA6, &['\u{C2}', '\u{300}']),
+    (0x1EA7, &['\u{E2}', '\u{300}']),
+    (0x1EA8, &['\u{C2}', '\u{309}']),
+    (0x1EA9, &['\u{E2}', '\u{309}']),
+    (0x1EAA, &['\u{C2}', '\u{303}']),
+    (0x1EAB, &['\u{E2}', '\u{303}']),
+    (0x1EAC, &['\u{1EA0}', '\u{302}']),
+    (0x1EAD, &['\u{1EA1}', '\u{302}']),
+    (0x1EAE, &['\u{102}', '\u{301}']),
+    (0x1EAF, &['\u{103}', '\u{301}']),
+    (0x1EB0, &['\u{102}', '\u{300}']),
+    (0x1EB1, &['\u{103}', '\u{300}']),
+    (0x1EB2, &['\u{102}', '\u{309}']),
+    (0x1EB3, &['\u{103}', '\u{309}']),
+    (0x1EB4, &['\u{102}', '\u{303}']),
+    (0x1EB5, &['\u{103}', '\u{303}']),
+    (0x1EB6, &['\u{1EA0}', '\u{306}']),
+    (0x1EB7, &['\u{1EA1}', '\u{306}']),
+    (0x1EB8, &['\u{45}', '\u{323}']),
+    (0x1EB9, &['\u{65}', '\u{323}']),
+    (0x1EBA, &['\u{45}', '\u{309}']),
+    (0x1EBB, &['\u{65}', '\u{309}']),
+    (0x1EBC, &['\u{45}', '\u{303}']),
+    (0x1EBD, &['\u{65}', '\u{303}']),
+    (0x1EBE, &['\u{CA}', '\u{301}']),
+    (0x1EBF, &['\u{EA}', '\u{301}']),
+    (0x1EC0, &['\u{CA}', '\u{300}']),
+    (0x1EC1, &['\u{EA}', '\u{300}']),
+    (0x1EC2, &['\u{CA}', '\u{309}']),
+    (0x1EC3, &['\u{EA}', '\u{309}']),
+    (0x1EC4, &['\u{CA}', '\u{303}']),
+    (0x1EC5, &['\u{EA}', '\u{303}']),
+    (0x1EC6, &['\u{1EB8}', '\u{302}']),
+    (0x1EC7, &['\u{1EB9}', '\u{302}']),
+    (0x1EC8, &['\u{49}', '\u{309}']),
+    (0x1EC9, &['\u{69}', '\u{309}']),
+    (0x1ECA, &['\u{49}', '\u{323}']),
+    (0x1ECB, &['\u{69}', '\u{323}']),
+    (0x1ECC, &['\u{4F}', '\u{323}']),
+    (0x1ECD, &['\u{6F}', '\u{323}']),
+    (0x1ECE, &['\u{4F}', '\u{309}']),
+    (0x1ECF, &['\u{6F}', '\u{309}']),
+    (0x1ED0, &['\u{D4}', '\u{301}']),
+    (0x1ED1, &['\u{F4}', '\u{301}']),
+    (0x1ED2, &['\u{D4}', '\u{300}']),
+    (0x1ED3, &['\u{F4}', '\u{300}']),
+    (0x1ED4, &['\u{D4}', '\u{309}']),
+    (0x1ED5, &['\u{F4}', '\u{309}']),
+    (0x1ED6, &['\u{D4}', '\u{303}']),
+    (0x1ED7, &['\u{F4}', '\u{303}']),
+    (0x1ED8, &['\u{1ECC}', '\u{302}']),
+    (0x1ED9, &['\u{1ECD}', '\u{302}']),
+    (0x1EDA, &['\u{1A0}', '\u{301}']),
+    (0x1EDB, &['\u{1A1}', '\u{301}']),
+    (0x1EDC, &['\u{1A0}', '\u{300}']),
+    (0x1EDD, &['\u{1A1}', '\u{300}']),
+    (0x1EDE, &['\u{1A0}', '\u{309}']),
+    (0x1EDF, &['\u{1A1}', '\u{309}']),
+    (0x1EE0, &['\u{1A0}', '\u{303}']),
+    (0x1EE1, &['\u{1A1}', '\u{303}']),
+    (0x1EE2, &['\u{1A0}', '\u{323}']),
+    (0x1EE3, &['\u{1A1}', '\u{323}']),
+    (0x1EE4, &['\u{55}', '\u{323}']),
+    (0x1EE5, &['\u{75}', '\u{323}']),
+    (0x1EE6, &['\u{55}', '\u{309}']),
+    (0x1EE7, &['\u{75}', '\u{309}']),
+    (0x1EE8, &['\u{1AF}', '\u{301}']),
+    (0x1EE9, &['\u{1B0}', '\u{301}']),
+    (0x1EEA, &['\u{1AF}', '\u{300}']),
+    (0x1EEB, &['\u{1B0}', '\u{300}']),
+    (0x1EEC, &['\u{1AF}', '\u{309}']),
+    (0x1EED, &['\u{1B0}', '\u{309}']),
+    (0x1EEE, &['\u{1AF}', '\u{303}']),
+    (0x1EEF, &['\u{1B0}', '\u{303}']),
+    (0x1EF0, &['\u{1AF}', '\u{323}']),
+    (0x1EF1, &['\u{1B0}', '\u{323}']),
+    (0x1EF2, &['\u{59}', '\u{300}']),
+    (0x1EF3, &['\u{79}', '\u{300}']),
+    (0x1EF4, &['\u{59}', '\u{323}']),
+    (0x1EF5, &['\u{79}', '\u{323}']),
+    (0x1EF6, &['\u{59}', '\u{309}']),
+    (0x1EF7, &['\u{79}', '\u{309}']),
+    (0x1EF8, &['\u{59}', '\u{303}']),
+    (0x1EF9, &['\u{79}', '\u{303}']),
+    (0x1F00, &['\u{3B1}', '\u{313}']),
+    (0x1F01, &['\u{3B1}', '\u{314}']),
+    (0x1F02, &['\u{1F00}', '\u{300}']),
+    (0x1F03, &['\u{1F01}', '\u{300}']),
+    (0x1F04, &['\u{1F00}', '\u{301}']),
+    (0x1F05, &['\u{1F01}', '\u{301}']),
+    (0x1F06, &['\u{1F00}', '\u{342}']),
+    (0x1F07, &['\u{1F01}', '\u{342}']),
+    (0x1F08, &['\u{391}', '\u{313}']),
+    (0x1F09, &['\u{391}', '\u{314}']),
+    (0x1F0A, &['\u{1F08}', '\u{300}']),
+    (0x1F0B, &['\u{1F09}', '\u{300}']),
+    (0x1F0C, &['\u{1F08}', '\u{301}']),
+    (0x1F0D, &['\u{1F09}', '\u{301}']),
+    (0x1F0E, &['\u{1F08}', '\u{342}']),
+    (0x1F0F, &['\u{1F09}', '\u{342}']),
+    (0x1F10, &['\u{3B5}', '\u{313}']),
+    (0x1F11, &['\u{3B5}', '\u{314}']),
+    (0x1F12, &['\u{1F10}', '\u{300}']),
+    (0x1F13, &['\u{1F11}', '\u{300}']),
+    (0x1F14, &['\u{1F10}', '\u{301}']),
+    (0x1F15, &['\u{1F11}', '\u{301}']),
+    (0x1F18, &['\u{395}', '\u{313}']),
+    (0x1F19, &['\u{395}', '\u{314}']),
+    (0x1F1A, &['\u{1F18}', '\u{300}']),
+    (0x1F1B, &['\u{1F19}', '\u{300}']),
+    (0x1F1C, &['\u{1F18}', '\u{301}']),
+    (0x1F1D, &['\u{1F19}', '\u{301}']),
+    (0x1F20, &['\u{3B7}', '\u{313}']),
+    (0x1F21, &['\u{3B7}', '\u{314}']),
+    (0x1F22, &['\u{1F20}', '\u{300}']),
+    (0x1F23, &['\u{1F21}', '\u{300}']),
+    (0x1F24, &['\u{1F20}', '\u{301}']),
+    (0x1F25, &['\u{1F21}', '\u{301}']),
+    (0x1F26, &['\u{1F20}', '\u{342}']),
+    (0x1F27, &['\u{1F21}', '\u{342}']),
+    (0x1F28, &['\u{397}', '\u{313}']),
+    (0x1F29, &['\u{397}', '\u{314}']),
+    (0x1F2A, &['\u{1F28}', '\u{300}']),
+    (0x1F2B, &['\u{1F29}', '\u{300}']),
+    (0x1F2C, &['\u{1F28}', '\u{301}']),
+    (0x1F2D, &['\u{1F29}', '\u{301}']),
+    (0x1F2E, &['\u{1F28}', '\u{342}']),
+    (0x1F2F, &['\u{1F29}', '\u{342}']),
+    (0x1F30, &['\u{3B9}', '\u{313}']),
+    (0x1F31, &['\u{3B9}', '\u{314}']),
+    (0x1F32, &['\u{1F30}', '\u{300}']),
+    (0x1F33, &['\u{1F31}', '\u{300}']),
+    (0x1F34, &['\u{1F30}', '\u{301}']),
+    (0x1F35, &['\u{1F31}', '\u{301}']),
+    (0x1F36, &['\u{1F30}', '\u{342}']),
+    (0x1F37, &['\u{1F31}', '\u{342}']),
+    (0x1F38, &['\u{399}', '\u{313}']),
+    (0x1F39, &['\u{399}', '\u{314}']),
+    (0x1F3A, &['\u{1F38}', '\u{300}']),
+    (0x1F3B, &['\u{1F39}', '\u{300}']),
+    (0x1F3C, &['\u{1F38}', '\u{301}']),
+    (0x1F3D, &['\u{1F39}', '\u{301}']),
+    (0x1F3E, &['\u{1F38}', '\u{342}']),
+    (0x1F3F, &['\u{1F39}', '\u{342}']),
+    (0x1F40, &['\u{3BF}', '\u{313}']),
+    (0x1F41, &['\u{3BF}', '\u{314}']),
+    (0x1F42, &['\u{1F40}', '\u{300}']),
+    (0x1F43, &['\u{1F41}', '\u{300}']),
+    (0x1F44, &['\u{1F40}', '\u{301}']),
+    (0x1F45, &['\u{1F41}', '\u{301}']),
+    (0x1F48, &['\u{39F}', '\u{313}']),
+    (0x1F49, &['\u{39F}', '\u{314}']),
+    (0x1F4A, &['\u{1F48}', '\u{300}']),
+    (0x1F4B, &['\u{1F49}', '\u{300}']),
+    (0x1F4C, &['\u{1F48}', '\u{301}']),
+    (0x1F4D, &['\u{1F49}', '\u{301}']),
+    (0x1F50, &['\u{3C5}', '\u{313}']),
+    (0x1F51, &['\u{3C5}', '\u{314}']),
+    (0x1F52, &['\u{1F50}', '\u{300}']),
+    (0x1F53, &['\u{1F51}', '\u{300}']),
+    (0x1F54, &['\u{1F50}', '\u{301}']),
+    (0x1F55, &['\u{1F51}', '\u{301}']),
+    (0x1F56, &['\u{1F50}', '\u{342}']),
+    (0x1F57, &['\u{1F51}', '\u{342}']),
+    (0x1F59, &['\u{3A5}', '\u{314}']),
+    (0x1F5B, &['\u{1F59}', '\u{300}']),
+    (0x1F5D, &['\u{1F59}', '\u{301}']),
+    (0x1F5F, &['\u{1F59}', '\u{342}']),
+    (0x1F60, &['\u{3C9}', '\u{313}']),
+    (0x1F61, &['\u{3C9}', '\u{314}']),
+    (0x1F62, &['\u{1F60}', '\u{300}']),
+    (0x1F63, &['\u{1F61}', '\u{300}']),
+    (0x1F64, &['\u{1F60}', '\u{301}']),
+    (0x1F65, &['\u{1F61}', '\u{301}']),
+    (0x1F66, &['\u{1F60}', '\u{342}']),
+    (0x1F67, &['\u{1F61}', '\u{342}']),
+    (0x1F68, &['\u{3A9}', '\u{313}']),
+    (0x1F69, &['\u{3A9}', '\u{314}']),
+    (0x1F6A, &['\u{1F68}', '\u{300}']),
+    (0x1F6B, &['\u{1F69}', '\u{300}']),
+    (0x1F6C, &['\u{1F68}', '\u{301}']),
+    (0x1F6D, &['\u{1F69}', '\u{301}']),
+    (0x1F6E, &['\u{1F68}', '\u{342}']),
+    (0x1F6F, &['\u{1F69}', '\u{342}']),
+    (0x1F70, &['\u{3B1}', '\u{300}']),
+    (0x1F71, &['\u{3AC}']),
+    (0x1F72, &['\u{3B5}', '\u{300}']),
+    (0x1F73, &['\u{3AD}']),
+    (0x1F74, &['\u{3B7}', '\u{300}']),
+    (0x1F75, &['\u{3AE}']),
+    (0x1F76, &['\u{3B9}', '\u{300}']),
+    (0x1F77, &['\u{3AF}']),
+    (0x1F78, &['\u{3BF}', '\u{300}']),
+    (0x1F79, &['\u{3CC}']),
+    (0x1F7A, &['\u{3C5}', '\u{300}']),
+    (0x1F7B, &['\u{3CD}']),
+    (0x1F7C, &['\u{3C9}', '\u{300}']),
+    (0x1F7D, &['\u{3CE}']),
+    (0x1F80, &['\u{1F00}', '\u{345}']),
+    (0x1F81, &['\u{1F01}', '\u{345}']),
+    (0x1F82, &['\u{1F02}', '\u{345}']),
+    (0x1F83, &['\u{1F03}', '\u{345}']),
+    (0x1F84, &['\u{1F04}', '\u{345}']),
+    (0x1F85, &['\u{1F05}', '\u{345}']),
+    (0x1F86, &['\u{1F06}', '\u{345}']),
+    (0x1F87, &['\u{1F07}', '\u{345}']),
+    (0x1F88, &['\u{1F08}', '\u{345}']),
+    (0x1F89, &['\u{1F09}', '\u{345}']),
+    (0x1F8A, &['\u{1F0A}', '\u{345}']),
+    (0x1F8B, &['\u{1F0B}', '\u{345}']),
+    (0x1F8C, &['\u{1F0C}', '\u{345}']),
+    (0x1F8D, &['\u{1F0D}', '\u{345}']),
+    (0x1F8E, &['\u{1F0E}', '\u{345}']),
+    (0x1F8F, &['\u{1F0F}', '\u{345}']),
+    (0x1F90, &['\u{1F20}', '\u{345}']),
+    (0x1F91, &['\u{1F21}', '\u{345}']),
+    (0x1F92, &['\u{1F22}', '\u{345}']),
+    (0x1F93, &['\u{1F23}', '\u{345}']),
+    (0x1F94, &['\u{1F24}', '\u{345}']),
+    (0x1F95, &['\u{1F25}', '\u{345}']),
+    (0x1F96, &['\u{1F26}', '\u{345}']),
+    (0x1F97, &['\u{1F27}', '\u{345}']),
+    (0x1F98, &['\u{1F28}', '\u{345}']),
+    (0x1F99, &['\u{1F29}', '\u{345}']),
+    (0x1F9A, &['\u{1F2A}', '\u{345}']),
+    (0x1F9B, &['\u{1F2B}', '\u{345}']),
+    (0x1F9C, &['\u{1F2C}', '\u{345}']),
+    (0x1F9D, &['\u{1F2D}', '\u{345}']),
+    (0x1F9E, &['\u{1F2E}', '\u{345}']),
+    (0x1F9F, &['\u{1F2F}', '\u{345}']),
+    (0x1FA0, &['\u{1F60}', '\u{345}']),
+    (0x1FA1, &['\u{1F61}', '\u{345}']),
+    (0x1FA2, &['\u{1F62}', '\u{345}']),
+    (0x1FA3, &['\u{1F63}', '\u{345}']),
+    (0x1FA4, &['\u{1F64}', '\u{345}']),
+    (0x1FA5, &['\u{1F65}', '\u{345}']),
+    (0x1FA6, &['\u{1F66}', '\u{345}']),
+    (0x1FA7, &['\u{1F67}', '\u{345}']),
+    (0x1FA8, &['\u{1F68}', '\u{345}']),
+    (0x1FA9, &['\u{1F69}', '\u{345}']),
+    (0x1FAA, &['\u{1F6A}', '\u{345}']),
+    (0x1FAB, &['\u{1F6B}', '\u{345}']),
+    (0x1FAC, &['\u{1F6C}', '\u{345}']),
+    (0x1FAD, &['\u{1F6D}', '\u{345}']),
+    (0x1FAE, &['\u{1F6E}', '\u{345}']),
+    (0x1FAF, &['\u{1F6F}', '\u{345}']),
+    (0x1FB0, &['\u{3B1}', '\u{306}']),
+    (0x1FB1, &['\u{3B1}', '\u{304}']),
+    (0x1FB2, &['\u{1F70}', '\u{345}']),
+    (0x1FB3, &['\u{3B1}', '\u{345}']),
+    (0x1FB4, &['\u{3AC}', '\u{345}']),
+    (0x1FB6, &['\u{3B1}', '\u{342}']),
+    (0x1FB7, &['\u{1FB6}', '\u{345}']),
+    (0x1FB8, &['\u{391}', '\u{306}']),
+    (0x1FB9, &['\u{391}', '\u{304}']),
+    (0x1FBA, &['\u{391}', '\u{300}']),
+    (0x1FBB, &['\u{386}']),
+    (0x1FBC, &['\u{391}', '\u{345}']),
+    (0x1FBE, &['\u{3B9}']),
+    (0x1FC1, &['\u{A8}', '\u{342}']),
+    (0x1FC2, &['\u{1F74}', '\u{345}']),
+    (0x1FC3, &['\u{3B7}', '\u{345}']),
+    (0x1FC4, &['\u{3AE}', '\u{345}']),
+    (0x1FC6, &['\u{3B7}', '\u{342}']),
+    (0x1FC7, &['\u{1FC6}', '\u{345}']),
+    (0x1FC8, &['\u{395}', '\u{300}']),
+    (0x1FC9, &['\u{388}']),
+    (0x1FCA, &['\u{397}', '\u{300}']),
+    (0x1FCB, &['\u{389}']),
+    (0x1FCC, &['\u{397}', '\u{345}']),
+    (0x1FCD, &['\u{1FBF}', '\u{300}']),
+    (0x1FCE, &['\u{1FBF}', '\u{301}']),
+    (0x1FCF, &['\u{1FBF}', '\u{342}']),
+    (0x1FD0, &['\u{3B9}', '\u{306}']),
+    (0x1FD1, &['\u{3B9}', '\u{304}']),
+    (0x1FD2, &['\u{3CA}', '\u{300}']),
+    (0x1FD3, &['\u{390}']),
+    (0x1FD6, &['\u{3B9}', '\u{342}']),
+    (0x1FD7, &['\u{3CA}', '\u{342}']),
+    (0x1FD8, &['\u{399}', '\u{306}']),
+    (0x1FD9, &['\u{399}', '\u{304}']),
+    (0x1FDA, &['\u{399}', '\u{300}']),
+    (0x1FDB, &['\u{38A}']),
+    (0x1FDD, &['\u{1FFE}', '\u{300}']),
+    (0x1FDE, &['\u{1FFE}', '\u{301}']),
+    (0x1FDF, &['\u{1FFE}', '\u{342}']),
+    (0x1FE0, &['\u{3C5}', '\u{306}']),
+    (0x1FE1, &['\u{3C5}', '\u{304}']),
+    (0x1FE2, &['\u{3CB}', '\u{300}']),
+    (0x1FE3, &['\u{3B0}']),
+    (0x1FE4, &['\u{3C1}', '\u{313}']),
+    (0x1FE5, &['\u{3C1}', '\u{314}']),
+    (0x1FE6, &['\u{3C5}', '\u{342}']),
+    (0x1FE7, &['\u{3CB}', '\u{342}']),
+    (0x1FE8, &['\u{3A5}', '\u{306}']),
+    (0x1FE9, &['\u{3A5}', '\u{304}']),
+    (0x1FEA, &['\u{3A5}', '\u{300}']),
+    (0x1FEB, &['\u{38E}']),
+    (0x1FEC, &['\u{3A1}', '\u{314}']),
+    (0x1FED, &['\u{A8}', '\u{300}']),
+    (0x1FEE, &['\u{385}']),
+    (0x1FEF, &['\u{60}']),
+    (0x1FF2, &['\u{1F7C}', '\u{345}']),
+    (0x1FF3, &['\u{3C9}', '\u{345}']),
+    (0x1FF4, &['\u{3CE}', '\u{345}']),
+    (0x1FF6, &['\u{3C9}', '\u{342}']),
+    (0x1FF7, &['\u{1FF6}', '\u{345}']),
+    (0x1FF8, &['\u{39F}', '\u{300}']),
+    (0x1FF9, &['\u{38C}']),
+    (0x1FFA, &['\u{3A9}', '\u{300}']),
+    (0x1FFB, &['\u{38F}']),
+    (0x1FFC, &['\u{3A9}', '\u{345}']),
+    (0x1FFD, &['\u{B4}']),
+    (0x2000, &['\u{2002}']),
+    (0x2001, &['\u{2003}']),
+    (0x2126, &['\u{3A9}']),
+    (0x212A, &['\u{4B}']),
+    (0x212B, &['\u{C5}']),
+    (0x219A, &['\u{2190}', '\u{338}']),
+    (0x219B, &['\u{2192}', '\u{338}']),
+    (0x21AE, &['\u{2194}', '\u{338}']),
+    (0x21CD, &['\u{21D0}', '\u{338}']),
+    (0x21CE, &['\u{21D4}', '\u{338}']),
+    (0x21CF, &['\u{21D2}', '\u{338}']),
+    (0x2204, &['\u{2203}', '\u{338}']),
+    (0x2209, &['\u{2208}', '\u{338}']),
+    (0x220C, &['\u{220B}', '\u{338}']),
+    (0x2224, &['\u{2223}', '\u{338}']),
+    (0x2226, &['\u{2225}', '\u{338}']),
+    (0x2241, &['\u{223C}', '\u{338}']),
+    (0x2244, &['\u{2243}', '\u{338}']),
+    (0x2247, &['\u{2245}', '\u{338}']),
+    (0x2249, &['\u{2248}', '\u{338}']),
+    (0x2260, &['\u{3D}', '\u{338}']),
+    (0x2262, &['\u{2261}', '\u{338}']),
+    (0x226D, &['\u{224D}', '\u{338}']),
+    (0x226E, &['\u{3C}', '\u{338}']),
+    (0x226F, &['\u{3E}', '\u{338}']),
+    (0x2270, &['\u{2264}', '\u{338}']),
+    (0x2271, &['\u{2265}', '\u{338}']),
+    (0x2274, &['\u{2272}', '\u{338}']),
+    (0x2275, &['\u{2273}', '\u{338}']),
+    (0x2278, &['\u{2276}', '\u{338}']),
+    (0x2279, &['\u{2277}', '\u{338}']),
+    (0x2280, &['\u{227A}', '\u{338}']),
+    (0x2281, &['\u{227B}', '\u{338}']),
+    (0x2284, &['\u{2282}', '\u{338}']),
+    (0x2285, &['\u{2283}', '\u{338}']),
+    (0x2288, &['\u{2286}', '\u{338}']),
+    (0x2289, &['\u{2287}', '\u{338}']),
+    (0x22AC, &['\u{22A2}', '\u{338}']),
+    (0x22AD, &['\u{22A8}', '\u{338}']),
+    (0x22AE, &['\u{22A9}', '\u{338}']),
+    (0x22AF, &['\u{22AB}', '\u{338}']),
+    (0x22E0, &['\u{227C}', '\u{338}']),
+    (0x22E1, &['\u{227D}', '\u{338}']),
+    (0x22E2, &['\u{2291}', '\u{338}']),
+    (0x22E3, &['\u{2292}', '\u{338}']),
+    (0x22EA, &['\u{22B2}', '\u{338}']),
+    (0x22EB, &['\u{22B3}', '\u{338}']),
+    (0x22EC, &['\u{22B4}', '\u{338}']),
+    (0x22ED, &['\u{22B5}', '\u{338}']),
+    (0x2329, &['\u{3008}']),
+    (0x232A, &['\u{3009}']),
+    (0x2ADC, &['\u{2ADD}', '\u{338}']),
+    (0x304C, &['\u{304B}', '\u{3099}']),
+    (0x304E, &['\u{304D}', '\u{3099}']),
+    (0x3050, &['\u{304F}', '\u{3099}']),
+    (0x3052, &['\u{3051}', '\u{3099}']),
+    (0x3054, &['\u{3053}', '\u{3099}']),
+    (0x3056, &['\u{3055}', '\u{3099}']),
+    (0x3058, &['\u{3057}', '\u{3099}']),
+    (0x305A, &['\u{3059}', '\u{3099}']),
+    (0x305C, &['\u{305B}', '\u{3099}']),
+    (0x305E, &['\u{305D}', '\u{3099}']),
+    (0x3060, &['\u{305F}', '\u{3099}']),
+    (0x3062, &['\u{3061}', '\u{3099}']),
+    (0x3065, &['\u{3064}', '\u{3099}']),
+    (0x3067, &['\u{3066}', '\u{3099}']),
+    (0x3069, &['\u{3068}', '\u{3099}']),
+    (0x3070, &['\u{306F}', '\u{3099}']),
+    (0x3071, &['\u{306F}', '\u{309A}']),
+    (0x3073, &['\u{3072}', '\u{3099}']),
+    (0x3074, &['\u{3072}', '\u{309A}']),
+    (0x3076, &['\u{3075}', '\u{3099}']),
+    (0x3077, &['\u{3075}', '\u{309A}']),
+    (0x3079, &['\u{3078}', '\u{3099}']),
+    (0x307A, &['\u{3078}', '\u{309A}']),
+    (0x307C, &['\u{307B}', '\u{3099}']),
+    (0x307D, &['\u{307B}', '\u{309A}']),
+    (0x3094, &['\u{3046}', '\u{3099}']),
+    (0x309E, &['\u{309D}', '\u{3099}']),
+    (0x30AC, &['\u{30AB}', '\u{3099}']),
+    (0x30AE, &['\u{30AD}', '\u{3099}']),
+    (0x30B0, &['\u{30AF}', '\u{3099}']),
+    (0x30B2, &['\u{30B1}', '\u{3099}']),
+    (0x30B4, &['\u{30B3}', '\u{3099}']),
+    (0x30B6, &['\u{30B5}', '\u{3099}']),
+    (0x30B8, &['\u{30B7}', '\u{3099}']),
+    (0x30BA, &['\u{30B9}', '\u{3099}']),
+    (0x30BC, &['\u{30BB}', '\u{3099}']),
+    (0x30BE, &['\u{30BD}', '\u{3099}']),
+    (0x30C0, &['\u{30BF}', '\u{3099}']),
+    (0x30C2, &['\u{30C1}', '\u{3099}']),
+    (0x30C5, &['\u{30C4}', '\u{3099}']),
+    (0x30C7, &['\u{30C6}', '\u{3099}']),
+    (0x30C9, &['\u{30C8}', '\u{3099}']),
+    (0x30D0, &['\u{30CF}', '\u{3099}']),
+    (0x30D1, &['\u{30CF}', '\u{309A}']),
+    (0x30D3, &['\u{30D2}', '\u{3099}']),
+    (0x30D4, &['\u{30D2}', '\u{309A}']),
+    (0x30D6, &['\u{30D5}', '\u{3099}']),
+    (0x30D7, &['\u{30D5}', '\u{309A}']),
+    (0x30D9, &['\u{30D8}', '\u{3099}']),
+    (0x30DA, &['\u{30D8}', '\u{309A}']),
+    (0x30DC, &['\u{30DB}', '\u{3099}']),
+    (0x30DD, &['\u{30DB}', '\u{309A}']),
+    (0x30F4, &['\u{30A6}', '\u{3099}']),
+    (0x30F7, &['\u{30EF}', '\u{3099}']),
+    (0x30F8, &['\u{30F0}', '\u{3099}']),
+    (0x30F9, &['\u{30F1}', '\u{3099}']),
+    (0x30FA, &['\u{30F2}', '\u{3099}']),
+    (0x30FE, &['\u{30FD}', '\u{3099}']),
+    (0xF900, &['\u{8C48}']),
+    (0xF901, &['\u{66F4}']),
+    (0xF902, &['\u{8ECA}']),
+    (0xF903, &['\u{8CC8}']),
+    (0xF904, &['\u{6ED1}']),
+    (0xF905, &['\u{4E32}']),
+    (0xF906, &['\u{53E5}']),
+    (0xF907, &['\u{9F9C}']),
+    (0xF908, &['\u{9F9C}']),
+    (0xF909, &['\u{5951}']),
+    (0xF90A, &['\u{91D1}']),
+    (0xF90B, &['\u{5587}']),
+    (0xF90C, &['\u{5948}']),
+    (0xF90D, &['\u{61F6}']),
+    (0xF90E, &['\u{7669}']),
+    (0xF90F, &['\u{7F85}']),
+    (0xF910, &['\u{863F}']),
+    (0xF911, &['\u{87BA}']),
+    (0xF912, &['\u{88F8}']),
+    (0xF913, &['\u{908F}']),
+    (0xF914, &['\u{6A02}']),
+    (0xF915, &['\u{6D1B}']),
+    (0xF916, &['\u{70D9}']),
+    (0xF917, &['\u{73DE}']),
+    (0xF918, &['\u{843D}']),
+    (0xF919, &['\u{916A}']),
+    (0xF91A, &['\u{99F1}']),
+    (0xF91B, &['\u{4E82}']),
+    (0xF91C, &['\u{5375}']),
+    (0xF91D, &['\u{6B04}']),
+    (0xF91E, &['\u{721B}']),
+    (0xF91F, &['\u{862D}']),
+    (0xF920, &['\u{9E1E}']),
+    (0xF921, &['\u{5D50}']),
+    (0xF922, &['\u{6FEB}']),
+    (0xF923, &['\u{85CD}']),
+    (0xF924, &['\u{8964}']),
+    (0xF925, &['\u{62C9}']),
+    (0xF926, &['\u{81D8}']),
+    (0xF927, &['\u{881F}']),
+    (0xF928, &['\u{5ECA}']),
+    (0xF929, &['\u{6717}']),
+    (0xF92A, &['\u{6D6A}']),
+    (0xF92B, &['\u{72FC}']),
+    (0xF92C, &['\u{90CE}']),
+    (0xF92D, &['\u{4F86}']),
+    (0xF92E, &['\u{51B7}']),
+    (0xF92F, &['\u{52DE}']),
+    (0xF930, &['\u{64C4}']),
+    (0xF931, &['\u{6AD3}']),
+    (0xF932, &['\u{7210}']),
+    (0xF933, &['\u{76E7}']),
+    (0xF934, &['\u{8001}']),
+    (0xF935, &['\u{8606}']),
+    (0xF936, &['\u{865C}']),
+    (0xF937, &['\u{8DEF}']),
+    (0xF938, &['\u{9732}']),
+    (0xF939, &['\u{9B6F}']),
+    (0xF93A, &['\u{9DFA}']),
+    (0xF93B, &['\u{788C}']),
+    (0xF93C, &['\u{797F}']),
+    (0xF93D, &['\u{7DA0}']),
+    (0xF93E, &['\u{83C9}']),
+    (0xF93F, &['\u{9304}']),
+    (0xF940, &['\u{9E7F}']),
+    (0xF941, &['\u{8AD6}']),
+    (0xF942, &['\u{58DF}']),
+    (0xF943, &['\u{5F04}']),
+    (0xF944, &['\u{7C60}']),
+    (0xF945, &['\u{807E}']),
+    (0xF946, &['\u{7262}']),
+    (0xF947, &['\u{78CA}']),
+    (0xF948, &['\u{8CC2}']),
+    (0xF949, &['\u{96F7}']),
+    (0xF94A, &['\u{58D8}']),
+    (0xF94B, &['\u{5C62}']),
+    (0xF94C, &['\u{6A13}']),
+    (0xF94D, &['\u{6DDA}']),
+    (0xF94E, &['\u{6F0F}']),
+    (0xF94F, &['\u{7D2F}']),
+    (0xF950, &['\u{7E37}']),
+    (0xF951, &['\u{964B}']),
+    (0xF952, &['\u{52D2}']),
+    (0xF953, &['\u{808B}']),
+    (0xF954, &['\u{51DC}']),
+    (0xF955, &['\u{51CC}']),
+    (0xF956, &['\u{7A1C}']),
+    (0xF957, &['\u{7DBE}']),
+    (0xF958, &['\u{83F1}']),
+    (0xF959, &['\u{9675}']),
+    (0xF95A, &['\u{8B80}']),
+    (0xF95B, &['\u{62CF}']),
+    (0xF95C, &['\u{6A02}']),
+    (0xF95D, &['\u{8AFE}']),
+    (0xF95E, &['\u{4E39}']),
+    (0xF95F, &['\u{5BE7}']),
+    (0xF960, &['\u{6012}']),
+    (0xF961, &['\u{7387}']),
+    (0xF962, &['\u{7570}']),
+    (0xF963, &['\u{5317}']),
+    (0xF964, &['\u{78FB}']),
+    (0xF965, &['\u{4FBF}']),
+    (0xF966, &['\u{5FA9}']),
+    (0xF967, &['\u{4E0D}']),
+    (0xF968, &['\u{6CCC}']),
+    (0xF969, &['\u{6578}']),
+    (0xF96A, &['\u{7D22}']),
+    (0xF96B, &['\u{53C3}']),
+    (0xF96C, &['\u{585E}']),
+    (0xF96D, &['\u{7701}']),
+    (0xF96E, &['\u{8449}']),
+    (0xF96F, &['\u{8AAA}']),
+    (0xF970, &['\u{6BBA}']),
+    (0xF971, &['\u{8FB0}']),
+    (0xF972, &['\u{6C88}']),
+    (0xF973, &['\u{62FE}']),
+    (0xF974, &['\u{82E5}']),
+    (0xF975, &['\u{63A0}']),
+    (0xF976, &['\u{7565}']),
+    (0xF977, &['\u{4EAE}']),
+    (0xF978, &['\u{5169}']),
+    (0xF979, &['\u{51C9}']),
+    (0xF97A, &['\u{6881}']),
+    (0xF97B, &['\u{7CE7}']),
+    (0xF97C, &['\u{826F}']),
+    (0xF97D, &['\u{8AD2}']),
+    (0xF97E, &['\u{91CF}']),
+    (0xF97F, &['\u{52F5}']),
+    (0xF980, &['\u{5442}']),
+    (0xF981, &['\u{5973}']),
+    (0xF982, &['\u{5EEC}']),
+    (0xF983, &['\u{65C5}']),
+    (0xF984, &['\u{6FFE}']),
+    (0xF985, &['\u{792A}']),
+    (0xF986, &['\u{95AD}']),
+    (0xF987, &['\u{9A6A}']),
+    (0xF988, &['\u{9E97}']),
+    (0xF989, &['\u{9ECE}']),
+    (0xF98A, &['\u{529B}']),
+    (0xF98B, &['\u{66C6}']),
+    (0xF98C, &['\u{6B77}']),
+    (0xF98D, &['\u{8F62}']),
+    (0xF98E, &['\u{5E74}']),
+    (0xF98F, &['\u{6190}']),
+    (0xF990, &['\u{6200}']),
+    (0xF991, &['\u{649A}']),
+    (0xF992, &['\u{6F23}']),
+    (0xF993, &['\u{7149}']),
+    (0xF994, &['\u{7489}']),
+    (0xF995, &['\u{79CA}']),
+    (0xF996, &['\u{7DF4}']),
+    (0xF997, &['\u{806F}']),
+    (0xF998, &['\u{8F26}']),
+    (0xF999, &['\u{84EE}']),
+    (0xF99A, &['\u{9023}']),
+    (0xF99B, &['\u{934A}']),
+    (0xF99C, &['\u{5217}']),
+    (0xF99D, &['\u{52A3}']),
+    (0xF99E, &['\u{54BD}']),
+    (0xF99F, &['\u{70C8}']),
+    (0xF9A0, &['\u{88C2}']),
+    (0xF9A1, &['\u{8AAA}']),
+    (0xF9A2, &['\u{5EC9}']),
+    (0xF9A3, &['\u{5FF5}']),
+    (0xF9A4, &['\u{637B}']),
+    (0xF9A5, &['\u{6BAE}']),
+    (0xF9A6, &['\u{7C3E}']),
+    (0xF9A7, &['\u{7375}']),
+    (0xF9A8, &['\u{4EE4}']),
+    (0xF9A9, &['\u{56F9}']),
+    (0xF9AA, &['\u{5BE7}']),
+    (0xF9AB, &['\u{5DBA}']),
+    (0xF9AC, &['\u{601C}']),
+    (0xF9AD, &['\u{73B2}']),
+    (0xF9AE, &['\u{7469}']),
+    (0xF9AF, &['\u{7F9A}']),
+    (0xF9B0, &['\u{8046}']),
+    (0xF9B1, &['\u{9234}']),
+    (0xF9B2, &['\u{96F6}']),
+    (0xF9B3, &['\u{9748}']),
+    (0xF9B4, &['\u{9818}']),
+    (0xF9B5, &['\u{4F8B}']),
+    (0xF9B6, &['\u{79AE}']),
+    (0xF9B7, &['\u{91B4}']),
+    (0xF9B8, &['\u{96B8}']),
+    (0xF9B9, &['\u{60E1}']),
+    (0xF9BA, &['\u{4E86}']),
+    (0xF9BB, &['\u{50DA}']),
+    (0xF9BC, &['\u{5BEE}']),
+    (0xF9BD, &['\u{5C3F}']),
+    (0xF9BE, &['\u{6599}']),
+    (0xF9BF, &['\u{6A02}']),
+    (0xF9C0, &['\u{71CE}']),
+    (0xF9C1, &['\u{7642}']),
+    (0xF9C2, &['\u{84FC}']),
+    (0xF9C3, &['\u{907C}']),
+    (0xF9C4, &['\u{9F8D}']),
+    (0xF9C5, &['\u{6688}']),
+    (0xF9C6, &['\u{962E}']),
+    (0xF9C7, &['\u{5289}']),
+    (0xF9C8, &['\u{677B}']),
+    (0xF9C9, &['\u{67F3}']),
+    (0xF9CA, &['\u{6D41}']),
+    (0xF9CB, &['\u{6E9C}']),
+    (0xF9CC, &['\u{7409}']),
+    (0xF9CD, &['\u{7559}']),
+    (0xF9CE, &['\u{786B}']),
+    (0xF9CF, &['\u{7D10}']),
+    (0xF9D0, &['\u{985E}']),
+    (0xF9D1, &['\u{516D}']),
+    (0xF9D2, &['\u{622E}']),
+    (0xF9D3, &['\u{9678}']),
+    (0xF9D4, &['\u{502B}']),
+    (0xF9D5, &['\u{5D19}']),
+    (0xF9D6, &['\u{6DEA}']),
+    (0xF9D7, &['\u{8F2A}']),
+    (0xF9D8, &['\u{5F8B}']),
+    (0xF9D9, &['\u{6144}']),
+    (0xF9DA, &['\u{6817}']),
+    (0xF9DB, &['\u{7387}']),
+    (0xF9DC, &['\u{9686}']),
+    (0xF9DD, &['\u{5229}']),
+    (0xF9DE, &['\u{540F}']),
+    (0xF9DF, &['\u{5C65}']),
+    (0xF9E0, &['\u{6613}']),
+    (0xF9E1, &['\u{674E}']),
+    (0xF9E2, &['\u{68A8}']),
+    (0xF9E3, &['\u{6CE5}']),
+    (0xF9E4, &['\u{7406}']),
+    (0xF9E5, &['\u{75E2}']),
+    (0xF9E6, &['\u{7F79}']),
+    (0xF9E7, &['\u{88CF}']),
+    (0xF9E8, &['\u{88E1}']),
+    (0xF9E9, &['\u{91CC}']),
+    (0xF9EA, &['\u{96E2}']),
+    (0xF9EB, &['\u{533F}']),
+    (0xF9EC, &['\u{6EBA}']),
+    (0xF9ED, &['\u{541D}']),
+    (0xF9EE, &['\u{71D0}']),
+    (0xF9EF, &['\u{7498}']),
+    (0xF9F0, &['\u{85FA}']),
+    (0xF9F1, &['\u{96A3}']),
+    (0xF9F2, &['\u{9C57}']),
+    (0xF9F3, &['\u{9E9F}']),
+    (0xF9F4, &['\u{6797}']),
+    (0xF9F5, &['\u{6DCB}']),
+    (0xF9F6, &['\u{81E8}']),
+    (0xF9F7, &['\u{7ACB}']),
+    (0xF9F8, &['\u{7B20}']),
+    (0xF9F9, &['\u{7C92}']),
+    (0xF9FA, &['\u{72C0}']),
+    (0xF9FB, &['\u{7099}']),
+    (0xF9FC, &['\u{8B58}']),
+    (0xF9FD, &['\u{4EC0}']),
+    (0xF9FE, &['\u{8336}']),
+    (0xF9FF, &['\u{523A}']),
+    (0xFA00, &['\u{5207}']),
+    (0xFA01, &['\u{5EA6}']),
+    (0xFA02, &['\u{62D3}']),
+    (0xFA03, &['\u{7CD6}']),
+    (0xFA04, &['\u{5B85}']),
+    (0xFA05, &['\u{6D1E}']),
+    (0xFA06, &['\u{66B4}']),
+    (0xFA07, &['\u{8F3B}']),
+    (0xFA08, &['\u{884C}']),
+    (0xFA09, &['\u{964D}']),
+    (0xFA0A, &['\u{898B}']),
+    (0xFA0B, &['\u{5ED3}']),
+    (0xFA0C, &['\u{5140}']),
+    (0xFA0D, &['\u{55C0}']),
+    (0xFA10, &['\u{585A}']),
+    (0xFA12, &['\u{6674}']),
+    (0xFA15, &['\u{51DE}']),
+    (0xFA16, &['\u{732A}']),
+    (0xFA17, &['\u{76CA}']),
+    (0xFA18, &['\u{793C}']),
+    (0xFA19, &['\u{795E}']),
+    (0xFA1A, &['\u{7965}']),
+    (0xFA1B, &['\u{798F}']),
+    (0xFA1C, &['\u{9756}']),
+    (0xFA1D, &['\u{7CBE}']),
+    (0xFA1E, &['\u{7FBD}']),
+    (0xFA20, &['\u{8612}']),
+    (0xFA22, &['\u{8AF8}']),
+    (0xFA25, &['\u{9038}']),
+    (0xFA26, &['\u{90FD}']),
+    (0xFA2A, &['\u{98EF}']),
+    (0xFA2B, &['\u{98FC}']),
+    (0xFA2C, &['\u{9928}']),
+    (0xFA2D, &['\u{9DB4}']),
+    (0xFA2E, &['\u{90DE}']),
+    (0xFA2F, &['\u{96B7}']),
+    (0xFA30, &['\u{4FAE}']),
+    (0xFA31, &['\u{50E7}']),
+    (0xFA32, &['\u{514D}']),
+    (0xFA33, &['\u{52C9}']),
+    (0xFA34, &['\u{52E4}']),
+    (0xFA35, &['\u{5351}']),
+    (0xFA36, &['\u{559D}']),
+    (0xFA37, &['\u{5606}']),
+    (0xFA38, &['\u{5668}']),
+    (0xFA39, &['\u{5840}']),
+    (0xFA3A, &['\u{58A8}']),
+    (0xFA3B, &['\u{5C64}']),
+    (0xFA3C, &['\u{5C6E}']),
+    (0xFA3D, &['\u{6094}']),
+    (0xFA3E, &['\u{6168}']),
+    (0xFA3F, &['\u{618E}']),
+    (0xFA40, &['\u{61F2}']),
+    (0xFA41, &['\u{654F}']),
+    (0xFA42, &['\u{65E2}']),
+    (0xFA43, &['\u{6691}']),
+    (0xFA44, &['\u{6885}']),
+    (0xFA45, &['\u{6D77}']),
+    (0xFA46, &['\u{6E1A}']),
+    (0xFA47, &['\u{6F22}']),
+    (0xFA48, &['\u{716E}']),
+    (0xFA49, &['\u{722B}']),
+    (0xFA4A, &['\u{7422}']),
+    (0xFA4B, &['\u{7891}']),
+    (0xFA4C, &['\u{793E}']),
+    (0xFA4D, &['\u{7949}']),
+    (0xFA4E, &['\u{7948}']),
+    (0xFA4F, &['\u{7950}']),
+    (0xFA50, &['\u{7956}']),
+    (0xFA51, &['\u{795D}']),
+    (0xFA52, &['\u{798D}']),
+    (0xFA53, &['\u{798E}']),
+    (0xFA54, &['\u{7A40}']),
+    (0xFA55, &['\u{7A81}']),
+    (0xFA56, &['\u{7BC0}']),
+    (0xFA57, &['\u{7DF4}']),
+    (0xFA58, &['\u{7E09}']),
+    (0xFA59, &['\u{7E41}']),
+    (0xFA5A, &['\u{7F72}']),
+    (0xFA5B, &['\u{8005}']),
+    (0xFA5C, &['\u{81ED}']),
+    (0xFA5D, &['\u{8279}']),
+    (0xFA5E, &['\u{8279}']),
+    (0xFA5F, &['\u{8457}']),
+    (0xFA60, &['\u{8910}']),
+    (0xFA61, &['\u{8996}']),
+    (0xFA62, &['\u{8B01}']),
+    (0xFA63, &['\u{8B39}']),
+    (0xFA64, &['\u{8CD3}']),
+    (0xFA65, &['\u{8D08}']),
+    (0xFA66, &['\u{8FB6}']),
+    (0xFA67, &['\u{9038}']),
+    (0xFA68, &['\u{96E3}']),
+    (0xFA69, &['\u{97FF}']),
+    (0xFA6A, &['\u{983B}']),
+    (0xFA6B, &['\u{6075}']),
+    (0xFA6C, &['\u{242EE}']),
+    (0xFA6D, &['\u{8218}']),
+    (0xFA70, &['\u{4E26}']),
+    (0xFA71, &['\u{51B5}']),
+    (0xFA72, &['\u{5168}']),
+    (0xFA73, &['\u{4F80}']),
+    (0xFA74, &['\u{5145}']),
+    (0xFA75, &['\u{5180}']),
+    (0xFA76, &['\u{52C7}']),
+    (0xFA77, &['\u{52FA}']),
+    (0xFA78, &['\u{559D}']),
+    (0xFA79, &['\u{5555}']),
+    (0xFA7A, &['\u{5599}']),
+    (0xFA7B, &['\u{55E2}']),
+    (0xFA7C, &['\u{585A}']),
+    (0xFA7D, &['\u{58B3}']),
+    (0xFA7E, &['\u{5944}']),
+    (0xFA7F, &['\u{5954}']),
+    (0xFA80, &['\u{5A62}']),
+    (0xFA81, &['\u{5B28}']),
+    (0xFA82, &['\u{5ED2}']),
+    (0xFA83, &['\u{5ED9}']),
+    (0xFA84, &['\u{5F69}']),
+    (0xFA85, &['\u{5FAD}']),
+    (0xFA86, &['\u{60D8}']),
+    (0xFA87, &['\u{614E}']),
+    (0xFA88, &['\u{6108}']),
+    (0xFA89, &['\u{618E}']),
+    (0xFA8A, &['\u{6160}']),
+    (0xFA8B, &['\u{61F2}']),
+    (0xFA8C, &['\u{6234}']),
+    (0xFA8D, &['\u{63C4}']),
+    (0xFA8E, &['\u{641C}']),
+    (0xFA8F, &['\u{6452}']),
+    (0xFA90, &['\u{6556}']),
+    (0xFA91, &['\u{6674}']),
+    (0xFA92, &['\u{6717}']),
+    (0xFA93, &['\u{671B}']),
+    (0xFA94, &['\u{6756}']),
+    (0xFA95, &['\u{6B79}']),
+    (0xFA96, &['\u{6BBA}']),
+    (0xFA97, &['\u{6D41}']),
+    (0xFA98, &['\u{6EDB}']),
+    (0xFA99, &['\u{6ECB}']),
+    (0xFA9A, &['\u{6F22}']),
+    (0xFA9B, &['\u{701E}']),
+    (0xFA9C, &['\u{716E}']),
+    (0xFA9D, &['\u{77A7}']),
+    (0xFA9E, &['\u{7235}']),
+    (0xFA9F, &['\u{72AF}']),
+    (0xFAA0, &['\u{732A}']),
+    (0xFAA1, &['\u{7471}']),
+    (0xFAA2, &['\u{7506}']),
+    (0xFAA3, &['\u{753B}']),
+    (0xFAA4, &['\u{761D}']),
+    (0xFAA5, &['\u{761F}']),
+    (0xFAA6, &['\u{76CA}']),
+    (0xFAA7, &['\u{76DB}']),
+    (0xFAA8, &['\u{76F4}']),
+    (0xFAA9, &['\u{774A}']),
+    (0xFAAA, &['\u{7740}']),
+    (0xFAAB, &['\u{78CC}']),
+    (0xFAAC, &['\u{7AB1}']),
+    (0xFAAD, &['\u{7BC0}']),
+    (0xFAAE, &['\u{7C7B}']),
+    (0xFAAF, &['\u{7D5B}']),
+    (0xFAB0, &['\u{7DF4}']),
+    (0xFAB1, &['\u{7F3E}']),
+    (0xFAB2, &['\u{8005}']),
+    (0xFAB3, &['\u{8352}']),
+    (0xFAB4, &['\u{83EF}']),
+    (0xFAB5, &['\u{8779}']),
+    (0xFAB6, &['\u{8941}']),
+    (0xFAB7, &['\u{8986}']),
+    (0xFAB8, &['\u{8996}']),
+    (0xFAB9, &['\u{8ABF}']),
+    (0xFABA, &['\u{8AF8}']),
+    (0xFABB, &['\u{8ACB}']),
+    (0xFABC, &['\u{8B01}']),
+    (0xFABD, &['\u{8AFE}']),
+    (0xFABE, &['\u{8AED}']),
+    (0xFABF, &['\u{8B39}']),
+    (0xFAC0, &['\u{8B8A}']),
+    (0xFAC1, &['\u{8D08}']),
+    (0xFAC2, &['\u{8F38}']),
+    (0xFAC3, &['\u{9072}']),
+    (0xFAC4, &['\u{9199}']),
+    (0xFAC5, &['\u{9276}']),
+    (0xFAC6, &['\u{967C}']),
+    (0xFAC7, &['\u{96E3}']),
+    (0xFAC8, &['\u{9756}']),
+    (0xFAC9, &['\u{97DB}']),
+    (0xFACA, &['\u{97FF}']),
+    (0xFACB, &['\u{980B}']),
+    (0xFACC, &['\u{983B}']),
+    (0xFACD, &['\u{9B12}']),
+    (0xFACE, &['\u{9F9C}']),
+    (0xFACF, &['\u{2284A}']),
+    (0xFAD0, &['\u{22844}']),
+    (0xFAD1, &['\u{233D5}']),
+    (0xFAD2, &['\u{3B9D}']),
+    (0xFAD3, &['\u{4018}']),
+    (0xFAD4, &['\u{4039}']),
+    (0xFAD5, &['\u{25249}']),
+    (0xFAD6, &['\u{25CD0}']),
+    (0xFAD7, &['\u{27ED3}']),
+    (0xFAD8, &['\u{9F43}']),
+    (0xFAD9, &['\u{9F8E}']),
+    (0xFB1D, &['\u{5D9}', '\u{5B4}']),
+    (0xFB1F, &['\u{5F2}', '\u{5B7}']),
+    (0xFB2A, &['\u{5E9}', '\u{5C1}']),
+    (0xFB2B, &['\u{5E9}', '\u{5C2}']),
+    (0xFB2C, &['\u{FB49}', '\u{5C1}']),
+    (0xFB2D, &['\u{FB49}', '\u{5C2}']),
+    (0xFB2E, &['\u{5D0}', '\u{5B7}']),
+    (0xFB2F, &['\u{5D0}', '\u{5B8}']),
+    (0xFB30, &['\u{5D0}', '\u{5BC}']),
+    (0xFB31, &['\u{5D1}', '\u{5BC}']),
+    (0xFB32, &['\u{5D2}', '\u{5BC}']),
+    (0xFB33, &['\u{5D3}', '\u{5BC}']),
+    (0xFB34, &['\u{5D4}', '\u{5BC}']),
+    (0xFB35, &['\u{5D5}', '\u{5BC}']),
+    (0xFB36, &['\u{5D6}', '\u{5BC}']),
+    (0xFB38, &['\u{5D8}', '\u{5BC}']),
+    (0xFB39, &['\u{5D9}', '\u{5BC}']),
+    (0xFB3A, &['\u{5DA}', '\u{5BC}']),
+    (0xFB3B, &['\u{5DB}', '\u{5BC}']),
+    (0xFB3C, &['\u{5DC}', '\u{5BC}']),
+    (0xFB3E, &['\u{5DE}', '\u{5BC}']),
+    (0xFB40, &['\u{5E0}', '\u{5BC}']),
+    (0xFB41, &['\u{5E1}', '\u{5BC}']),
+    (0xFB43, &['\u{5E3}', '\u{5BC}']),
+    (0xFB44, &['\u{5E4}', '\u{5BC}']),
+    (0xFB46, &['\u{5E6}', '\u{5BC}']),
+    (0xFB47, &['\u{5E7}', '\u{5BC}']),
+    (0xFB48, &['\u{5E8}', '\u{5BC}']),
+    (0xFB49, &['\u{5E9}', '\u{5BC}']),
+    (0xFB4A, &['\u{5EA}', '\u{5BC}']),
+    (0xFB4B, &['\u{5D5}', '\u{5B9}']),
+    (0xFB4C, &['\u{5D1}', '\u{5BF}']),
+    (0xFB4D, &['\u{5DB}', '\u{5BF}']),
+    (0xFB4E, &['\u{5E4}', '\u{5BF}']),
+    (0x1109A, &['\u{11099}', '\u{110BA}']),
+    (0x1109C, &['\u{1109B}', '\u{110BA}']),
+    (0x110AB, &['\u{110A5}', '\u{110BA}']),
+    (0x1112E, &['\u{11131}', '\u{11127}']),
+    (0x1112F, &['\u{11132}', '\u{11127}']),
+    (0x1134B, &['\u{11347}', '\u{1133E}']),
+    (0x1134C, &['\u{11347}', '\u{11357}']),
+    (0x114BB, &['\u{114B9}', '\u{114BA}']),
+    (0x114BC, &['\u{114B9}', '\u{114B0}']),
+    (0x114BE, &['\u{114B9}', '\u{114BD}']),
+    (0x115BA, &['\u{115B8}', '\u{115AF}']),
+    (0x115BB, &['\u{115B9}', '\u{115AF}']),
+    (0x11938, &['\u{11935}', '\u{11930}']),
+    (0x1D15E, &['\u{1D157}', '\u{1D165}']),
+    (0x1D15F, &['\u{1D158}', '\u{1D165}']),
+    (0x1D160, &['\u{1D15F}', '\u{1D16E}']),
+    (0x1D161, &['\u{1D15F}', '\u{1D16F}']),
+    (0x1D162, &['\u{1D15F}', '\u{1D170}']),
+    (0x1D163, &['\u{1D15F}', '\u{1D171}']),
+    (0x1D164, &['\u{1D15F}', '\u{1D172}']),
+    (0x1D1BB, &['\u{1D1B9}', '\u{1D165}']),
+    (0x1D1BC, &['\u{1D1BA}', '\u{1D165}']),
+    (0x1D1BD, &['\u{1D1BB}', '\u{1D16E}']),
+    (0x1D1BE, &['\u{1D1BC}', '\u{1D16E}']),
+    (0x1D1BF, &['\u{1D1BB}', '\u{1D16F}']),
+    (0x1D1C0, &['\u{1D1BC}', '\u{1D16F}']),
+    (0x2F800, &['\u{4E3D}']),
+    (0x2F801, &['\u{4E38}']),
+    (0x2F802, &['\u{4E41}']),
+    (0x2F803, &['\u{20122}']),
+    (0x2F804, &['\u{4F60}']),
+    (0x2F805, &['\u{4FAE}']),
+    (0x2F806, &['\u{4FBB}']),
+    (0x2F807, &['\u{5002}']),
+    (0x2F808, &['\u{507A}']),
+    (0x2F809, &['\u{5099}']),
+    (0x2F80A, &['\u{50E7}']),
+    (0x2F80B, &['\u{50CF}']),
+    (0x2F80C, &['\u{349E}']),
+    (0x2F80D, &['\u{2063A}']),
+    (0x2F80E, &['\u{514D}']),
+    (0x2F80F, &['\u{5154}']),
+    (0x2F810, &['\u{5164}']),
+    (0x2F811, &['\u{5177}']),
+    (0x2F812, &['\u{2051C}']),
+    (0x2F813, &['\u{34B9}']),
+    (0x2F814, &['\u{5167}']),
+    (0x2F815, &['\u{518D}']),
+    (0x2F816, &['\u{2054B}']),
+    (0x2F817, &['\u{5197}']),
+    (0x2F818, &['\u{51A4}']),
+    (0x2F819, &['\u{4ECC}']),
+    (0x2F81A, &['\u{51AC}']),
+    (0x2F81B, &['\u{51B5}']),
+    (0x2F81C, &['\u{291DF}']),
+    (0x2F81D, &['\u{51F5}']),
+    (0x2F81E, &['\u{5203}']),
+    (0x2F81F, &['\u{34DF}']),
+    (0x2F820, &['\u{523B}']),
+    (0x2F821, &['\u{5246}']),
+    (0x2F822, &['\u{5272}']),
+    (0x2F823, &['\u{5277}']),
+    (0x2F824, &['\u{3515}']),
+    (0x2F825, &['\u{52C7}']),
+    (0x2F826, &['\u{52C9}']),
+    (0x2F827, &['\u{52E4}']),
+    (0x2F828, &['\u{52FA}']),
+    (0x2F829, &['\u{5305}']),
+    (0x2F82A, &['\u{5306}']),
+    (0x2F82B, &['\u{5317}']),
+    (0x2F82C, &['\u{5349}']),
+    (0x2F82D, &['\u{5351}']),
+    (0x2F82E, &['\u{535A}']),
+    (0x2F82F, &['\u{5373}']),
+    (0x2F830, &['\u{537D}']),
+    (0x2F831, &['\u{537F}']),
+    (0x2F832, &['\u{537F}']),
+    (0x2F833, &['\u{537F}']),
+    (0x2F834, &['\u{20A2C}']),
+    (0x2F835, &['\u{7070}']),
+    (0x2F836, &['\u{53CA}']),
+    (0x2F837, &['\u{53DF}']),
+    (0x2F838, &['\u{20B63}']),
+    (0x2F839, &['\u{53EB}']),
+    (0x2F83A, &['\u{53F1}']),
+    (0x2F83B, &['\u{5406}']),
+    (0x2F83C, &['\u{549E}']),
+    (0x2F83D, &['\u{5438}']),
+    (0x2F83E, &['\u{5448}']),
+    (0x2F83F, &['\u{5468}']),
+    (0x2F840, &['\u{54A2}']),
+    (0x2F841, &['\u{54F6}']),
+    (0x2F842, &['\u{5510}']),
+    (0x2F843, &['\u{5553}']),
+    (0x2F844, &['\u{5563}']),
+    (0x2F845, &['\u{5584}']),
+    (0x2F846, &['\u{5584}']),
+    (0x2F847, &['\u{5599}']),
+    (0x2F848, &['\u{55AB}']),
+    (0x2F849, &['\u{55B3}']),
+    (0x2F84A, &['\u{55C2}']),
+    (0x2F84B, &['\u{5716}']),
+    (0x2F84C, &['\u{5606}']),
+    (0x2F84D, &['\u{5717}']),
+    (0x2F84E, &['\u{5651}']),
+    (0x2F84F, &['\u{5674}']),
+    (0x2F850, &['\u{5207}']),
+    (0x2F851, &['\u{58EE}']),
+    (0x2F852, &['\u{57CE}']),
+    (0x2F853, &['\u{57F4}']),
+    (0x2F854, &['\u{580D}']),
+    (0x2F855, &['\u{578B}']),
+    (0x2F856, &['\u{5832}']),
+    (0x2F857, &['\u{5831}']),
+    (0x2F858, &['\u{58AC}']),
+    (0x2F859, &['\u{214E4}']),
+    (0x2F85A, &['\u{58F2}']),
+    (0x2F85B, &['\u{58F7}']),
+    (0x2F85C, &['\u{5906}']),
+    (0x2F85D, &['\u{591A}']),
+    (0x2F85E, &['\u{5922}']),
+    (0x2F85F, &['\u{5962}']),
+    (0x2F860, &['\u{216A8}']),
+    (0x2F861, &['\u{216EA}']),
+    (0x2F862, &['\u{59EC}']),
+    (0x2F863, &['\u{5A1B}']),
+    (0x2F864, &['\u{5A27}']),
+    (0x2F865, &['\u{59D8}']),
+    (0x2F866, &['\u{5A66}']),
+    (0x2F867, &['\u{36EE}']),
+    (0x2F868, &['\u{36FC}']),
+    (0x2F869, &['\u{5B08}']),
+    (0x2F86A, &['\u{5B3E}']),
+    (0x2F86B, &['\u{5B3E}']),
+    (0x2F86C, &['\u{219C8}']),
+    (0x2F86D, &['\u{5BC3}']),
+    (0x2F86E, &['\u{5BD8}']),
+    (0x2F86F, &['\u{5BE7}']),
+    (0x2F870, &['\u{5BF3}']),
+    (0x2F871, &['\u{21B18}']),
+    (0x2F872, &['\u{5BFF}']),
+    (0x2F873, &['\u{5C06}']),
+    (0x2F874, &['\u{5F53}']),
+    (0x2F875, &['\u{5C22}']),
+    (0x2F876, &['\u{3781}']),
+    (0x2F877, &['\u{5C60}']),
+    (0x2F878, &['\u{5C6E}']),
+    (0x2F879, &['\u{5CC0}']),
+    (0x2F87A, &['\u{5C8D}']),
+    (0x2F87B, &['\u{21DE4}']),
+    (0x2F87C, &['\u{5D43}']),
+    (0x2F87D, &['\u{21DE6}']),
+    (0x2F87E, &['\u{5D6E}']),
+    (0x2F87F, &['\u{5D6B}']),
+    (0x2F880, &['\u{5D7C}']),
+    (0x2F881, &['\u{5DE1}']),
+    (0x2F882, &['\u{5DE2}']),
+    (0x2F883, &['\u{382F}']),
+    (0x2F884, &['\u{5DFD}']),
+    (0x2F885, &['\u{5E28}']),
+    (0x2F886, &['\u{5E3D}']),
+    (0x2F887, &['\u{5E69}']),
+    (0x2F888, &['\u{3862}']),
+    (0x2F889, &['\u{22183}']),
+    (0x2F88A, &['\u{387C}']),
+    (0x2F88B, &['\u{5EB0}']),
+    (0x2F88C, &['\u{5EB3}']),
+    (0x2F88D, &['\u{5EB6}']),
+    (0x2F88E, &['\u{5ECA}']),
+    (0x2F88F, &['\u{2A392}']),
+    (0x2F890, &['\u{5EFE}']),
+    (0x2F891, &['\u{22331}']),
+    (0x2F892, &['\u{22331}']),
+    (0x2F893, &['\u{8201}']),
+    (0x2F894, &['\u{5F22}']),
+    (0x2F895, &['\u{5F22}']),
+    (0x2F896, &['\u{38C7}']),
+    (0x2F897, &['\u{232B8}']),
+    (0x2F898, &['\u{261DA}']),
+    (0x2F899, &['\u{5F62}']),
+    (0x2F89A, &['\u{5F6B}']),
+    (0x2F89B, &['\u{38E3}']),
+    (0x2F89C, &['\u{5F9A}']),
+    (0x2F89D, &['\u{5FCD}']),
+    (0x2F89E, &['\u{5FD7}']),
+    (0x2F89F, &['\u{5FF9}']),
+    (0x2F8A0, &['\u{6081}']),
+    (0x2F8A1, &['\u{393A}']),
+    (0x2F8A2, &['\u{391C}']),
+    (0x2F8A3, &['\u{6094}']),
+    (0x2F8A4, &['\u{226D4}']),
+    (0x2F8A5, &['\u{60C7}']),
+    (0x2F8A6, &['\u{6148}']),
+    (0x2F8A7, &['\u{614C}']),
+    (0x2F8A8, &['\u{614E}']),
+    (0x2F8A9, &['\u{614C}']),
+    (0x2F8AA, &['\u{617A}']),
+    (0x2F8AB, &['\u{618E}']),
+    (0x2F8AC, &['\u{61B2}']),
+    (0x2F8AD, &['\u{61A4}']),
+    (0x2F8AE, &['\u{61AF}']),
+    (0x2F8AF, &['\u{61DE}']),
+    (0x2F8B0, &['\u{61F2}']),
+    (0x2F8B1, &['\u{61F6}']),
+    (0x2F8B2, &['\u{6210}']),
+    (0x2F8B3, &['\u{621B}']),
+    (0x2F8B4, &['\u{625D}']),
+    (0x2F8B5, &['\u{62B1}']),
+    (0x2F8B6, &['\u{62D4}']),
+    (0x2F8B7, &['\u{6350}']),
+    (0x2F8B8, &['\u{22B0C}']),
+    (0x2F8B9, &['\u{633D}']),
+    (0x2F8BA, &['\u{62FC}']),
+    (0x2F8BB, &['\u{6368}']),
+    (0x2F8BC, &['\u{6383}']),
+    (0x2F8BD, &['\u{63E4}']),
+    (0x2F8BE, &['\u{22BF1}']),
+    (0x2F8BF, &['\u{6422}']),
+    (0x2F8C0, &['\u{63C5}']),
+    (0x2F8C1, &['\u{63A9}']),
+    (0x2F8C2, &['\u{3A2E}']),
+    (0x2F8C3, &['\u{6469}']),
+    (0x2F8C4, &['\u{647E}']),
+    (0x2F8C5, &['\u{649D}']),
+    (0x2F8C6, &['\u{6477}']),
+    (0x2F8C7, &['\u{3A6C}']),
+    (0x2F8C8, &['\u{654F}']),
+    (0x2F8C9, &['\u{656C}']),
+    (0x2F8CA, &['\u{2300A}']),
+    (0x2F8CB, &['\u{65E3}']),
+    (0x2F8CC, &['\u{66F8}']),
+    (0x2F8CD, &['\u{6649}']),
+    (0x2F8CE, &['\u{3B19}']),
+    (0x2F8CF, &['\u{6691}']),
+    (0x2F8D0, &['\u{3B08}']),
+    (0x2F8D1, &['\u{3AE4}']),
+    (0x2F8D2, &['\u{5192}']),
+    (0x2F8D3, &['\u{5195}']),
+    (0x2F8D4, &['\u{6700}']),
+    (0x2F8D5, &['\u{669C}']),
+    (0x2F8D6, &['\u{80AD}']),
+    (0x2F8D7, &['\u{43D9}']),
+    (0x2F8D8, &['\u{6717}']),
+    (0x2F8D9, &['\u{671B}']),
+    (0x2F8DA, &['\u{6721}']),
+    (0x2F8DB, &['\u{675E}']),
+    (0x2F8DC, &['\u{6753}']),
+    (0x2F8DD, &['\u{233C3}']),
+    (0x2F8DE, &['\u{3B49}']),
+    (0x2F8DF, &['\u{67FA}']),
+    (0x2F8E0, &['\u{6785}']),
+    (0x2F8E1, &['\u{6852}']),
+    (0x2F8E2, &['\u{6885}']),
+    (0x2F8E3, &['\u{2346D}']),
+    (0x2F8E4, &['\u{688E}']),
+    (0x2F8E5, &['\u{681F}']),
+    (0x2F8E6, &['\u{6914}']),
+    (0x2F8E7, &['\u{3B9D}']),
+    (0x2F8E8, &['\u{6942}']),
+    (0x2F8E9, &['\u{69A3}']),
+    (0x2F8EA, &['\u{69EA}']),
+    (0x2F8EB, &['\u{6AA8}']),
+    (0x2F8EC, &['\u{236A3}']),
+    (0x2F8ED, &['\u{6ADB}']),
+    (0x2F8EE, &['\u{3C18}']),
+    (0x2F8EF, &['\u{6B21}']),
+    (0x2F8F0, &['\u{238A7}']),
+    (0x2F8F1, &['\u{6B54}']),
+    (0x2F8F2, &['\u{3C4E}']),
+    (0x2F8F3, &['\u{6B72}']),
+    (0x2F8F4, &['\u{6B9F}']),
+    (0x2F8F5, &['\u{6BBA}']),
+    (0x2F8F6, &['\u{6BBB}']),
+    (0x2F8F7, &['\u{23A8D}']),
+    (0x2F8F8, &['\u{21D0B}']),
+    (0x2F8F9, &['\u{23AFA}']),
+    (0x2F8FA, &['\u{6C4E}']),
+    (0x2F8FB, &['\u{23CBC}']),
+    (0x2F8FC, &['\u{6CBF}']),
+    (0x2F8FD, &['\u{6CCD}']),
+    (0x2F8FE, &['\u{6C67}']),
+    (0x2F8FF, &['\u{6D16}']),
+    (0x2F900, &['\u{6D3E}']),
+    (0x2F901, &['\u{6D77}']),
+    (0x2F902, &['\u{6D41}']),
+    (0x2F903, &['\u{6D69}']),
+    (0x2F904, &['\u{6D78}']),
+    (0x2F905, &['\u{6D85}']),
+    (0x2F906, &['\u{23D1E}']),
+    (0x2F907, &['\u{6D34}']),
+    (0x2F908, &['\u{6E2F}']),
+    (0x2F909, &['\u{6E6E}']),
+    (0x2F90A, &['\u{3D33}']),
+    (0x2F90B, &['\u{6ECB}']),
+    (0x2F90C, &['\u{6EC7}']),
+    (0x2F90D, &['\u{23ED1}']),
+    (0x2F90E, &['\u{6DF9}']),
+    (0x2F90F, &['\u{6F6E}']),
+    (0x2F910, &['\u{23F5E}']),
+    (0x2F911, &['\u{23F8E}']),
+    (0x2F912, &['\u{6FC6}']),
+    (0x2F913, &['\u{7039}']),
+    (0x2F914, &['\u{701E}']),
+    (0x2F915, &['\u{701B}']),
+    (0x2F916, &['\u{3D96}']),
+    (0x2F917, &['\u{704A}']),
+    (0x2F918, &['\u{707D}']),
+    (0x2F919, &['\u{7077}']),
+    (0x2F91A, &['\u{70AD}']),
+    (0x2F91B, &['\u{20525}']),
+    (0x2F91C, &['\u{7145}']),
+    (0x2F91D, &['\u{24263}']),
+    (0x2F91E, &['\u{719C}']),
+    (0x2F91F, &['\u{243AB}']),
+    (0x2F920, &['\u{7228}']),
+    (0x2F921, &['\u{7235}']),
+    (0x2F922, &['\u{7250}']),
+    (0x2F923, &['\u{24608}']),
+    (0x2F924, &['\u{7280}']),
+    (0x2F925, &['\u{7295}']),
+    (0x2F926, &['\u{24735}']),
+    (0x2F927, &['\u{24814}']),
+    (0x2F928, &['\u{737A}']),
+    (0x2F929, &['\u{738B}']),
+    (0x2F92A, &['\u{3EAC}']),
+    (0x2F92B, &['\u{73A5}']),
+    (0x2F92C, &['\u{3EB8}']),
+    (0x2F92D, &['\u{3EB8}']),
+    (0x2F92E, &['\u{7447}']),
+    (0x2F92F, &['\u{745C}']),
+    (0x2F930, &['\u{7471}']),
+    (0x2F931, &['\u{7485}']),
+    (0x2F932, &['\u{74CA}']),
+    (0x2F933, &['\u{3F1B}']),
+    (0x2F934, &['\u{7524}']),
+    (0x2F935, &['\u{24C36}']),
+    (0x2F936, &['\u{753E}']),
+    (0x2F937, &['\u{24C92}']),
+    (0x2F938, &['\u{7570}']),
+    (0x2F939, &['\u{2219F}']),
+    (0x2F93A, &['\u{7610}']),
+    (0x2F93B, &['\u{24FA1}']),
+    (0x2F93C, &['\u{24FB8}']),
+    (0x2F93D, &['\u{25044}']),
+    (0x2F93E, &['\u{3FFC}']),
+    (0x2F93F, &['\u{4008}']),
+    (0x2F940, &['\u{76F4}']),
+    (0x2F941, &['\u{250F3}']),
+    (0x2F942, &['\u{250F2}']),
+    (0x2F943, &['\u{25119}']),
+    (0x2F944, &['\u{25133}']),
+    (0x2F945, &['\u{771E}']),
+    (0x2F946, &['\u{771F}']),
+    (0x2F947, &['\u{771F}']),
+    (0x2F948, &['\u{774A}']),
+    (0x2F949, &['\u{4039}']),
+    (0x2F94A, &['\u{778B}']),
+    (0x2F94B, &['\u{4046}']),
+    (0x2F94C, &['\u{4096}']),
+    (0x2F94D, &['\u{2541D}']),
+    (0x2F94E, &['\u{784E}']),
+    (0x2F94F, &['\u{788C}']),
+    (0x2F950, &['\u{78CC}']),
+    (0x2F951, &['\u{40E3}']),
+    (0x2F952, &['\u{25626}']),
+    (0x2F953, &['\u{7956}']),
+    (0x2F954, &['\u{2569A}']),
+    (0x2F955, &['\u{256C5}']),
+    (0x2F956, &['\u{798F}']),
+    (0x2F957, &['\u{79EB}']),
+    (0x2F958, &['\u{412F}']),
+    (0x2F959, &['\u{7A40}']),
+    (0x2F95A, &['\u{7A4A}']),
+    (0x2F95B, &['\u{7A4F}']),
+    (0x2F95C, &['\u{2597C}']),
+    (0x2F95D, &['\u{25AA7}']),
+    (0x2F95E, &['\u{25AA7}']),
+    (0x2F95F, &['\u{7AEE}']),
+    (0x2F960, &['\u{4202}']),
+    (0x2F961, &['\u{25BAB}']),
+    (0x2F962, &['\u{7BC6}']),
+    (0x2F963, &['\u{7BC9}']),
+    (0x2F964, &['\u{4227}']),
+    (0x2F965, &['\u{25C80}']),
+    (0x2F966, &['\u{7CD2}']),
+    (0x2F967, &['\u{42A0}']),
+    (0x2F968, &['\u{7CE8}']),
+    (0x2F969, &['\u{7CE3}']),
+    (0x2F96A, &['\u{7D00}']),
+    (0x2F96B, &['\u{25F86}']),
+    (0x2F96C, &['\u{7D63}']),
+    (0x2F96D, &['\u{4301}']),
+    (0x2F96E, &['\u{7DC7}']),
+    (0x2F96F, &['\u{7E02}']),
+    (0x2F970, &['\u{7E45}']),
+    (0x2F971, &['\u{4334}']),
+    (0x2F972, &['\u{26228}']),
+    (0x2F973, &['\u{26247}']),
+    (0x2F974, &['\u{4359}']),
+    (0x2F975, &['\u{262D9}']),
+    (0x2F976, &['\u{7F7A}']),
+    (0x2F977, &['\u{2633E}']),
+    (0x2F978, &['\u{7F95}']),
+    (0x2F979, &['\u{7FFA}']),
+    (0x2F97A, &['\u{8005}']),
+    (0x2F97B, &['\u{264DA}']),
+    (0x2F97C, &['\u{26523}']),
+    (0x2F97D, &['\u{8060}']),
+    (0x2F97E, &['\u{265A8}']),
+    (0x2F97F, &['\u{8070}']),
+    (0x2F980, &['\u{2335F}']),
+    (0x2F981, &['\u{43D5}']),
+    (0x2F982, &['\u{80B2}']),
+    (0x2F983, &['\u{8103}']),
+    (0x2F984, &['\u{440B}']),
+    (0x2F985, &['\u{813E}']),
+    (0x2F986, &['\u{5AB5}']),
+    (0x2F987, &['\u{267A7}']),
+    (0x2F988, &['\u{267B5}']),
+    (0x2F989, &['\u{23393}']),
+    (0x2F98A, &['\u{2339C}']),
+    (0x2F98B, &['\u{8201}']),
+    (0x2F98C, &['\u{8204}']),
+    (0x2F98D, &['\u{8F9E}']),
+    (0x2F98E, &['\u{446B}']),
+    (0x2F98F, &['\u{8291}']),
+    (0x2F990, &['\u{828B}']),
+    (0x2F991, &['\u{829D}']),
+    (0x2F992, &['\u{52B3}']),
+    (0x2F993, &['\u{82B1}']),
+    (0x2F994, &['\u{82B3}']),
+    (0x2F995, &['\u{82BD}']),
+    (0x2F996, &['\u{82E6}']),
+    (0x2F997, &['\u{26B3C}']),
+    (0x2F998, &['\u{82E5}']),
+    (0x2F999, &['\u{831D}']),
+    (0x2F99A, &['\u{8363}']),
+    (0x2F99B, &['\u{83AD}']),
+    (0x2F99C, &['\u{8323}']),
+    (0x2F99D, &['\u{83BD}']),
+    (0x2F99E, &['\u{83E7}']),
+    (0x2F99F, &['\u{8457}']),
+    (0x2F9A0, &['\u{8353}']),
+    (0x2F9A1, &['\u{83CA}']),
+    (0x2F9A2, &['\u{83CC}']),
+    (0x2F9A3, &['\u{83DC}']),
+    (0x2F9A4, &['\u{26C36}']),
+    (0x2F9A5, &['\u{26D6B}']),
+    (0x2F9A6, &['\u{26CD5}']),
+    (0x2F9A7, &['\u{452B}']),
+    (0x2F9A8, &['\u{84F1}']),
+    (0x2F9A9, &['\u{84F3}']),
+    (0x2F9AA, &['\u{8516}']),
+    (0x2F9AB, &['\u{273CA}']),
+    (0x2F9AC, &['\u{8564}']),
+    (0x2F9AD, &['\u{26F2C}']),
+    (0x2F9AE, &['\u{455D}']),
+    (0x2F9AF, &['\u{4561}']),
+    (0x2F9B0, &['\u{26FB1}']),
+    (0x2F9B1, &['\u{270D2}']),
+    (0x2F9B2, &['\u{456B}']),
+    (0x2F9B3, &['\u{8650}']),
+    (0x2F9B4, &['\u{865C}']),
+    (0x2F9B5, &['\u{8667}']),
+    (0x2F9B6, &['\u{8669}']),
+    (0x2F9B7, &['\u{86A9}']),
+    (0x2F9B8, &['\u{8688}']),
+    (0x2F9B9, &['\u{870E}']),
+    (0x2F9BA, &['\u{86E2}']),
+    (0x2F9BB, &['\u{8779}']),
+    (0x2F9BC, &['\u{8728}']),
+    (0x2F9BD, &['\u{876B}']),
+    (0x2F9BE, &['\u{8786}']),
+    (0x2F9BF, &['\u{45D7}']),
+    (0x2F9C0, &['\u{87E1}']),
+    (0x2F9C1, &['\u{8801}']),
+    (0x2F9C2, &['\u{45F9}']),
+    (0x2F9C3, &['\u{8860}']),
+    (0x2F9C4, &['\u{8863}']),
+    (0x2F9C5, &['\u{27667}']),
+    (0x2F9C6, &['\u{88D7}']),
+    (0x2F9C7, &['\u{88DE}']),
+    (0x2F9C8, &['\u{4635}']),
+    (0x2F9C9, &['\u{88FA}']),
+    (0x2F9CA, &['\u{34BB}']),
+    (0x2F9CB, &['\u{278AE}']),
+    (0x2F9CC, &['\u{27966}']),
+    (0x2F9CD, &['\u{46BE}']),
+    (0x2F9CE, &['\u{46C7}']),
+    (0x2F9CF, &['\u{8AA0}']),
+    (0x2F9D0, &['\u{8AED}']),
+    (0x2F9D1, &['\u{8B8A}']),
+    (0x2F9D2, &['\u{8C55}']),
+    (0x2F9D3, &['\u{27CA8}']),
+    (0x2F9D4, &['\u{8CAB}']),
+    (0x2F9D5, &['\u{8CC1}']),
+    (0x2F9D6, &['\u{8D1B}']),
+    (0x2F9D7, &['\u{8D77}']),
+    (0x2F9D8, &['\u{27F2F}']),
+    (0x2F9D9, &['\u{20804}']),
+    (0x2F9DA, &['\u{8DCB}']),
+    (0x2F9DB, &['\u{8DBC}']),
+    (0x2F9DC, &['\u{8DF0}']),
+    (0x2F9DD, &['\u{208DE}']),
+    (0x2F9DE, &['\u{8ED4}']),
+    (0x2F9DF, &['\u{8F38}']),
+    (0x2F9E0, &['\u{285D2}']),
+    (0x2F9E1, &['\u{285ED}']),
+    (0x2F9E2, &['\u{9094}']),
+    (0x2F9E3, &['\u{90F1}']),
+    (0x2F9E4, &['\u{9111}']),
+    (0x2F9E5, &['\u{2872E}']),
+    (0x2F9E6, &['\u{911B}']),
+    (0x2F9E7, &['\u{9238}']),
+    (0x2F9E8, &['\u{92D7}']),
+    (0x2F9E9, &['\u{92D8}']),
+    (0x2F9EA, &['\u{927C}']),
+    (0x2F9EB, &['\u{93F9}']),
+    (0x2F9EC, &['\u{9415}']),
+    (0x2F9ED, &['\u{28BFA}']),
+    (0x2F9EE, &['\u{958B}']),
+    (0x2F9EF, &['\u{4995}']),
+    (0x2F9F0, &['\u{95B7}']),
+    (0x2F9F1, &['\u{28D77}']),
+    (0x2F9F2, &['\u{49E6}']),
+    (0x2F9F3, &['\u{96C3}']),
+    (0x2F9F4, &['\u{5DB2}']),
+    (0x2F9F5, &['\u{9723}']),
+    (0x2F9F6, &['\u{29145}']),
+    (0x2F9F7, &['\u{2921A}']),
+    (0x2F9F8, &['\u{4A6E}']),
+    (0x2F9F9, &['\u{4A76}']),
+    (0x2F9FA, &['\u{97E0}']),
+    (0x2F9FB, &['\u{2940A}']),
+    (0x2F9FC, &['\u{4AB2}']),
+    (0x2F9FD, &['\u{29496}']),
+    (0x2F9FE, &['\u{980B}']),
+    (0x2F9FF, &['\u{980B}']),
+    (0x2FA00, &['\u{9829}']),
+    (0x2FA01, &['\u{295B6}']),
+    (0x2FA02, &['\u{98E2}']),
+    (0x2FA03, &['\u{4B33}']),
+    (0x2FA04, &['\u{9929}']),
+    (0x2FA05, &['\u{99A7}']),
+    (0x2FA06, &['\u{99C2}']),
+    (0x2FA07, &['\u{99FE}']),
+    (0x2FA08, &['\u{4BCE}']),
+    (0x2FA09, &['\u{29B30}']),
+    (0x2FA0A, &['\u{9B12}']),
+    (0x2FA0B, &['\u{9C40}']),
+    (0x2FA0C, &['\u{9CFD}']),
+    (0x2FA0D, &['\u{4CCE}']),
+    (0x2FA0E, &['\u{4CED}']),
+    (0x2FA0F, &['\u{9D67}']),
+    (0x2FA10, &['\u{2A0CE}']),
+    (0x2FA11, &['\u{4CF8}']),
+    (0x2FA12, &['\u{2A105}']),
+    (0x2FA13, &['\u{2A20E}']),
+    (0x2FA14, &['\u{2A291}']),
+    (0x2FA15, &['\u{9EBB}']),
+    (0x2FA16, &['\u{4D56}']),
+    (0x2FA17, &['\u{9EF9}']),
+    (0x2FA18, &['\u{9EFE}']),
+    (0x2FA19, &['\u{9F05}']),
+    (0x2FA1A, &['\u{9F0F}']),
+    (0x2FA1B, &['\u{9F16}']),
+    (0x2FA1C, &['\u{9F3B}']),
+    (0x2FA1D, &['\u{2A600}']),
+];
+
+/// The pairs of characters that compose into one in NFC, with the character they
+/// compose into, sorted by pair. Hangul syllables are left out, they compose
+/// algorithmically.
+pub(super) const COMPOSITIONS: &[(u32, u32, char)] = &[
+    (0x3C, 0x338, '\u{226E}'),
+    (0x3D, 0x338, '\u{2260}'),
+    (0x3E, 0x338, '\u{226F}'),
+    (0x41, 0x300, '\u{C0}'),
+    (0x41, 0x301, '\u{C1}'),
+    (0x41, 0x302, '\u{C2}'),
+    (0x41, 0x303, '\u{C3}'),
+    (0x41, 0x304, '\u{100}'),
+    (0x41, 0x306, '\u{102}'),
+    (0x41, 0x307, '\u{226}'),
+    (0x41, 0x308, '\u{C4}'),
+    (0x41, 0x309, '\u{1EA2}'),
+    (0x41, 0x30A, '\u{C5}'),
+    (0x41, 0x30C, '\u{1CD}'),
+    (0x41, 0x30F, '\u{200}'),
+    (0x41, 0x311, '\u{202}'),
+    (0x41, 0x323, '\u{1EA0}'),
+    (0x41, 0x325, '\u{1E00}'),
+    (0x41, 0x328, '\u{104}'),
+    (0x42, 0x307, '\u{1E02}'),
+    (0x42, 0x323, '\u{1E04}'),
+    (0x42, 0x331, '\u{1E06}'),
+    (0x43, 0x301, '\u{106}'),
+    (0x43, 0x302, '\u{108}'),
+    (0x43, 0x307, '\u{10A}'),
+    (0x43, 0x30C, '\u{10C}'),
+    (0x43, 0x327, '\u{C7}'),
+    (0x44, 0x307, '\u{1E0A}'),
+    (0x44, 0x30C, '\u{10E}'),
+    (0x44, 0x323, '\u{1E0C}'),
+    (0x44, 0x327, '\u{1E10}'),
+    (0x44, 0x32D, '\u{1E12}'),
+    (0x44, 0x331, '\u{1E0E}'),
+    (0x45, 0x300, '\u{C8}'),
+    (0x45, 0x301, '\u{C9}'),
+    (0x45, 0x302, '\u{CA}'),
+    (0x45, 0x303, '\u{1EBC}'),
+    (0x45, 0x304, '\u{112}'),
+    (0x45, 0x306, '\u{114}'),
+    (0x45, 0x307, '\u{116}'),
+    (0x45, 0x308, '\u{CB}'),
+    (0x45, 0x309, '\u{1EBA}'),
+    (0x45, 0x30C, '\u{11A}'),
+    (0x45, 0x30F, '\u{204}'),
+    (0x45, 0x311, '\u{206}'),
+    (0x45, 0x323, '\u{1EB8}'),
+    (0x45, 0x327, '\u{228}'),
+    (0x45, 0x328, '\u{118}'),
+    (0x45, 0x32D, '\u{1E18}'),
+    (0x45, 0x330, '\u{1E1A}'),
+    (0x46, 0x307, '\u{1E1E}'),
+    (0x47, 0x301, '\u{1F4}'),
+    (0x47, 0x302, '\u{11C}'),
+    (0x47, 0x304, '\u{1E20}'),
+    (0x47, 0x306, '\u{11E}'),
+    (0x47, 0x307, '\u{120}'),
+    (0x47, 0x30C, '\u{1E6}'),
+    (0x47, 0x327, '\u{122}'),
+    (0x48, 0x302, '\u{124}'),
+    (0x48, 0x307, '\u{1E22}'),
+    (0x48, 0x308, '\u{1E26}'),
+    (0x48, 0x30C, '\u{21E}'),
+    (0x48, 0x323, '\u{1E24}'),
+    (0x48, 0x327, '\u{1E28}'),
+    (0x48, 0x32E, '\u{1E2A}'),
+    (0x49, 0x300, '\u{CC}'),
+    (0x49, 0x301, '\u{CD}'),
+    (0x49, 0x302, '\u{CE}'),
+    (0x49, 0x303, '\u{128}'),
+    (0x49, 0x304, '\u{12A}'),
+    (0x49, 0x306, '\u{12C}'),
+    (0x49, 0x307, '\u{130}'),
+    (0x49, 0x308, '\u{CF}'),
+    (0x49, 0x309, '\u{1EC8}'),
+    (0x49, 0x30C, '\u{1CF}'),
+    (0x49, 0x30F, '\u{208}'),
+    (0x49, 0x311, '\u{20A}'),
+    (0x49, 0x323, '\u{1ECA}'),
+    (0x49, 0x328, '\u{12E}'),
+    (0x49, 0x330, '\u{1E2C}'),
+    (0x4A, 0x302, '\u{134}'),
+    (0x4B, 0x301, '\u{1E30}'),
+    (0x4B, 0x30C, '\u{1E8}'),
+    (0x4B, 0x323, '\u{1E32}'),
+    (0x4B, 0x327, '\u{136}'),
+    (0x4B, 0x331, '\u{1E34}'),
+    (0x4C, 0x301, '\u{139}'),
+    (0x4C, 0x30C, '\u{13D}'),
+    (0x4C, 0x323, '\u{1E36}'),
+    (0x4C, 0x327, '\u{13B}'),
+    (0x4C, 0x32D, '\u{1E3C}'),
+    (0x4C, 0x331, '\u{1E3A}'),
+    (0x4D, 0x301, '\u{1E3E}'),
+    (0x4D, 0x307, '\u{1E40}'),
+    (0x4D, 0x323, '\u{1E42}'),
+    (0x4E, 0x300, '\u{1F8}'),
+    (0x4E, 0x301, '\u{143}'),
+    (0x4E, 0x303, '\u{D1}'),
+    (0x4E, 0x307, '\u{1E44}'),
+    (0x4E, 0x30C, '\u{147}'),
+    (0x4E, 0x323, '\u{1E46}'),
+    (0x4E, 0x327, '\u{145}'),
+    (0x4E, 0x32D, '\u{1E4A}'),
+    (0x4E, 0x331, '\u{1E48}'),
+    (0x4F, 0x300, '\u{D2}'),
+    (0x4F, 0x301, '\u{D3}'),
+    (0x4F, 0x302, '\u{D4}'),
+    (0x4F, 0x303, '\u{D5}'),
+    (0x4F, 0x304, '\u{14C}'),
+    (0x4F, 0x306, '\u{14E}'),
+    (0x4F, 0x307, '\u{22E}'),
+    (0x4F, 0x308, '\u{D6}'),
+    (0x4F, 0x309, '\u{1ECE}'),
+    (0x4F, 0x30B, '\u{150}'),
+    (0x4F, 0x30C, '\u{1D1}'),
+    (0x4F, 0x30F, '\u{20C}'),
+    (0x4F, 0x311, '\u{20E}'),
+    (0x4F, 0x31B, '\u{1A0}'),
+    (0x4F, 0x323, '\u{1ECC}'),
+    (0x4F, 0x328, '\u{1EA}'),
+    (0x50, 0x301, '\u{1E54}'),
+    (0x50, 0x307, '\u{1E56}'),
+    (0x52, 0x301, '\u{154}'),
+    (0x52, 0x307, '\u{1E58}'),
+    (0x52, 0x30C, '\u{158}'),
+    (0x52, 0x30F, '\u{210}'),
+    (0x52, 0x311, '\u{212}'),
+    (0x52, 0x323, '\u{1E5A}'),
+    (0x52, 0x327, '\u{156}'),
+    (0x52, 0x331, '\u{1E5E}'),
+    (0x53, 0x301, '\u{15A}'),
+    (0x53, 0x302, '\u{15C}'),
+    (0x53, 0x307, '\u{1E60}'),
+    (0x53, 0x30C, '\u{160}'),
+    (0x53, 0x323, '\u{1E62}'),
+    (0x53, 0x326, '\u{218}'),
+    (0x53, 0x327, '\u{15E}'),
+    (0x54, 0x307, '\u{1E6A}'),
+    (0x54, 0x30C, '\u{164}'),
+    (0x54, 0x323, '\u{1E6C}'),
+    (0x54, 0x326, '\u{21A}'),
+    (0x54, 0x327, '\u{162}'),
+    (0x54, 0x32D, '\u{1E70}'),
+    (0x54, 0x331, '\u{1E6E}'),
+    (0x55, 0x300, '\u{D9}'),
+    (0x55, 0x301, '\u{DA}'),
+    (0x55, 0x302, '\u{DB}'),
+    (0x55, 0x303, '\u{168}'),
+    (0x55, 0x304, '\u{16A}'),
+    (0x55, 0x306, '\u{16C}'),
+    (0x55, 0x308, '\u{DC}'),
+    (0x55, 0x309, '\u{1EE6}'),
+    (0x55, 0x30A, '\u{16E}'),
+    (0x55, 0x30B, '\u{170}'),
+    (0x55, 0x30C, '\u{1D3}'),
+    (0x55, 0x30F, '\u{214}'),
+    (0x55, 0x311, '\u{216}'),
+    (0x55, 0x31B, '\u{1AF}'),
+    (0x55, 0x323, '\u{1EE4}'),
+    (0x55, 0x324, '\u{1E72}'),
+    (0x55, 0x328, '\u{172}'),
+    (0x55, 0x32D, '\u{1E76}'),
+    (0x55, 0x330, '\u{1E74}'),
+    (0x56, 0x303, '\u{1E7C}'),
+    (0x56, 0x323, '\u{1E7E}'),
+    (0x57, 0x300, '\u{1E80}'),
+    (0x57, 0x301, '\u{1E82}'),
+    (0x57, 0x302, '\u{174}'),
+    (0x57, 0x307, '\u{1E86}'),
+    (0x57, 0x308, '\u{1E84}'),
+    (0x57, 0x323, '\u{1E88}'),
+    (0x58, 0x307, '\u{1E8A}'),
+    (0x58, 0x308, '\u{1E8C}'),
+    (0x59, 0x300, '\u{1EF2}'),
+    (0x59, 0x301, '\u{DD}'),
+    (0x59, 0x302, '\u{176}'),
+    (0x59, 0x303, '\u{1EF8}'),
+    (0x59, 0x304, '\u{232}'),
+    (0x59, 0x307, '\u{1E8E}'),
+    (0x59, 0x308, '\u{178}'),
+    (0x59, 0x309, '\u{1EF6}'),
+    (0x59, 0x323, '\u{1EF4}'),
+    (0x5A, 0x301, '\u{179}'),
+    (0x5A, 0x302, '\u{1E90}'),
+    (0x5A, 0x307, '\u{17B}'),
+    (0x5A, 0x30C, '\u{17D}'),
+    (0x5A, 0x323, '\u{1E92}'),
+    (0x5A, 0x331, '\u{1E94}'),
+    (0x61, 0x300, '\u{E0}'),
+    (0x61, 0x301, '\u{E1}'),
+    (0x61, 0x302, '\u{E2}'),
+    (0x61, 0x303, '\u{E3}'),
+    (0x61, 0x304, '\u{101}'),
+    (0x61, 0x306, '\u{103}'),
+    (0x61, 0x307, '\u{227}'),
+    (0x61, 0x308, '\u{E4}'),
+    (0x61, 0x309, '\u{1EA3}'),
+    (0x61, 0x30A, '\u{E5}'),
+    (0x61, 0x30C, '\u{1CE}'),
+    (0x61, 0x30F, '\u{201}'),
+    (0x61, 0x311, '\u{203}'),
+    (0x61, 0x323, '\u{1EA1}'),
+    (0x61, 0x325, '\u{1E01}'),
+    (0x61, 0x328, '\u{105}'),
+    (0x62, 0x307, '\u{1E03}'),
+    (0x62, 0x323, '\u{1E05}'),
+    (0x62, 0x331, '\u{1E07}'),
+    (0x63, 0x301, '\u{107}'),
+    (0x63, 0x302, '\u{109}'),
+    (0x63, 0x307, '\u{10B}'),
+    (0x63, 0x30C, '\u{10D}'),
+    (0x63, 0x327, '\u{E7}'),
+    (0x64, 0x307, '\u{1E0B}'),
+    (0x64, 0x30C, '\u{10F}'),
+    (0x64, 0x323, '\u{1E0D}'),
+    (0x64, 0x327, '\u{1E11}'),
+    (0x64, 0x32D, '\u{1E13}'),
+    (0x64, 0x331, '\u{1E0F}'),
+    (0x65, 0x300, '\u{E8}'),
+    (0x65, 0x301, '\u{E9}'),
+    (0x65, 0x302, '\u{EA}'),
+    (0x65, 0x303, '\u{1EBD}'),
+    (0x65, 0x304, '\u{113}'),
+    (0x65, 0x306, '\u{115}'),
+    (0x65, 0x307, '\u{117}'),
+    (0x65, 0x308, '\u{EB}'),
+    (0x65, 0x309, '\u{1EBB}'),
+    (0x65, 0x30C, '\u{11B}'),
+    (0x65, 0x30F, '\u{205}'),
+    (0x65, 0x311, '\u{207}'),
+    (0x65, 0x323, '\u{1EB9}'),
+    (0x65, 0x327, '\u{229}'),
+    (0x65, 0x328, '\u{119}'),
+    (0x65, 0x32D, '\u{1E19}'),
+    (0x65, 0x330, '\u{1E1B}'),
+    (0x66, 0x307, '\u{1E1F}'),
+    (0x67, 0x301, '\u{1F5}'),
+    (0x67, 0x302, '\u{11D}'),
+    (0x67, 0x304, '\u{1E21}'),
+    (0x67, 0x306, '\u{11F}'),
+    (0x67, 0x307, '\u{121}'),
+    (0x67, 0x30C, '\u{1E7}'),
+    (0x67, 0x327, '\u{123}'),
+    (0x68, 0x302, '\u{125}'),
+    (0x68, 0x307, '\u{1E23}'),
+    (0x68, 0x308, '\u{1E27}'),
+    (0x68, 0x30C, '\u{21F}'),
+    (0x68, 0x323, '\u{1E25}'),
+    (0x68, 0x327, '\u{1E29}'),
+    (0x68, 0x32E, '\u{1E2B}'),
+    (0x68, 0x331, '\u{1E96}'),
+    (0x69, 0x300, '\u{EC}'),
+    (0x69, 0x301, '\u{ED}'),
+    (0x69, 0x302, '\u{EE}'),
+    (0x69, 0x303, '\u{129}'),
+    (0x69, 0x304, '\u{12B}'),
+    (0x69, 0x306, '\u{12D}'),
+    (0x69, 0x308, '\u{EF}'),
+    (0x69, 0x309, '\u{1EC9}'),
+    (0x69, 0x30C, '\u{1D0}'),
+    (0x69, 0x30F, '\u{209}'),
+    (0x69, 0x311, '\u{20B}'),
+    (0x69, 0x323, '\u{1ECB}'),
+    (0x69, 0x328, '\u{12F}'),
+    (0x69, 0x330, '\u{1E2D}'),
+    (0x6A, 0x302, '\u{135}'),
+    (0x6A, 0x30C, '\u{1F0}'),
+    (0x6B, 0x301, '\u{1E31}'),
+    (0x6B, 0x30C, '\u{1E9}'),
+    (0x6B, 0x323, '\u{1E33}'),
+    (0x6B, 0x327, '\u{137}'),
+    (0x6B, 0x331, '\u{1E35}'),
+    (0x6C, 0x301, '\u{13A}'),
+    (0x6C, 0x30C, '\u{13E}'),
+    (0x6C, 0x323, '\u{1E37}'),
+    (0x6C, 0x327, '\u{13C}'),
+    (0x6C, 0x32D, '\u{1E3D}'),
+    (0x6C, 0x331, '\u{1E3B}'),
+    (0x6D, 0x301, '\u{1E3F}'),
+    (0x6D, 0x307, '\u{1E41}'),
+    (0x6D, 0x323, '\u{1E43}'),
+    (0x6E, 0x300, '\u{1F9}'),
+    (0x6E, 0x301, '\u{144}'),
+    (0x6E, 0x303, '\u{F1}'),
+    (0x6E, 0x307, '\u{1E45}'),
+    (0x6E, 0x30C, '\u{148}'),
+    (0x6E, 0x323, '\u{1E47}'),
+    (0x6E, 0x327, '\u{146}'),
+    (0x6E, 0x32D, '\u{1E4B}'),
+    (0x6E, 0x331, '\u{1E49}'),
+    (0x6F, 0x300, '\u{F2}'),
+    (0x6F, 0x301, '\u{F3}'),
+    (0x6F, 0x302, '\u{F4}'),
+    (0x6F, 0x303, '\u{F5}'),
+    (0x6F, 0x304, '\u{14D}'),
+    (0x6F, 0x306, '\u{14F}'),
+    (0x6F, 0x307, '\u{22F}'),
+    (0x6F, 0x308, '\u{F6}'),
+    (0x6F, 0x309, '\u{1ECF}'),
+    (0x6F, 0x30B, '\u{151}'),
+    (0x6F, 0x30C, '\u{1D2}'),
+    (0x6F, 0x30F, '\u{20D}'),
+    (0x6F, 0x311, '\u{20F}'),
+    (0x6F, 0x31B, '\u{1A1}'),
+    (0x6F, 0x323, '\u{1ECD}'),
+    (0x6F, 0x328, '\u{1EB}'),
+    (0x70, 0x301, '\u{1E55}'),
+    (0x70, 0x307, '\u{1E57}'),
+    (0x72, 0x301, '\u{155}'),
+    (0x72, 0x307, '\u{1E59}'),
+    (0x72, 0x30C, '\u{159}'),
+    (0x72, 0x30F, '\u{211}'),
+    (0x72, 0x311, '\u{213}'),
+    (0x72, 0x323, '\u{1E5B}'),
+    (0x72, 0x327, '\u{157}'),
+    (0x72, 0x331, '\u{1E5F}'),
+    (0x73, 0x301, '\u{15B}'),
+    (0x73, 0x302, '\u{15D}'),
+    (0x73, 0x307, '\u{1E61}'),
+    (0x73, 0x30C, '\u{161}'),
+    (0x73, 0x323, '\u{1E63}'),
+    (0x73, 0x326, '\u{219}'),
+    (0x73, 0x327, '\u{15F}'),
+    (0x74, 0x307, '\u{1E6B}'),
+    (0x74, 0x308, '\u{1E97}'),
+    (0x74, 0x30C, '\u{165}'),
+    (0x74, 0x323, '\u{1E6D}'),
+    (0x74, 0x326, '\u{21B}'),
+    (0x74, 0x327, '\u{163}'),
+    (0x74, 0x32D, '\u{1E71}'),
+    (0x74, 0x331, '\u{1E6F}'),
+    (0x75, 0x300, '\u{F9}'),
+    (0x75, 0x301, '\u{FA}'),
+    (0x75, 0x302, '\u{FB}'),
+    (0x75, 0x303, '\u{169}'),
+    (0x75, 0x304, '\u{16B}'),
+    (0x75, 0x306, '\u{16D}'),
+    (0x75, 0x308, '\u{FC}'),
+    (0x75, 0x309, '\u{1EE7}'),
+    (0x75, 0x30A, '\u{16F}'),
+    (0x75, 0x30B, '\u{171}'),
+    (0x75, 0x30C, '\u{1D4}'),
+    (0x75, 0x30F, '\u{215}'),
+    (0x75, 0x311, '\u{217}'),
+    (0x75, 0x31B, '\u{1B0}'),
+    (0x75, 0x323, '\u{1EE5}'),
+    (0x75, 0x324, '\u{1E73}'),
+    (0x75, 0x328, '\u{173}'),
+    (0x75, 0x32D, '\u{1E77}'),
+    (0x75, 0x330, '\u{1E75}'),
+    (0x76, 0x303, '\u{1E7D}'),
+    (0x76, 0x323, '\u{1E7F}'),
+    (0x77, 0x300, '\u{1E81}'),
+    (0x77, 0x301, '\u{1E83}'),
+    (0x77, 0x302, '\u{175}'),
+    (0x77, 0x307, '\u{1E87}'),
+    (0x77, 0x308, '\u{1E85}'),
+    (0x77, 0x30A, '\u{1E98}'),
+    (0x77, 0x323, '\u{1E89}'),
+    (0x78, 0x307, '\u{1E8B}'),
+    (0x78, 0x308, '\u{1E8D}'),
+    (0x79, 0x300, '\u{1EF3}'),
+    (0x79, 0x301, '\u{FD}'),
+    (0x79, 0x302, '\u{177}'),
+    (0x79, 0x303, '\u{1EF9}'),
+    (0x79, 0x304, '\u{233}'),
+    (0x79, 0x307, '\u{1E8F}'),
+    (0x79, 0x308, '\u{FF}'),
+    (0x79, 0x309, '\u{1EF7}'),
+    (0x79, 0x30A, '\u{1E99}'),
+    (0x79, 0x323, '\u{1EF5}'),
+    (0x7A, 0x301, '\u{17A}'),
+    (0x7A, 0x302, '\u{1E91}'),
+    (0x7A, 0x307, '\u{17C}'),
+    (0x7A, 0x30C, '\u{17E}'),
+    (0x7A, 0x323, '\u{1E93}'),
+    (0x7A, 0x331, '\u{1E95}'),
+    (0xA8, 0x300, '\u{1FED}'),
+    (0xA8, 0x301, '\u{385}'),
+    (0xA8, 0x342, '\u{1FC1}'),
+    (0xC2, 0x300, '\u{1EA6}'),
+    (0xC2, 0x301, '\u{1EA4}'),
+    (0xC2, 0x303, '\u{1EAA}'),
+    (0xC2, 0x309, '\u{1EA8}'),
+    (0xC4, 0x304, '\u{1DE}'),
+    (0xC5, 0x301, '\u{1FA}'),
+    (0xC6, 0x301, '\u{1FC}'),
+    (0xC6, 0x304, '\u{1E2}'),
+    (0xC7, 0x301, '\u{1E08}'),
+    (0xCA, 0x300, '\u{1EC0}'),
+    (0xCA, 0x301, '\u{1EBE}'),
+    (0xCA, 0x303, '\u{1EC4}'),
+    (0xCA, 0x309, '\u{1EC2}'),
+    (0xCF, 0x301, '\u{1E2E}'),
+    (0xD4, 0x300, '\u{1ED2}'),
+    (0xD4, 0x301, '\u{1ED0}'),
+    (0xD4, 0x303, '\u{1ED6}'),
+    (0xD4, 0x309, '\u{1ED4}'),
+    (0xD5, 0x301, '\u{1E4C}'),
+    (0xD5, 0x304, '\u{22C}'),
+    (0xD5, 0x308, '\u{1E4E}'),
+    (0xD6, 0x304, '\u{22A}'),
+    (0xD8, 0x301, '\u{1FE}'),
+    (0xDC, 0x300, '\u{1DB}'),
+    (0xDC, 0x301, '\u{1D7}'),
+    (0xDC, 0x304, '\u{1D5}'),
+    (0xDC, 0x30C, '\u{1D9}'),
+    (0xE2, 0x300, '\u{1EA7}'),
+    (0xE2, 0x301, '\u{1EA5}'),
+    (0xE2, 0x303, '\u{1EAB}'),
+    (0xE2, 0x309, '\u{1EA9}'),
+    (0xE4, 0x304, '\u{1DF}'),
+    (0xE5, 0x301, '\u{1FB}'),
+    (0xE6, 0x301, '\u{1FD}'),
+    (0xE6, 0x304, '\u{1E3}'),
+    (0xE7, 0x301, '\u{1E09}'),
+    (0xEA, 0x300, '\u{1EC1}'),
+    (0xEA, 0x301, '\u{1EBF}'),
+    (0xEA, 0x303, '\u{1EC5}'),
+    (0xEA, 0x309, '\u{1EC3}'),
+    (0xEF, 0x301, '\u{1E2F}'),
+    (0xF4, 0x300, '\u{1ED3}'),
+    (0xF4, 0x301, '\u{1ED1}'),
+    (0xF4, 0x303, '\u{1ED7}'),
+    (0xF4, 0x309, '\u{1ED5}'),
+    (0xF5, 0x301, '\u{1E4D}'),
+    (0xF5, 0x304, '\u{22D}'),
+    (0xF5, 0x308, '\u{1E4F}'),
+    (0xF6, 0x304, '\u{22B}'),
+    (0xF8, 0x301, '\u{1FF}'),
+    (0xFC, 0x300, '\u{1DC}'),
+    (0xFC, 0x301, '\u{1D8}'),
+    (0xFC, 0x304, '\u{1D6}'),
+    (0xFC, 0x30C, '\u{1DA}'),
+    (0x102, 0x300, '\u{1EB0}'),
+    (0x102, 0x301, '\u{1EAE}'),
+    (0x102, 0x303, '\u{1EB4}'),
+    (0x102, 0x309, '\u{1EB2}'),
+    (0x103, 0x300, '\u{1EB1}'),
+    (0x103, 0x301, '\u{1EAF}'),
+    (0x103, 0x303, '\u{1EB5}'),
+    (0x103, 0x309, '\u{1EB3}'),
+    (0x112, 0x300, '\u{1E14}'),
+    (0x112, 0x301, '\u{1E16}'),
+    (0x113, 0x300, '\u{1E15}'),
+    (0x113, 0x301, '\u{1E17}'),
+    (0x14C, 0x300, '\u{1E50}'),
+    (0x14C, 0x301, '\u{1E52}'),
+    (0x14D, 0x300, '\u{1E51}'),
+    (0x14D, 0x301, '\u{1E53}'),
+    (0x15A, 0x307, '\u{1E64}'),
+    (0x15B, 0x307, '\u{1E65}'),
+    (0x160, 0x307, '\u{1E66}'),
+    (0x161, 0x307, '\u{1E67}'),
+    (0x168, 0x301, '\u{1E78}'),
+    (0x169, 0x301, '\u{1E79}'),
+    (0x16A, 0x308, '\u{1E7A}'),
+    (0x16B, 0x308, '\u{1E7B}'),
+    (0x17F, 0x307, '\u{1E9B}'),
+    (0x1A0, 0x300, '\u{1EDC}'),
+    (0x1A0, 0x301, '\u{1EDA}'),
+    (0x1A0, 0x303, '\u{1EE0}'),
+    (0x1A0, 0x309, '\u{1EDE}'),
+    (0x1A0, 0x323, '\u{1EE2}'),
+    (0x1A1, 0x300, '\u{1EDD}'),
+    (0x1A1, 0x301, '\u{1EDB}'),
+    (0x1A1, 0x303, '\u{1EE1}'),
+    (0x1A1, 0x309, '\u{1EDF}'),
+    (0x1A1, 0x323, '\u{1EE3}'),
+    (0x1AF, 0x300, '\u{1EEA}'),
+    (0x1AF, 0x301, '\u{1EE8}'),
+    (0x1AF, 0x303, '\u{1EEE}'),
+    (0x1AF, 0x309, '\u{1EEC}'),
+    (0x1AF, 0x323, '\u{1EF0}'),
+    (0x1B0, 0x300, '\u{1EEB}'),
+    (0x1B0, 0x301, '\u{1EE9}'),
+    (0x1B0, 0x303, '\u{1EEF}'),
+    (0x1B0, 0x309, '\u{1EED}'),
+    (0x1B0, 0x323, '\u{1EF1}'),
+    (0x1B7, 0x30C, '\u{1EE}'),
+    (0x1EA, 0x304, '\u{1EC}'),
+    (0x1EB, 0x304, '\u{1ED}'),
+    (0x226, 0x304, '\u{1E0}'),
+    (0x227, 0x304, '\u{1E1}'),
+    (0x228, 0x306, '\u{1E1C}'),
+    (0x229, 0x306, '\u{1E1D}'),
+    (0x22E, 0x304, '\u{230}'),
+    (0x22F, 0x304, '\u{231}'),
+    (0x292, 0x30C, '\u{1EF}'),
+    (0x391, 0x300, '\u{1FBA}'),
+    (0x391, 0x301, '\u{386}'),
+    (0x391, 0x304, '\u{1FB9}'),
+    (0x391, 0x306, '\u{1FB8}'),
+    (0x391, 0x313, '\u{1F08}'),
+    (0x391, 0x314, '\u{1F09}'),
+    (0x391, 0x345, '\u{1FBC}'),
+    (0x395, 0x300, '\u{1FC8}'),
+    (0x395, 0x301, '\u{388}'),
+    (0x395, 0x313, '\u{1F18}'),
+    (0x395, 0x314, '\u{1F19}'),
+    (0x397, 0x300, '\u{1FCA}'),
+    (0x397, 0x301, '\u{389}'),
+    (0x397, 0x313, '\u{1F28}'),
+    (0x397, 0x314, '\u{1F29}'),
+    (0x397, 0x345, '\u{1FCC}'),
+    (0x399, 0x300, '\u{1FDA}'),
+    (0x399, 0x301, '\u{38A}'),
+    (0x399, 0x304, '\u{1FD9}'),
+    (0x399, 0x306, '\u{1FD8}'),
+    (0x399, 0x308, '\u{3AA}'),
+    (0x399, 0x313, '\u{1F38}'),
+    (0x399, 0x314, '\u{1F39}'),
+    (0x39F, 0x300, '\u{1FF8}'),
+    (0x39F, 0x301, '\u{38C}'),
+    (0x39F, 0x313, '\u{1F48}'),
+    (0x39F, 0x314, '\u{1F49}'),
+    (0x3A1, 0x314, '\u{1FEC}'),
+    (0x3A5, 0x300, '\u{1FEA}'),
+    (0x3A5, 0x301, '\u{38E}'),
+    (0x3A5, 0x304, '\u{1FE9}'),
+    (0x3A5, 0x306, '\u{1FE8}'),
+    (0x3A5, 0x308, '\u{3AB}'),
+    (0x3A5, 0x314, '\u{1F59}'),
+    (0x3A9, 0x300, '\u{1FFA}'),
+    (0x3A9, 0x301, '\u{38F}'),
+    (0x3A9, 0x313, '\u{1F68}'),
+    (0x3A9, 0x314, '\u{1F69}'),
+    (0x3A9, 0x345, '\u{1FFC}'),
+    (0x3AC, 0x345, '\u{1FB4}'),
+    (0x3AE, 0x345, '\u{1FC4}'),
+    (0x3B1, 0x300, '\u{1F70}'),
+    (0x3B1, 0x301, '\u{3AC}'),
+    (0x3B1, 0x304, '\u{1FB1}'),
+    (0x3B1, 0x306, '\u{1FB0}'),
+    (0x3B1, 0x313, '\u{1F00}'),
+    (0x3B1, 0x314, '\u{1F01}'),
+    (0x3B1, 0x342, '\u{1FB6}'),
+    (0x3B1, 0x345, '\u{1FB3}'),
+    (0x3B5, 0x300, '\u{1F72}'),
+    (0x3B5, 0x301, '\u{3AD}'),
+    (0x3B5, 0x313, '\u{1F10}'),
+    (0x3B5, 0x314, '\u{1F11}'),
+    (0x3B7, 0x300, '\u{1F74}'),
+    (0x3B7, 0x301, '\u{3AE}'),
+    (0x3B7, 0x313, '\u{1F20}'),
+    (0x3B7, 0x314, '\u{1F21}'),
+    (0x3B7, 0x342, '\u{1FC6}'),
+    (0x3B7, 0x345, '\u{1FC3}'),
+    (0x3B9, 0x300, '\u{1F76}'),
+    (0x3B9, 0x301, '\u{3AF}'),
+    (0x3B9, 0x304, '\u{1FD1}'),
+    (0x3B9, 0x306, '\u{1FD0}'),
+    (0x3B9, 0x308, '\u{3CA}'),
+    (0x3B9, 0x313, '\u{1F30}'),
+    (0x3B9, 0x314, '\u{1F31}'),
+    (0x3B9, 0x342, '\u{1FD6}'),
+    (0x3BF, 0x300, '\u{1F78}'),
+    (0x3BF, 0x301, '\u{3CC}'),
+    (0x3BF, 0x313, '\u{1F40}'),
+    (0x3BF, 0x314, '\u{1F41}'),
+    (0x3C1, 0x313, '\u{1FE4}'),
+    (0x3C1, 0x314, '\u{1FE5}'),
+    (0x3C5, 0x300, '\u{1F7A}'),
+    (0x3C5, 0x301, '\u{3CD}'),
+    (0x3C5, 0x304, '\u{1FE1}'),
+    (0x3C5, 0x306, '\u{1FE0}'),
+    (0x3C5, 0x308, '\u{3CB}'),
+    (0x3C5, 0x313, '\u{1F50}'),
+    (0x3C5, 0x314, '\u{1F51}'),
+    (0x3C5, 0x342, '\u{1FE6}'),
+    (0x3C9, 0x300, '\u{1F7C}'),
+    (0x3C9, 0x301, '\u{3CE}'),
+    (0x3C9, 0x313, '\u{1F60}'),
+    (0x3C9, 0x314, '\u{1F61}'),
+    (0x3C9, 0x342, '\u{1FF6}'),
+    (0x3C9, 0x345, '\u{1FF3}'),
+    (0x3CA, 0x300, '\u{1FD2}'),
+    (0x3CA, 0x301, '\u{390}'),
+    (0x3CA, 0x342, '\u{1FD7}'),
+    (0x3CB, 0x300, '\u{1FE2}'),
+    (0x3CB, 0x301, '\u{3B0}'),
+    (0x3CB, 0x342, '\u{1FE7}'),
+    (0x3CE, 0x345, '\u{1FF4}'),
+    (0x3D2, 0x301, '\u{3D3}'),
+    (0x3D2, 0x308, '\u{3D4}'),
+    (0x406, 0x308, '\u{407}'),
+    (0x410, 0x306, '\u{4D0}'),
+    (0x410, 0x308, '\u{4D2}'),
+    (0x413, 0x301, '\u{403}'),
+    (0x415, 0x300, '\u{400}'),
+    (0x415, 0x306, '\u{4D6}'),
+    (0x415, 0x308, '\u{401}'),
+    (0x416, 0x306, '\u{4C1}'),
+    (0x416, 0x308, '\u{4DC}'),
+    (0x417, 0x308, '\u{4DE}'),
+    (0x418, 0x300, '\u{40D}'),
+    (0x418, 0x304, '\u{4E2}'),
+    (0x418, 0x306, '\u{419}'),
+    (0x418, 0x308, '\u{4E4}'),
+    (0x41A, 0x301, '\u{40C}'),
+    (0x41E, 0x308, '\u{4E6}'),
+    (0x423, 0x304, '\u{4EE}'),
+    (0x423, 0x306, '\u{40E}'),
+    (0x423, 0x308, '\u{4F0}'),
+    (0x423, 0x30B, '\u{4F2}'),
+    (0x427, 0x308, '\u{4F4}'),
+    (0x42B, 0x308, '\u{4F8}'),
+    (0x42D, 0x308, '\u{4EC}'),
+    (0x430, 0x306, '\u{4D1}'),
+    (0x430, 0x308, '\u{4D3}'),
+    (0x433, 0x301, '\u{453}'),
+    (0x435, 0x300, '\u{450}'),
+    (0x435, 0x306, '\u{4D7}'),
+    (0x435, 0x308, '\u{451}'),
+    (0x436, 0x306, '\u{4C2}'),
+    (0x436, 0x308, '\u{4DD}'),
+    (0x437, 0x308, '\u{4DF}'),
+    (0x438, 0x300, '\u{45D}'),
+    (0x438, 0x304, '\u{4E3}'),
+    (0x438, 0x306, '\u{439}'),
+    (0x438, 0x308, '\u{4E5}'),
+    (0x43A, 0x301, '\u{45C}'),
+    (0x43E, 0x308, '\u{4E7}'),
+    (0x443, 0x304, '\u{4EF}'),
+    (0x443, 0x306, '\u{45E}'),
+    (0x443, 0x308, '\u{4F1}'),
+    (0x443, 0x30B, '\u{4F3}'),
+    (0x447, 0x308, '\u{4F5}'),
+    (0x44B, 0x308, '\u{4F9}'),
+    (0x44D, 0x308, '\u{4ED}'),
+    (0x456, 0x308, '\u{457}'),
+    (0x474, 0x30F, '\u{476}'),
+    (0x475, 0x30F, '\u{477}'),
+    (0x4D8, 0x308, '\u{4DA}'),
+    (0x4D9, 0x308, '\u{4DB}'),
+    (0x4E8, 0x308, '\u{4EA}'),
+    (0x4E9, 0x308, '\u{4EB}'),
+    (0x627, 0x653, '\u{622}'),
+    (0x627, 0x654, '\u{623}'),
+    (0x627, 0x655, '\u{625}'),
+    (0x648, 0x654, '\u{624}'),
+    (0x64A, 0x654, '\u{626}'),
+    (0x6C1, 0x654, '\u{6C2}'),
+    (0x6D2, 0x654, '\u{6D3}'),
+    (0x6D5, 0x654, '\u{6C0}'),
+    (0x928, 0x93C, '\u{929}'),
+    (0x930, 0x93C, '\u{931}'),
+    (0x933, 0x93C, '\u{934}'),
+    (0x9C7, 0x9BE, '\u{9CB}'),
+    (0x9C7, 0x9D7, '\u{9CC}'),
+    (0xB47, 0xB3E, '\u{B4B}'),
+    (0xB47, 0xB56, '\u{B48}'),
+    (0xB47, 0xB57, '\u{B4C}'),
+    (0xB92, 0xBD7, '\u{B94}'),
+    (0xBC6, 0xBBE, '\u{BCA}'),
+    (0xBC6, 0xBD7, '\u{BCC}'),
+    (0xBC7, 0xBBE, '\u{BCB}'),
+    (0xC46, 0xC56, '\u{C48}'),
+    (0xCBF, 0xCD5, '\u{CC0}'),
+    (0xCC6, 0xCC2, '\u{CCA}'),
+    (0xCC6, 0xCD5, '\u{CC7}'),
+    (0xCC6, 0xCD6, '\u{CC8}'),
+    (0xCCA, 0xCD5, '\u{CCB}'),
+    (0xD46, 0xD3E, '\u{D4A}'),
+    (0xD46, 0xD57, '\u{D4C}'),
+    (0xD47, 0xD3E, '\u{D4B}'),
+    (0xDD9, 0xDCA, '\u{DDA}'),
+    (0xDD9, 0xDCF, '\u{DDC}'),
+    (0xDD9, 0xDDF, '\u{DDE}'),
+    (0xDDC, 0xDCA, '\u{DDD}'),
+    (0x1025, 0x102E, '\u{1026}'),
+    (0x1B05, 0x1B35, '\u{1B06}'),
+    (0x1B07, 0x1B35, '\u{1B08}'),
+    (0x1B09, 0x1B35, '\u{1B0A}'),
+    (0x1B0B, 0x1B35, '\u{1B0C}'),
+    (0x1B0D, 0x1B35, '\u{1B0E}'),
+    (0x1B11, 0x1B35, '\u{1B12}'),
+    (0x1B3A, 0x1B35, '\u{1B3B}'),
+    (0x1B3C, 0x1B35, '\u{1B3D}'),
+    (0x1B3E, 0x1B35, '\u{1B40}'),
+    (0x1B3F, 0x1B35, '\u{1B41}'),
+    (0x1B42, 0x1B35, '\u{1B43}'),
+    (0x1E36, 0x304, '\u{1E38}'),
+    (0x1E37, 0x304, '\u{1E39}'),
+    (0x1E5A, 0x304, '\u{1E5C}'),
+    (0x1E5B, 0x304, '\u{1E5D}'),
+    (0x1E62, 0x307, '\u{1E68}'),
+    (0x1E63, 0x307, '\u{1E69}'),
+    (0x1EA0, 0x302, '\u{1EAC}'),
+    (0x1EA0, 0x306, '\u{1EB6}'),
+    (0x1EA1, 0x302, '\u{1EAD}'),
+    (0x1EA1, 0x306, '\u{1EB7}'),
+    (0x1EB8, 0x302, '\u{1EC6}'),
+    (0x1EB9, 0x302, '\u{1EC7}'),
+    (0x1ECC, 0x302, '\u{1ED8}'),
+    (0x1ECD, 0x302, '\u{1ED9}'),
+    (0x1F00, 0x300, '\u{1F02}'),
+    (0x1F00, 0x301, '\u{1F04}'),
+    (0x1F00, 0x342, '\u{1F06}'),
+    (0x1F00, 0x345, '\u{1F80}'),
+    (0x1F01, 0x300, '\u{1F03}'),
+    (0x1F01, 0x301, '\u{1F05}'),
+    (0x1F01, 0x342, '\u{1F07}'),
+    (0x1F01, 0x345, '\u{1F81}'),
+    (0x1F02, 0x345, '\u{1F82}'),
+    (0x1F03, 0x345, '\u{1F83}'),
+    (0x1F04, 0x345, '\u{1F84}'),
+    (0x1F05, 0x345, '\u{1F85}'),
+    (0x1F06, 0x345, '\u{1F86}'),
+    (0x1F07, 0x345, '\u{1F87}'),
+    (0x1F08, 0x300, '\u{1F0A}'),
+    (0x1F08, 0x301, '\u{1F0C}'),
+    (0x1F08, 0x342, '\u{1F0E}'),
+    (0x1F08, 0x345, '\u{1F88}'),
+    (0x1F09, 0x300, '\u{1F0B}'),
+    (0x1F09, 0x301, '\u{1F0D}'),
+    (0x1F09, 0x342, '\u{1F0F}'),
+    (0x1F09, 0x345, '\u{1F89}'),
+    (0x1F0A, 0x345, '\u{1F8A}'),
+    (0x1F0B, 0x345, '\u{1F8B}'),
+    (0x1F0C, 0x345, '\u{1F8C}'),
+    (0x1F0D, 0x345, '\u{1F8D}'),
+    (0x1F0E, 0x345, '\u{1F8E}'),
+    (0x1F0F, 0x345, '\u{1F8F}'),
+    (0x1F10, 0x300, '\u{1F12}'),
+    (0x1F10, 0x301, '\u{1F14}'),
+    (0x1F11, 0x300, '\u{1F13}'),
+    (0x1F11, 0x301, '\u{1F15}'),
+    (0x1F18, 0x300, '\u{1F1A}'),
+    (0x1F18, 0x301, '\u{1F1C}'),
+    (0x1F19, 0x300, '\u{1F1B}'),
+    (0x1F19, 0x301, '\u{1F1D}'),
+    (0x1F20, 0x300, '\u{1F22}'),
+    (0x1F20, 0x301, '\u{1F24}'),
+    (0x1F20, 0x342, '\u{1F26}'),
+    (0x1F20, 0x345, '\u{1F90}'),
+    (0x1F21, 0x300, '\u{1F23}'),
+    (0x1F21, 0x301, '\u{1F25}'),
+    (0x1F21, 0x342, '\u{1F27}'),
+    (0x1F21, 0x345, '\u{1F91}'),
+    (0x1F22, 0x345, '\u{1F92}'),
+    (0x1F23, 0x345, '\u{1F93}'),
+    (0x1F24, 0x345, '\u{1F94}'),
+    (0x1F25, 0x345, '\u{1F95}'),
+    (0x1F26, 0x345, '\u{1F96}'),
+    (0x1F27, 0x345, '\u{1F97}'),
+    (0x1F28, 0x300, '\u{1F2A}'),
+    (0x1F28, 0x301, '\u{1F2C}'),
+    (0x1F28, 0x342, '\u{1F2E}'),
+    (0x1F28, 0x345, '\u{1F98}'),
+    (0x1F29, 0x300, '\u{1F2B}'),
+    (0x1F29, 0x301, '\u{1F2D}'),
+    (0x1F29, 0x342, '\u{1F2F}'),
+    (0x1F29, 0x345, '\u{1F99}'),
+    (0x1F2A, 0x345, '\u{1F9A}'),
+    (0x1F2B, 0x345, '\u{1F9B}'),
+    (0x1F2C, 0x345, '\u{1F9C}'),
+    (0x1F2D, 0x345, '\u{1F9D}'),
+    (0x1F2E, 0x345, '\u{1F9E}'),
+    (0x1F2F, 0x345, '\u{1F9F}'),
+    (0x1F30, 0x300, '\u{1F32}'),
+    (0x1F30, 0x301, '\u{1F34}'),
+    (0x1F30, 0x342, '\u{1F36}'),
+    (0x1F31, 0x300, '\u{1F33}'),
+    (0x1F31, 0x301, '\u{1F35}'),
+    (0x1F31, 0x342, '\u{1F37}'),
+    (0x1F38, 0x300, '\u{1F3A}'),
+    (0x1F38, 0x301, '\u{1F3C}'),
+    (0x1F38, 0x342, '\u{1F3E}'),
+    (0x1F39, 0x300, '\u{1F3B}'),
+    (0x1F39, 0x301, '\u{1F3D}'),
+    (0x1F39, 0x342, '\u{1F3F}'),
+    (0x1F40, 0x300, '\u{1F42}'),
+    (0x1F40, 0x301, '\u{1F44}'),
+    (0x1F41, 0x300, '\u{1F43}'),
+    (0x1F41, 0x301, '\u{1F45}'),
+    (0x1F48, 0x300, '\u{1F4A}'),
+    (0x1F48, 0x301, '\u{1F4C}'),
+    (0x1F49, 0x300, '\u{1F4B}'),
+    (0x1F49, 0x301, '\u{1F4D}'),
+    (0x1F50, 0x300, '\u{1F52}'),
+    (0x1F50, 0x301, '\u{1F54}'),
+    (0x1F50, 0x342, '\u{1F56}'),
+    (0x1F51, 0x300, '\u{1F53}'),
+    (0x1F51, 0x301, '\u{1F55}'),
+    (0x1F51, 0x342, '\u{1F57}'),
+    (0x1F59, 0x300, '\u{1F5B}'),
+    (0x1F59, 0x301, '\u{1F5D}'),
+    (0x1F59, 0x342, '\u{1F5F}'),
+    (0x1F60, 0x300, '\u{1F62}'),
+    (0x1F60, 0x301, '\u{1F64}'),
+    (0x1F60, 0x342, '\u{1F66}'),
+    (0x1F60, 0x345, '\u{1FA0}'),
+    (0x1F61, 0x300, '\u{1F63}'),
+    (0x1F61, 0x301, '\u{1F65}'),
+    (0x1F61, 0x342, '\u{1F67}'),
+    (0x1F61, 0x345, '\u{1FA1}'),
+    (0x1F62, 0x345, '\u{1FA2}'),
+    (0x1F63, 0x345, '\u{1FA3}'),
+    (0x1F64, 0x345, '\u{1FA4}'),
+    (0x1F65, 0x345, '\u{1FA5}'),
+    (0x1F66, 0x345, '\u{1FA6}'),
+    (0x1F67, 0x345, '\u{1FA7}'),
+    (0x1F68, 0x300, '\u{1F6A}'),
+    (0x1F68, 0x301, '\u{1F6C}'),
+    (0x1F68, 0x342, '\u{1F6E}'),
+    (0x1F68, 0x345, '\u{1FA8}'),
+    (0x1F69, 0x300, '\u{1F6B}'),
+    (0x1F69, 0x301, '\u{1F6D}'),
+    (0x1F69, 0x342, '\u{1F6F}'),
+    (0x1F69, 0x345, '\u{1FA9}'),
+    (0x1F6A, 0x345, '\u{1FAA}'),
+    (0x1F6B, 0x345, '\u{1FAB}'),
+    (0x1F6C, 0x345, '\u{1FAC}'),
+    (0x1F6D, 0x345, '\u{1FAD}'),
+    (0x1F6E, 0x345, '\u{1FAE}'),
+    (0x1F6F, 0x345, '\u{1FAF}'),
+    (0x1F70, 0x345, '\u{1FB2}'),
+    (0x1F74, 0x345, '\u{1FC2}'),
+    (0x1F7C, 0x345, '\u{1FF2}'),
+    (0x1FB6, 0x345, '\u{1FB7}'),
+    (0x1FBF, 0x300, '\u{1FCD}'),
+    (0x1FBF, 0x301, '\u{1FCE}'),
+    (0x1FBF, 0x342, '\u{1FCF}'),
+    (0x1FC6, 0x345, '\u{1FC7}'),
+    (0x1FF6, 0x345, '\u{1FF7}'),
+    (0x1FFE, 0x300, '\u{1FDD}'),
+    (0x1FFE, 0x301, '\u{1FDE}'),
+    (0x1FFE, 0x342, '\u{1FDF}'),
+    (0x2190, 0x338, '\u{219A}'),
+    (0x2192, 0x338, '\u{219B}'),
+    (0x2194, 0x338, '\u{21AE}'),
+    (0x21D0, 0x338, '\u{21CD}'),
+    (0x21D2, 0x338, '\u{21CF}'),
+    (0x21D4, 0x338, '\u{21CE}'),
+    (0x2203, 0x338, '\u{2204}'),
+    (0x2208, 0x338, '\u{2209}'),
+    (0x220B, 0x338, '\u{220C}'),
+    (0x2223, 0x338, '\u{2224}'),
+    (0x2225, 0x338, '\u{2226}'),
+    (0x223C, 0x338, '\u{2241}'),
+    (0x2243, 0x338, '\u{2244}'),
+    (0x2245, 0x338, '\u{2247}'),
+    (0x2248, 0x338, '\u{2249}'),
+    (0x224D, 0x338, '\u{226D}'),
+    (0x2261, 0x338, '\u{2262}'),
+    (0x2264, 0x338, '\u{2270}'),
+    (0x2265, 0x338, '\u{2271}'),
+    (0x2272, 0x338, '\u{2274}'),
+    (0x2273, 0x338, '\u{2275}'),
+    (0x2276, 0x338, '\u{2278}'),
+    (0x2277, 0x338, '\u{2279}'),
+    (0x227A, 0x338, '\u{2280}'),
+    (0x227B, 0x338, '\u{2281}'),
+    (0x227C, 0x338, '\u{22E0}'),
+    (0x227D, 0x338, '\u{22E1}'),
+    (0x2282, 0x338, '\u{2284}'),
+    (0x2283, 0x338, '\u{2285}'),
+    (0x2286, 0x338, '\u{2288}'),
+    (0x2287, 0x338, '\u{2289}'),
+    (0x2291, 0x338, '\u{22E2}'),
+    (0x2292, 0x338, '\u{22E3}'),
+    (0x22A2, 0x338, '\u{22AC}'),
+    (0x22A8, 0x338, '\u{22AD}'),
+    (0x22A9, 0x338, '\u{22AE}'),
+    (0x22AB, 0x338, '\u{22AF}'),
+    (0x22B2, 0x338, '\u{22EA}'),
+    (0x22B3, 0x338, '\u{22EB}'),
+    (0x22B4, 0x338, '\u{22EC}'),
+    (0x22B5, 0x338, '\u{22ED}'),
+    (0x3046, 0x3099, '\u{3094}'),
+    (0x304B, 0x3099, '\u{304C}'),
+    (0x304D, 0x3099, '\u{304E}'),
+    (0x304F, 0x3099, '\u{3050}'),
+    (0x3051, 0x3099, '\u{3052}'),
+    (0x3053, 0x3099, '\u{3054}'),
+    (0x3055, 0x3099, '\u{3056}'),
+    (0x3057, 0x3099, '\u{3058}'),
+    (0x3059, 0x3099, '\u{305A}'),
+    (0x305B, 0x3099, '\u{305C}'),
+    (0x305D, 0x3099, '\u{305E}'),
+    (0x305F, 0x3099, '\u{3060}'),
+    (0x3061, 0x3099, '\u{3062}'),
+    (0x3064, 0x3099, '\u{3065}'),
+    (0x3066, 0x3099, '\u{3067}'),
+    (0x3068, 0x3099, '\u{3069}'),
+    (0x306F, 0x3099, '\u{3070}'),
+    (0x306F, 0x309A, '\u{3071}'),
+    (0x3072, 0x3099, '\u{3073}'),
+    (0x3072, 0x309A, '\u{3074}'),
+    (0x3075, 0x3099, '\u{3076}'),
+    (0x3075, 0x309A, '\u{3077}'),
+    (0x3078, 0x3099, '\u{3079}'),
+    (0x3078, 0x309A, '\u{307A}'),
+    (0x307B, 0x3099, '\u{307C}'),
+    (0x307B, 0x309A, '\u{307D}'),
+    (0x309D, 0x3099, '\u{309E}'),
+    (0x30A6, 0x3099, '\u{30F4}'),
+    (0x30AB, 0x3099, '\u{30AC}'),
+    (0x30AD, 0x3099, '\u{30AE}'),
+    (0x30AF, 0x3099, '\u{30B0}'),
+    (0x30B1, 0x3099, '\u{30B2}'),
+    (0x30B3, 0x3099, '\u{30B4}'),
+    (0x30B5, 0x3099, '\u{30B6}'),
+    (0x30B7, 0x3099, '\u{30B8}'),
+    (0x30B9, 0x3099, '\u{30BA}'),
+    (0x30BB, 0x3099, '\u{30BC}'),
+    (0x30BD, 0x3099, '\u{30BE}'),
+    (0x30BF, 0x3099, '\u{30C0}'),
+    (0x30C1, 0x3099, '\u{30C2}'),
+    (0x30C4, 0x3099, '\u{30C5}'),
+    (0x30C6, 0x3099, '\u{30C7}'),
+    (0x30C8, 0x3099, '\u{30C9}'),
+    (0x30CF, 0x3099, '\u{30D0}'),
+    (0x30CF, 0x309A, '\u{30D1}'),
+    (0x30D2, 0x3099, '\u{30D3}'),
+    (0x30D2, 0x309A, '\u{30D4}'),
+    (0x30D5, 0x3099, '\u{30D6}'),
+    (0x30D5, 0x309A, '\u{30D7}'),
+    (0x30D8, 0x3099, '\u{30D9}'),
+    (0x30D8, 0x309A, '\u{30DA}'),
+    (0x30DB, 0x3099, '\u{30DC}'),
+    (0x30DB, 0x309A, '\u{30DD}'),
+    (0x30EF, 0x3099, '\u{30F7}'),
+    (0x30F0, 0x3099, '\u{30F8}'),
+    (0x30F1, 0x3099, '\u{30F9}'),
+    (0x30F2, 0x3099, '\u{30FA}'),
+    (0x30FD, 0x3099, '\u{30FE}'),
+    (0x11099, 0x110BA, '\u{1109A}'),
+    (0x1109B, 0x110BA, '\u{1109C}'),
+    (0x110A5, 0x110BA, '\u{110AB}'),
+    (0x11131, 0x11127, '\u{1112E}'),
+    (0x11132, 0x11127, '\u{1112F}'),
+    (0x11347, 0x1133E, '\u{1134B}'),
+    (0x11347, 0x11357, '\u{1134C}'),
+    (0x114B9, 0x114B0, '\u{114BC}'),
+    (0x114B9, 0x114BA, '\u{114BB}'),
+    (0x114B9, 0x114BD, '\u{114BE}'),
+    (0x115B8, 0x115AF, '\u{115BA}'),
+    (0x115B9, 0x115AF, '\u{115BB}'),
+    (0x11935, 0x11930, '\u{11938}'),
+];
+
+/// The grapheme cluster break property of every character that isn't `Other`, as
+/// sorted, disjoint ranges of code points. Extended pictographic characters, all
+/// `Other` otherwise, have a value of their own.
+pub(super) const GRAPHEME_BREAKS: &[(u32, u32, GraphemeBreak)] = &[
+    (0x0, 0x9, Control),
+    (0xA, 0xA, Lf),
+    (0xB, 0xC, Control),
+    (0xD, 0xD, Cr),
+    (0xE, 0x1F, Control),
+    (0x7F, 0x9F, Control),
+    (0xA9, 0xA9, Pictographic),
+    (0xAD, 0xAD, Control),
+    (0xAE, 0xAE, Pictographic),
+    (0x300, 0x36F, Extend),
+    (0x483, 0x489, Extend),
+    (0x591, 0x5BD, Extend),
+    (0x5BF, 0x5BF, Extend),
+    (0x5C1, 0x5C2, Extend),
+    (0x5C4, 0x5C5, Extend),
+    (0x5C7, 0x5C7, Extend),
+    (0x600, 0x605, Prepend),
+    (0x610, 0x61A, Extend),
+    (0x61C, 0x61C, Control),
+    (0x64B, 0x65F, Extend),
+    (0x670, 0x670, Extend),
+    (0x6D6, 0x6DC, Extend),
+    (0x6DD, 0x6DD, Prepend),
+    (0x6DF, 0x6E4, Extend),
+    (0x6E7, 0x6E8, Extend),
+    (0x6EA, 0x6ED, Extend),
+    (0x70F, 0x70F, Prepend),
+    (0x711, 0x711, Extend),
+    (0x730, 0x74A, Extend),
+    (0x7A6, 0x7B0, Extend),
+    (0x7EB, 0x7F3, Extend),
+    (0x7FD, 0x7FD, Extend),
+    (0x816, 0x819, Extend),
+    (0x81B, 0x823, Extend),
+    (0x825, 0x827, Extend),
+    (0x829, 0x82D, Extend),
+    (0x859, 0x85B, Extend),
+    (0x890, 0x891, Prepend),
+    (0x898, 0x89F, Extend),
+    (0x8CA, 0x8E1, Extend),
+    (0x8E2, 0x8E2, Prepend),
+    (0x8E3, 0x902, Extend),
+    (0x903, 0x903, SpacingMark),
+    (0x93A, 0x93A, Extend),
+    (0x93B, 0x93B, SpacingMark),
+    (0x93C, 0x93C, Extend),
+    (0x93E, 0x940, SpacingMark),
+    (0x941, 0x948, Extend),
+    (0x949, 0x94C, SpacingMark),
+    (0x94D, 0x94D, Extend),
+    (0x94E, 0x94F, SpacingMark),
+    (0x951, 0x957, Extend),
+    (0x962, 0x963, Extend),
+    (0x981, 0x981, Extend),
+    (0x982, 0x983, SpacingMark),
+    (0x9BC, 0x9BC, Extend),
+    (0x9BE, 0x9BE, Extend),
+    (0x9BF, 0x9C0, SpacingMark),
+    (0x9C1, 0x9C4, Extend),
+    (0x9C7, 0x9C8, SpacingMark),
+    (0x9CB, 0x9CC, SpacingMark),
+    (0x9CD, 0x9CD, Extend),
+    (0x9D7, 0x9D7, Extend),
+    (0x9E2, 0x9E3, Extend),
+    (0x9FE, 0x9FE, Extend),
+    (0xA01, 0xA02, Extend),
+    (0xA03, 0xA03, SpacingMark),
+    (0xA3C, 0xA3C, Extend),
+    (0xA3E, 0xA40, SpacingMark),
+    (0xA41, 0xA42, Extend),
+    (0xA47, 0xA48, Extend),
+    (0xA4B, 0xA4D, Extend),
+    (0xA51, 0xA51, Extend),
+    (0xA70, 0xA71, Extend),
+    (0xA75, 0xA75, Extend),
+    (0xA81, 0xA82, Extend),
+    (0xA83, 0xA83, SpacingMark),
+    (0xABC, 0xABC, Extend),
+    (0xABE, 0xAC0, SpacingMark),
+    (0xAC1, 0xAC5, Extend),
+    (0xAC7, 0xAC8, Extend),
+    (0xAC9, 0xAC9, SpacingMark),
+    (0xACB, 0xACC, SpacingMark),
+    (0xACD, 0xACD, Extend),
+    (0xAE2, 0xAE3, Extend),
+    (0xAFA, 0xAFF, Extend),
+    (0xB01, 0xB01, Extend),
+    (0xB02, 0xB03, SpacingMark),
+    (0xB3C, 0xB3C, Extend),
+    (0xB3E, 0xB3F, Extend),
+    (0xB40, 0xB40, SpacingMark),
+    (0xB41, 0xB44, Extend),
+    (0xB47, 0xB48, SpacingMark),
+    (0xB4B, 0xB4C, SpacingMark),
+    (0xB4D, 0xB4D, Extend),
+    (0xB55, 0xB57, Extend),
+    (0xB62, 0xB63, Extend),
+    (0xB82, 0xB82, Extend),
+    (0xBBE, 0xBBE, Extend),
+    (0xBBF, 0xBBF, SpacingMark),
+    (0xBC0, 0xBC0, Extend),
+    (0xBC1, 0xBC2, SpacingMark),
+    (0xBC6, 0xBC8, SpacingMark),
+    (0xBCA, 0xBCC, SpacingMark),
+    (0xBCD, 0xBCD, Extend),
+    (0xBD7, 0xBD7, Extend),
+    (0xC00, 0xC00, Extend),
+    (0xC01, 0xC03, SpacingMark),
+    (0xC04, 0xC04, Extend),
+    (0xC3C, 0xC3C, Extend),
+    (0xC3E, 0xC40, Extend),
+    (0xC41, 0xC44, SpacingMark),
+    (0xC46, 0xC48, Extend),
+    (0xC4A, 0xC4D, Extend),
+    (0xC55, 0xC56, Extend),
+    (0xC62, 0xC63, Extend),
+    (0xC81, 0xC81, Extend),
+    (0xC82, 0xC83, SpacingMark),
+    (0xCBC, 0xCBC, Extend),
+    (0xCBE, 0xCBE, SpacingMark),
+    (0xCBF, 0xCBF, Extend),
+    (0xCC0, 0xCC1, SpacingMark),
+    (0xCC2, 0xCC2, Extend),
+    (0xCC3, 0xCC4, SpacingMark),
+    (0xCC6, 0xCC6, Extend),
+    (0xCC7, 0xCC8, SpacingMark),
+    (0xCCA, 0xCCB, SpacingMark),
+    (0xCCC, 0xCCD, Extend),
+    (0xCD5, 0xCD6, Extend),
+    (0xCE2, 0xCE3, Extend),
+    (0xD00, 0xD01, Extend),
+    (0xD02, 0xD03, SpacingMark),
+    (0xD3B, 0xD3C, Extend),
+    (0xD3E, 0xD3E, Extend),
+    (0xD3F, 0xD40, SpacingMark),
+    (0xD41, 0xD44, Extend),
+    (0xD46, 0xD48, SpacingMark),
+    (0xD4A, 0xD4C, SpacingMark),
+    (0xD4D, 0xD4D, Extend),
+    (0xD4E, 0xD4E, Prepend),
+    (0xD57, 0xD57, Extend),
+    (0xD62, 0xD63, Extend),
+    (0xD81, 0xD81, Extend),
+    (0xD82, 0xD83, SpacingMark),
+    (0xDCA, 0xDCA, Extend),
+    (0xDCF, 0xDCF, Extend),
+    (0xDD0, 0xDD1, SpacingMark),
+    (0xDD2, 0xDD4, Extend),
+    (0xDD6, 0xDD6, Extend),
+    (0xDD8, 0xDDE, SpacingMark),
+    (0xDDF, 0xDDF, Extend),
+    (0xDF2, 0xDF3, SpacingMark),
+    (0xE31, 0xE31, Extend),
+    (0xE33, 0xE33, SpacingMark),
+    (0xE34, 0xE3A, Extend),
+    (0xE47, 0xE4E, Extend),
+    (0xEB1, 0xEB1, Extend),
+    (0xEB3, 0xEB3, SpacingMark),
+    (0xEB4, 0xEBC, Extend),
+    (0xEC8, 0xECD, Extend),
+    (0xF18, 0xF19, Extend),
+    (0xF35, 0xF35, Extend),
+    (0xF37, 0xF37, Extend),
+    (0xF39, 0xF39, Extend),
+    (0xF3E, 0xF3F, SpacingMark),
+    (0xF71, 0xF7E, Extend),
+    (0xF7F, 0xF7F, SpacingMark),
+    (0xF80, 0xF84, Extend),
+    (0xF86, 0xF87, Extend),
+    (0xF8D, 0xF97, Extend),
+    (0xF99, 0xFBC, Extend),
+    (0xFC6, 0xFC6, Extend),
+    (0x102D, 0x1030, Extend),
+    (0x1031, 0x1031, SpacingMark),
+    (0x1032, 0x1037, Extend),
+    (0x1039, 0x103A, Extend),
+    (0x103B, 0x103C, SpacingMark),
+    (0x103D, 0x103E, Extend),
+    (0x1056, 0x1057, SpacingMark),
+    (0x1058, 0x1059, Extend),
+    (0x105E, 0x1060, Extend),
+    (0x1071, 0x1074, Extend),
+    (0x1082, 0x1082, Extend),
+    (0x1084, 0x1084, SpacingMark),
+    (0x1085, 0x1086, Extend),
+    (0x108D, 0x108D, Extend),
+    (0x109D, 0x109D, Extend),
+    (0x1100, 0x115F, L),
+    (0x1160, 0x11A7, V),
+    (0x11A8, 0x11FF, T),
+    (0x135D, 0x135F, Extend),
+    (0x1712, 0x1714, Extend),
+    (0x1715, 0x1715, SpacingMark),
+    (0x1732, 0x1733, Extend),
+    (0x1734, 0x1734, SpacingMark),
+    (0x1752, 0x1753, Extend),
+    (0x1772, 0x1773, Extend),
+    (0x17B4, 0x17B5, Extend),
+    (0x17B6, 0x17B6, SpacingMark),
+    (0x17B7, 0x17BD, Extend),
+    (0x17BE, 0x17C5, SpacingMark),
+    (0x17C6, 0x17C6, Extend),
+    (0x17C7, 0x17C8, SpacingMark),
+    (0x17C9, 0x17D3, Extend),
+    (0x17DD, 0x17DD, Extend),
+    (0x180B, 0x180D, Extend),
+    (0x180E, 0x180E, Control),
+    (0x180F, 0x180F, Extend),
+    (0x1885, 0x1886, Extend),
+    (0x18A9, 0x18A9, Extend),
+    (0x1920, 0x1922, Extend),
+    (0x1923, 0x1926, SpacingMark),
+    (0x1927, 0x1928, Extend),
+    (0x1929, 0x192B, SpacingMark),
+    (0x1930, 0x1931, SpacingMark),
+    (0x1932, 0x1932, Extend),
+    (0x1933, 0x1938, SpacingMark),
+    (0x1939, 0x193B, Extend),
+    (0x1A17, 0x1A18, Extend),
+    (0x1A19, 0x1A1A, SpacingMark),
+    (0x1A1B, 0x1A1B, Extend),
+    (0x1A55, 0x1A55, SpacingMark),
+    (0x1A56, 0x1A56, Extend),
+    (0x1A57, 0x1A57, SpacingMark),
+    (0x1A58, 0x1A5E, Extend),
+    (0x1A60, 0x1A60, Extend),
+    (0x1A62, 0x1A62, Extend),
+    (0x1A65, 0x1A6C, Extend),
+    (0x1A6D, 0x1A72, SpacingMark),
+    (0x1A73, 0x1A7C, Extend),
+    (0x1A7F, 0x1A7F, Extend),
+    (0x1AB0, 0x1ACE, Extend),
+    (0x1B00, 0x1B03, Extend),
+    (0x1B04, 0x1B04, SpacingMark),
+    (0x1B34, 0x1B3A, Extend),
+    (0x1B3B, 0x1B3B, SpacingMark),
+    (0x1B3C, 0x1B3C, Extend),
+    (0x1B3D, 0x1B41, SpacingMark),
+    (0x1B42, 0x1B42, Extend),
+    (0x1B43, 0x1B44, SpacingMark),
+    (0x1B6B, 0x1B73, Extend),
+    (0x1B80, 0x1B81, Extend),
+    (0x1B82, 0x1B82, SpacingMark),
+    (0x1BA1, 0x1BA1, SpacingMark),
+    (0x1BA2, 0x1BA5, Extend),
+    (0x1BA6, 0x1BA7, SpacingMark),
+    (0x1BA8, 0x1BA9, Extend),
+    (0x1BAA, 0x1BAA, SpacingMark),
+    (0x1BAB, 0x1BAD, Extend),
+    (0x1BE6, 0x1BE6, Extend),
+    (0x1BE7, 0x1BE7, SpacingMark),
+    (0x1BE8, 0x1BE9, Extend),
+    (0x1BEA, 0x1BEC, SpacingMark),
+    (0x1BED, 0x1BED, Extend),
+    (0x1BEE, 0x1BEE, SpacingMark),
+    (0x1BEF, 0x1BF1, Extend),
+    (0x1BF2, 0x1BF3, SpacingMark),
+    (0x1C24, 0x1C2B, SpacingMark),
+    (0x1C2C, 0x1C33, Extend),
+    (0x1C34, 0x1C35, SpacingMark),
+    (0x1C36, 0x1C37, Extend),
+    (0x1CD0, 0x1CD2, Extend),
+    (0x1CD4, 0x1CE0, Extend),
+    (0x1CE1, 0x1CE1, SpacingMark),
+    (0x1CE2, 0x1CE8, Extend),
+    (0x1CED, 0x1CED, Extend),
+    (0x1CF4, 0x1CF4, Extend),
+    (0x1CF7, 0x1CF7, SpacingMark),
+    (0x1CF8, 0x1CF9, Extend),
+    (0x1DC0, 0x1DFF, Extend),
+    (0x200B, 0x200B, Control),
+    (0x200C, 0x200C, Extend),
+    (0x200D, 0x200D, Zwj),
+    (0x200E, 0x200F, Control),
+    (0x2028, 0x202E, Control),
+    (0x203C, 0x203C, Pictographic),
+    (0x2049, 0x2049, Pictographic),
+    (0x2060, 0x206F, Control),
+    (0x20D0, 0x20F0, Extend),
+    (0x2122, 0x2122, Pictographic),
+    (0x2139, 0x2139, Pictographic),
+    (0x2194, 0x2199, Pictographic),
+    (0x21A9, 0x21AA, Pictographic),
+    (0x231A, 0x231B, Pictographic),
+    (0x2328, 0x2328, Pictographic),
+    (0x2388, 0x2388, Pictographic),
+    (0x23CF, 0x23CF, Pictographic),
+    (0x23E9, 0x23F3, Pictographic),
+    (0x23F8, 0x23FA, Pictographic),
+    (0x24C2, 0x24C2, Pictographic),
+    (0x25AA, 0x25AB, Pictographic),
+    (0x25B6, 0x25B6, Pictographic),
+    (0x25C0, 0x25C0, Pictographic),
+    (0x25FB, 0x25FE, Pictographic),
+    (0x2600, 0x2605, Pictographic),
+    (0x2607, 0x2612, Pictographic),
+    (0x2614, 0x2685, Pictographic),
+    (0x2690, 0x2705, Pictographic),
+    (0x2708, 0x2712, Pictographic),
+    (0x2714, 0x2714, Pictographic),
+    (0x2716, 0x2716, Pictographic),
+    (0x271D, 0x271D, Pictographic),
+    (0x2721, 0x2721, Pictographic),
+    (0x2728, 0x2728, Pictographic),
+    (0x2733, 0x2734, Pictographic),
+    (0x2744, 0x2744, Pictographic),
+    (0x2747, 0x2747, Pictographic),
+    (0x274C, 0x274C, Pictographic),
+    (0x274E, 0x274E, Pictographic),
+    (0x2753, 0x2755, Pictographic),
+    (0x2757, 0x2757, Pictographic),
+    (0x2763, 0x2767, Pictographic),
+    (0x2795, 0x2797, Pictographic),
+    (0x27A1, 0x27A1, Pictographic),
+    (0x27B0, 0x27B0, Pictographic),
+    (0x27BF, 0x27BF, Pictographic),
+    (0x2934, 0x2935, Pictographic),
+    (0x2B05, 0x2B07, Pictographic),
+    (0x2B1B, 0x2B1C, Pictographic),
+    (0x2B50, 0x2B50, Pictographic),
+    (0x2B55, 0x2B55, Pictographic),
+    (0x2CEF, 0x2CF1, Extend),
+    (0x2D7F, 0x2D7F, Extend),
+    (0x2DE0, 0x2DFF, Extend),
+    (0x302A, 0x302F, Extend),
+    (0x3030, 0x3030, Pictographic),
+    (0x303D, 0x303D, Pictographic),
+    (0x3099, 0x309A, Extend),
+    (0x3297, 0x3297, Pictographic),
+    (0x3299, 0x3299, Pictographic),
+    (0xA66F, 0xA672, Extend),
+    (0xA674, 0xA67D, Extend),
+    (0xA69E, 0xA69F, Extend),
+    (0xA6F0, 0xA6F1, Extend),
+    (0xA802, 0xA802, Extend),
+    (0xA806, 0xA806, Extend),
+    (0xA80B, 0xA80B, Extend),
+    (0xA823, 0xA824, SpacingMark),
+    (0xA825, 0xA826, Extend),
+    (0xA827, 0xA827, SpacingMark),
+    (0xA82C, 0xA82C, Extend),
+    (0xA880, 0xA881, SpacingMark),
+    (0xA8B4, 0xA8C3, SpacingMark),
+    (0xA8C4, 0xA8C5, Extend),
+    (0xA8E0, 0xA8F1, Extend),
+    (0xA8FF, 0xA8FF, Extend),
+    (0xA926, 0xA92D, Extend),
+    (0xA947, 0xA951, Extend),
+    (0xA952, 0xA953, SpacingMark),
+    (0xA960, 0xA97C, L),
+    (0xA980, 0xA982, Extend),
+    (0xA983, 0xA983, SpacingMark),
+    (0xA9B3, 0xA9B3, Extend),
+    (0xA9B4, 0xA9B5, SpacingMark),
+    (0xA9B6, 0xA9B9, Extend),
+    (0xA9BA, 0xA9BB, SpacingMark),
+    (0xA9BC, 0xA9BD, Extend),
+    (0xA9BE, 0xA9C0, SpacingMark),
+    (0xA9E5, 0xA9E5, Extend),
+    (0xAA29, 0xAA2E, Extend),
+    (0xAA2F, 0xAA30, SpacingMark),
+    (0xAA31, 0xAA32, Extend),
+    (0xAA33, 0xAA34, SpacingMark),
+    (0xAA35, 0xAA36, Extend),
+    (0xAA43, 0xAA43, Extend),
+    (0xAA4C, 0xAA4C, Extend),
+    (0xAA4D, 0xAA4D, SpacingMark),
+    (0xAA7C, 0xAA7C, Extend),
+    (0xAAB0, 0xAAB0, Extend),
+    (0xAAB2, 0xAAB4, Extend),
+    (0xAAB7, 0xAAB8, Extend),
+    (0xAABE, 0xAABF, Extend),
+    (0xAAC1, 0xAAC1, Extend),
+    (0xAAEB, 0xAAEB, SpacingMark),
+    (0xAAEC, 0xAAED, Extend),
+    (0xAAEE, 0xAAEF, SpacingMark),
+    (0xAAF5, 0xAAF5, SpacingMark),
+    (0xAAF6, 0xAAF6, Extend),
+    (0xABE3, 0xABE4, SpacingMark),
+    (0xABE5, 0xABE5, Extend),
+    (0xABE6, 0xABE7, SpacingMark),
+    (0xABE8, 0xABE8, Extend),
+    (0xABE9, 0xABEA, SpacingMark),
+    (0xABEC, 0xABEC, SpacingMark),
+    (0xABED, 0xABED, Extend),
+    (0xAC00, 0xAC00, Lv),
+    (0xAC01, 0xAC1B, Lvt),
+    (0xAC1C, 0xAC1C, Lv),
+    (0xAC1D, 0xAC37, Lvt),
+    (0xAC38, 0xAC38, Lv),
+    (0xAC39, 0xAC53, Lvt),
+    (0xAC54, 0xAC54, Lv),
+    (0xAC55, 0xAC6F, Lvt),
+    (0xAC70, 0xAC70, Lv),
+    (0xAC71, 0xAC8B, Lvt),
+    (0xAC8C, 0xAC8C, Lv),
+    (0xAC8D, 0xACA7, Lvt),
+    (0xACA8, 0xACA8, Lv),
+    (0xACA9, 0xACC3, Lvt),
+    (0xACC4, 0xACC4, Lv),
+    (0xACC5, 0xACDF, Lvt),
+    (0xACE0, 0xACE0, Lv),
+    (0xACE1, 0xACFB, Lvt),
+    (0xACFC, 0xACFC, Lv),
+    (0xACFD, 0xAD17, Lvt),
+    (0xAD18, 0xAD18, Lv),
+    (0xAD19, 0xAD33, Lvt),
+    (0xAD34, 0xAD34, Lv),
+    (0xAD35, 0xAD4F, Lvt),
+    (0xAD50, 0xAD50, Lv),
+    (0xAD51, 0xAD6B, Lvt),
+    (0xAD6C, 0xAD6C, Lv),
+    (0xAD6D, 0xAD87, Lvt),
+    (0xAD88, 0xAD88, Lv),
+    (0xAD89, 0xADA3, Lvt),
+    (0xADA4, 0xADA4, Lv),
+    (0xADA5, 0xADBF, Lvt),
+    (0xADC0, 0xADC0, Lv),
+    (0xADC1, 0xADDB, Lvt),
+    (0xADDC, 0xADDC, Lv),
+    (0xADDD, 0xADF7, Lvt),
+    (0xADF8, 0xADF8, Lv),
+    (0xADF9, 0xAE13, Lvt),
+    (0xAE14, 0xAE14, Lv),
+    (0xAE15, 0xAE2F, Lvt),
+    (0xAE30, 0xAE30, Lv),
+    (0xAE31, 0xAE4B, Lvt),
+    (0xAE4C, 0xAE4C, Lv),
+    (0xAE4D, 0xAE67, Lvt),
+    (0xAE68, 0xAE68, Lv),
+    (0xAE69, 0xAE83, Lvt),
+    (0xAE84, 0xAE84, Lv),
+    (0xAE85, 0xAE9F, Lvt),
+    (0xAEA0, 0xAEA0, Lv),
+    (0xAEA1, 0xAEBB, Lvt),
+    (0xAEBC, 0xAEBC, Lv),
+    (0xAEBD, 0xAED7, Lvt),
+    (0xAED8, 0xAED8, Lv),
+    (0xAED9, 0xAEF3, Lvt),
+    (0xAEF4, 0xAEF4, Lv),
+    (0xAEF5, 0xAF0F, Lvt),
+    (0xAF10, 0xAF10, Lv),
+    (0xAF11, 0xAF2B, Lvt),
+    (0xAF2C, 0xAF2C, Lv),
+    (0xAF2D, 0xAF47, Lvt),
+    (0xAF48, 0xAF48, Lv),
+    (0xAF49, 0xAF63, Lvt),
+    (0xAF64, 0xAF64, Lv),
+    (0xAF65, 0xAF7F, Lvt),
+    (0xAF80, 0xAF80, Lv),
+    (0xAF81, 0xAF9B, Lvt),
+    (0xAF9C, 0xAF9C, Lv),
+    (0xAF9D, 0xAFB7, Lvt),
+    (0xAFB8, 0xAFB8, Lv),
+    (0xAFB9, 0xAFD3, Lvt),
+    (0xAFD4, 0xAFD4, Lv),
+    (0xAFD5, 0xAFEF, Lvt),
+    (0xAFF0, 0xAFF0, Lv),
+    (0xAFF1, 0xB00B, Lvt),
+    (0xB00C, 0xB00C, Lv),
+    (0xB00D, 0xB027, Lvt),
+    (0xB028, 0xB028, Lv),
+    (0xB029, 0xB043, Lvt),
+    (0xB044, 0xB044, Lv),
+    (0xB045, 0xB05F, Lvt),
+    (0xB060, 0xB060, Lv),
+    (0xB061, 0xB07B, Lvt),
+    (0xB07C, 0xB07C, Lv),
+    (0xB07D, 0xB097, Lvt),
+    (0xB098, 0xB098, Lv),
+    (0xB099, 0xB0B3, Lvt),
+    (0xB0B4, 0xB0B4, Lv),
+    (0xB0B5, 0xB0CF, Lvt),
+    (0xB0D0, 0xB0D0, Lv),
+    (0xB0D1, 0xB0EB, Lvt),
+    (0xB0EC, 0xB0EC, Lv),
+    (0xB0ED, 0xB107, Lvt),
+    (0xB108, 0xB108, Lv),
+    (0xB109, 0xB123, Lvt),
+    (0xB124, 0xB124, Lv),
+    (0xB125, 0xB13F, Lvt),
+    (0xB140, 0xB140, Lv),
+    (0xB141, 0xB15B, Lvt),
+    (0xB15C, 0xB15C, Lv),
+    (0xB15D, 0xB177, Lvt),
+    (0xB178, 0xB178, Lv),
+    (0xB179, 0xB193, Lvt),
+    (0xB194, 0xB194, Lv),
+    (0xB195, 0xB1AF, Lvt),
+    (0xB1B0, 0xB1B0, Lv),
+    (0xB1B1, 0xB1CB, Lvt),
+    (0xB1CC, 0xB1CC, Lv),
+    (0xB1CD, 0xB1E7, Lvt),
+    (0xB1E8, 0xB1E8, Lv),
+    (0xB1E9, 0xB203, Lvt),
+    (0xB204, 0xB204, Lv),
+    (0xB205, 0xB21F, Lvt),
+    (0xB220, 0xB220, Lv),
+    (0xB221, 0xB23B, Lvt),
+    (0xB23C, 0xB23C, Lv),
+    (0xB23D, 0xB257, Lvt),
+    (0xB258, 0xB258, Lv),
+    (0xB259, 0xB273, Lvt),
+    (0xB274, 0xB274, Lv),
+    (0xB275, 0xB28F, Lvt),
+    (0xB290, 0xB290, Lv),
+    (0xB291, 0xB2AB, Lvt),
+    (0xB2AC, 0xB2AC, Lv),
+    (0xB2AD, 0xB2C7, Lvt),
+    (0xB2C8, 0xB2C8, Lv),
+    (0xB2C9, 0xB2E3, Lvt),
+    (0xB2E4, 0xB2E4, Lv),
+    (0xB2E5, 0xB2FF, Lvt),
+    (0xB300, 0xB300, Lv),
+    (0xB301, 0xB31B, Lvt),
+    (0xB31C, 0xB31C, Lv),
+    (0xB31D, 0xB337, Lvt),
+    (0xB338, 0xB338, Lv),
+    (0xB339, 0xB353, Lvt),
+    (0xB354, 0xB354, Lv),
+    (0xB355, 0xB36F, Lvt),
+    (0xB370, 0xB370, Lv),
+    (0xB371, 0xB38B, Lvt),
+    (0xB38C, 0xB38C, Lv),
+    (0xB38D, 0xB3A7, Lvt),
+    (0xB3A8, 0xB3A8, Lv),
+    (0xB3A9, 0xB3C3, Lvt),
+    (0xB3C4, 0xB3C4, Lv),
+    (0xB3C5, 0xB3DF, Lvt),
+    (0xB3E0, 0xB3E0, Lv),
+    (0xB3E1, 0xB3FB, Lvt),
+    (0xB3FC, 0xB3FC, Lv),
+    (0xB3FD, 0xB417, Lvt),
+    (0xB418, 0xB418, Lv),
+    (0xB419, 0xB433, Lvt),
+    (0xB434, 0xB434, Lv),
+    (0xB435, 0xB44F, Lvt),
+    (0xB450, 0xB450, Lv),
+    (0xB451, 0xB46B, Lvt),
+    (0xB46C, 0xB46C, Lv),
+    (0xB46D, 0xB487, Lvt),
+    (0xB488, 0xB488, Lv),
+    (0xB489, 0xB4A3, Lvt),
+    (0xB4A4, 0xB4A4, Lv),
+    (0xB4A5, 0xB4BF, Lvt),
+    (0xB4C0, 0xB4C0, Lv),
+    (0xB4C1, 0xB4DB, Lvt),
+    (0xB4DC, 0xB4DC, Lv),
+    (0xB4DD, 0xB4F7, Lvt),
+    (0xB4F8, 0xB4F8, Lv),
+    (0xB4F9, 0xB513, Lvt),
+    (0xB514, 0xB514, Lv),
+    (0xB515, 0xB52F, Lvt),
+    (0xB530, 0xB530, Lv),
+    (0xB531, 0xB54B, Lvt),
+    (0xB54C, 0xB54C, Lv),
+    (0xB54D, 0xB567, Lvt),
+    (0xB568, 0xB568, Lv),
+    (0xB569, 0xB583, Lvt),
+    (0xB584, 0xB584, Lv),
+    (0xB585, 0xB59F, Lvt),
+    (0xB5A0, 0xB5A0, Lv),
+    (0xB5A1, 0xB5BB, Lvt),
+    (0xB5BC, 0xB5BC, Lv),
+    (0xB5BD, 0xB5D7, Lvt),
+    (0xB5D8, 0xB5D8, Lv),
+    (0xB5D9, 0xB5F3, Lvt),
+    (0xB5F4, 0xB5F4, Lv),
+    (0xB5F5, 0xB60F, Lvt),
+    (0xB610, 0xB610, Lv),
+    (0xB611, 0xB62B, Lvt),
+    (0xB62C, 0xB62C, Lv),
+    (0xB62D, 0xB647, Lvt),
+    (0xB648, 0xB648, Lv),
+    (0xB649, 0xB663, Lvt),
+    (0xB664, 0xB664, Lv),
+    (0xB665, 0xB67F, Lvt),
+    (0xB680, 0xB680, Lv),
+    (0xB681, 0xB69B, Lvt),
+    (0xB69C, 0xB69C, Lv),
+    (0xB69D, 0xB6B7, Lvt),
+    (0xB6B8, 0xB6B8, Lv),
+    (0xB6B9, 0xB6D3, Lvt),
+    (0xB6D4, 0xB6D4, Lv),
+    (0xB6D5, 0xB6EF, Lvt),
+    (0xB6F0, 0xB6F0, Lv),
+    (0xB6F1, 0xB70B, Lvt),
+    (0xB70C, 0xB70C, Lv),
+    (0xB70D, 0xB727, Lvt),
+    (0xB728, 0xB728, Lv),
+    (0xB729, 0xB743, Lvt),
+    (0xB744, 0xB744, Lv),
+    (0xB745, 0xB75F, Lvt),
+    (0xB760, 0xB760, Lv),
+    (0xB761, 0xB77B, Lvt),
+    (0xB77C, 0xB77C, Lv),
+    (0xB77D, 0xB797, Lvt),
+    (0xB798, 0xB798, Lv),
+    (0xB799, 0xB7B3, Lvt),
+    (0xB7B4, 0xB7B4, Lv),
+    (0xB7B5, 0xB7CF, Lvt),
+    (0xB7D0, 0xB7D0, Lv),
+    (0xB7D1, 0xB7EB, Lvt),
+    (0xB7EC, 0xB7EC, Lv),
+    (0xB7ED, 0xB807, Lvt),
+    (0xB808, 0xB808, Lv),
+    (0xB809, 0xB823, Lvt),
+    (0xB824, 0xB824, Lv),
+    (0xB825, 0xB83F, Lvt),
+    (0xB840, 0xB840, Lv),
+    (0xB841, 0xB85B, Lvt),
+    (0xB85C, 0xB85C, Lv),
+    (0xB85D, 0xB877, Lvt),
+    (0xB878, 0xB878, Lv),
+    (0xB879, 0xB893, Lvt),
+    (0xB894, 0xB894, Lv),
+    (0xB895, 0xB8AF, Lvt),
+    (0xB8B0, 0xB8B0, Lv),
+    (0xB8B1, 0xB8CB, Lvt),
+    (0xB8CC, 0xB8CC, Lv),
+    (0xB8CD, 0xB8E7, Lvt),
+    (0xB8E8, 0xB8E8, Lv),
+    (0xB8E9, 0xB903, Lvt),
+    (0xB904, 0xB904, Lv),
+    (0xB905, 0xB91F, Lvt),
+    (0xB920, 0xB920, Lv),
+    (0xB921, 0xB93B, Lvt),
+    (0xB93C, 0xB93C, Lv),
+    (0xB93D, 0xB957, Lvt),
+    (0xB958, 0xB958, Lv),
+    (0xB959, 0xB973, Lvt),
+    (0xB974, 0xB974, Lv),
+    (0xB975, 0xB98F, Lvt),
+    (0xB990, 0xB990, Lv),
+    (0xB991, 0xB9AB, Lvt),
+    (0xB9AC, 0xB9AC, Lv),
+    (0xB9AD, 0xB9C7, Lvt),
+    (0xB9C8, 0xB9C8, Lv),
+    (0xB9C9, 0xB9E3, Lvt),
+    (0xB9E4, 0xB9E4, Lv),
+    (0xB9E5, 0xB9FF, Lvt),
+    (0xBA00, 0xBA00, Lv),
+    (0xBA01, 0xBA1B, Lvt),
+    (0xBA1C, 0xBA1C, Lv),
+    (0xBA1D, 0xBA37, Lvt),
+    (0xBA38, 0xBA38, Lv),
+    (0xBA39, 0xBA53, Lvt),
+    (0xBA54, 0xBA54, Lv),
+    (0xBA55, 0xBA6F, Lvt),
+    (0xBA70, 0xBA70, Lv),
+    (0xBA71, 0xBA8B, Lvt),
+    (0xBA8C, 0xBA8C, Lv),
+    (0xBA8D, 0xBAA7, Lvt),
+    (0xBAA8, 0xBAA8, Lv),
+    (0xBAA9, 0xBAC3, Lvt),
+    (0xBAC4, 0xBAC4, Lv),
+    (0xBAC5, 0xBADF, Lvt),
+    (0xBAE0, 0xBAE0, Lv),
+    (0xBAE1, 0xBAFB, Lvt),
+    (0xBAFC, 0xBAFC, Lv),
+    (0xBAFD, 0xBB17, Lvt),
+    (0xBB18, 0xBB18, Lv),
+    (0xBB19, 0xBB33, Lvt),
+    (0xBB34, 0xBB34, Lv),
+    (0xBB35, 0xBB4F, Lvt),
+    (0xBB50, 0xBB50, Lv),
+    (0xBB51, 0xBB6B, Lvt),
+    (0xBB6C, 0xBB6C, Lv),
+    (0xBB6D, 0xBB87, Lvt),
+    (0xBB88, 0xBB88, Lv),
+    (0xBB89, 0xBBA3, Lvt),
+    (0xBBA4, 0xBBA4, Lv),
+    (0xBBA5, 0xBBBF, Lvt),
+    (0xBBC0, 0xBBC0, Lv),
+    (0xBBC1, 0xBBDB, Lvt),
+    (0xBBDC, 0xBBDC, Lv),
+    (0xBBDD, 0xBBF7, Lvt),
+    (0xBBF8, 0xBBF8, Lv),
+    (0xBBF9, 0xBC13, Lvt),
+    (0xBC14, 0xBC14, Lv),
+    (0xBC15, 0xBC2F, Lvt),
+    (0xBC30, 0xBC30, Lv),
+    (0xBC31, 0xBC4B, Lvt),
+    (0xBC4C, 0xBC4C, Lv),
+    (0xBC4D, 0xBC67, Lvt),
+    (0xBC68, 0xBC68, Lv),
+    (0xBC69, 0xBC83, Lvt),
+    (0xBC84, 0xBC84, Lv),
+    (0xBC85, 0xBC9F, Lvt),
+    (0xBCA0, 0xBCA0, Lv),
+    (0xBCA1, 0xBCBB, Lvt),
+    (0xBCBC, 0xBCBC, Lv),
+    (0xBCBD, 0xBCD7, Lvt),
+    (0xBCD8, 0xBCD8, Lv),
+    (0xBCD9, 0xBCF3, Lvt),
+    (0xBCF4, 0xBCF4, Lv),
+    (0xBCF5, 0xBD0F, Lvt),
+    (0xBD10, 0xBD10, Lv),
+    (0xBD11, 0xBD2B, Lvt),
+    (0xBD2C, 0xBD2C, Lv),
+    (0xBD2D, 0xBD47, Lvt),
+    (0xBD48, 0xBD48, Lv),
+    (0xBD49, 0xBD63, Lvt),
+    (0xBD64, 0xBD64, Lv),
+    (0xBD65, 0xBD7F, Lvt),
+    (0xBD80, 0xBD80, Lv),
+    (0xBD81, 0xBD9B, Lvt),
+    (0xBD9C, 0xBD9C, Lv),
+    (0xBD9D, 0xBDB7, Lvt),
+    (0xBDB8, 0xBDB8, Lv),
+    (0xBDB9, 0xBDD3, Lvt),
+    (0xBDD4, 0xBDD4, Lv),
+    (0xBDD5, 0xBDEF, Lvt),
+    (0xBDF0, 0xBDF0, Lv),
+    (0xBDF1, 0xBE0B, Lvt),
+    (0xBE0C, 0xBE0C, Lv),
+    (0xBE0D, 0xBE27, Lvt),
+    (0xBE28, 0xBE28, Lv),
+    (0xBE29, 0xBE43, Lvt),
+    (0xBE44, 0xBE44, Lv),
+    (0xBE45, 0xBE5F, Lvt),
+    (0xBE60, 0xBE60, Lv),
+    (0xBE61, 0xBE7B, Lvt),
+    (0xBE7C, 0xBE7C, Lv),
+    (0xBE7D, 0xBE97, Lvt),
+    (0xBE98, 0xBE98, Lv),
+    (0xBE99, 0xBEB3, Lvt),
+    (0xBEB4, 0xBEB4, Lv),
+    (0xBEB5, 0xBECF, Lvt),
+    (0xBED0, 0xBED0, Lv),
+    (0xBED1, 0xBEEB, Lvt),
+    (0xBEEC, 0xBEEC, Lv),
+    (0xBEED, 0xBF07, Lvt),
+    (0xBF08, 0xBF08, Lv),
+    (0xBF09, 0xBF23, Lvt),
+    (0xBF24, 0xBF24, Lv),
+    (0xBF25, 0xBF3F, Lvt),
+    (0xBF40, 0xBF40, Lv),
+    (0xBF41, 0xBF5B, Lvt),
+    (0xBF5C, 0xBF5C, Lv),
+    (0xBF5D, 0xBF77, Lvt),
+    (0xBF78, 0xBF78, Lv),
+    (0xBF79, 0xBF93, Lvt),
+    (0xBF94, 0xBF94, Lv),
+    (0xBF95, 0xBFAF, Lvt),
+    (0xBFB0, 0xBFB0, Lv),
+    (0xBFB1, 0xBFCB, Lvt),
+    (0xBFCC, 0xBFCC, Lv),
+    (0xBFCD, 0xBFE7, Lvt),
+    (0xBFE8, 0xBFE8, Lv),
+    (0xBFE9, 0xC003, Lvt),
+    (0xC004, 0xC004, Lv),
+    (0xC005, 0xC01F, Lvt),
+    (0xC020, 0xC020, Lv),
+    (0xC021, 0xC03B, Lvt),
+    (0xC03C, 0xC03C, Lv),
+    (0xC03D, 0xC057, Lvt),
+    (0xC058, 0xC058, Lv),
+    (0xC059, 0xC073, Lvt),
+    (0xC074, 0xC074, Lv),
+    (0xC075, 0xC08F, Lvt),
+    (0xC090, 0xC090, Lv),
+    (0xC091, 0xC0AB, Lvt),
+    (0xC0AC, 0xC0AC, Lv),
+    (0xC0AD, 0xC0C7, Lvt),
+    (0xC0C8, 0xC0C8, Lv),
+    (0xC0C9, 0xC0E3, Lvt),
+    (0xC0E4, 0xC0E4, Lv),
+    (0xC0E5, 0xC0FF, Lvt),
+    (0xC100, 0xC100, Lv),
+    (0xC101, 0xC11B, Lvt),
+    (0xC11C, 0xC11C, Lv),
+    (0xC11D, 0xC137, Lvt),
+    (0xC138, 0xC138, Lv),
+    (0xC139, 0xC153, Lvt),
+    (0xC154, 0xC154, Lv),
+    (0xC155, 0xC16F, Lvt),
+    (0xC170, 0xC170, Lv),
+    (0xC171, 0xC18B, Lvt),
+    (0xC18C, 0xC18C, Lv),
+    (0xC18D, 0xC1A7, Lvt),
+    (0xC1A8, 0xC1A8, Lv),
+    (0xC1A9, 0xC1C3, Lvt),
+    (0xC1C4, 0xC1C4, Lv),
+    (0xC1C5, 0xC1DF, Lvt),
+    (0xC1E0, 0xC1E0, Lv),
+    (0xC1E1, 0xC1FB, Lvt),
+    (0xC1FC, 0xC1FC, Lv),
+    (0xC1FD, 0xC217, Lvt),
+    (0xC218, 0xC218, Lv),
+    (0xC219, 0xC233, Lvt),
+    (0xC234, 0xC234, Lv),
+    (0xC235, 0xC24F, Lvt),
+    (0xC250, 0xC250, Lv),
+    (0xC251, 0xC26B, Lvt),
+    (0xC26C, 0xC26C, Lv),
+    (0xC26D, 0xC287, Lvt),
+    (0xC288, 0xC288, Lv),
+    (0xC289, 0xC2A3, Lvt),
+    (0xC2A4, 0xC2A4, Lv),
+    (0xC2A5, 0xC2BF, Lvt),
+    (0xC2C0, 0xC2C0, Lv),
+    (0xC2C1, 0xC2DB, Lvt),
+    (0xC2DC, 0xC2DC, Lv),
+    (0xC2DD, 0xC2F7, Lvt),
+    (0xC2F8, 0xC2F8, Lv),
+    (0xC2F9, 0xC313, Lvt),
+    (0xC314, 0xC314, Lv),
+    (0xC315, 0xC32F, Lvt),
+    (0xC330, 0xC330, Lv),
+    (0xC331, 0xC34B, Lvt),
+    (0xC34C, 0xC34C, Lv),
+    (0xC34D, 0xC367, Lvt),
+    (0xC368, 0xC368, Lv),
+    (0xC369, 0xC383, Lvt),
+    (0xC384, 0xC384, Lv),
+    (0xC385, 0xC39F, Lvt),
+    (0xC3A0, 0xC3A0, Lv),
+    (0xC3A1, 0xC3BB, Lvt),
+    (0xC3BC, 0xC3BC, Lv),
+    (0xC3BD, 0xC3D7, Lvt),
+    (0xC3D8, 0xC3D8, Lv),
+    (0xC3D9, 0xC3F3, Lvt),
+    (0xC3F4, 0xC3F4, Lv),
+    (0xC3F5, 0xC40F, Lvt),
+    (0xC410, 0xC410, Lv),
+    (0xC411, 0xC42B, Lvt),
+    (0xC42C, 0xC42C, Lv),
+    (0xC42D, 0xC447, Lvt),
+    (0xC448, 0xC448, Lv),
+    (0xC449, 0xC463, Lvt),
+    (0xC464, 0xC464, Lv),
+    (0xC465, 0xC47F, Lvt),
+    (0xC480, 0xC480, Lv),
+    (0xC481, 0xC49B, Lvt),
+    (0xC49C, 0xC49C, Lv),
+    (0xC49D, 0xC4B7, Lvt),
+    (0xC4B8, 0xC4B8, Lv),
+    (0xC4B9, 0xC4D3, Lvt),
+    (0xC4D4, 0xC4D4, Lv),
+    (0xC4D5, 0xC4EF, Lvt),
+    (0xC4F0, 0xC4F0, Lv),
+    (0xC4F1, 0xC50B, Lvt),
+    (0xC50C, 0xC50C, Lv),
+    (0xC50D, 0xC527, Lvt),
+    (0xC528, 0xC528, Lv),
+    (0xC529, 0xC543, Lvt),
+    (0xC544, 0xC544, Lv),
+    (0xC545, 0xC55F, Lvt),
+    (0xC560, 0xC560, Lv),
+    (0xC561, 0xC57B, Lvt),
+    (0xC57C, 0xC57C, Lv),
+    (0xC57D, 0xC597, Lvt),
+    (0xC598, 0xC598, Lv),
+    (0xC599, 0xC5B3, Lvt),
+    (0xC5B4, 0xC5B4, Lv),
+    (0xC5B5, 0xC5CF, Lvt),
+    (0xC5D0, 0xC5D0, Lv),
+    (0xC5D1, 0xC5EB, Lvt),
+    (0xC5EC, 0xC5EC, Lv),
+    (0xC5ED, 0xC607, Lvt),
+    (0xC608, 0xC608, Lv),
+    (0xC609, 0xC623, Lvt),
+    (0xC624, 0xC624, Lv),
+    (0xC625, 0xC63F, Lvt),
+    (0xC640, 0xC640, Lv),
+    (0xC641, 0xC65B, Lvt),
+    (0xC65C, 0xC65C, Lv),
+    (0xC65D, 0xC677, Lvt),
+    (0xC678, 0xC678, Lv),
+    (0xC679, 0xC693, Lvt),
+    (0xC694, 0xC694, Lv),
+    (0xC695, 0xC6AF, Lvt),
+    (0xC6B0, 0xC6B0, Lv),
+    (0xC6B1, 0xC6CB, Lvt),
+    (0xC6CC, 0xC6CC, Lv),
+    (0xC6CD, 0xC6E7, Lvt),
+    (0xC6E8, 0xC6E8, Lv),
+    (0xC6E9, 0xC703, Lvt),
+    (0xC704, 0xC704, Lv),
+    (0xC705, 0xC71F, Lvt),
+    (0xC720, 0xC720, Lv),
+    (0xC721, 0xC73B, Lvt),
+    (0xC73C, 0xC73C, Lv),
+    (0xC73D, 0xC757, Lvt),
+    (0xC758, 0xC758, Lv),
+    (0xC759, 0xC773, Lvt),
+    (0xC774, 0xC774, Lv),
+    (0xC775, 0xC78F, Lvt),
+    (0xC790, 0xC790, Lv),
+    (0xC791, 0xC7AB, Lvt),
+    (0xC7AC, 0xC7AC, Lv),
+    (0xC7AD, 0xC7C7, Lvt),
+    (0xC7C8, 0xC7C8, Lv),
+    (0xC7C9, 0xC7E3, Lvt),
+    (0xC7E4, 0xC7E4, Lv),
+    (0xC7E5, 0xC7FF, Lvt),
+    (0xC800, 0xC800, Lv),
+    (0xC801, 0xC81B, Lvt),
+    (0xC81C, 0xC81C, Lv),
+    (0xC81D, 0xC837, Lvt),
+    (0xC838, 0xC838, Lv),
+    (0xC839, 0xC853, Lvt),
+    (0xC854, 0xC854, Lv),
+    (0xC855, 0xC86F, Lvt),
+    (0xC870, 0xC870, Lv),
+    (0xC871, 0xC88B, Lvt),
+    (0xC88C, 0xC88C, Lv),
+    (0xC88D, 0xC8A7, Lvt),
+    (0xC8A8, 0xC8A8, Lv),
+    (0xC8A9, 0xC8C3, Lvt),
+    (0xC8C4, 0xC8C4, Lv),
+    (0xC8C5, 0xC8DF, Lvt),
+    (0xC8E0, 0xC8E0, Lv),
+    (0xC8E1, 0xC8FB, Lvt),
+    (0xC8FC, 0xC8FC, Lv),
+    (0xC8FD, 0xC917, Lvt),
+    (0xC918, 0xC918, Lv),
+    (0xC919, 0xC933, Lvt),
+    (0xC934, 0xC934, Lv),
+    (0xC935, 0xC94F, Lvt),
+    (0xC950, 0xC950, Lv),
+    (0xC951, 0xC96B, Lvt),
+    (0xC96C, 0xC96C, Lv),
+    (0xC96D, 0xC987, Lvt),
+    (0xC988, 0xC988, Lv),
+    (0xC989, 0xC9A3, Lvt),
+    (0xC9A4, 0xC9A4, Lv),
+    (0xC9A5, 0xC9BF, Lvt),
+    (0xC9C0, 0xC9C0, Lv),
+    (0xC9C1, 0xC9DB, Lvt),
+    (0xC9DC, 0xC9DC, Lv),
+    (0xC9DD, 0xC9F7, Lvt),
+    (0xC9F8, 0xC9F8, Lv),
+    (0xC9F9, 0xCA13, Lvt),
+    (0xCA14, 0xCA14, Lv),
+    (0xCA15, 0xCA2F, Lvt),
+    (0xCA30, 0xCA30, Lv),
+    (0xCA31, 0xCA4B, Lvt),
+    (0xCA4C, 0xCA4C, Lv),
+    (0xCA4D, 0xCA67, Lvt),
+    (0xCA68, 0xCA68, Lv),
+    (0xCA69, 0xCA83, Lvt),
+    (0xCA84, 0xCA84, Lv),
+    (0xCA85, 0xCA9F, Lvt),
+    (0xCAA0, 0xCAA0, Lv),
+    (0xCAA1, 0xCABB, Lvt),
+    (0xCABC, 0xCABC, Lv),
+    (0xCABD, 0xCAD7, Lvt),
+    (0xCAD8, 0xCAD8, Lv),
+    (0xCAD9, 0xCAF3, Lvt),
+    (0xCAF4, 0xCAF4, Lv),
+    (0xCAF5, 0xCB0F, Lvt),
+    (0xCB10, 0xCB10, Lv),
+    (0xCB11, 0xCB2B, Lvt),
+    (0xCB2C, 0xCB2C, Lv),
+    (0xCB2D, 0xCB47, Lvt),
+    (0xCB48, 0xCB48, Lv),
+    (0xCB49, 0xCB63, Lvt),
+    (0xCB64, 0xCB64, Lv),
+    (0xCB65, 0xCB7F, Lvt),
+    (0xCB80, 0xCB80, Lv),
+    (0xCB81, 0xCB9B, Lvt),
+    (0xCB9C, 0xCB9C, Lv),
+    (0xCB9D, 0xCBB7, Lvt),
+    (0xCBB8, 0xCBB8, Lv),
+    (0xCBB9, 0xCBD3, Lvt),
+    (0xCBD4, 0xCBD4, Lv),
+    (0xCBD5, 0xCBEF, Lvt),
+    (0xCBF0, 0xCBF0, Lv),
+    (0xCBF1, 0xCC0B, Lvt),
+    (0xCC0C, 0xCC0C, Lv),
+    (0xCC0D, 0xCC27, Lvt),
+    (0xCC28, 0xCC28, Lv),
+    (0xCC29, 0xCC43, Lvt),
+    (0xCC44, 0xCC44, Lv),
+    (0xCC45, 0xCC5F, Lvt),
+    (0xCC60, 0xCC60, Lv),
+    (0xCC61, 0xCC7B, Lvt),
+    (0xCC7C, 0xCC7C, Lv),
+    (0xCC7D, 0xCC97, Lvt),
+    (0xCC98, 0xCC98, Lv),
+    (0xCC99, 0xCCB3, Lvt),
+    (0xCCB4, 0xCCB4, Lv),
+    (0xCCB5, 0xCCCF, Lvt),
+    (0xCCD0, 0xCCD0, Lv),
+    (0xCCD1, 0xCCEB, Lvt),
+    (0xCCEC, 0xCCEC, Lv),
+    (0xCCED, 0xCD07, Lvt),
+    (0xCD08, 0xCD08, Lv),
+    (0xCD09, 0xCD23, Lvt),
+    (0xCD24, 0xCD24, Lv),
+    (0xCD25, 0xCD3F, Lvt),
+    (0xCD40, 0xCD40, Lv),
+    (0xCD41, 0xCD5B, Lvt),
+    (0xCD5C, 0xCD5C, Lv),
+    (0xCD5D, 0xCD77, Lvt),
+    (0xCD78, 0xCD78, Lv),
+    (0xCD79, 0xCD93, Lvt),
+    (0xCD94, 0xCD94, Lv),
+    (0xCD95, 0xCDAF, Lvt),
+    (0xCDB0, 0xCDB0, Lv),
+    (0xCDB1, 0xCDCB, Lvt),
+    (0xCDCC, 0xCDCC, Lv),
+    (0xCDCD, 0xCDE7, Lvt),
+    (0xCDE8, 0xCDE8, Lv),
+    (0xCDE9, 0xCE03, Lvt),
+    (0xCE04, 0xCE04, Lv),
+    (0xCE05, 0xCE1F, Lvt),
+    (0xCE20, 0xCE20, Lv),
+    (0xCE21, 0xCE3B, Lvt),
+    (0xCE3C, 0xCE3C, Lv),
+    (0xCE3D, 0xCE57, Lvt),
+    (0xCE58, 0xCE58, Lv),
+    (0xCE59, 0xCE73, Lvt),
+    (0xCE74, 0xCE74, Lv),
+    (0xCE75, 0xCE8F, Lvt),
+    (0xCE90, 0xCE90, Lv),
+    (0xCE91, 0xCEAB, Lvt),
+    (0xCEAC, 0xCEAC, Lv),
+    (0xCEAD, 0xCEC7, Lvt),
+    (0xCEC8, 0xCEC8, Lv),
+    (0xCEC9, 0xCEE3, Lvt),
+    (0xCEE4, 0xCEE4, Lv),
+    (0xCEE5, 0xCEFF, Lvt),
+    (0xCF00, 0xCF00, Lv),
+    (0xCF01, 0xCF1B, Lvt),
+    (0xCF1C, 0xCF1C, Lv),
+    (0xCF1D, 0xCF37, Lvt),
+    (0xCF38, 0xCF38, Lv),
+    (0xCF39, 0xCF53, Lvt),
+    (0xCF54, 0xCF54, Lv),
+    (0xCF55, 0xCF6F, Lvt),
+    (0xCF70, 0xCF70, Lv),
+    (0xCF71, 0xCF8B, Lvt),
+    (0xCF8C, 0xCF8C, Lv),
+    (0xCF8D, 0xCFA7, Lvt),
+    (0xCFA8, 0xCFA8, Lv),
+    (0xCFA9, 0xCFC3, Lvt),
+    (0xCFC4, 0xCFC4, Lv),
+    (0xCFC5, 0xCFDF, Lvt),
+    (0xCFE0, 0xCFE0, Lv),
+    (0xCFE1, 0xCFFB, Lvt),
+    (0xCFFC, 0xCFFC, Lv),
+    (0xCFFD, 0xD017, Lvt),
+    (0xD018, 0xD018, Lv),
+    (0xD019, 0xD033, Lvt),
+    (0xD034, 0xD034, Lv),
+    (0xD035, 0xD04F, Lvt),
+    (0xD050, 0xD050, Lv),
+    (0xD051, 0xD06B, Lvt),
+    (0xD06C, 0xD06C, Lv),
+    (0xD06D, 0xD087, Lvt),
+    (0xD088, 0xD088, Lv),
+    (0xD089, 0xD0A3, Lvt),
+    (0xD0A4, 0xD0A4, Lv),
+    (0xD0A5, 0xD0BF, Lvt),
+    (0xD0C0, 0xD0C0, Lv),
+    (0xD0C1, 0xD0DB, Lvt),
+    (0xD0DC, 0xD0DC, Lv),
+    (0xD0DD, 0xD0F7, Lvt),
+    (0xD0F8, 0xD0F8, Lv),
+    (0xD0F9, 0xD113, Lvt),
+    (0xD114, 0xD114, Lv),
+    (0xD115, 0xD12F, Lvt),
+    (0xD130, 0xD130, Lv),
+    (0xD131, 0xD14B, Lvt),
+    (0xD14C, 0xD14C, Lv),
+    (0xD14D, 0xD167, Lvt),
+    (0xD168, 0xD168, Lv),
+    (0xD169, 0xD183, Lvt),
+    (0xD184, 0xD184, Lv),
+    (0xD185, 0xD19F, Lvt),
+    (0xD1A0, 0xD1A0, Lv),
+    (0xD1A1, 0xD1BB, Lvt),
+    (0xD1BC, 0xD1BC, Lv),
+    (0xD1BD, 0xD1D7, Lvt),
+    (0xD1D8, 0xD1D8, Lv),
+    (0xD1D9, 0xD1F3, Lvt),
+    (0xD1F4, 0xD1F4, Lv),
+    (0xD1F5, 0xD20F, Lvt),
+    (0xD210, 0xD210, Lv),
+    (0xD211, 0xD22B, Lvt),
+    (0xD22C, 0xD22C, Lv),
+    (0xD22D, 0xD247, Lvt),
+    (0xD248, 0xD248, Lv),
+    (0xD249, 0xD263, Lvt),
+    (0xD264, 0xD264, Lv),
+    (0xD265, 0xD27F, Lvt),
+    (0xD280, 0xD280, Lv),
+    (0xD281, 0xD29B, Lvt),
+    (0xD29C, 0xD29C, Lv),
+    (0xD29D, 0xD2B7, Lvt),
+    (0xD2B8, 0xD2B8, Lv),
+    (0xD2B9, 0xD2D3, Lvt),
+    (0xD2D4, 0xD2D4, Lv),
+    (0xD2D5, 0xD2EF, Lvt),
+    (0xD2F0, 0xD2F0, Lv),
+    (0xD2F1, 0xD30B, Lvt),
+    (0xD30C, 0xD30C, Lv),
+    (0xD30D, 0xD327, Lvt),
+    (0xD328, 0xD328, Lv),
+    (0xD329, 0xD343, Lvt),
+    (0xD344, 0xD344, Lv),
+    (0xD345, 0xD35F, Lvt),
+    (0xD360, 0xD360, Lv),
+    (0xD361, 0xD37B, Lvt),
+    (0xD37C, 0xD37C, Lv),
+    (0xD37D, 0xD397, Lvt),
+    (0xD398, 0xD398, Lv),
+    (0xD399, 0xD3B3, Lvt),
+    (0xD3B4, 0xD3B4, Lv),
+    (0xD3B5, 0xD3CF, Lvt),
+    (0xD3D0, 0xD3D0, Lv),
+    (0xD3D1, 0xD3EB, Lvt),
+    (0xD3EC, 0xD3EC, Lv),
+    (0xD3ED, 0xD407, Lvt),
+    (0xD408, 0xD408, Lv),
+    (0xD409, 0xD423, Lvt),
+    (0xD424, 0xD424, Lv),
+    (0xD425, 0xD43F, Lvt),
+    (0xD440, 0xD440, Lv),
+    (0xD441, 0xD45B, Lvt),
+    (0xD45C, 0xD45C, Lv),
+    (0xD45D, 0xD477, Lvt),
+    (0xD478, 0xD478, Lv),
+    (0xD479, 0xD493, Lvt),
+    (0xD494, 0xD494, Lv),
+    (0xD495, 0xD4AF, Lvt),
+    (0xD4B0, 0xD4B0, Lv),
+    (0xD4B1, 0xD4CB, Lvt),
+    (0xD4CC, 0xD4CC, Lv),
+    (0xD4CD, 0xD4E7, Lvt),
+    (0xD4E8, 0xD4E8, Lv),
+    (0xD4E9, 0xD503, Lvt),
+    (0xD504, 0xD504, Lv),
+    (0xD505, 0xD51F, Lvt),
+    (0xD520, 0xD520, Lv),
+    (0xD521, 0xD53B, Lvt),
+    (0xD53C, 0xD53C, Lv),
+    (0xD53D, 0xD557, Lvt),
+    (0xD558, 0xD558, Lv),
+    (0xD559, 0xD573, Lvt),
+    (0xD574, 0xD574, Lv),
+    (0xD575, 0xD58F, Lvt),
+    (0xD590, 0xD590, Lv),
+    (0xD591, 0xD5AB, Lvt),
+    (0xD5AC, 0xD5AC, Lv),
+    (0xD5AD, 0xD5C7, Lvt),
+    (0xD5C8, 0xD5C8, Lv),
+    (0xD5C9, 0xD5E3, Lvt),
+    (0xD5E4, 0xD5E4, Lv),
+    (0xD5E5, 0xD5FF, Lvt),
+    (0xD600, 0xD600, Lv),
+    (0xD601, 0xD61B, Lvt),
+    (0xD61C, 0xD61C, Lv),
+    (0xD61D, 0xD637, Lvt),
+    (0xD638, 0xD638, Lv),
+    (0xD639, 0xD653, Lvt),
+    (0xD654, 0xD654, Lv),
+    (0xD655, 0xD66F, Lvt),
+    (0xD670, 0xD670, Lv),
+    (0xD671, 0xD68B, Lvt),
+    (0xD68C, 0xD68C, Lv),
+    (0xD68D, 0xD6A7, Lvt),
+    (0xD6A8, 0xD6A8, Lv),
+    (0xD6A9, 0xD6C3, Lvt),
+    (0xD6C4, 0xD6C4, Lv),
+    (0xD6C5, 0xD6DF, Lvt),
+    (0xD6E0, 0xD6E0, Lv),
+    (0xD6E1, 0xD6FB, Lvt),
+    (0xD6FC, 0xD6FC, Lv),
+    (0xD6FD, 0xD717, Lvt),
+    (0xD718, 0xD718, Lv),
+    (0xD719, 0xD733, Lvt),
+    (0xD734, 0xD734, Lv),
+    (0xD735, 0xD74F, Lvt),
+    (0xD750, 0xD750, Lv),
+    (0xD751, 0xD76B, Lvt),
+    (0xD76C, 0xD76C, Lv),
+    (0xD76D, 0xD787, Lvt),
+    (0xD788, 0xD788, Lv),
+    (0xD789, 0xD7A3, Lvt),
+    (0xD7B0, 0xD7C6, V),
+    (0xD7CB, 0xD7FB, T),
+    (0xFB1E, 0xFB1E, Extend),
+    (0xFE00, 0xFE0F, Extend),
+    (0xFE20, 0xFE2F, Extend),
+    (0xFEFF, 0xFEFF, Control),
+    (0xFF9E, 0xFF9F, Extend),
+    (0xFFF0, 0xFFFB, Control),
+    (0x101FD, 0x101FD, Extend),
+    (0x102E0, 0x102E0, Extend),
+    (0x10376, 0x1037A, Extend),
+    (0x10A01, 0x10A03, Extend),
+    (0x10A05, 0x10A06, Extend),
+    (0x10A0C, 0x10A0F, Extend),
+    (0x10A38, 0x10A3A, Extend),
+    (0x10A3F, 0x10A3F, Extend),
+    (0x10AE5, 0x10AE6, Extend),
+    (0x10D24, 0x10D27, Extend),
+    (0x10EAB, 0x10EAC, Extend),
+    (0x10F46, 0x10F50, Extend),
+    (0x10F82, 0x10F85, Extend),
+    (0x11000, 0x11000, SpacingMark),
+    (0x11001, 0x11001, Extend),
+    (0x11002, 0x11002, SpacingMark),
+    (0x11038, 0x11046, Extend),
+    (0x11070, 0x11070, Extend),
+    (0x11073, 0x11074, Extend),
+    (0x1107F, 0x11081, Extend),
+    (0x11082, 0x11082, SpacingMark),
+    (0x110B0, 0x110B2, SpacingMark),
+    (0x110B3, 0x110B6, Extend),
+    (0x110B7, 0x110B8, SpacingMark),
+    (0x110B9, 0x110BA, Extend),
+    (0x110BD, 0x110BD, Prepend),
+    (0x110C2, 0x110C2, Extend),
+    (0x110CD, 0x110CD, Prepend),
+    (0x11100, 0x11102, Extend),
+    (0x11127, 0x1112B, Extend),
+    (0x1112C, 0x1112C, SpacingMark),
+    (0x1112D, 0x11134, Extend),
+    (0x11145, 0x11146, SpacingMark),
+    (0x11173, 0x11173, Extend),
+    (0x11180, 0x11181, Extend),
+    (0x11182, 0x11182, SpacingMark),
+    (0x111B3, 0x111B5, SpacingMark),
+    (0x111B6, 0x111BE, Extend),
+    (0x111BF, 0x111C0, SpacingMark),
+    (0x111C2, 0x111C3, Prepend),
+    (0x111C9, 0x111CC, Extend),
+    (0x111CE, 0x111CE, SpacingMark),
+    (0x111CF, 0x111CF, Extend),
+    (0x1122C, 0x1122E, SpacingMark),
+    (0x1122F, 0x11231, Extend),
+    (0x11232, 0x11233, SpacingMark),
+    (0x11234, 0x11234, Extend),
+    (0x11235, 0x11235, SpacingMark),
+    (0x11236, 0x11237, Extend),
+    (0x1123E, 0x1123E, Extend),
+    (0x112DF, 0x112DF, Extend),
+    (0x112E0, 0x112E2, SpacingMark),
+    (0x112E3, 0x112EA, Extend),
+    (0x11300, 0x11301, Extend),
+    (0x11302, 0x11303, SpacingMark),
+    (0x1133B, 0x1133C, Extend),
+    (0x1133E, 0x1133E, Extend),
+    (0x1133F, 0x1133F, SpacingMark),
+    (0x11340, 0x11340, Extend),
+    (0x11341, 0x11344, SpacingMark),
+    (0x11347, 0x11348, SpacingMark),
+    (0x1134B, 0x1134D, SpacingMark),
+    (0x11357, 0x11357, Extend),
+    (0x11362, 0x11363, SpacingMark),
+    (0x11366, 0x1136C, Extend),
+    (0x11370, 0x11374, Extend),
+    (0x11435, 0x11437, SpacingMark),
+    (0x11438, 0x1143F, Extend),
+    (0x11440, 0x11441, SpacingMark),
+    (0x11442, 0x11444, Extend),
+    (0x11445, 0x11445, SpacingMark),
+    (0x11446, 0x11446, Extend),
+    (0x1145E, 0x1145E, Extend),
+    (0x114B0, 0x114B0, Extend),
+    (0x114B1, 0x114B2, SpacingMark),
+    (0x114B3, 0x114B8, Extend),
+    (0x114B9, 0x114B9, SpacingMark),
+    (0x114BA, 0x114BA, Extend),
+    (0x114BB, 0x114BC, SpacingMark),
+    (0x114BD, 0x114BD, Extend),
+    (0x114BE, 0x114BE, SpacingMark),
+    (0x114BF, 0x114C0, Extend),
+    (0x114C1, 0x114C1, SpacingMark),
+    (0x114C2, 0x114C3, Extend),
+    (0x115AF, 0x115AF, Extend),
+    (0x115B0, 0x115B1, SpacingMark),
+    (0x115B2, 0x115B5, Extend),
+    (0x115B8, 0x115BB, SpacingMark),
+    (0x115BC, 0x115BD, Extend),
+    (0x115BE, 0x115BE, SpacingMark),
+    (0x115BF, 0x115C0, Extend),
+    (0x115DC, 0x115DD, Extend),
+    (0x11630, 0x11632, SpacingMark),
+    (0x11633, 0x1163A, Extend),
+    (0x1163B, 0x1163C, SpacingMark),
+    (0x1163D, 0x1163D, Extend),
+    (0x1163E, 0x1163E, SpacingMark),
+    (0x1163F, 0x11640, Extend),
+    (0x116AB, 0x116AB, Extend),
+    (0x116AC, 0x116AC, SpacingMark),
+    (0x116AD, 0x116AD, Extend),
+    (0x116AE, 0x116AF, SpacingMark),
+    (0x116B0, 0x116B5, Extend),
+    (0x116B6, 0x116B6, SpacingMark),
+    (0x116B7, 0x116B7, Extend),
+    (0x1171D, 0x1171F, Extend),
+    (0x11722, 0x11725, Extend),
+    (0x11726, 0x11726, SpacingMark),
+    (0x11727, 0x1172B, Extend),
+    (0x1182C, 0x1182E, SpacingMark),
+    (0x1182F, 0x11837, Extend),
+    (0x11838, 0x11838, SpacingMark),
+    (0x11839, 0x1183A, Extend),
+    (0x11930, 0x11930, Extend),
+    (0x11931, 0x11935, SpacingMark),
+    (0x11937, 0x11938, SpacingMark),
+    (0x1193B, 0x1193C, Extend),
+    (0x1193D, 0x1193D, SpacingMark),
+    (0x1193E, 0x1193E, Extend),
+    (0x1193F, 0x1193F, Prepend),
+    (0x11940, 0x11940, SpacingMark),
+    (0x11941, 0x11941, Prepend),
+    (0x11942, 0x11942, SpacingMark),
+    (0x11943, 0x11943, Extend),
+    (0x119D1, 0x119D3, SpacingMark),
+    (0x119D4, 0x119D7, Extend),
+    (0x119DA, 0x119DB, Extend),
+    (0x119DC, 0x119DF, SpacingMark),
+    (0x119E0, 0x119E0, Extend),
+    (0x119E4, 0x119E4, SpacingMark),
+    (0x11A01, 0x11A0A, Extend),
+    (0x11A33, 0x11A38, Extend),
+    (0x11A39, 0x11A39, SpacingMark),
+    (0x11A3A, 0x11A3A, Prepend),
+    (0x11A3B, 0x11A3E, Extend),
+    (0x11A47, 0x11A47, Extend),
+    (0x11A51, 0x11A56, Extend),
+    (0x11A57, 0x11A58, SpacingMark),
+    (0x11A59, 0x11A5B, Extend),
+    (0x11A84, 0x11A89, Prepend),
+    (0x11A8A, 0x11A96, Extend),
+    (0x11A97, 0x11A97, SpacingMark),
+    (0x11A98, 0x11A99, Extend),
+    (0x11C2F, 0x11C2F, SpacingMark),
+    (0x11C30, 0x11C36, Extend),
+    (0x11C38, 0x11C3D, Extend),
+    (0x11C3E, 0x11C3E, SpacingMark),
+    (0x11C3F, 0x11C3F, Extend),
+    (0x11C92, 0x11CA7, Extend),
+    (0x11CA9, 0x11CA9, SpacingMark),
+    (0x11CAA, 0x11CB0, Extend),
+    (0x11CB1, 0x11CB1, SpacingMark),
+    (0x11CB2, 0x11CB3, Extend),
+    (0x11CB4, 0x11CB4, SpacingMark),
+    (0x11CB5, 0x11CB6, Extend),
+    (0x11D31, 0x11D36, Extend),
+    (0x11D3A, 0x11D3A, Extend),
+    (0x11D3C, 0x11D3D, Extend),
+    (0x11D3F, 0x11D45, Extend),
+    (0x11D46, 0x11D46, Prepend),
+    (0x11D47, 0x11D47, Extend),
+    (0x11D8A, 0x11D8E, SpacingMark),
+    (0x11D90, 0x11D91, Extend),
+    (0x11D93, 0x11D94, SpacingMark),
+    (0x11D95, 0x11D95, Extend),
+    (0x11D96, 0x11D96, SpacingMark),
+    (0x11D97, 0x11D97, Extend),
+    (0x11EF3, 0x11EF4, Extend),
+    (0x11EF5, 0x11EF6, SpacingMark),
+    (0x13430, 0x13438, Control),
+    (0x16AF0, 0x16AF4, Extend),
+    (0x16B30, 0x16B36, Extend),
+    (0x16F4F, 0x16F4F, Extend),
+    (0x16F51, 0x16F87, SpacingMark),
+    (0x16F8F, 0x16F92, Extend),
+    (0x16FE4, 0x16FE4, Extend),
+    (0x16FF0, 0x16FF1, SpacingMark),
+    (0x1BC9D, 0x1BC9E, Extend),
+    (0x1BCA0, 0x1BCA3, Control),
+    (0x1CF00, 0x1CF2D, Extend),
+    (0x1CF30, 0x1CF46, Extend),
+    (0x1D165, 0x1D165, Extend),
+    (0x1D166, 0x1D166, SpacingMark),
+    (0x1D167, 0x1D169, Extend),
+    (0x1D16D, 0x1D16D, SpacingMark),
+    (0x1D16E, 0x1D172, Extend),
+    (0x1D173, 0x1D17A, Control),
+    (0x1D17B, 0x1D182, Extend),
+    (0x1D185, 0x1D18B, Extend),
+    (0x1D1AA, 0x1D1AD, Extend),
+    (0x1D242, 0x1D244, Extend),
+    (0x1DA00, 0x1DA36, Extend),
+    (0x1DA3B, 0x1DA6C, Extend),
+    (0x1DA75, 0x1DA75, Extend),
+    (0x1DA84, 0x1DA84, Extend),
+    (0x1DA9B, 0x1DA9F, Extend),
+    (0x1DAA1, 0x1DAAF, Extend),
+    (0x1E000, 0x1E006, Extend),
+    (0x1E008, 0x1E018, Extend),
+    (0x1E01B, 0x1E021, Extend),
+    (0x1E023, 0x1E024, Extend),
+    (0x1E026, 0x1E02A, Extend),
+    (0x1E130, 0x1E136, Extend),
+    (0x1E2AE, 0x1E2AE, Extend),
+    (0x1E2EC, 0x1E2EF, Extend),
+    (0x1E8D0, 0x1E8D6, Extend),
+    (0x1E944, 0x1E94A, Extend),
+    (0x1F000, 0x1F0FF, Pictographic),
+    (0x1F10D, 0x1F10F, Pictographic),
+    (0x1F12F, 0x1F12F, Pictographic),
+    (0x1F16C, 0x1F171, Pictographic),
+    (0x1F17E, 0x1F17F, Pictographic),
+    (0x1F18E, 0x1F18E, Pictographic),
+    (0x1F191, 0x1F19A, Pictographic),
+    (0x1F1AD, 0x1F1E5, Pictographic),
+    (0x1F1E6, 0x1F1FF, RegionalIndicator),
+    (0x1F201, 0x1F20F, Pictographic),
+    (0x1F21A, 0x1F21A, Pictographic),
+    (0x1F22F, 0x1F22F, Pictographic),
+    (0x1F232, 0x1F23A, Pictographic),
+    (0x1F23C, 0x1F23F, Pictographic),
+    (0x1F249, 0x1F3FA, Pictographic),
+    (0x1F3FB, 0x1F3FF, Extend),
+    (0x1F400, 0x1F53D, Pictographic),
+    (0x1F546, 0x1F64F, Pictographic),
+    (0x1F680, 0x1F6FF, Pictographic),
+    (0x1F774, 0x1F77F, Pictographic),
+    (0x1F7D5, 0x1F7FF, Pictographic),
+    (0x1F80C, 0x1F80F, Pictographic),
+    (0x1F848, 0x1F84F, Pictographic),
+    (0x1F85A, 0x1F85F, Pictographic),
+    (0x1F888, 0x1F88F, Pictographic),
+    (0x1F8AE, 0x1F8FF, Pictographic),
+    (0x1F90C, 0x1F93A, Pictographic),
+    (0x1F93C, 0x1F945, Pictographic),
+    (0x1F947, 0x1FAFF, Pictographic),
+    (0x1FC00, 0x1FFFD, Pictographic),
+    (0xE0000, 0xE001F, Control),
+    (0xE0020, 0xE007F, Extend),
+    (0xE0080, 0xE00FF, Control),
+    (0xE0100, 0xE01EF, Extend),
+    (0xE01F0, 0xE0FFF, Control),
+];
+
+/// The full case folding of every character that folds, C and F in
+/// `CaseFolding.txt`, sorted by code point. The Turkic foldings of `I` and `İ` are
+/// left out.
+pub(super) const CASE_FOLDS: &[(u32, &str)] = &[
+    (0x41, "a"),
+    (0x42, "b"),
+    (0x43, "c"),
+    (0x44, "d"),
+    (0x45, "e"),
+    (0x46, "f"),
+    (0x47, "g"),
+    (0x48, "h"),
+    (0x49, "i"),
+    (0x4A, "j"),
+    (0x4B, "k"),
+    (0x4C, "l"),
+    (0x4D, "m"),
+    (0x4E, "n"),
+    (0x4F, "o"),
+    (0x50, "p"),
+    (0x51, "q"),
+    (0x52, "r"),
+    (0x53, "s"),
+    (0x54, "t"),
+    (0x55, "u"),
+    (0x56, "v"),
+    (0x57, "w"),
+    (0x58, "x"),
+    (0x59, "y"),
+    (0x5A, "z"),
+    (0xB5, "\u{3BC}"),
+    (0xC0, "\u{E0}"),
+    (0xC1, "\u{E1}"),
+    (0xC2, "\u{E2}"),
+    (0xC3, "\u{E3}"),
+    (0xC4, "\u{E4}"),
+    (0xC5, "\u{E5}"),
+    (0xC6, "\u{E6}"),
+    (0xC7, "\u{E7}"),
+    (0xC8, "\u{E8}"),
+    (0xC9, "\u{E9}"),
+    (0xCA, "\u{EA}"),
+    (0xCB, "\u{EB}"),
+    (0xCC, "\u{EC}"),
+    (0xCD, "\u{ED}"),
+    (0xCE, "\u{EE}"),
+    (0xCF, "\u{EF}"),
+    (0xD0, "\u{F0}"),
+    (0xD1, "\u{F1}"),
+    (0xD2, "\u{F2}"),
+    (0xD3, "\u{F3}"),
+    (0xD4, "\u{F4}"),
+    (0xD5, "\u{F5}"),
+    (0xD6, "\u{F6}"),
+    (0xD8, "\u{F8}"),
+    (0xD9, "\u{F9}"),
+    (0xDA, "\u{FA}"),
+    (0xDB, "\u{FB}"),
+    (0xDC, "\u{FC}"),
+    (0xDD, "\u{FD}"),
+    (0xDE, "\u{FE}"),
+    (0xDF, "ss"),
+    (0x100, "\u{101}"),
+    (0x102, "\u{103}"),
+    (0x104, "\u{105}"),
+    (0x106, "\u{107}"),
+    (0x108, "\u{109}"),
+    (0x10A, "\u{10B}"),
+    (0x10C, "\u{10D}"),
+    (0x10E, "\u{10F}"),
+    (0x110, "\u{111}"),
+    (0x112, "\u{113}"),
+    (0x114, "\u{115}"),
+    (0x116, "\u{117}"),
+    (0x118, "\u{119}"),
+    (0x11A, "\u{11B}"),
+    (0x11C, "\u{11D}"),
+    (0x11E, "\u{11F}"),
+    (0x120, "\u{121}"),
+    (0x122, "\u{123}"),
+    (0x124, "\u{125}"),
+    (0x126, "\u{127}"),
+    (0x128, "\u{129}"),
+    (0x12A, "\u{12B}"),
+    (0x12C, "\u{12D}"),
+    (0x12E, "\u{12F}"),
+    (0x130, "i\u{307}"),
+    (0x132, "\u{133}"),
+    (0x134, "\u{135}"),
+    (0x136, "\u{137}"),
+    (0x139, "\u{13A}"),
+    (0x13B, "\u{13C}"),
+    (0x13D, "\u{13E}"),
+    (0x13F, "\u{140}"),
+    (0x141, "\u{142}"),
+    (0x143, "\u{144}"),
+    (0x145, "\u{146}"),
+    (0x147, "\u{148}"),
+    (0x149, "\u{2BC}n"),
+    (0x14A, "\u{14B}"),
+    (0x14C, "\u{14D}"),
+    (0x14E, "\u{14F}"),
+    (0x150, "\u{151}"),
+    (0x152, "\u{153}"),
+    (0x154, "\u{155}"),
+    (0x156, "\u{157}"),
+    (0x158, "\u{159}"),
+    (0x15A, "\u{15B}"),
+    (0x15C, "\u{15D}"),
+    (0x15E, "\u{15F}"),
+    (0x160, "\u{161}"),
+    (0x162, "\u{163}"),
+    (0x164, "\u{165}"),
+    (0x166, "\u{167}"),
+    (0x168, "\u{169}"),
+    (0x16A, "\u{16B}"),
+    (0x16C, "\u{16D}"),
+    (0x16E, "\u{16F}"),
+    (0x170, "\u{171}"),
+    (0x172, "\u{173}"),
+    (0x174, "\u{175}"),
+    (0x176, "\u{177}"),
+    (0x178, "\u{FF}"),
+    (0x179, "\u{17A}"),
+    (0x17B, "\u{17C}"),
+    (0x17D, "\u{17E}"),
+    (0x17F, "s"),
+    (0x181, "\u{253}"),
+    (0x182, "\u{183}"),
+    (0x184, "\u{185}"),
+    (0x186, "\u{254}"),
+    (0x187, "\u{188}"),
+    (0x189, "\u{256}"),
+    (0x18A, "\u{257}"),
+    (0x18B, "\u{18C}"),
+    (0x18E, "\u{1DD}"),
+    (0x18F, "\u{259}"),
+    (0x190, "\u{25B}"),
+    (0x191, "\u{192}"),
+    (0x193, "\u{260}"),
+    (0x194, "\u{263}"),
+    (0x196, "\u{269}"),
+    (0x197, "\u{268}"),
+    (0x198, "\u{199}"),
+    (0x19C, "\u{26F}"),
+    (0x19D, "\u{272}"),
+    (0x19F, "\u{275}"),
+    (0x1A0, "\u{1A1}"),
+    (0x1A2, "\u{1A3}"),
+    (0x1A4, "\u{1A5}"),
+    (0x1A6, "\u{280}"),
+    (0x1A7, "\u{1A8}"),
+    (0x1A9, "\u{283}"),
+    (0x1AC, "\u{1AD}"),
+    (0x1AE, "\u{288}"),
+    (0x1AF, "\u{1B0}"),
+    (0x1B1, "\u{28A}"),
+    (0x1B2, "\u{28B}"),
+    (0x1B3, "\u{1B4}"),
+    (0x1B5, "\u{1B6}"),
+    (0x1B7, "\u{292}"),
+    (0x1B8, "\u{1B9}"),
+    (0x1BC, "\u{1BD}"),
+    (0x1C4, "\u{1C6}"),
+    (0x1C5, "\u{1C6}"),
+    (0x1C7, "\u{1C9}"),
+    (0x1C8, "\u{1C9}"),
+    (0x1CA, "\u{1CC}"),
+    (0x1CB, "\u{1CC}"),
+    (0x1CD, "\u{1CE}"),
+    (0x1CF, "\u{1D0}"),
+    (0x1D1, "\u{1D2}"),
+    (0x1D3, "\u{1D4}"),
+    (0x1D5, "\u{1D6}"),
+    (0x1D7, "\u{1D8}"),
+    (0x1D9, "\u{1DA}"),
+    (0x1DB, "\u{1DC}"),
+    (0x1DE, "\u{1DF}"),
+    (0x1E0, "\u{1E1}"),
+    (0x1E2, "\u{1E3}"),
+    (0x1E4, "\u{1E5}"),
+    (0x1E6, "\u{1E7}"),
+    (0x1E8, "\u{1E9}"),
+    (0x1EA, "\u{1EB}"),
+    (0x1EC, "\u{1ED}"),
+    (0x1EE, "\u{1EF}"),
+    (0x1F0, "j\u{30C}"),
+    (0x1F1, "\u{1F3}"),
+    (0x1F2, "\u{1F3}"),
+    (0x1F4, "\u{1F5}"),
+    (0x1F6, "\u{195}"),
+    (0x1F7, "\u{1BF}"),
+    (0x1F8, "\u{1F9}"),
+    (0x1FA, "\u{1FB}"),
+    (0x1FC, "\u{1FD}"),
+    (0x1FE, "\u{1FF}"),
+    (0x200, "\u{201}"),
+    (0x202, "\u{203}"),
+    (0x204, "\u{205}"),
+    (0x206, "\u{207}"),
+    (0x208, "\u{209}"),
+    (0x20A, "\u{20B}"),
+    (0x20C, "\u{20D}"),
+    (0x20E, "\u{20F}"),
+    (0x210, "\u{211}"),
+    (0x212, "\u{213}"),
+    (0x214, "\u{215}"),
+    (0x216, "\u{217}"),
+    (0x218, "\u{219}"),
+    (0x21A, "\u{21B}"),
+    (0x21C, "\u{21D}"),
+    (0x21E, "\u{21F}"),
+    (0x220, "\u{19E}"),
+    (0x222, "\u{223}"),
+    (0x224, "\u{225}"),
+    (0x226, "\u{227}"),
+    (0x228, "\u{229}"),
+    (0x22A, "\u{22B}"),
+    (0x22C, "\u{22D}"),
+    (0x22E, "\u{22F}"),
+    (0x230, "\u{231}"),
+    (0x232, "\u{233}"),
+    (0x23A, "\u{2C65}"),
+    (0x23B, "\u{23C}"),
+    (0x23D, "\u{19A}"),
+    (0x23E, "\u{2C66}"),
+    (0x241, "\u{242}"),
+    (0x243, "\u{180}"),
+    (0x244, "\u{289}"),
+    (0x245, "\u{28C}"),
+    (0x246, "\u{247}"),
+    (0x248, "\u{249}"),
+    (0x24A, "\u{24B}"),
+    (0x24C, "\u{24D}"),
+    (0x24E, "\u{24F}"),
+    (0x345, "\u{3B9}"),
+    (0x370, "\u{371}"),
+    (0x372, "\u{373}"),
+    (0x376, "\u{377}"),
+    (0x37F, "\u{3F3}"),
+    (0x386, "\u{3AC}"),
+    (0x388, "\u{3AD}"),
+    (0x389, "\u{3AE}"),
+    (0x38A, "\u{3AF}"),
+    (0x38C, "\u{3CC}"),
+    (0x38E, "\u{3CD}"),
+    (0x38F, "\u{3CE}"),
+    (0x390, "\u{3B9}\u{308}\u{301}"),
+    (0x391, "\u{3B1}"),
+    (0x392, "\u{3B2}"),
+    (0x393, "\u{3B3}"),
+    (0x394, "\u{3B4}"),
+    (0x395, "\u{3B5}"),
+    (0x396, "\u{3B6}"),
+    (0x397, "\u{3B7}"),
+    (0x398, "\u{3B8}"),
+    (0x399, "\u{3B9}"),
+    (0x39A, "\u{3BA}"),
+    (0x39B, "\u{3BB}"),
+    (0x39C, "\u{3BC}"),
+    (0x39D, "\u{3BD}"),
+    (0x39E, "\u{3BE}"),
+    (0x39F, "\u{3BF}"),
+    (0x3A0, "\u{3C0}"),
+    (0x3A1, "\u{3C1}"),
+    (0x3A3, "\u{3C3}"),
+    (0x3A4, "\u{3C4}"),
+    (0x3A5, "\u{3C5}"),
+    (0x3A6, "\u{3C6}"),
+    (0x3A7, "\u{3C7}"),
+    (0x3A8, "\u{3C8}"),
+    (0x3A9, "\u{3C9}"),
+    (0x3AA, "\u{3CA}"),
+    (0x3AB, "\u{3CB}"),
+    (0x3B0, "\u{3C5}\u{308}\u{301}"),
+    (0x3C2, "\u{3C3}"),
+    (0x3CF, "\u{3D7}"),
+    (0x3D0, "\u{3B2}"),
+    (0x3D1, "\u{3B8}"),
+    (0x3D5, "\u{3C6}"),
+    (0x3D6, "\u{3C0}"),
+    (0x3D8, "\u{3D9}"),
+    (0x3DA, "\u{3DB}"),
+    (0x3DC, "\u{3DD}"),
+    (0x3DE, "\u{3DF}"),
+    (0x3E0, "\u{3E1}"),
+    (0x3E2, "\u{3E3}"),
+    (0x3E4, "\u{3E5}"),
+    (0x3E6, "\u{3E7}"),
+    (0x3E8, "\u{3E9}"),
+    (0x3EA, "\u{3EB}"),
+    (0x3EC, "\u{3ED}"),
+    (0x3EE, "\u{3EF}"),
+    (0x3F0, "\u{3BA}"),
+    (0x3F1, "\u{3C1}"),
+    (0x3F4, "\u{3B8}"),
+    (0x3F5, "\u{3B5}"),
+    (0x3F7, "\u{3F8}"),
+    (0x3F9, "\u{3F2}"),
+    (0x3FA, "\u{3FB}"),
+    (0x3FD, "\u{37B}"),
+    (0x3FE, "\u{37C}"),
+    (0x3FF, "\u{37D}"),
+    (0x400, "\u{450}"),
+    (0x401, "\u{451}"),
+    (0x402, "\u{452}"),
+    (0x403, "\u{453}"),
+    (0x404, "\u{454}"),
+    (0x405, "\u{455}"),
+    (0x406, "\u{456}"),
+    (0x407, "\u{457}"),
+    (0x408, "\u{458}"),
+    (0x409, "\u{459}"),
+    (0x40A, "\u{45A}"),
+    (0x40B, "\u{45B}"),
+    (0x40C, "\u{45C}"),
+    (0x40D, "\u{45D}"),
+    (0x40E, "\u{45E}"),
+    (0x40F, "\u{45F}"),
+    (0x410, "\u{430}"),
+    (0x411, "\u{431}"),
+    (0x412, "\u{432}"),
+    (0x413, "\u{433}"),
+    (0x414, "\u{434}"),
+    (0x415, "\u{435}"),
+    (0x416, "\u{436}"),
+    (0x417, "\u{437}"),
+    (0x418, "\u{438}"),
+    (0x419, "\u{439}"),
+    (0x41A, "\u{43A}"),
+    (0x41B, "\u{43B}"),
+    (0x41C, "\u{43C}"),
+    (0x41D, "\u{43D}"),
+    (0x41E, "\u{43E}"),
+    (0x41F, "\u{43F}"),
+    (0x420, "\u{440}"),
+    (0x421, "\u{441}"),
+    (0x422, "\u{442}"),
+    (0x423, "\u{443}"),
+    (0x424, "\u{444}"),
+    (0x425, "\u{445}"),
+    (0x426, "\u{446}"),
+    (0x427, "\u{447}"),
+    (0x428, "\u{448}"),
+    (0x429, "\u{449}"),
+    (0x42A, "\u{44A}"),
+    (0x42B, "\u{44B}"),
+    (0x42C, "\u{44C}"),
+    (0x42D, "\u{44D}"),
+    (0x42E, "\u{44E}"),
+    (0x42F, "\u{44F}"),
+    (0x460, "\u{461}"),
+    (0x462, "\u{463}"),
+    (0x464, "\u{465}"),
+    (0x466, "\u{467}"),
+    (0x468, "\u{469}"),
+    (0x46A, "\u{46B}"),
+    (0x46C, "\u{46D}"),
+    (0x46E, "\u{46F}"),
+    (0x470, "\u{471}"),
+    (0x472, "\u{473}"),
+    (0x474, "\u{475}"),
+    (0x476, "\u{477}"),
+    (0x478, "\u{479}"),
+    (0x47A, "\u{47B}"),
+    (0x47C, "\u{47D}"),
+    (0x47E, "\u{47F}"),
+    (0x480, "\u{481}"),
+    (0x48A, "\u{48B}"),
+    (0x48C, "\u{48D}"),
+    (0x48E, "\u{48F}"),
+    (0x490, "\u{491}"),
+    (0x492, "\u{493}"),
+    (0x494, "\u{495}"),
+    (0x496, "\u{497}"),
+    (0x498, "\u{499}"),
+    (0x49A, "\u{49B}"),
+    (0x49C, "\u{49D}"),
+    (0x49E, "\u{49F}"),
+    (0x4A0, "\u{4A1}"),
+    (0x4A2, "\u{4A3}"),
+    (0x4A4, "\u{4A5}"),
+    (0x4A6, "\u{4A7}"),
+    (0x4A8, "\u{4A9}"),
+    (0x4AA, "\u{4AB}"),
+    (0x4AC, "\u{4AD}"),
+    (0x4AE, "\u{4AF}"),
+    (0x4B0, "\u{4B1}"),
+    (0x4B2, "\u{4B3}"),
+    (0x4B4, "\u{4B5}"),
+    (0x4B6, "\u{4B7}"),
+    (0x4B8, "\u{4B9}"),
+    (0x4BA, "\u{4BB}"),
+    (0x4BC, "\u{4BD}"),
+    (0x4BE, "\u{4BF}"),
+    (0x4C0, "\u{4CF}"),
+    (0x4C1, "\u{4C2}"),
+    (0x4C3, "\u{4C4}"),
+    (0x4C5, "\u{4C6}"),
+    (0x4C7, "\u{4C8}"),
+    (0x4C9, "\u{4CA}"),
+    (0x4CB, "\u{4CC}"),
+    (0x4CD, "\u{4CE}"),
+    (0x4D0, "\u{4D1}"),
+    (0x4D2, "\u{4D3}"),
+    (0x4D4, "\u{4D5}"),
+    (0x4D6, "\u{4D7}"),
+    (0x4D8, "\u{4D9}"),
+    (0x4DA, "\u{4DB}"),
+    (0x4DC, "\u{4DD}"),
+    (0x4DE, "\u{4DF}"),
+    (0x4E0, "\u{4E1}"),
+    (0x4E2, "\u{4E3}"),
+    (0x4E4, "\u{4E5}"),
+    (0x4E6, "\u{4E7}"),
+    (0x4E8, "\u{4E9}"),
+    (0x4EA, "\u{4EB}"),
+    (0x4EC, "\u{4ED}"),
+    (0x4EE, "\u{4EF}"),
+    (0x4F0, "\u{4F1}"),
+    (0x4F2, "\u{4F3}"),
+    (0x4F4, "\u{4F5}"),
+    (0x4F6, "\u{4F7}"),
+    (0x4F8, "\u{4F9}"),
+    (0x4FA, "\u{4FB}"),
+    (0x4FC, "\u{4FD}"),
+    (0x4FE, "\u{4FF}"),
+    (0x500, "\u{501}"),
+    (0x502, "\u{503}"),
+    (0x504, "\u{505}"),
+    (0x506, "\u{507}"),
+    (0x508, "\u{509}"),
+    (0x50A, "\u{50B}"),
+    (0x50C, "\u{50D}"),
+    (0x50E, "\u{50F}"),
+    (0x510, "\u{511}"),
+    (0x512, "\u{513}"),
+    (0x514, "\u{515}"),
+    (0x516, "\u{517}"),
+    (0x518, "\u{519}"),
+    (0x51A, "\u{51B}"),
+    (0x51C, "\u{51D}"),
+    (0x51E, "\u{51F}"),
+    (0x520, "\u{521}"),
+    (0x522, "\u{523}"),
+    (0x524, "\u{525}"),
+    (0x526, "\u{527}"),
+    (0x528, "\u{529}"),
+    (0x52A, "\u{52B}"),
+    (0x52C, "\u{52D}"),
+    (0x52E, "\u{52F}"),
+    (0x531, "\u{561}"),
+    (0x532, "\u{562}"),
+    (0x533, "\u{563}"),
+    (0x534, "\u{564}"),
+    (0x535, "\u{565}"),
+    (0x536, "\u{566}"),
+    (0x537, "\u{567}"),
+    (0x538, "\u{568}"),
+    (0x539, "\u{569}"),
+    (0x53A, "\u{56A}"),
+    (0x53B, "\u{56B}"),
+    (0x53C, "\u{56C}"),
+    (0x53D, "\u{56D}"),
+    (0x53E, "\u{56E}"),
+    (0x53F, "\u{56F}"),
+    (0x540, "\u{570}"),
+    (0x541, "\u{571}"),
+    (0x542, "\u{572}"),
+    (0x543, "\u{573}"),
+    (0x544, "\u{574}"),
+    (0x545, "\u{575}"),
+    (0x546, "\u{576}"),
+    (0x547, "\u{577}"),
+    (0x548, "\u{578}"),
+    (0x549, "\u{579}"),
+    (0x54A, "\u{57A}"),
+    (0x54B, "\u{57B}"),
+    (0x54C, "\u{57C}"),
+    (0x54D, "\u{57D}"),
+    (0x54E, "\u{57E}"),
+    (0x54F, "\u{57F}"),
+    (0x550, "\u{580}"),
+    (0x551, "\u{581}"),
+    (0x552, "\u{582}"),
+    (0x553, "\u{583}"),
+    (0x554, "\u{584}"),
+    (0x555, "\u{585}"),
+    (0x556, "\u{586}"),
+    (0x587, "\u{565}\u{582}"),
+    (0x10A0, "\u{2D00}"),
+    (0x10A1, "\u{2D01}"),
+    (0x10A2, "\u{2D02}"),
+    (0x10A3, "\u{2D03}"),
+    (0x10A4, "\u{2D04}"),
+    (0x10A5, "\u{2D05}"),
+    (0x10A6, "\u{2D06}"),
+    (0x10A7, "\u{2D07}"),
+    (0x10A8, "\u{2D08}"),
+    (0x10A9, "\u{2D09}"),
+    (0x10AA, "\u{2D0A}"),
+    (0x10AB, "\u{2D0B}"),
+    (0x10AC, "\u{2D0C}"),
+    (0x10AD, "\u{2D0D}"),
+    (0x10AE, "\u{2D0E}"),
+    (0x10AF, "\u{2D0F}"),
+    (0x10B0, "\u{2D10}"),
+    (0x10B1, "\u{2D11}"),
+    (0x10B2, "\u{2D12}"),
+    (0x10B3, "\u{2D13}"),
+    (0x10B4, "\u{2D14}"),
+    (0x10B5, "\u{2D15}"),
+    (0x10B6, "\u{2D16}"),
+    (0x10B7, "\u{2D17}"),
+    (0x10B8, "\u{2D18}"),
+    (0x10B9, "\u{2D19}"),
+    (0x10BA, "\u{2D1A}"),
+    (0x10BB, "\u{2D1B}"),
+    (0x10BC, "\u{2D1C}"),
+    (0x10BD, "\u{2D1D}"),
+    (0x10BE, "\u{2D1E}"),
+    (0x10BF, "\u{2D1F}"),
+    (0x10C0, "\u{2D20}"),
+    (0x10C1, "\u{2D21}"),
+    (0x10C2, "\u{2D22}"),
+    (0x10C3, "\u{2D23}"),
+    (0x10C4, "\u{2D24}"),
+    (0x10C5, "\u{2D25}"),
+    (0x10C7, "\u{2D27}"),
+    (0x10CD, "\u{2D2D}"),
+    (0x13F8, "\u{13F0}"),
+    (0x13F9, "\u{13F1}"),
+    (0x13FA, "\u{13F2}"),
+    (0x13FB, "\u{13F3}"),
+    (0x13FC, "\u{13F4}"),
+    (0x13FD, "\u{13F5}"),
+    (0x1C80, "\u{432}"),
+    (0x1C81, "\u{434}"),
+    (0x1C82, "\u{43E}"),
+    (0x1C83, "\u{441}"),
+    (0x1C84, "\u{442}"),
+    (0x1C85, "\u{442}"),
+    (0x1C86, "\u{44A}"),
+    (0x1C87, "\u{463}"),
+    (0x1C88, "\u{A64B}"),
+    (0x1C90, "\u{10D0}"),
+    (0x1C91, "\u{10D1}"),
+    (0x1C92, "\u{10D2}"),
+    (0x1C93, "\u{10D3}"),
+    (0x1C94, "\u{10D4}"),
+    (0x1C95, "\u{10D5}"),
+    (0x1C96, "\u{10D6}"),
+    (0x1C97, "\u{10D7}"),
+    (0x1C98, "\u{10D8}"),
+    (0x1C99, "\u{10D9}"),
+    (0x1C9A, "\u{10DA}"),
+    (0x1C9B, "\u{10DB}"),
+    (0x1C9C, "\u{10DC}"),
+    (0x1C9D, "\u{10DD}"),
+    (0x1C9E, "\u{10DE}"),
+    (0x1C9F, "\u{10DF}"),
+    (0x1CA0, "\u{10E0}"),
+    (0x1CA1, "\u{10E1}"),
+    (0x1CA2, "\u{10E2}"),
+    (0x1CA3, "\u{10E3}"),
+    (0x1CA4, "\u{10E4}"),
+    (0x1CA5, "\u{10E5}"),
+    (0x1CA6, "\u{10E6}"),
+    (0x1CA7, "\u{10E7}"),
+    (0x1CA8, "\u{10E8}"),
+    (0x1CA9, "\u{10E9}"),
+    (0x1CAA, "\u{10EA}"),
+    (0x1CAB, "\u{10EB}"),
+    (0x1CAC, "\u{10EC}"),
+    (0x1CAD, "\u{10ED}"),
+    (0x1CAE, "\u{10EE}"),
+    (0x1CAF, "\u{10EF}"),
+    (0x1CB0, "\u{10F0}"),
+    (0x1CB1, "\u{10F1}"),
+    (0x1CB2, "\u{10F2}"),
+    (0x1CB3, "\u{10F3}"),
+    (0x1CB4, "\u{10F4}"),
+    (0x1CB5, "\u{10F5}"),
+    (0x1CB6, "\u{10F6}"),
+    (0x1CB7, "\u{10F7}"),
+    (0x1CB8, "\u{10F8}"),
+    (0x1CB9, "\u{10F9}"),
+    (0x1CBA, "\u{10FA}"),
+    (0x1CBD, "\u{10FD}"),
+    (0x1CBE, "\u{10FE}"),
+    (0x1CBF, "\u{10FF}"),
+    (0x1E00, "\u{1E01}"),
+    (0x1E02, "\u{1E03}"),
+    (0x1E04, "\u{1E05}"),
+    (0x1E06, "\u{1E07}"),
+    (0x1E08, "\u{1E09}"),
+    (0x1E0A, "\u{1E0B}"),
+    (0x1E0C, "\u{1E0D}"),
+    (0x1E0E, "\u{1E0F}"),
+    (0x1E10, "\u{1E11}"),
+    (0x1E12, "\u{1E13}"),
+    (0x1E14, "\u{1E15}"),
+    (0x1E16, "\u{1E17}"),
+    (0x1E18, "\u{1E19}"),
+    (0x1E1A, "\u{1E1B}"),
+    (0x1E1C, "\u{1E1D}"),
+    (0x1E1E, "\u{1E1F}"),
+    (0x1E20, "\u{1E21}"),
+    (0x1E22, "\u{1E23}"),
+    (0x1E24, "\u{1E25}"),
+    (0x1E26, "\u{1E27}"),
+    (0x1E28, "\u{1E29}"),
+    (0x1E2A, "\u{1E2B}"),
+    (0x1E2C, "\u{1E2D}"),
+    (0x1E2E, "\u{1E2F}"),
+    (0x1E30, "\u{1E31}"),
+    (0x1E32, "\u{1E33}"),
+    (0x1E34, "\u{1E35}"),
+    (0x1E36, "\u{1E37}"),
+    (0x1E38, "\u{1E39}"),
+    (0x1E3A, "\u{1E3B}"),
+    (0x1E3C, "\u{1E3D}"),
+    (0x1E3E, "\u{1E3F}"),
+    (0x1E40, "\u{1E41}"),
+    (0x1E42, "\u{1E43}"),
+    (0x1E44, "\u{1E45}"),
+    (0x1E46, "\u{1E47}"),
+    (0x1E48, "\u{1E49}"),
+    (0x1E4A, "\u{1E4B}"),
+    (0x1E4C, "\u{1E4D}"),
+    (0x1E4E, "\u{1E4F}"),
+    (0x1E50, "\u{1E51}"),
+    (0x1E52, "\u{1E53}"),
+    (0x1E54, "\u{1E55}"),
+    (0x1E56, "\u{1E57}"),
+    (0x1E58, "\u{1E59}"),
+    (0x1E5A, "\u{1E5B}"),
+    (0x1E5C, "\u{1E5D}"),
+    (0x1E5E, "\u{1E5F}"),
+    (0x1E60, "\u{1E61}"),
+    (0x1E62, "\u{1E63}"),
+    (0x1E64, "\u{1E65}"),
+    (0x1E66, "\u{1E67}"),
+    (0x1E68, "\u{1E69}"),
+    (0x1E6A, "\u{1E6B}"),
+    (0x1E6C, "\u{1E6D}"),
+    (0x1E6E, "\u{1E6F}"),
+    (0x1E70, "\u{1E71}"),
+    (0x1E72, "\u{1E73}"),
+    (0x1E74, "\u{1E75}"),
+    (0x1E76, "\u{1E77}"),
+    (0x1E78, "\u{1E79}"),
+    (0x1E7A, "\u{1E7B}"),
+    (0x1E7C, "\u{1E7D}"),
+    (0x1E7E, "\u{1E7F}"),
+    (0x1E80, "\u{1E81}"),
+    (0x1E82, "\u{1E83}"),
+    (0x1E84, "\u{1E85}"),
+    (0x1E86, "\u{1E87}"),
+    (0x1E88, "\u{1E89}"),
+    (0x1E8A, "\u{1E8B}"),
+    (0x1E8C, "\u{1E8D}"),
+    (0x1E8E, "\u{1E8F}"),
+    (0x1E90, "\u{1E91}"),
+    (0x1E92, "\u{1E93}"),
+    (0x1E94, "\u{1E95}"),
+    (0x1E96, "h\u{331}"),
+    (0x1E97, "t\u{308}"),
+    (0x1E98, "w\u{30A}"),
+    (0x1E99, "y\u{30A}"),
+    (0x1E9A, "a\u{2BE}"),
+    (0x1E9B, "\u{1E61}"),
+    (0x1E9E, "ss"),
+    (0x1EA0, "\u{1EA1}"),
+    (0x1EA2, "\u{1EA3}"),
+    (0x1EA4, "\u{1EA5}"),
+    (0x1EA6, "\u{1EA7}"),
+    (0x1EA8, "\u{1EA9}"),
+    (0x1EAA, "\u{1EAB}"),
+    (0x1EAC, "\u{1EAD}"),
+    (0x1EAE, "\u{1EAF}"),
+    (0x1EB0, "\u{1EB1}"),
+    (0x1EB2, "\u{1EB3}"),
+    (0x1EB4, "\u{1EB5}"),
+    (0x1EB6, "\u{1EB7}"),
+    (0x1EB8, "\u{1EB9}"),
+    (0x1EBA, "\u{1EBB}"),
+    (0x1EBC, "\u{1EBD}"),
+    (0x1EBE, "\u{1EBF}"),
+    (0x1EC0, "\u{1EC1}"),
+    (0x1EC2, "\u{1EC3}"),
+    (0x1EC4, "\u{1EC5}"),
+    (0x1EC6, "\u{1EC7}"),
+    (0x1EC8, "\u{1EC9}"),
+    (0x1ECA, "\u{1ECB}"),
+    (0x1ECC, "\u{1ECD}"),
+    (0x1ECE, "\u{1ECF}"),
+    (0x1ED0, "\u{1ED1}"),
+    (0x1ED2, "\u{1ED3}"),
+    (0x1ED4, "\u{1ED5}"),
+    (0x1ED6, "\u{1ED7}"),
+    (0x1ED8, "\u{1ED9}"),
+    (0x1EDA, "\u{1EDB}"),
+    (0x1EDC, "\u{1EDD}"),
+    (0x1EDE, "\u{1EDF}"),
+    (0x1EE0, "\u{1EE1}"),
+    (0x1EE2, "\u{1EE3}"),
+    (0x1EE4, "\u{1EE5}"),
+    (0x1EE6, "\u{1EE7}"),
+    (0x1EE8, "\u{1EE9}"),
+    (0x1EEA, "\u{1EEB}"),
+    (0x1EEC, "\u{1EED}"),
+    (0x1EEE, "\u{1EEF}"),
+    (0x1EF0, "\u{1EF1}"),
+    (0x1EF2, "\u{1EF3}"),
+    (0x1EF4, "\u{1EF5}"),
+    (0x1EF6, "\u{1EF7}"),
+    (0x1EF8, "\u{1EF9}"),
+    (0x1EFA, "\u{1EFB}"),
+    (0x1EFC, "\u{1EFD}"),
+    (0x1EFE, "\u{1EFF}"),
+    (0x1F08, "\u{1F00}"),
+    (0x1F09, "\u{1F01}"),
+    (0x1F0A, "\u{1F02}"),
+    (0x1F0B, "\u{1F03}"),
+    (0x1F0C, "\u{1F04}"),
+    (0x1F0D, "\u{1F05}"),
+    (0x1F0E, "\u{1F06}"),
+    (0x1F0F, "\u{1F07}"),
+    (0x1F18, "\u{1F10}"),
+    (0x1F19, "\u{1F11}"),
+    (0x1F1A, "\u{1F12}"),
+    (0x1F1B, "\u{1F13}"),
+    (0x1F1C, "\u{1F14}"),
+    (0x1F1D, "\u{1F15}"),
+    (0x1F28, "\u{1F20}"),
+    (0x1F29, "\u{1F21}"),
+    (0x1F2A, "\u{1F22}"),
+    (0x1F2B, "\u{1F23}"),
+    (0x1F2C, "\u{1F24}"),
+    (0x1F2D, "\u{1F25}"),
+    (0x1F2E, "\u{1F26}"),
+    (0x1F2F, "\u{1F27}"),
+    (0x1F38, "\u{1F30}"),
+    (0x1F39, "\u{1F31}"),
+    (0x1F3A, "\u{1F32}"),
+    (0x1F3B, "\u{1F33}"),
+    (0x1F3C, "\u{1F34}"),
+    (0x1F3D, "\u{1F35}"),
+    (0x1F3E, "\u{1F36}"),
+    (0x1F3F, "\u{1F37}"),
+    (0x1F48, "\u{1F40}"),
+    (0x1F49, "\u{1F41}"),
+    (0x1F4A, "\u{1F42}"),
+    (0x1F4B, "\u{1F43}"),
+    (0x1F4C, "\u{1F44}"),
+    (0x1F4D, "\u{1F45}"),
+    (0x1F50, "\u{3C5}\u{313}"),
+    (0x1F52, "\u{3C5}\u{313}\u{300}"),
+    (0x1F54, "\u{3C5}\u{313}\u{301}"),
+    (0x1F56, "\u{3C5}\u{313}\u{342}"),
+    (0x1F59, "\u{1F51}"),
+    (0x1F5B, "\u{1F53}"),
+    (0x1F5D, "\u{1F55}"),
+    (0x1F5F, "\u{1F57}"),
+    (0x1F68, "\u{1F60}"),
+    (0x1F69, "\u{1F61}"),
+    (0x1F6A, "\u{1F62}"),
+    (0x1F6B, "\u{1F63}"),
+    (0x1F6C, "\u{1F64}"),
+    (0x1F6D, "\u{1F65}"),
+    (0x1F6E, "\u{1F66}"),
+    (0x1F6F, "\u{1F67}"),
+    (0x1F80, "\u{1F00}\u{3B9}"),
+    (0x1F81, "\u{1F01}\u{3B9}"),
+    (0x1F82, "\u{1F02}\u{3B9}"),
+    (0x1F83, "\u{1F03}\u{3B9}"),
+    (0x1F84, "\u{1F04}\u{3B9}"),
+    (0x1F85, "\u{1F05}\u{3B9}"),
+    (0x1F86, "\u{1F06}\u{3B9}"),
+    (0x1F87, "\u{1F07}\u{3B9}"),
+    (0x1F88, "\u{1F00}\u{3B9}"),
+    (0x1F89, "\u{1F01}\u{3B9}"),
+    (0x1F8A, "\u{1F02}\u{3B9}"),
+    (0x1F8B, "\u{1F03}\u{3B9}"),
+    (0x1F8C, "\u{1F04}\u{3B9}"),
+    (0x1F8D, "\u{1F05}\u{3B9}"),
+    (0x1F8E, "\u{1F06}\u{3B9}"),
+    (0x1F8F, "\u{1F07}\u{3B9}"),
+    (0x1F90, "\u{1F20}\u{3B9}"),
+    (0x1F91, "\u{1F21}\u{3B9}"),
+    (0x1F92, "\u{1F22}\u{3B9}"),
+    (0x1F93, "\u{1F23}\u{3B9}"),
+    (0x1F94, "\u{1F24}\u{3B9}"),
+    (0x1F95, "\u{1F25}\u{3B9}"),
+    (0x1F96, "\u{1F26}\u{3B9}"),
+    (0x1F97, "\u{1F27}\u{3B9}"),
+    (0x1F98, "\u{1F20}\u{3B9}"),
+    (0x1F99, "\u{1F21}\u{3B9}"),
+    (0x1F9A, "\u{1F22}\u{3B9}"),
+    (0x1F9B, "\u{1F23}\u{3B9}"),
+    (0x1F9C, "\u{1F24}\u{3B9}"),
+    (0x1F9D, "\u{1F25}\u{3B9}"),
+    (0x1F9E, "\u{1F26}\u{3B9}"),
+    (0x1F9F, "\u{1F27}\u{3B9}"),
+    (0x1FA0, "\u{1F60}\u{3B9}"),
+    (0x1FA1, "\u{1F61}\u{3B9}"),
+    (0x1FA2, "\u{1F62}\u{3B9}"),
+    (0x1FA3, "\u{1F63}\u{3B9}"),
+    (0x1FA4, "\u{1F64}\u{3B9}"),
+    (0x1FA5, "\u{1F65}\u{3B9}"),
+    (0x1FA6, "\u{1F66}\u{3B9}"),
+    (0x1FA7, "\u{1F67}\u{3B9}"),
+    (0x1FA8, "\u{1F60}\u{3B9}"),
+    (0x1FA9, "\u{1F61}\u{3B9}"),
+    (0x1FAA, "\u{1F62}\u{3B9}"),
+    (0x1FAB, "\u{1F63}\u{3B9}"),
+    (0x1FAC, "\u{1F64}\u{3B9}"),
+    (0x1FAD, "\u{1F65}\u{3B9}"),
+    (0x1FAE, "\u{1F66}\u{3B9}"),
+    (0x1FAF, "\u{1F67}\u{3B9}"),
+    (0x1FB2, "\u{1F70}\u{3B9}"),
+    (0x1FB3, "\u{3B1}\u{3B9}"),
+    (0x1FB4, "\u{3AC}\u{3B9}"),
+    (0x1FB6, "\u{3B1}\u{342}"),
+    (0x1FB7, "\u{3B1}\u{342}\u{3B9}"),
+    (0x1FB8, "\u{1FB0}"),
+    (0x1FB9, "\u{1FB1}"),
+    (0x1FBA, "\u{1F70}"),
+    (0x1FBB, "\u{1F71}"),
+    (0x1FBC, "\u{3B1}\u{3B9}"),
+    (0x1FBE, "\u{3B9}"),
+    (0x1FC2, "\u{1F74}\u{3B9}"),
+    (0x1FC3, "\u{3B7}\u{3B9}"),
+    (0x1FC4, "\u{3AE}\u{3B9}"),
+    (0x1FC6, "\u{3B7}\u{342}"),
+    (0x1FC7, "\u{3B7}\u{342}\u{3B9}"),
+    (0x1FC8, "\u{1F72}"),
+    (0x1FC9, "\u{1F73}"),
+    (0x1FCA, "\u{1F74}"),
+    (0x1FCB, "\u{1F75}"),
+    (0x1FCC, "\u{3B7}\u{3B9}"),
+    (0x1FD2, "\u{3B9}\u{308}\u{300}"),
+    (0x1FD3, "\u{3B9}\u{308}\u{301}"),
+    (0x1FD6, "\u{3B9}\u{342}"),
+    (0x1FD7, "\u{3B9}\u{308}\u{342}"),
+    (0x1FD8, "\u{1FD0}"),
+    (0x1FD9, "\u{1FD1}"),
+    (0x1FDA, "\u{1F76}"),
+    (0x1FDB, "\u{1F77}"),
+    (0x1FE2, "\u{3C5}\u{308}\u{300}"),
+    (0x1FE3, "\u{3C5}\u{308}\u{301}"),
+    (0x1FE4, "\u{3C1}\u{313}"),
+    (0x1FE6, "\u{3C5}\u{342}"),
+    (0x1FE7, "\u{3C5}\u{308}\u{342}"),
+    (0x1FE8, "\u{1FE0}"),
+    (0x1FE9, "\u{1FE1}"),
+    (0x1FEA, "\u{1F7A}"),
+    (0x1FEB, "\u{1F7B}"),
+    (0x1FEC, "\u{1FE5}"),
+    (0x1FF2, "\u{1F7C}\u{3B9}"),
+    (0x1FF3, "\u{3C9}\u{3B9}"),
+    (0x1FF4, "\u{3CE}\u{3B9}"),
+    (0x1FF6, "\u{3C9}\u{342}"),
+    (0x1FF7, "\u{3C9}\u{342}\u{3B9}"),
+    (0x1FF8, "\u{1F78}"),
+    (0x1FF9, "\u{1F79}"),
+    (0x1FFA, "\u{1F7C}"),
+    (0x1FFB, "\u{1F7D}"),
+    (0x1FFC, "\u{3C9}\u{3B9}"),
+    (0x2126, "\u{3C9}"),
+    (0x212A, "k"),
+    (0x212B, "\u{E5}"),
+    (0x2132, "\u{214E}"),
+    (0x2160, "\u{2170}"),
+    (0x2161, "\u{2171}"),
+    (0x2162, "\u{2172}"),
+    (0x2163, "\u{2173}"),
+    (0x2164, "\u{2174}"),
+    (0x2165, "\u{2175}"),
+    (0x2166, "\u{2176}"),
+    (0x2167, "\u{2177}"),
+    (0x2168, "\u{2178}"),
+    (0x2169, "\u{2179}"),
+    (0x216A, "\u{217A}"),
+    (0x216B, "\u{217B}"),
+    (0x216C, "\u{217C}"),
+    (0x216D, "\u{217D}"),
+    (0x216E, "\u{217E}"),
+    (0x216F, "\u{217F}"),
+    (0x2183, "\u{2184}"),
+    (0x24B6, "\u{24D0}"),
+    (0x24B7, "\u{24D1}"),
+    (0x24B8, "\u{24D2}"),
+    (0x24B9, "\u{24D3}"),
+    (0x24BA, "\u{24D4}"),
+    (0x24BB, "\u{24D5}"),
+    (0x24BC, "\u{24D6}"),
+    (0x24BD, "\u{24D7}"),
+    (0x24BE, "\u{24D8}"),
+    (0x24BF, "\u{24D9}"),
+    (0x24C0, "\u{24DA}"),
+    (0x24C1, "\u{24DB}"),
+    (0x24C2, "\u{24DC}"),
+    (0x24C3, "\u{24DD}"),
+    (0x24C4, "\u{24DE}"),
+    (0x24C5, "\u{24DF}"),
+    (0x24C6, "\u{24E0}"),
+    (0x24C7, "\u{24E1}"),
+    (0x24C8, "\u{24E2}"),
+    (0x24C9, "\u{24E3}"),
+    (0x24CA, "\u{24E4}"),
+    (0x24CB, "\u{24E5}"),
+    (0x24CC, "\u{24E6}"),
+    (0x24CD, "\u{24E7}"),
+    (0x24CE, "\u{24E8}"),
+    (0x24CF, "\u{24E9}"),
+    (0x2C00, "\u{2C30}"),
+    (0x2C01, "\u{2C31}"),
+    (0x2C02, "\u{2C32}"),
+    (0x2C03, "\u{2C33}"),
+    (0x2C04, "\u{2C34}"),
+    (0x2C05, "\u{2C35}"),
+    (0x2C06, "\u{2C36}"),
+    (0x2C07, "\u{2C37}"),
+    (0x2C08, "\u{2C38}"),
+    (0x2C09, "\u{2C39}"),
+    (0x2C0A, "\u{2C3A}"),
+    (0x2C0B, "\u{2C3B}"),
+    (0x2C0C, "\u{2C3C}"),
+    (0x2C0D, "\u{2C3D}"),
+    (0x2C0E, "\u{2C3E}"),
+    (0x2C0F, "\u{2C3F}"),
+    (0x2C10, "\u{2C40}"),
+    (0x2C11, "\u{2C41}"),
+    (0x2C12, "\u{2C42}"),
+    (0x2C13, "\u{2C43}"),
+    (0x2C14, "\u{2C44}"),
+    (0x2C15, "\u{2C45}"),
+    (0x2C16, "\u{2C46}"),
+    (0x2C17, "\u{2C47}"),
+    (0x2C18, "\u{2C48}"),
+    (0x2C19, "\u{2C49}"),
+    (0x2C1A, "\u{2C4A}"),
+    (0x2C1B, "\u{2C4B}"),
+    (0x2C1C, "\u{2C4C}"),
+    (0x2C1D, "\u{2C4D}"),
+    (0x2C1E, "\u{2C4E}"),
+    (0x2C1F, "\u{2C4F}"),
+    (0x2C20, "\u{2C50}"),
+    (0x2C21, "\u{2C51}"),
+    (0x2C22, "\u{2C52}"),
+    (0x2C23, "\u{2C53}"),
+    (0x2C24, "\u{2C54}"),
+    (0x2C25, "\u{2C55}"),
+    (0x2C26, "\u{2C56}"),
+    (0x2C27, "\u{2C57}"),
+    (0x2C28, "\u{2C58}"),
+    (0x2C29, "\u{2C59}"),
+    (0x2C2A, "\u{2C5A}"),
+    (0x2C2B, "\u{2C5B}"),
+    (0x2C2C, "\u{2C5C}"),
+    (0x2C2D, "\u{2C5D}"),
+    (0x2C2E, "\u{2C5E}"),
+    (0x2C2F, "\u{2C5F}"),
+    (0x2C60, "\u{2C61}"),
+    (0x2C62, "\u{26B}"),
+    (0x2C63, "\u{1D7D}"),
+    (0x2C64, "\u{27D}"),
+    (0x2C67, "\u{2C68}"),
+    (0x2C69, "\u{2C6A}"),
+    (0x2C6B, "\u{2C6C}"),
+    (0x2C6D, "\u{251}"),
+    (0x2C6E, "\u{271}"),
+    (0x2C6F, "\u{250}"),
+    (0x2C70, "\u{252}"),
+    (0x2C72, "\u{2C73}"),
+    (0x2C75, "\u{2C76}"),
+    (0x2C7E, "\u{23F}"),
+    (0x2C7F, "\u{240}"),
+    (0x2C80, "\u{2C81}"),
+    (0x2C82, "\u{2C83}"),
+    (0x2C84, "\u{2C85}"),
+    (0x2C86, "\u{2C87}"),
+    (0x2C88, "\u{2C89}"),
+    (0x2C8A, "\u{2C8B}"),
+    (0x2C8C, "\u{2C8D}"),
+    (0x2C8E, "\u{2C8F}"),
+    (0x2C90, "\u{2C91}"),
+    (0x2C92, "\u{2C93}"),
+    (0x2C94, "\u{2C95}"),
+    (0x2C96, "\u{2C97}"),
+    (0x2C98, "\u{2C99}"),
+    (0x2C9A, "\u{2C9B}"),
+    (0x2C9C, "\u{2C9D}"),
+    (0x2C9E, "\u{2C9F}"),
+    (0x2CA0, "\u{2CA1}"),
+    (0x2CA2, "\u{2CA3}"),
+    (0x2CA4, "\u{2CA5}"),
+    (0x2CA6, "\u{2CA7}"),
+    (0x2CA8, "\u{2CA9}"),
+    (0x2CAA, "\u{2CAB}"),
+    (0x2CAC, "\u{2CAD}"),
+    (0x2CAE, "\u{2CAF}"),
+    (0x2CB0, "\u{2CB1}"),
+    (0x2CB2, "\u{2CB3}"),
+    (0x2CB4, "\u{2CB5}"),
+    (0x2CB6, "\u{2CB7}"),
+    (0x2CB8, "\u{2CB9}"),
+    (0x2CBA, "\u{2CBB}"),
+    (0x2CBC, "\u{2CBD}"),
+    (0x2CBE, "\u{2CBF}"),
+    (0x2CC0, "\u{2CC1}"),
+    (0x2CC2, "\u{2CC3}"),
+    (0x2CC4, "\u{2CC5}"),
+    (0x2CC6, "\u{2CC7}"),
+    (0x2CC8, "\u{2CC9}"),
+    (0x2CCA, "\u{2CCB}"),
+    (0x2CCC, "\u{2CCD}"),
+    (0x2CCE, "\u{2CCF}"),
+    (0x2CD0, "\u{2CD1}"),
+    (0x2CD2, "\u{2CD3}"),
+    (0x2CD4, "\u{2CD5}"),
+    (0x2CD6, "\u{2CD7}"),
+    (0x2CD8, "\u{2CD9}"),
+    (0x2CDA, "\u{2CDB}"),
+    (0x2CDC, "\u{2CDD}"),
+    (0x2CDE, "\u{2CDF}"),
+    (0x2CE0, "\u{2CE1}"),
+    (0x2CE2, "\u{2CE3}"),
+    (0x2CEB, "\u{2CEC}"),
+    (0x2CED, "\u{2CEE}"),
+    (0x2CF2, "\u{2CF3}"),
+    (0xA640, "\u{A641}"),
+    (0xA642, "\u{A643}"),
+    (0xA644, "\u{A645}"),
+    (0xA646, "\u{A647}"),
+    (0xA648, "\u{A649}"),
+    (0xA64A, "\u{A64B}"),
+    (0xA64C, "\u{A64D}"),
+    (0xA64E, "\u{A64F}"),
+    (0xA650, "\u{A651}"),
+    (0xA652, "\u{A653}"),
+    (0xA654, "\u{A655}"),
+    (0xA656, "\u{A657}"),
+    (0xA658, "\u{A659}"),
+    (0xA65A, "\u{A65B}"),
+    (0xA65C, "\u{A65D}"),
+    (0xA65E, "\u{A65F}"),
+    (0xA660, "\u{A661}"),
+    (0xA662, "\u{A663}"),
+    (0xA664, "\u{A665}"),
+    (0xA666, "\u{A667}"),
+    (0xA668, "\u{A669}"),
+    (0xA66A, "\u{A66B}"),
+    (0xA66C, "\u{A66D}"),
+    (0xA680, "\u{A681}"),
+    (0xA682, "\u{A683}"),
+    (0xA684, "\u{A685}"),
+    (0xA686, "\u{A687}"),
+    (0xA688, "\u{A689}"),
+    (0xA68A, "\u{A68B}"),
+    (0xA68C, "\u{A68D}"),
+    (0xA68E, "\u{A68F}"),
+    (0xA690, "\u{A691}"),
+    (0xA692, "\u{A693}"),
+    (0xA694, "\u{A695}"),
+    (0xA696, "\u{A697}"),
+    (0xA698, "\u{A699}"),
+    (0xA69A, "\u{A69B}"),
+    (0xA722, "\u{A723}"),
+    (0xA724, "\u{A725}"),
+    (0xA726, "\u{A727}"),
+    (0xA728, "\u{A729}"),
+    (0xA72A, "\u{A72B}"),
+    (0xA72C, "\u{A72D}"),
+    (0xA72E, "\u{A72F}"),
+    (0xA732, "\u{A733}"),
+    (0xA734, "\u{A735}"),
+    (0xA736, "\u{A737}"),
+    (0xA738, "\u{A739}"),
+    (0xA73A, "\u{A73B}"),
+    (0xA73C, "\u{A73D}"),
+    (0xA73E, "\u{A73F}"),
+    (0xA740, "\u{A741}"),
+    (0xA742, "\u{A743}"),
+    (0xA744, "\u{A745}"),
+    (0xA746, "\u{A747}"),
+    (0xA748, "\u{A749}"),
+    (0xA74A, "\u{A74B}"),
+    (0xA74C, "\u{A74D}"),
+    (0xA74E, "\u{A74F}"),
+    (0xA750, "\u{A751}"),
+    (0xA752, "\u{A753}"),
+    (0xA754, "\u{A755}"),
+    (0xA756, "\u{A757}"),
+    (0xA758, "\u{A759}"),
+    (0xA75A, "\u{A75B}"),
+    (0xA75C, "\u{A75D}"),
+    (0xA75E, "\u{A75F}"),
+    (0xA760, "\u{A761}"),
+    (0xA762, "\u{A763}"),
+    (0xA764, "\u{A765}"),
+    (0xA766, "\u{A767}"),
+    (0xA768, "\u{A769}"),
+    (0xA76A, "\u{A76B}"),
+    (0xA76C, "\u{A76D}"),
+    (0xA76E, "\u{A76F}"),
+    (0xA779, "\u{A77A}"),
+    (0xA77B, "\u{A77C}"),
+    (0xA77D, "\u{1D79}"),
+    (0xA77E, "\u{A77F}"),
+    (0xA780, "\u{A781}"),
+    (0xA782, "\u{A783}"),
+    (0xA784, "\u{A785}"),
+    (0xA786, "\u{A787}"),
+    (0xA78B, "\u{A78C}"),
+    (0xA78D, "\u{265}"),
+    (0xA790, "\u{A791}"),
+    (0xA792, "\u{A793}"),
+    (0xA796, "\u{A797}"),
+    (0xA798, "\u{A799}"),
+    (0xA79A, "\u{A79B}"),
+    (0xA79C, "\u{A79D}"),
+    (0xA79E, "\u{A79F}"),
+    (0xA7A0, "\u{A7A1}"),
+    (0xA7A2, "\u{A7A3}"),
+    (0xA7A4, "\u{A7A5}"),
+    (0xA7A6, "\u{A7A7}"),
+    (0xA7A8, "\u{A7A9}"),
+    (0xA7AA, "\u{266}"),
+    (0xA7AB, "\u{25C}"),
+    (0xA7AC, "\u{261}"),
+    (0xA7AD, "\u{26C}"),
+    (0xA7AE, "\u{26A}"),
+    (0xA7B0, "\u{29E}"),
+    (0xA7B1, "\u{287}"),
+    (0xA7B2, "\u{29D}"),
+    (0xA7B3, "\u{AB53}"),
+    (0xA7B4, "\u{A7B5}"),
+    (0xA7B6, "\u{A7B7}"),
+    (0xA7B8, "\u{A7B9}"),
+    (0xA7BA, "\u{A7BB}"),
+    (0xA7BC, "\u{A7BD}"),
+    (0xA7BE, "\u{A7BF}"),
+    (0xA7C0, "\u{A7C1}"),
+    (0xA7C2, "\u{A7C3}"),
+    (0xA7C4, "\u{A794}"),
+    (0xA7C5, "\u{282}"),
+    (0xA7C6, "\u{1D8E}"),
+    (0xA7C7, "\u{A7C8}"),
+    (0xA7C9, "\u{A7CA}"),
+    (0xA7D0, "\u{A7D1}"),
+    (0xA7D6, "\u{A7D7}"),
+    (0xA7D8, "\u{A7D9}"),
+    (0xA7F5, "\u{A7F6}"),
+    (0xAB70, "\u{13A0}"),
+    (0xAB71, "\u{13A1}"),
+    (0xAB72, "\u{13A2}"),
+    (0xAB73, "\u{13A3}"),
+    (0xAB74, "\u{13A4}"),
+    (0xAB75, "\u{13A5}"),
+    (0xAB76, "\u{13A6}"),
+    (0xAB77, "\u{13A7}"),
+    (0xAB78, "\u{13A8}"),
+    (0xAB79, "\u{13A9}"),
+    (0xAB7A, "\u{13AA}"),
+    (0xAB7B, "\u{13AB}"),
+    (0xAB7C, "\u{13AC}"),
+    (0xAB7D, "\u{13AD}"),
+    (0xAB7E, "\u{13AE}"),
+    (0xAB7F, "\u{13AF}"),
+    (0xAB80, "\u{13B0}"),
+    (0xAB81, "\u{13B1}"),
+    (0xAB82, "\u{13B2}"),
+    (0xAB83, "\u{13B3}"),
+    (0xAB84, "\u{13B4}"),
+    (0xAB85, "\u{13B5}"),
+    (0xAB86, "\u{13B6}"),
+    (0xAB87, "\u{13B7}"),
+    (0xAB88, "\u{13B8}"),
+    (0xAB89, "\u{13B9}"),
+    (0xAB8A, "\u{13BA}"),
+    (0xAB8B, "\u{13BB}"),
+    (0xAB8C, "\u{13BC}"),
+    (0xAB8D, "\u{13BD}"),
+    (0xAB8E, "\u{13BE}"),
+    (0xAB8F, "\u{13BF}"),
+    (0xAB90, "\u{13C0}"),
+    (0xAB91, "\u{13C1}"),
+    (0xAB92, "\u{13C2}"),
+    (0xAB93, "\u{13C3}"),
+    (0xAB94, "\u{13C4}"),
+    (0xAB95, "\u{13C5}"),
+    (0xAB96, "\u{13C6}"),
+    (0xAB97, "\u{13C7}"),
+    (0xAB98, "\u{13C8}"),
+    (0xAB99, "\u{13C9}"),
+    (0xAB9A, "\u{13CA}"),
+    (0xAB9B, "\u{13CB}"),
+    (0xAB9C, "\u{13CC}"),
+    (0xAB9D, "\u{13CD}"),
+    (0xAB9E, "\u{13CE}"),
+    (0xAB9F, "\u{13CF}"),
+    (0xABA0, "\u{13D0}"),
+    (0xABA1, "\u{13D1}"),
+    (0xABA2, "\u{13D2}"),
+    (0xABA3, "\u{13D3}"),
+    (0xABA4, "\u{13D4}"),
+    (0xABA5, "\u{13D5}"),
+    (0xABA6, "\u{13D6}"),
+    (0xABA7, "\u{13D7}"),
+    (0xABA8, "\u{13D8}"),
+    (0xABA9, "\u{13D9}"),
+    (0xABAA, "\u{13DA}"),
+    (0xABAB, "\u{13DB}"),
+    (0xABAC, "\u{13DC}"),
+    (0xABAD, "\u{13DD}"),
+    (0xABAE, "\u{13DE}"),
+    (0xABAF, "\u{13DF}"),
+    (0xABB0, "\u{13E0}"),
+    (0xABB1, "\u{13E1}"),
+    (0xABB2, "\u{13E2}"),
+    (0xABB3, "\u{13E3}"),
+    (0xABB4, "\u{13E4}"),
+    (0xABB5, "\u{13E5}"),
+    (0xABB6, "\u{13E6}"),
+    (0xABB7, "\u{13E7}"),
+    (0xABB8, "\u{13E8}"),
+    (0xABB9, "\u{13E9}"),
+    (0xABBA, "\u{13EA}"),
+    (0xABBB, "\u{13EB}"),
+    (0xABBC, "\u{13EC}"),
+    (0xABBD, "\u{13ED}"),
+    (0xABBE, "\u{13EE}"),
+    (0xABBF, "\u{13EF}"),
+    (0xFB00, "ff"),
+    (0xFB01, "fi"),
+    (0xFB02, "fl"),
+    (0xFB03, "ffi"),
+    (0xFB04, "ffl"),
+    (0xFB05, "st"),
+    (0xFB06, "st"),
+    (0xFB13, "\u{574}\u{576}"),
+    (0xFB14, "\u{574}\u{565}"),
+    (0xFB15, "\u{574}\u{56B}"),
+    (0xFB16, "\u{57E}\u{576}"),
+    (0xFB17, "\u{574}\u{56D}"),
+    (0xFF21, "\u{FF41}"),
+    (0xFF22, "\u{FF42}"),
+    (0xFF23, "\u{FF43}"),
+    (0xFF24, "\u{FF44}"),
+    (0xFF25, "\u{FF45}"),
+    (0xFF26, "\u{FF46}"),
+    (0xFF27, "\u{FF47}"),
+    (0xFF28, "\u{FF48}"),
+    (0xFF29, "\u{FF49}"),
+    (0xFF2A, "\u{FF4A}"),
+    (0xFF2B, "\u{FF4B}"),
+    (0xFF2C, "\u{FF4C}"),
+    (0xFF2D, "\u{FF4D}"),
+    (0xFF2E, "\u{FF4E}"),
+    (0xFF2F, "\u{FF4F}"),
+    (0xFF30, "\u{FF50}"),
+    (0xFF31, "\u{FF51}"),
+    (0xFF32, "\u{FF52}"),
+    (0xFF33, "\u{FF53}"),
+    (0xFF34, "\u{FF54}"),
+    (0xFF35, "\u{FF55}"),
+    (0xFF36, "\u{FF56}"),
+    (0xFF37, "\u{FF57}"),
+    (0xFF38, "\u{FF58}"),
+    (0xFF39, "\u{FF59}"),
+    (0xFF3A, "\u{FF5A}"),
+    (0x10400, "\u{10428}"),
+    (0x10401, "\u{10429}"),
+    (0x10402, "\u{1042A}"),
+    (0x10403, "\u{1042B}"),
+    (0x10404, "\u{1042C}"),
+    (0x10405, "\u{1042D}"),
+    (0x10406, "\u{1042E}"),
+    (0x10407, "\u{1042F}"),
+    (0x10408, "\u{10430}"),
+    (0x10409, "\u{10431}"),
+    (0x1040A, "\u{10432}"),
+    (0x1040B, "\u{10433}"),
+    (0x1040C, "\u{10434}"),
+    (0x1040D, "\u{10435}"),
+    (0x1040E, "\u{10436}"),
+    (0x1040F, "\u{10437}"),
+    (0x10410, "\u{10438}"),
+    (0x10411, "\u{10439}"),
+    (0x10412, "\u{1043A}"),
+    (0x10413, "\u{1043B}"),
+    (0x10414, "\u{1043C}"),
+    (0x10415, "\u{1043D}"),
+    (0x10416, "\u{1043E}"),
+    (0x10417, "\u{1043F}"),
+    (0x10418, "\u{10440}"),
+    (0x10419, "\u{10441}"),
+    (0x1041A, "\u{10442}"),
+    (0x1041B, "\u{10443}"),
+    (0x1041C, "\u{10444}"),
+    (0x1041D, "\u{10445}"),
+    (0x1041E, "\u{10446}"),
+    (0x1041F, "\u{10447}"),
+    (0x10420, "\u{10448}"),
+    (0x10421, "\u{10449}"),
+    (0x10422, "\u{1044A}"),
+    (0x10423, "\u{1044B}"),
+    (0x10424, "\u{1044C}"),
+    (0x10425, "\u{1044D}"),
+    (0x10426, "\u{1044E}"),
+    (0x10427, "\u{1044F}"),
+    (0x104B0, "\u{104D8}"),
+    (0x104B1, "\u{104D9}"),
+    (0x104B2, "\u{104DA}"),
+    (0x104B3, "\u{104DB}"),
+    (0x104B4, "\u{104DC}"),
+    (0x104B5, "\u{104DD}"),
+    (0x104B6, "\u{104DE}"),
+    (0x104B7, "\u{104DF}"),
+    (0x104B8, "\u{104E0}"),
+    (0x104B9, "\u{104E1}"),
+    (0x104BA, "\u{104E2}"),
+    (0x104BB, "\u{104E3}"),
+    (0x104BC, "\u{104E4}"),
+    (0x104BD, "\u{104E5}"),
+    (0x104BE, "\u{104E6}"),
+    (0x104BF, "\u{104E7}"),
+    (0x104C0, "\u{104E8}"),
+    (0x104C1, "\u{104E9}"),
+    (0x104C2, "\u{104EA}"),
+    (0x104C3, "\u{104EB}"),
+    (0x104C4, "\u{104EC}"),
+    (0x104C5, "\u{104ED}"),
+    (0x104C6, "\u{104EE}"),
+    (0x104C7, "\u{104EF}"),
+    (0x104C8, "\u{104F0}"),
+    (0x104C9, "\u{104F1}"),
+    (0x104CA, "\u{104F2}"),
+    (0x104CB, "\u{104F3}"),
+    (0x104CC, "\u{104F4}"),
+    (0x104CD, "\u{104F5}"),
+    (0x104CE, "\u{104F6}"),
+    (0x104CF, "\u{104F7}"),
+    (0x104D0, "\u{104F8}"),
+    (0x104D1, "\u{104F9}"),
+    (0x104D2, "\u{104FA}"),
+    (0x104D3, "\u{104FB}"),
+    (0x10570, "\u{10597}"),
+    (0x10571, "\u{10598}"),
+    (0x10572, "\u{10599}"),
+    (0x10573, "\u{1059A}"),
+    (0x10574, "\u{1059B}"),
+    (0x10575, "\u{1059C}"),
+    (0x10576, "\u{1059D}"),
+    (0x10577, "\u{1059E}"),
+    (0x10578, "\u{1059F}"),
+    (0x10579, "\u{105A0}"),
+    (0x1057A, "\u{105A1}"),
+    (0x1057C, "\u{105A3}"),
+    (0x1057D, "\u{105A4}"),
+    (0x1057E, "\u{105A5}"),
+    (0x1057F, "\u{105A6}"),
+    (0x10580, "\u{105A7}"),
+    (0x10581, "\u{105A8}"),
+    (0x10582, "\u{105A9}"),
+    (0x10583, "\u{105AA}"),
+    (0x10584, "\u{105AB}"),
+    (0x10585, "\u{105AC}"),
+    (0x10586, "\u{105AD}"),
+    (0x10587, "\u{105AE}"),
+    (0x10588, "\u{105AF}"),
+    (0x10589, "\u{105B0}"),
+    (0x1058A, "\u{105B1}"),
+    (0x1058C, "\u{105B3}"),
+    (0x1058D, "\u{105B4}"),
+    (0x1058E, "\u{105B5}"),
+    (0x1058F, "\u{105B6}"),
+    (0x10590, "\u{105B7}"),
+    (0x10591, "\u{105B8}"),
+    (0x10592, "\u{105B9}"),
+    (0x10594, "\u{105BB}"),
+    (0x10595, "\u{105BC}"),
+    (0x10C80, "\u{10CC0}"),
+    (0x10C81, "\u{10CC1}"),
+    (0x10C82, "\u{10CC2}"),
+    (0x10C83, "\u{10CC3}"),
+    (0x10C84, "\u{10CC4}"),
+    (0x10C85, "\u{10CC5}"),
+    (0x10C86, "\u{10CC6}"),
+    (0x10C87, "\u{10CC7}"),
+    (0x10C88, "\u{10CC8}"),
+    (0x10C89, "\u{10CC9}"),
+    (0x10C8A, "\u{10CCA}"),
+    (0x10C8B, "\u{10CCB}"),
+    (0x10C8C, "\u{10CCC}"),
+    (0x10C8D, "\u{10CCD}"),
+    (0x10C8E, "\u{10CCE}"),
+    (0x10C8F, "\u{10CCF}"),
+    (0x10C90, "\u{10CD0}"),
+    (0x10C91, "\u{10CD1}"),
+    (0x10C92, "\u{10CD2}"),
+    (0x10C93, "\u{10CD3}"),
+    (0x10C94, "\u{10CD4}"),
+    (0x10C95, "\u{10CD5}"),
+    (0x10C96, "\u{10CD6}"),
+    (0x10C97, "\u{10CD7}"),
+    (0x10C98, "\u{10CD8}"),
+    (0x10C99, "\u{10CD9}"),
+    (0x10C9A, "\u{10CDA}"),
+    (0x10C9B, "\u{10CDB}"),
+    (0x10C9C, "\u{10CDC}"),
+    (0x10C9D, "\u{10CDD}"),
+    (0x10C9E, "\u{10CDE}"),
+    (0x10C9F, "\u{10CDF}"),
+    (0x10CA0, "\u{10CE0}"),
+    (0x10CA1, "\u{10CE1}"),
+    (0x10CA2, "\u{10CE2}"),
+    (0x10CA3, "\u{10CE3}"),
+    (0x10CA4, "\u{10CE4}"),
+    (0x10CA5, "\u{10CE5}"),
+    (0x10CA6, "\u{10CE6}"),
+    (0x10CA7, "\u{10CE7}"),
+    (0x10CA8, "\u{10CE8}"),
+    (0x10CA9, "\u{10CE9}"),
+    (0x10CAA, "\u{10CEA}"),
+    (0x10CAB, "\u{10CEB}"),
+    (0x10CAC, "\u{10CEC}"),
+    (0x10CAD, "\u{10CED}"),
+    (0x10CAE, "\u{10CEE}"),
+    (0x10CAF, "\u{10CEF}"),
+    (0x10CB0, "\u{10CF0}"),
+    (0x10CB1, "\u{10CF1}"),
+    (0x10CB2, "\u{10CF2}"),
+    (0x118A0, "\u{118C0}"),
+    (0x118A1, "\u{118C1}"),
+    (0x118A2, "\u{118C2}"),
+    (0x118A3, "\u{118C3}"),
+    (0x118A4, "\u{118C4}"),
+    (0x118A5, "\u{118C5}"),
+    (0x118A6, "\u{118C6}"),
+    (0x118A7, "\u{118C7}"),
+    (0x118A8, "\u{118C8}"),
+    (0x118A9, "\u{118C9}"),
+    (0x118AA, "\u{118CA}"),
+    (0x118AB, "\u{118CB}"),
+    (0x118AC, "\u{118CC}"),
+    (0x118AD, "\u{118CD}"),
+    (0x118AE, "\u{118CE}"),
+    (0x118AF, "\u{118CF}"),
+    (0x118B0, "\u{118D0}"),
+    (0x118B1, "\u{118D1}"),
+    (0x118B2, "\u{118D2}"),
+    (0x118B3, "\u{118D3}"),
+    (0x118B4, "\u{118D4}"),
+    (0x118B5, "\u{118D5}"),
+    (0x118B6, "\u{118D6}"),
+    (0x118B7, "\u{118D7}"),
+    (0x118B8, "\u{118D8}"),
+    (0x118B9, "\u{118D9}"),
+    (0x118BA, "\u{118DA}"),
+    (0x118BB, "\u{118DB}"),
+    (0x118BC, "\u{118DC}"),
+    (0x118BD, "\u{118DD}"),
+    (0x118BE, "\u{118DE}"),
+    (0x118BF, "\u{118DF}"),
+    (0x16E40, "\u{16E60}"),
+    (0x16E41, "\u{16E61}"),
+    (0x16E42, "\u{16E62}"),
+    (0x16E43, "\u{16E63}"),
+    (0x16E44, "\u{16E64}"),
+    (0x16E45, "\u{16E65}"),
+    (0x16E46, "\u{16E66}"),
+    (0x16E47, "\u{16E67}"),
+    (0x16E48, "\u{16E68}"),
+    (0x16E49, "\u{16E69}"),
+    (0x16E4A, "\u{16E6A}"),
+    (0x16E4B, "\u{16E6B}"),
+    (0x16E4C, "\u{16E6C}"),
+    (0x16E4D, "\u{16E6D}"),
+    (0x16E4E, "\u{16E6E}"),
+    (0x16E4F, "\u{16E6F}"),
+    (0x16E50, "\u{16E70}"),
+    (0x16E51, "\u{16E71}"),
+    (0x16E52, "\u{16E72}"),
+    (0x16E53, "\u{16E73}"),
+    (0x16E54, "\u{16E74}"),
+    (0x16E55, "\u{16E75}"),
+    (0x16E56, "\u{16E76}"),
+    (0x16E57, "\u{16E77}"),
+    (0x16E58, "\u{16E78}"),
+    (0x16E59, "\u{16E79}"),
+    (0x16E5A, "\u{16E7A}"),
+    (0x16E5B, "\u{16E7B}"),
+    (0x16E5C, "\u{16E7C}"),
+    (0x16E5D, "\u{16E7D}"),
+    (0x16E5E, "\u{16E7E}"),
+    (0x16E5F, "\u{16E7F}"),
+    (0x1E900, "\u{1E922}"),
+    (0x1E901, "\u{1E923}"),
+    (0x1E902, "\u{1E924}"),
+    (0x1E903, "\u{1E925}"),
+    (0x1E904, "\u{1E926}"),
+    (0x1E905, "\u{1E927}"),
+    (0x1E906, "\u{1E928}"),
+    (0x1E907, "\u{1E929}"),
+    (0x1E908, "\u{1E92A}"),
+    (0x1E909, "\u{1E92B}"),
+    (0x1E90A, "\u{1E92C}"),
+    (0x1E90B, "\u{1E92D}"),
+    (0x1E90C, "\u{1E92E}"),
+    (0x1E90D, "\u{1E92F}"),
+    (0x1E90E, "\u{1E930}"),
+    (0x1E90F, "\u{1E931}"),
+    (0x1E910, "\u{1E932}"),
+    (0x1E911, "\u{1E933}"),
+    (0x1E912, "\u{1E934}"),
+    (0x1E913, "\u{1E935}"),
+    (0x1E914, "\u{1E936}"),
+    (0x1E915, "\u{1E937}"),
+    (0x1E916, "\u{1E938}"),
+    (0x1E917, "\u{1E939}"),
+    (0x1E918, "\u{1E93A}"),
+    (0x1E919, "\u{1E93B}"),
+    (0x1E91A, "\u{1E93C}"),
+    (0x1E91B, "\u{1E93D}"),
+    (0x1E91C, "\u{1E93E}"),
+    (0x1E91D, "\u{1E93F}"),
+    (0x1E91E, "\u{1E940}"),
+    (0x1E91F, "\u{1E941}"),
+    (0x1E920, "\u{1E942}"),
+    (0x1E921, "\u{1E943}"),
+];
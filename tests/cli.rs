@@ -0,0 +1,406 @@
+//! Runs the command line tool on small inputs, checking what it prints and its exit
+//! status.
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+use std::thread;
+
+/// Runs the tool with `args`, feeding it `input` on stdin.
+fn run(args: &[&str], input: &[u8]) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_grep-starter-rust"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("The tool starts");
+    let mut stdin = child.stdin.take().expect("Stdin is piped");
+    let input = input.to_vec();
+    // The tool may exit before reading everything, which is fine
+    let writer = thread::spawn(move || stdin.write_all(&input).ok());
+    let output = child.wait_with_output().expect("The tool runs");
+    writer.join().expect("Writing the input doesn't panic");
+    output
+}
+
+fn stdout(output: &Output) -> &str {
+    std::str::from_utf8(&output.stdout).expect("Output is UTF-8")
+}
+
+fn stderr(output: &Output) -> &str {
+    std::str::from_utf8(&output.stderr).expect("Errors are UTF-8")
+}
+
+#[test]
+fn max_line_length() {
+    let output = run(&["-E", "b", "--max-line-length=4"], b"ab\naaaaab\nb\n");
+    assert_eq!(stdout(&output), "ab\nb\n");
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stderr(&output), "Skipping line 2: longer than 4 bytes\n");
+
+    // Windows keep the offsets and number of the whole line
+    let args = [
+        "-b",
+        "-E",
+        "b",
+        "--max-line-length=4",
+        "--long-lines=window",
+    ];
+    let output = run(&args, b"aaaaab\n");
+    assert_eq!(stdout(&output), "4:ab\n");
+    // A line matching in several windows counts once
+    let args = [
+        "-c",
+        "-E",
+        "a",
+        "--max-line-length=4",
+        "--long-lines=window",
+    ];
+    let output = run(&args, b"aaaaaaaaaa\nb\n");
+    assert_eq!(stdout(&output), "1\n");
+    let output = run(
+        &[
+            "-v",
+            "-E",
+            "b",
+            "--max-line-length=4",
+            "--long-lines=window",
+        ],
+        b"aaaaab\nc\n",
+    );
+    assert!(
+        stderr(&output).contains("Searched 2 lines"),
+        "{}",
+        stderr(&output)
+    );
+    assert!(stderr(&output).contains(", 1 matched"));
+
+    let args = [
+        "-o",
+        "-E",
+        "é+",
+        "--max-line-length=4",
+        "--long-lines=window",
+    ];
+    let output = run(&args, "aéééé\n".as_bytes());
+    assert_eq!(stdout(&output), "é\néé\né\n");
+
+    for length in ["--max-line-length=0", "--max-line-length=1"] {
+        let output = run(
+            &["-E", "é", length, "--long-lines=window"],
+            "éé\n".as_bytes(),
+        );
+        assert_eq!(output.status.code(), Some(1), "{length}");
+        assert!(output.stdout.is_empty());
+    }
+}
+
+#[test]
+fn only_matching_spans() {
+    let output = run(&["-o", "-b", "-E", "c+"], b"abc\nccx\n");
+    assert_eq!(stdout(&output), "2:1:c\n4:2:cc\n");
+    // Spans count the BOM, like the offsets of whole lines
+    let output = run(&["-o", "-b", "-E", "b"], b"\xef\xbb\xbfab\nab\n");
+    assert_eq!(stdout(&output), "4:1:b\n7:1:b\n");
+
+    let output = run(
+        &["-o", "-b", "-E", "c", "--invalid-utf8=lossy"],
+        b"ab\xffc\n",
+    );
+    assert_eq!(output.status.code(), Some(1));
+    assert!(output.stdout.is_empty());
+    let output = run(&["-o", "-E", "c", "--invalid-utf8=lossy"], b"ab\xffc\n");
+    assert_eq!(stdout(&output), "c\n");
+}
+
+#[test]
+fn only_matching_skips_empty_matches() {
+    let output = run(&["-o", "-E", "x*"], b"abc\naxxb\n");
+    assert_eq!(stdout(&output), "xx\n");
+    assert_eq!(output.status.code(), Some(0));
+    let output = run(&["-o", "-b", "-E", "x*"], b"abc\n");
+    assert!(output.stdout.is_empty());
+}
+
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+#[test]
+fn interrupt() {
+    use std::io::{BufRead, BufReader, Read};
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_grep-starter-rust"))
+        .args(["-E", "a"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("The tool starts");
+    let mut stdin = child.stdin.take().expect("Stdin is piped");
+    stdin.write_all(b"a\nb\n").expect("Writing to stdin");
+    // Once the first line is out, the handler is in place
+    let mut stdout = BufReader::new(child.stdout.take().expect("Stdout is piped"));
+    let mut line = String::new();
+    stdout.read_line(&mut line).expect("Reading stdout");
+    assert_eq!(line, "a\n");
+
+    let status = Command::new("kill")
+        .args(["-INT", &child.id().to_string()])
+        .status()
+        .expect("kill runs");
+    assert!(status.success());
+    // The interrupted read of stdin carries on, until the input ends
+    stdin.write_all(b"a\n").ok();
+    drop(stdin);
+    let mut rest = String::new();
+    stdout.read_to_string(&mut rest).expect("Reading stdout");
+    assert_eq!(rest, "");
+    let output = child.wait_with_output().expect("The tool runs");
+    assert_eq!(output.status.code(), Some(130));
+    let stderr = String::from_utf8(output.stderr).expect("Errors are UTF-8");
+    assert!(stderr.contains("Interrupted"), "{stderr}");
+}
+
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+#[test]
+fn interrupt_statistics() {
+    use std::io::{BufRead, BufReader};
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_grep-starter-rust"))
+        .args(["-E", "a", "-e", "b", "--pattern-stats"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("The tool starts");
+    let mut stdin = child.stdin.take().expect("Stdin is piped");
+    stdin.write_all(b"a\n").expect("Writing to stdin");
+    let mut stdout = BufReader::new(child.stdout.take().expect("Stdout is piped"));
+    let mut line = String::new();
+    stdout.read_line(&mut line).expect("Reading stdout");
+    assert_eq!(line, "a\n");
+
+    let status = Command::new("kill")
+        .args(["-INT", &child.id().to_string()])
+        .status()
+        .expect("kill runs");
+    assert!(status.success());
+    drop(stdin);
+    let output = child.wait_with_output().expect("The tool runs");
+    assert_eq!(output.status.code(), Some(130));
+    let stderr = String::from_utf8(output.stderr).expect("Errors are UTF-8");
+    assert!(
+        stderr.contains("pattern 1: matched 1 of 1 lines"),
+        "{stderr}"
+    );
+    assert!(
+        stderr.contains("pattern 2: matched 0 of 1 lines"),
+        "{stderr}"
+    );
+    assert!(
+        stderr.ends_with("Interrupted, searched 1 lines\n"),
+        "{stderr}"
+    );
+}
+
+#[test]
+fn normalize() {
+    let input = "café\ncafe\u{301}\ncafe\n";
+    let output = run(&["-E", "e\u{301}$"], input.as_bytes());
+    assert_eq!(stdout(&output), "cafe\u{301}\n");
+
+    let output = run(&["--normalize", "-E", "e\u{301}$"], input.as_bytes());
+    assert_eq!(stdout(&output), "café\ncafé\n");
+    let output = run(&["--normalize", "-o", "-E", "[e\u{301}]"], input.as_bytes());
+    assert_eq!(stdout(&output), "é\né\n");
+}
+
+#[test]
+fn case_folding() {
+    let input = "STRASSE\nStraße\nILIK\nılık\n";
+    let output = run(&["-i", "-E", "strasse"], input.as_bytes());
+    assert_eq!(stdout(&output), "STRASSE\n");
+    let output = run(&["--case-fold=full", "-E", "strasse"], input.as_bytes());
+    assert_eq!(stdout(&output), "STRASSE\nStraße\n");
+    let output = run(&["--case-fold=full", "-E", "ılık"], input.as_bytes());
+    assert_eq!(stdout(&output), "ılık\n");
+    let output = run(&["--case-fold=turkic", "-E", "ılık"], input.as_bytes());
+    assert_eq!(stdout(&output), "ILIK\nılık\n");
+
+    // Only ASCII letters fold, and `\w` stays ASCII
+    let input = "CAFÉ\nCAFé\nÉTÉ\n";
+    let output = run(&["--case-fold=ascii", "-E", "café"], input.as_bytes());
+    assert_eq!(stdout(&output), "CAFé\n");
+    let output = run(&["--case-fold=ascii", "-E", r"^\w+$"], input.as_bytes());
+    assert!(output.stdout.is_empty());
+}
+
+#[test]
+fn max_output() {
+    let output = run(&["-E", "a", "--max-output-lines=2"], b"a1\na2\na3\n");
+    assert_eq!(stdout(&output), "a1\na2\n");
+    assert_eq!(output.status.code(), Some(3));
+    assert!(stderr(&output).contains("Output truncated"));
+
+    // Output that just fits isn't truncated
+    let output = run(&["-E", "a", "--max-output-lines=2"], b"a1\na2\nb\n");
+    assert_eq!(output.status.code(), Some(0));
+
+    let output = run(&["-E", "a", "--max-output-bytes=6"], b"abc\nabd\n");
+    assert_eq!(stdout(&output), "abc\nab");
+    assert_eq!(output.status.code(), Some(3));
+    let output = run(&["-E", "a", "--max-output-bytes=8"], b"abc\nabd\n");
+    assert_eq!(output.status.code(), Some(0));
+}
+
+#[test]
+fn sample() {
+    let input = b"a1\na2\nb\na3\n";
+    let output = run(&["-E", "a", "--sample=2"], input);
+    assert_eq!(stdout(&output), "a1\na2\n");
+    assert_eq!(
+        stderr(&output),
+        "Sampled 2 of 4 lines, 2 matched: about 4 would in the whole input\n"
+    );
+
+    // The sample is random, but fixed by the seed
+    let args = ["-E", "a", "--sample=2", "--sample-seed=42"];
+    let output = run(&args, input);
+    assert_eq!(stdout(&output), "a1\n");
+    assert_eq!(
+        stderr(&output),
+        "Sampled 2 of 4 lines, 1 matched: about 2 would in the whole input\n"
+    );
+}
+
+#[test]
+fn invalid_utf8() {
+    let input = b"a\xff\nb\nab\n";
+    let output = run(&["-E", "a"], input);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(output.stdout.is_empty());
+    assert!(stderr(&output).contains("Line 1 is not valid UTF-8"));
+
+    let output = run(&["-E", "a", "--invalid-utf8=lossy"], input);
+    assert_eq!(stdout(&output), "a\u{FFFD}\nab\n");
+    let output = run(&["-E", "a", "--invalid-utf8=skip"], input);
+    assert_eq!(stdout(&output), "ab\n");
+    assert_eq!(stderr(&output), "Skipping line 1: not valid UTF-8\n");
+    // The rest of a long line is skipped with the window, with a single warning
+    let args = [
+        "-E",
+        "a",
+        "--invalid-utf8=skip",
+        "--max-line-length=4",
+        "--long-lines=window",
+    ];
+    let output = run(&args, b"ab\xffaaa\xffaaaa\nab\n");
+    assert_eq!(stdout(&output), "ab\n");
+    assert_eq!(stderr(&output), "Skipping line 1: not valid UTF-8\n");
+    let output = run(&["-E", "a", "--invalid-utf8=binary"], input);
+    assert_eq!(stdout(&output), "Binary input matches\n");
+    assert_eq!(output.status.code(), Some(0));
+}
+
+#[test]
+fn line_terminator() {
+    let output = run(&["-E", "a", "--line-terminator=;"], b"a\nb;c;ba");
+    assert_eq!(stdout(&output), "a\nb;ba;");
+    let output = run(&["-E", "^b", "--line-terminator=\\0"], b"a\0b\0");
+    assert_eq!(output.stdout, b"b\0");
+    // A `\r` before a `\n` terminator isn't part of the line
+    let output = run(&["-E", "a$"], b"a\r\nb\n");
+    assert_eq!(stdout(&output), "a\n");
+}
+
+#[test]
+fn dedupe() {
+    let input = b"a1\na1\nb\na2\na1\n";
+    let output = run(&["-E", "a", "--dedupe"], input);
+    assert_eq!(stdout(&output), "a1\na2\n");
+    let output = run(&["-E", "a", "--dedupe=consecutive"], input);
+    assert_eq!(stdout(&output), "a1\na2\na1\n");
+    // Repeated lines still count as matching
+    let output = run(&["-E", "a", "--dedupe", "--count"], input);
+    assert_eq!(stdout(&output), "4\n");
+}
+
+#[test]
+fn rewrite() {
+    let input = b"mail bob@host and al@box\nnone\r\n";
+    let output = run(&["-E", r"(\w+)@(\w+)", "--rewrite=$2 at $1"], input);
+    assert_eq!(stdout(&output), "mail host at bob and box at al\nnone\r\n");
+    assert_eq!(output.status.code(), Some(0));
+    let output = run(&["-E", "z", "--rewrite=y"], input);
+    assert_eq!(output.stdout, input);
+    assert_eq!(output.status.code(), Some(1));
+
+    // Windows of a long line are rewritten in turn, as one line
+    let args = [
+        "-E",
+        "b",
+        "--rewrite=X",
+        "--max-line-length=4",
+        "--long-lines=window",
+    ];
+    let output = run(&args, b"abababab\nxb\n");
+    assert_eq!(stdout(&output), "aXaXaXaX\nxX\n");
+
+    let output = run(&["-E", "a", "--rewrite=$3"], b"a\n");
+    assert!(output.stdout.is_empty());
+    assert!(stderr(&output).contains("Invalid template for pattern 1"));
+}
+
+#[test]
+fn broken_pipe() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_grep-starter-rust"))
+        .args(["-E", "a"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("The tool starts");
+    // The reader goes away before the first line is printed
+    drop(child.stdout.take());
+    let mut stdin = child.stdin.take().expect("Stdin is piped");
+    let writer = thread::spawn(move || stdin.write_all(&b"a\n".repeat(100_000)).ok());
+    let output = child.wait_with_output().expect("The tool runs");
+    writer.join().expect("Writing the input doesn't panic");
+    assert_eq!(output.status.code(), Some(141));
+    assert_eq!(stderr(&output), "");
+}
+
+#[test]
+fn byte_order_marks() {
+    let output = run(&["-E", "^a"], b"\xef\xbb\xbfab\nab\n");
+    assert_eq!(stdout(&output), "ab\nab\n");
+    // Offsets are into the input, BOM included
+    let output = run(&["-b", "-E", "b"], b"\xef\xbb\xbfab\nab\n");
+    assert_eq!(stdout(&output), "3:ab\n6:ab\n");
+
+    let output = run(&["-E", "^b"], b"\xff\xfea\0\n\0b\0\n\0");
+    assert_eq!(stdout(&output), "b\n");
+    let output = run(&["-b", "-E", "b"], b"\xff\xfea\0\n\0b\0\n\0");
+    assert!(output.stdout.is_empty());
+    assert!(stderr(&output).contains("UTF-16"));
+}
+
+#[test]
+fn json_errors() {
+    let output = run(&["--json", "-E", "("], b"ab");
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        stderr(&output),
+        concat!(
+            r#"{"error": "syntax", "message": "Unterminated group", "position": 0, "#,
+            r#""hint": "close it with `)` or escape the `(` as `\\(`"}"#,
+            "\n"
+        )
+    );
+
+    let output = run(&["--json", "-f", "/nonexistent/patterns"], b"ab");
+    assert!(stderr(&output).starts_with(r#"{"error": "io", "message": "Failed to read"#));
+    let output = run(&["--json", "-E", "a", "--bogus"], b"ab");
+    assert_eq!(
+        stderr(&output),
+        "{\"error\": \"other\", \"message\": \"Unknown argument `--bogus`\"}\n"
+    );
+}
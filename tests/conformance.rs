@@ -0,0 +1,104 @@
+//! Runs the pattern/input/expectation cases listed in `tests/fixtures/*.tsv`.
+//!
+//! Every non-empty line not starting with `#` is one case with tab-separated fields:
+//!
+//! ```text
+//! pattern <TAB> input <TAB> match|no match [<TAB> expected matched text]
+//! ```
+//!
+//! Fields are taken verbatim, so patterns and inputs can't contain tabs.
+
+use std::fs;
+use std::path::Path;
+
+use grep_starter_rust::Pattern;
+
+struct Case {
+    location: String,
+    pattern: String,
+    input: String,
+    matches: bool,
+    matched_text: Option<String>,
+}
+
+fn load_cases(path: &Path) -> Vec<Case> {
+    let content = fs::read_to_string(path).expect("Fixture file is readable");
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(index, line)| {
+            let location = format!("{}:{}", path.display(), index + 1);
+            let fields: Vec<_> = line.split('\t').collect();
+            let (pattern, input, expected, matched_text) = match fields[..] {
+                [pattern, input, expected] => (pattern, input, expected, None),
+                [pattern, input, expected, text] => (pattern, input, expected, Some(text)),
+                _ => panic!("{location}: expected 3 or 4 tab-separated fields"),
+            };
+            let matches = match expected {
+                "match" => true,
+                "no match" => false,
+                other => panic!("{location}: unknown expectation `{other}`"),
+            };
+
+            Case {
+                location,
+                pattern: pattern.to_string(),
+                input: input.to_string(),
+                matches,
+                matched_text: matched_text.map(String::from),
+            }
+        })
+        .collect()
+}
+
+fn check(case: &Case) -> Result<(), String> {
+    let pattern = Pattern::new(&case.pattern)
+        .map_err(|e| format!("pattern {:?} failed to parse: {e}", case.pattern))?;
+    let found = pattern.find(&case.input);
+    if found.is_some() != case.matches {
+        return Err(format!(
+            "pattern {:?} on {:?}: expected {}",
+            case.pattern,
+            case.input,
+            if case.matches { "a match" } else { "no match" }
+        ));
+    }
+
+    match (&case.matched_text, found) {
+        (Some(expected), Some(found)) if found.as_str() != expected => Err(format!(
+            "pattern {:?} on {:?}: matched {:?}, expected {:?}",
+            case.pattern,
+            case.input,
+            found.as_str(),
+            expected
+        )),
+        _ => Ok(()),
+    }
+}
+
+#[test]
+fn conformance() {
+    let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+    let mut paths: Vec<_> = fs::read_dir(fixtures)
+        .expect("Fixture directory exists")
+        .map(|entry| entry.expect("Fixture directory is readable").path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "tsv"))
+        .collect();
+    paths.sort();
+
+    let cases: Vec<_> = paths.iter().flat_map(|path| load_cases(path)).collect();
+    assert!(!cases.is_empty(), "No conformance cases found");
+
+    let failures: Vec<_> = cases
+        .iter()
+        .filter_map(|case| check(case).err().map(|e| format!("{}: {e}", case.location)))
+        .collect();
+    assert!(
+        failures.is_empty(),
+        "{} of {} cases failed:\n{}",
+        failures.len(),
+        cases.len(),
+        failures.join("\n")
+    );
+}